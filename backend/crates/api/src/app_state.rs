@@ -1,29 +1,57 @@
 cfg_if::cfg_if! {
     if #[cfg(feature = "native")] {
         pub type DatabaseImpl = storage_sqlite::SqliteDb;
+        pub const DATABASE_BACKEND: &str = "sqlite";
 
         pub type BlockRepositoryImpl = storage_sqlite::repositories::SqliteBlockRepository;
         pub type BlockDirectionalLinkRepositoryImpl = storage_sqlite::repositories::SqliteBlockDirectionalLinkRepository;
         pub type BlockRelatedLinkRepositoryImpl = storage_sqlite::repositories::SqliteBlockRelatedLinkRepository;
+        pub type BlockPinRepositoryImpl = storage_sqlite::repositories::SqliteBlockPinRepository;
         pub type WorkspaceRepositoryImpl = storage_sqlite::repositories::SqliteWorkspaceRepository;
+        pub type CanvasRepositoryImpl = storage_sqlite::repositories::SqliteCanvasRepository;
+        pub type CanvasBlockRepositoryImpl = storage_sqlite::repositories::SqliteCanvasBlockRepository;
+        pub type CanvasPinRepositoryImpl = storage_sqlite::repositories::SqliteCanvasPinRepository;
+        pub type TagRepositoryImpl = storage_sqlite::repositories::SqliteTagRepository;
+        pub type SnapshotRepositoryImpl = storage_sqlite::repositories::SqliteSnapshotRepository;
 
         pub type BlockQueryServiceImpl = storage_sqlite::query_services::SqliteBlockQueryService;
         pub type BlockLinkQueryServiceImpl = storage_sqlite::query_services::SqliteBlockLinkQueryService;
+        pub type BlockDagQueryServiceImpl = storage_sqlite::query_services::SqliteBlockDagQueryService;
+        pub type BlockRevisionQueryServiceImpl = storage_sqlite::query_services::SqliteBlockRevisionQueryService;
+
+        pub type BlockDirectionalPathHelperImpl = storage_sqlite::helpers::SqliteBlockDirectionalPathHelper;
     } else if #[cfg(feature = "cloud")] {
         pub type DatabaseImpl = storage_postgres::PostgresDb;
+        pub const DATABASE_BACKEND: &str = "postgres";
 
         pub type BlockRepositoryImpl = storage_postgres::repositories::PostgresBlockRepository;
         pub type BlockDirectionalLinkRepositoryImpl =
             storage_postgres::repositories::PostgresBlockDirectionalLinkRepository;
         pub type BlockRelatedLinkRepositoryImpl =
             storage_postgres::repositories::PostgresBlockRelatedLinkRepository;
+        pub type BlockPinRepositoryImpl = storage_postgres::repositories::PostgresBlockPinRepository;
         pub type WorkspaceRepositoryImpl =
             storage_postgres::repositories::PostgresWorkspaceRepository;
+        pub type CanvasRepositoryImpl = storage_postgres::repositories::PostgresCanvasRepository;
+        pub type CanvasBlockRepositoryImpl =
+            storage_postgres::repositories::PostgresCanvasBlockRepository;
+        pub type CanvasPinRepositoryImpl =
+            storage_postgres::repositories::PostgresCanvasPinRepository;
+        pub type TagRepositoryImpl = storage_postgres::repositories::PostgresTagRepository;
+        pub type SnapshotRepositoryImpl =
+            storage_postgres::repositories::PostgresSnapshotRepository;
 
         pub type BlockQueryServiceImpl =
             storage_postgres::query_services::PostgresBlockQueryService;
         pub type BlockLinkQueryServiceImpl =
             storage_postgres::query_services::PostgresBlockLinkQueryService;
+        pub type BlockDagQueryServiceImpl =
+            storage_postgres::query_services::PostgresBlockDagQueryService;
+        pub type BlockRevisionQueryServiceImpl =
+            storage_postgres::query_services::PostgresBlockRevisionQueryService;
+
+        pub type BlockDirectionalPathHelperImpl =
+            storage_postgres::helpers::PostgresBlockDirectionalPathHelper;
     }
 }
 
@@ -32,7 +60,13 @@ pub struct Repositories {
     pub blocks: BlockRepositoryImpl,
     pub block_directional_links: BlockDirectionalLinkRepositoryImpl,
     pub block_related_links: BlockRelatedLinkRepositoryImpl,
+    pub block_pins: BlockPinRepositoryImpl,
     pub workspaces: WorkspaceRepositoryImpl,
+    pub canvases: CanvasRepositoryImpl,
+    pub canvas_blocks: CanvasBlockRepositoryImpl,
+    pub canvas_pins: CanvasPinRepositoryImpl,
+    pub tags: TagRepositoryImpl,
+    pub snapshots: SnapshotRepositoryImpl,
 }
 
 impl Repositories {
@@ -45,6 +79,8 @@ impl Repositories {
 pub struct QueryServices {
     pub blocks: BlockQueryServiceImpl,
     pub block_links: BlockLinkQueryServiceImpl,
+    pub block_dag: BlockDagQueryServiceImpl,
+    pub block_revisions: BlockRevisionQueryServiceImpl,
 }
 
 impl QueryServices {
@@ -53,22 +89,36 @@ impl QueryServices {
     }
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct Helpers {
+    pub block_directional_paths: BlockDirectionalPathHelperImpl,
+}
+
+impl Helpers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct AppState {
     pub db: DatabaseImpl,
     pub repos: Repositories,
     pub query_services: QueryServices,
+    pub helpers: Helpers,
 }
 
 impl AppState {
     pub fn new(db: DatabaseImpl) -> Self {
         let repos = Repositories::new();
         let query_services = QueryServices::new();
+        let helpers = Helpers::new();
 
         Self {
             db,
             repos,
             query_services,
+            helpers,
         }
     }
 }
@@ -0,0 +1,100 @@
+use std::process::ExitCode;
+
+use clap::Args;
+
+use super::error::{CliError, CliResult as Result};
+use storage::Database;
+use storage::repositories::snapshot_repository::copy_instance;
+use storage_postgres::PostgresDb;
+use storage_postgres::helpers::PostgresBlockDirectionalPathHelper;
+use storage_postgres::repositories::PostgresSnapshotRepository;
+use storage_sqlite::SqliteDb;
+use storage_sqlite::helpers::SqliteBlockDirectionalPathHelper;
+use storage_sqlite::repositories::SqliteSnapshotRepository;
+
+#[derive(Args, Debug)]
+pub(super) struct CopyArgs {
+    /// Database to copy from (`sqlite:` or `postgres:` URL)
+    #[arg(long)]
+    from: String,
+
+    /// Empty database to copy into (`sqlite:` or `postgres:` URL)
+    #[arg(long)]
+    to: String,
+}
+
+enum Backend {
+    Sqlite(SqliteDb),
+    Postgres(PostgresDb),
+}
+
+/// Copies every row from one instance into another, whichever backends they
+/// use. Both databases are migrated first; the target must be empty.
+pub(super) async fn run(args: CopyArgs) -> Result<ExitCode> {
+    let source = open(&args.from).await?;
+    let target = open(&args.to).await?;
+
+    let counts = match (&source, &target) {
+        (Backend::Sqlite(source), Backend::Sqlite(target)) => {
+            copy_instance(
+                &SqliteSnapshotRepository::new(),
+                source.pool(),
+                &SqliteSnapshotRepository::new(),
+                &SqliteBlockDirectionalPathHelper::new(),
+                target.pool(),
+            )
+            .await?
+        }
+        (Backend::Sqlite(source), Backend::Postgres(target)) => {
+            copy_instance(
+                &SqliteSnapshotRepository::new(),
+                source.pool(),
+                &PostgresSnapshotRepository::new(),
+                &PostgresBlockDirectionalPathHelper::new(),
+                target.pool(),
+            )
+            .await?
+        }
+        (Backend::Postgres(source), Backend::Sqlite(target)) => {
+            copy_instance(
+                &PostgresSnapshotRepository::new(),
+                source.pool(),
+                &SqliteSnapshotRepository::new(),
+                &SqliteBlockDirectionalPathHelper::new(),
+                target.pool(),
+            )
+            .await?
+        }
+        (Backend::Postgres(source), Backend::Postgres(target)) => {
+            copy_instance(
+                &PostgresSnapshotRepository::new(),
+                source.pool(),
+                &PostgresSnapshotRepository::new(),
+                &PostgresBlockDirectionalPathHelper::new(),
+                target.pool(),
+            )
+            .await?
+        }
+    };
+
+    for (table, count) in counts.tables() {
+        println!("{table:<24} {count}");
+    }
+    println!("Row counts match; ancestor/descendant table rebuilt");
+
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn open(database_url: &str) -> Result<Backend> {
+    if database_url.starts_with("sqlite:") {
+        let db = SqliteDb::connect(database_url).await?;
+        db.run_migration().await?;
+        Ok(Backend::Sqlite(db))
+    } else if database_url.starts_with("postgres:") || database_url.starts_with("postgresql:") {
+        let db = PostgresDb::connect(database_url).await?;
+        db.run_migration().await?;
+        Ok(Backend::Postgres(db))
+    } else {
+        Err(CliError::UnsupportedDatabaseUrl)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::features::export::ExportError;
+use crate::features::export_graph::ExportGraphError;
+use crate::features::export_markdown::ExportMarkdownError;
+use crate::features::import::ImportError;
+use crate::features::import_markdown::ImportMarkdownError;
+use domain::tags::TagError;
+use storage::database::DatabaseError;
+use storage::helpers::block_directional_path_helper::BlockDirectionalPathHelperError;
+use storage::query_services::block_query_service::BlockQueryServiceError;
+#[cfg(all(feature = "native", feature = "cloud"))]
+use storage::repositories::snapshot_repository::CopyInstanceError;
+use storage::repositories::snapshot_repository::SnapshotRepositoryError;
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum CliError {
+    #[error("DATABASE_URL is not set and --database-url was not given")]
+    MissingDatabaseUrl,
+
+    #[error("{path}: {source}")]
+    File {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid tag: {0}")]
+    Tag(#[from] TagError),
+
+    #[error("--root does not apply to the markdown format")]
+    RootWithMarkdown,
+
+    #[cfg(not(all(feature = "native", feature = "cloud")))]
+    #[error(
+        "copy is not available in this build; it needs both backends, \
+         so rebuild with `--features native` on top of the default features"
+    )]
+    CopyUnavailable,
+
+    #[cfg(all(feature = "native", feature = "cloud"))]
+    #[error("Unsupported database URL - expected a sqlite: or postgres: URL")]
+    UnsupportedDatabaseUrl,
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Database(#[from] DatabaseError),
+
+    #[error(transparent)]
+    Sqlx(#[from] sqlx::Error),
+
+    #[error("Export failed: {0}")]
+    Export(#[from] ExportError),
+
+    #[error("Export failed: {0}")]
+    ExportGraph(#[from] ExportGraphError),
+
+    #[error("Export failed: {0}")]
+    ExportMarkdown(#[from] ExportMarkdownError),
+
+    #[error("Import failed: {0}")]
+    Import(#[from] ImportError),
+
+    #[error("Import failed: {0}")]
+    ImportMarkdown(#[from] ImportMarkdownError),
+
+    #[cfg(all(feature = "native", feature = "cloud"))]
+    #[error("Copy failed: {0}")]
+    Copy(#[from] CopyInstanceError),
+
+    #[error(transparent)]
+    PathHelper(#[from] BlockDirectionalPathHelperError),
+
+    #[error(transparent)]
+    BlockQueryService(#[from] BlockQueryServiceError),
+
+    #[error(transparent)]
+    SnapshotRepository(#[from] SnapshotRepositoryError),
+}
+
+pub(crate) type CliResult<T> = Result<T, CliError>;
@@ -0,0 +1,94 @@
+use std::fs::File;
+use std::io::{Cursor, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{ArgAction, Args, ValueEnum};
+use uuid::Uuid;
+
+use super::error::{CliError, CliResult as Result};
+use crate::AppState;
+use crate::features::export::{ExportQuery, build_archive};
+use crate::features::export_graph::{GraphFormat, build_graph};
+use crate::features::export_markdown::build_vault;
+
+#[derive(Args, Debug)]
+pub(super) struct ExportArgs {
+    /// Where to write the export; `-` writes to stdout. Defaults to
+    /// `modunote-export.zip`, `modunote-markdown.zip` or `modunote.<format>`
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// `archive` is what `import` reads back; `markdown` is one `.md` file
+    /// per block; `graphml`, `gexf` and `dot` are graphs of blocks and links
+    #[arg(long, value_enum, default_value_t = ExportFormat::Archive)]
+    format: ExportFormat,
+
+    /// Limit an archive or graph export to this block and its descendants
+    #[arg(long)]
+    root: Option<Uuid>,
+
+    /// Whether an archive keeps related links; with `--root`, only those
+    /// between exported blocks
+    #[arg(long, default_value_t = true, action = ArgAction::Set)]
+    include_related: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ExportFormat {
+    Archive,
+    Markdown,
+    Graphml,
+    Gexf,
+    Dot,
+}
+
+pub(super) async fn run(args: ExportArgs, state: &AppState) -> Result<ExitCode> {
+    if matches!(args.format, ExportFormat::Markdown) && args.root.is_some() {
+        return Err(CliError::RootWithMarkdown);
+    }
+
+    let graph_format = match args.format {
+        ExportFormat::Archive | ExportFormat::Markdown => None,
+        ExportFormat::Graphml => Some(GraphFormat::Graphml),
+        ExportFormat::Gexf => Some(GraphFormat::Gexf),
+        ExportFormat::Dot => Some(GraphFormat::Dot),
+    };
+    // Archives are read back from their spool file rather than held in memory
+    let (mut export, default_output): (Box<dyn Read>, _) = match (args.format, graph_format) {
+        (_, Some(format)) => (
+            Box::new(Cursor::new(build_graph(state, format, args.root).await?)),
+            format.file_name(),
+        ),
+        (ExportFormat::Markdown, None) => (
+            Box::new(Cursor::new(build_vault(state).await?)),
+            "modunote-markdown.zip",
+        ),
+        (_, None) => {
+            let query = ExportQuery {
+                root: args.root,
+                include_related: args.include_related,
+            };
+            (
+                Box::new(build_archive(state, &query).await?),
+                "modunote-export.zip",
+            )
+        }
+    };
+    let output = args.output.unwrap_or_else(|| default_output.into());
+
+    if output.as_os_str() == "-" {
+        std::io::copy(&mut export, &mut std::io::stdout())?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let file_error = |source| CliError::File {
+        path: output.display().to_string(),
+        source,
+    };
+    let mut file = File::create(&output).map_err(file_error)?;
+    let written = std::io::copy(&mut export, &mut file).map_err(file_error)?;
+    println!("Wrote {written} bytes to {}", output.display());
+
+    Ok(ExitCode::SUCCESS)
+}
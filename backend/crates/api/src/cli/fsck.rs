@@ -0,0 +1,76 @@
+use std::process::ExitCode;
+
+use clap::Args;
+
+use super::error::CliResult as Result;
+use crate::AppState;
+use storage::Database;
+use storage::helpers::block_directional_path_helper::{
+    BlockDirectionalPathHelper, PathIntegrityReportDto,
+};
+
+#[derive(Args, Debug)]
+pub(super) struct FsckArgs {
+    /// Replace the table with the one recomputed from the links
+    #[arg(long)]
+    rebuild: bool,
+}
+
+/// Exits with 1 when the table is out of line with the links and was not
+/// rebuilt.
+pub(super) async fn run(args: FsckArgs, state: &AppState) -> Result<ExitCode> {
+    let helper = &state.helpers.block_directional_paths;
+
+    if args.rebuild {
+        let repaired = helper.rebuild(state.db.pool()).await?;
+        print_report(&repaired);
+        println!("Table rebuilt");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let report = helper.check_integrity(state.db.pool()).await?;
+    print_report(&report);
+
+    if report.is_consistent() {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+fn print_report(report: &PathIntegrityReportDto) {
+    for row in &report.missing {
+        println!(
+            "missing   {} -> {} (paths {}, depth {})",
+            row.block_ancestor_id, row.block_descendant_id, row.path_count, row.min_depth
+        );
+    }
+    for row in &report.extra {
+        println!(
+            "extra     {} -> {} (paths {}, depth {})",
+            row.block_ancestor_id, row.block_descendant_id, row.path_count, row.min_depth
+        );
+    }
+    for mismatch in &report.mismatched {
+        println!(
+            "mismatch  {} -> {} (paths {}, depth {}; expected paths {}, depth {})",
+            mismatch.stored.block_ancestor_id,
+            mismatch.stored.block_descendant_id,
+            mismatch.stored.path_count,
+            mismatch.stored.min_depth,
+            mismatch.expected.path_count,
+            mismatch.expected.min_depth
+        );
+    }
+    for block_id in &report.cyclic_block_ids {
+        println!("cycle     {block_id}");
+    }
+
+    println!(
+        "{} missing, {} extra, {} mismatched, {} blocks on cycles",
+        report.missing.len(),
+        report.extra.len(),
+        report.mismatched.len(),
+        report.cyclic_block_ids.len()
+    );
+}
@@ -0,0 +1,173 @@
+use std::fs::File;
+use std::io::Seek;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+
+use super::error::{CliError, CliResult as Result};
+use crate::AppState;
+use crate::features::export::archive::ARCHIVE_FORMAT_VERSION;
+use crate::features::export::spool::SpooledFile;
+use crate::features::import::{
+    ConflictReason, ConflictResolution, ImportConflict, ImportOptions, ImportStrategy,
+    import_archive,
+};
+
+#[derive(Args, Debug)]
+pub(super) struct ImportArgs {
+    /// Archive to import; `-` reads from stdin
+    file: PathBuf,
+
+    /// Report what would change without writing anything
+    #[arg(long)]
+    dry_run: bool,
+
+    /// How to resolve blocks that already exist
+    #[arg(long, value_enum, default_value_t = StrategyArg::NewerWins)]
+    strategy: StrategyArg,
+
+    /// Apply the whole archive in one transaction
+    #[arg(long)]
+    atomic: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum StrategyArg {
+    NewerWins,
+    KeepLocal,
+    Overwrite,
+    Copy,
+}
+
+impl From<StrategyArg> for ImportStrategy {
+    fn from(strategy: StrategyArg) -> Self {
+        match strategy {
+            StrategyArg::NewerWins => Self::NewerWins,
+            StrategyArg::KeepLocal => Self::KeepLocal,
+            StrategyArg::Overwrite => Self::Overwrite,
+            StrategyArg::Copy => Self::Copy,
+        }
+    }
+}
+
+pub(super) async fn run(args: ImportArgs, state: &AppState) -> Result<ExitCode> {
+    let options = ImportOptions {
+        dry_run: args.dry_run,
+        strategy: args.strategy.into(),
+        atomic: args.atomic,
+    };
+
+    // The archive is read in place; stdin can't seek, so it is spooled first
+    let summary = if args.file.as_os_str() == "-" {
+        let mut upload = SpooledFile::new();
+        std::io::copy(&mut std::io::stdin(), &mut upload)?;
+        upload.rewind()?;
+        import_archive(upload, &options, state).await?
+    } else {
+        let file = File::open(&args.file).map_err(|source| CliError::File {
+            path: args.file.display().to_string(),
+            source,
+        })?;
+        import_archive(file, &options, state).await?
+    };
+
+    if summary.format_version < ARCHIVE_FORMAT_VERSION {
+        println!(
+            "Archive format version {} upgraded to {}",
+            summary.format_version, ARCHIVE_FORMAT_VERSION
+        );
+    }
+    for conflict in &summary.conflicts {
+        println!("conflict  {}", describe(conflict));
+    }
+
+    println!(
+        "blocks: {} inserted, {} updated, {} skipped, {} copied",
+        summary.blocks_inserted,
+        summary.blocks_updated,
+        summary.blocks_skipped,
+        summary.blocks_copied
+    );
+    println!(
+        "directional links: {} inserted, {} skipped",
+        summary.dir_links_inserted, summary.dir_links_skipped
+    );
+    println!(
+        "related links: {} inserted, {} skipped",
+        summary.related_links_inserted, summary.related_links_skipped
+    );
+    println!(
+        "canvases: {} inserted, {} skipped ({} placements, {} pins)",
+        summary.canvases_inserted,
+        summary.canvases_skipped,
+        summary.canvas_blocks_inserted,
+        summary.canvas_pins_inserted
+    );
+    println!(
+        "workspace: {} tabs opened, {} blocks pinned",
+        summary.block_opens_inserted, summary.block_pins_inserted
+    );
+
+    if summary.dry_run {
+        println!("Dry run - nothing was written");
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn describe(conflict: &ImportConflict) -> String {
+    let (item, reason, resolution) = match conflict {
+        ImportConflict::Block {
+            id,
+            reason,
+            resolution,
+            new_id,
+        } => {
+            let item = match new_id {
+                Some(new_id) => format!("block {id} (copied as {new_id})"),
+                None => format!("block {id}"),
+            };
+            (item, reason, resolution)
+        }
+        ImportConflict::DirectionalLink {
+            block_from_id,
+            block_to_id,
+            reason,
+            resolution,
+        } => (
+            format!("directional link {block_from_id} -> {block_to_id}"),
+            reason,
+            resolution,
+        ),
+        ImportConflict::RelatedLink {
+            block_a_id,
+            block_b_id,
+            reason,
+            resolution,
+        } => (
+            format!("related link {block_a_id} <-> {block_b_id}"),
+            reason,
+            resolution,
+        ),
+        ImportConflict::Canvas {
+            id,
+            reason,
+            resolution,
+        } => (format!("canvas {id}"), reason, resolution),
+    };
+
+    let reason = match reason {
+        ConflictReason::AlreadyExists => "already exists",
+        ConflictReason::Trashed => "in the local trash",
+        ConflictReason::MissingBlock => "missing block",
+        ConflictReason::Cycle => "would close a cycle",
+    };
+    let resolution = match resolution {
+        ConflictResolution::Skipped => "skipped",
+        ConflictResolution::Overwritten => "overwritten",
+        ConflictResolution::Copied => "copied",
+    };
+
+    format!("{item}: {reason}, {resolution}")
+}
@@ -0,0 +1,69 @@
+use std::fs::File;
+use std::io::Seek;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+
+use super::error::{CliError, CliResult as Result};
+use crate::AppState;
+use crate::features::export::spool::SpooledFile;
+use crate::features::import_markdown::{UnresolvedReason, import_vault};
+
+#[derive(Args, Debug)]
+pub(super) struct ImportMarkdownArgs {
+    /// ZIP of `.md` files; `-` reads from stdin
+    file: PathBuf,
+
+    /// Report what would be created without writing anything
+    #[arg(long)]
+    dry_run: bool,
+}
+
+pub(super) async fn run(args: ImportMarkdownArgs, state: &AppState) -> Result<ExitCode> {
+    // Stdin can't seek, so it is spooled first
+    let summary = if args.file.as_os_str() == "-" {
+        let mut upload = SpooledFile::new();
+        std::io::copy(&mut std::io::stdin(), &mut upload)?;
+        upload.rewind()?;
+        import_vault(upload, args.dry_run, state).await?
+    } else {
+        let file = File::open(&args.file).map_err(|source| CliError::File {
+            path: args.file.display().to_string(),
+            source,
+        })?;
+        import_vault(file, args.dry_run, state).await?
+    };
+
+    for collision in &summary.collisions {
+        println!(
+            "collision   {}: {}",
+            collision.name,
+            collision.paths.join(", ")
+        );
+    }
+    for link in &summary.unresolved_links {
+        let reason = match link.reason {
+            UnresolvedReason::NotFound => "not found",
+            UnresolvedReason::Ambiguous => "ambiguous",
+        };
+        println!(
+            "unresolved  {} -> [[{}]] ({reason})",
+            link.note, link.target
+        );
+    }
+
+    println!(
+        "{} notes, {} folders, {} directional links, {} related links, {} files skipped",
+        summary.notes_imported,
+        summary.folders_created,
+        summary.dir_links_created,
+        summary.related_links_created,
+        summary.files_skipped
+    );
+    if summary.dry_run {
+        println!("Dry run - nothing was written");
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,12 @@
+use std::process::ExitCode;
+
+use super::error::CliResult as Result;
+use crate::AppState;
+use storage::Database;
+
+pub(super) async fn run(state: &AppState) -> Result<ExitCode> {
+    state.db.run_migration().await?;
+    println!("Migrations applied");
+
+    Ok(ExitCode::SUCCESS)
+}
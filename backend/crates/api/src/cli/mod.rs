@@ -0,0 +1,114 @@
+//! `modunote`, the admin CLI. It opens the database directly instead of going
+//! through the HTTP API, so it works while the server is down. The backend
+//! follows the crate features, like the server. Builds with both backends
+//! enabled also get `copy`, which moves an instance between them.
+
+#[cfg(all(feature = "native", feature = "cloud"))]
+mod copy;
+mod error;
+mod export;
+mod fsck;
+mod import;
+mod import_markdown;
+mod migrate;
+mod search;
+mod stats;
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use tracing_subscriber::EnvFilter;
+
+use crate::AppState;
+use crate::app_state::DatabaseImpl;
+use error::{CliError, CliResult as Result};
+use storage::Database;
+
+#[derive(Parser, Debug)]
+#[command(name = "modunote", version, about = "Modunote admin commands")]
+struct Cli {
+    /// Database to open
+    #[arg(long, env = "DATABASE_URL", global = true, hide_env_values = true)]
+    database_url: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Write every live block and link to a ZIP archive or Markdown vault
+    Export(export::ExportArgs),
+    /// Merge a ZIP archive written by `export` into the database
+    Import(import::ImportArgs),
+    /// Import a ZIP of Markdown notes, such as an Obsidian vault
+    ImportMarkdown(import_markdown::ImportMarkdownArgs),
+    /// Apply pending schema migrations
+    Migrate,
+    /// Check the ancestor/descendant table against the links
+    Fsck(fsck::FsckArgs),
+    /// Count blocks, links, tags, pins and canvases
+    Stats,
+    /// Search live blocks by title and content
+    Search(search::SearchArgs),
+    /// Copy every row of one database into an empty one, across backends
+    #[cfg(all(feature = "native", feature = "cloud"))]
+    Copy(copy::CopyArgs),
+    /// Unavailable: needs a build with both backends (`--features native`)
+    #[cfg(not(all(feature = "native", feature = "cloud")))]
+    Copy {
+        #[arg(hide = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+}
+
+pub async fn run() -> ExitCode {
+    let _ = dotenvy::dotenv();
+    let cli = Cli::parse();
+
+    // Warnings from the shared import and export code go to stderr
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+        )
+        .init();
+
+    match execute(cli).await {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("modunote: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+async fn execute(cli: Cli) -> Result<ExitCode> {
+    // Copy names both of its databases itself
+    #[cfg(all(feature = "native", feature = "cloud"))]
+    if let Command::Copy(args) = cli.command {
+        return copy::run(args).await;
+    }
+    #[cfg(not(all(feature = "native", feature = "cloud")))]
+    if let Command::Copy { .. } = cli.command {
+        return Err(CliError::CopyUnavailable);
+    }
+
+    let database_url = cli.database_url.ok_or(CliError::MissingDatabaseUrl)?;
+    let db = DatabaseImpl::connect(&database_url).await?;
+    let state = AppState::new(db);
+
+    match cli.command {
+        Command::Export(args) => export::run(args, &state).await,
+        Command::Import(args) => import::run(args, &state).await,
+        Command::ImportMarkdown(args) => import_markdown::run(args, &state).await,
+        Command::Migrate => migrate::run(&state).await,
+        Command::Fsck(args) => fsck::run(args, &state).await,
+        Command::Stats => stats::run(&state).await,
+        Command::Search(args) => search::run(args, &state).await,
+        #[cfg(all(feature = "native", feature = "cloud"))]
+        Command::Copy(_) => unreachable!("handled before connecting"),
+        #[cfg(not(all(feature = "native", feature = "cloud")))]
+        Command::Copy { .. } => unreachable!("handled before connecting"),
+    }
+}
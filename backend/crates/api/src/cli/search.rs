@@ -0,0 +1,62 @@
+use std::process::ExitCode;
+
+use clap::Args;
+use uuid::Uuid;
+
+use super::error::CliResult as Result;
+use crate::AppState;
+use domain::tags::Tag;
+use storage::Database;
+use storage::query_services::BlockQueryService;
+use storage::query_services::block_query_service::BlockSearchDto;
+
+#[derive(Args, Debug)]
+pub(super) struct SearchArgs {
+    /// Text to look for; leave empty to list blocks by last update
+    #[arg(default_value = "")]
+    query: String,
+
+    /// Only blocks carrying this tag, ignoring case
+    #[arg(long)]
+    tag: Option<String>,
+
+    /// Only blocks below this block in the directional hierarchy
+    #[arg(long)]
+    descendant_of: Option<Uuid>,
+
+    /// Only pinned blocks
+    #[arg(long)]
+    pinned: bool,
+
+    /// Maximum number of results
+    #[arg(long, default_value_t = BlockSearchDto::DEFAULT_LIMIT, value_parser = clap::value_parser!(i64).range(1..))]
+    limit: i64,
+}
+
+/// Prints one tab-separated `id`, `updated_at`, `title` line per match.
+pub(super) async fn run(args: SearchArgs, state: &AppState) -> Result<ExitCode> {
+    let search = BlockSearchDto {
+        tag: args.tag.as_deref().map(Tag::normalize_name).transpose()?,
+        descendant_of: args.descendant_of,
+        only_pinned: args.pinned,
+        limit: args.limit,
+        ..BlockSearchDto::new(&args.query)
+    };
+
+    let blocks = state
+        .query_services
+        .blocks
+        .search(&search, state.db.pool())
+        .await?;
+
+    for block in blocks {
+        println!(
+            "{}\t{}\t{}",
+            block.id,
+            block.updated_at.to_rfc3339(),
+            block.title
+        );
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
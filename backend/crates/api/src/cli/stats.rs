@@ -0,0 +1,32 @@
+use std::process::ExitCode;
+
+use super::error::CliResult as Result;
+use crate::AppState;
+use storage::Database;
+use storage::query_services::BlockQueryService;
+use storage::repositories::SnapshotRepository;
+
+pub(super) async fn run(state: &AppState) -> Result<ExitCode> {
+    let mut tx = state.db.pool().begin().await?;
+
+    let rows = state.repos.snapshots.count_rows(&mut *tx).await?;
+    let trashed = state.query_services.blocks.count_trashed(&mut *tx).await?;
+
+    tx.commit().await?;
+
+    let counts = [
+        ("blocks", rows.blocks - trashed),
+        ("trashed blocks", trashed),
+        ("directional links", rows.block_directional_links),
+        ("related links", rows.block_related_links),
+        ("tags", rows.tags),
+        ("pinned blocks", rows.block_pins),
+        ("canvases", rows.canvases),
+    ];
+
+    for (name, count) in counts {
+        println!("{name:<18} {count}");
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
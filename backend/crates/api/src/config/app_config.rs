@@ -8,6 +8,10 @@ use crate::telemetry::TelemetryConfig;
 pub struct AppConfig {
     pub database_url: String,
     pub frontend_url: String,
+    /// Days a block stays in the trash before it is purged; 0 disables auto-purge
+    pub trash_retention_days: u32,
+    /// Largest request body accepted by the import endpoints, in bytes
+    pub max_upload_bytes: usize,
     pub telemetry: TelemetryConfig,
 }
 
@@ -24,6 +28,12 @@ impl AppConfig {
         let config = Self {
             database_url,
             frontend_url: load_value("FRONTEND_URL", "frontend_url", &table)?,
+            trash_retention_days: load_value(
+                "TRASH_RETENTION_DAYS",
+                "trash_retention_days",
+                &table,
+            )?,
+            max_upload_bytes: load_value("MAX_UPLOAD_BYTES", "max_upload_bytes", &table)?,
             telemetry: TelemetryConfig::load(&table)?,
         };
 
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use tracing::instrument;
+
+use super::error::{CheckPathIntegrityError, ErrorResponse};
+use crate::AppState;
+use crate::features::admin::report::PathIntegrityReport;
+use storage::Database;
+use storage::helpers::block_directional_path_helper::BlockDirectionalPathHelper;
+
+/// Compares the stored ancestor/descendant table with the one implied by the
+/// directional links, without changing anything.
+#[instrument(err, skip(state))]
+#[utoipa::path(
+    get,
+    path = "/api/admin/paths/integrity",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Integrity report", body = PathIntegrityReport),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn check_path_integrity(
+    State(state): State<Arc<AppState>>,
+) -> Result<PathIntegrityReport, CheckPathIntegrityError> {
+    let report = state
+        .helpers
+        .block_directional_paths
+        .check_integrity(state.db.pool())
+        .await?;
+
+    Ok(report.into())
+}
@@ -0,0 +1,7 @@
+mod check_paths;
+mod rebuild_paths;
+mod report;
+
+mod routes;
+
+pub use routes::routes;
@@ -0,0 +1,45 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::helpers::block_directional_path_helper::BlockDirectionalPathHelperError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum RebuildPathsError {
+    #[error(transparent)]
+    PathHelper(#[from] BlockDirectionalPathHelperError),
+}
+
+impl IntoResponse for RebuildPathsError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::PathHelper(err) => match err {
+                BlockDirectionalPathHelperError::CyclicLinks { .. } => {
+                    (StatusCode::CONFLICT, err.to_string())
+                }
+                other => {
+                    error!(error = ?other, "Block directional path helper failure");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal server error".to_string(),
+                    )
+                }
+            },
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
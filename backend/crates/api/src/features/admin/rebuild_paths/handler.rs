@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use tracing::instrument;
+
+use super::error::{ErrorResponse, RebuildPathsError};
+use super::response::RebuildPathsResponse;
+use crate::AppState;
+use storage::Database;
+use storage::helpers::block_directional_path_helper::BlockDirectionalPathHelper;
+
+/// Recomputes the ancestor/descendant table from the directional links and
+/// replaces the stored one with it.
+#[instrument(err, skip(state))]
+#[utoipa::path(
+    post,
+    path = "/api/admin/paths/rebuild",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Table rebuilt", body = RebuildPathsResponse),
+        (status = 409, description = "Links form a cycle", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn rebuild_paths(
+    State(state): State<Arc<AppState>>,
+) -> Result<RebuildPathsResponse, RebuildPathsError> {
+    let report = state
+        .helpers
+        .block_directional_paths
+        .rebuild(state.db.pool())
+        .await?;
+
+    Ok(RebuildPathsResponse {
+        repaired: report.into(),
+    })
+}
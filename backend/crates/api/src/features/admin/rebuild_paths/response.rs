@@ -0,0 +1,22 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::features::admin::report::PathIntegrityReport;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RebuildPathsResponse {
+    /// What was wrong with the table before it was rebuilt
+    pub repaired: PathIntegrityReport,
+}
+
+impl IntoResponse for RebuildPathsResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
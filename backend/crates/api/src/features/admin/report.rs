@@ -0,0 +1,82 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use storage::helpers::block_directional_path_helper::{
+    ClosureRowDto, ClosureRowMismatchDto, PathIntegrityReportDto,
+};
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClosureRow {
+    pub ancestor_id: Uuid,
+    pub descendant_id: Uuid,
+    pub path_count: i64,
+    pub min_depth: i64,
+}
+
+impl From<ClosureRowDto> for ClosureRow {
+    fn from(row: ClosureRowDto) -> Self {
+        Self {
+            ancestor_id: row.block_ancestor_id,
+            descendant_id: row.block_descendant_id,
+            path_count: row.path_count,
+            min_depth: row.min_depth,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClosureRowMismatch {
+    pub stored: ClosureRow,
+    pub expected: ClosureRow,
+}
+
+impl From<ClosureRowMismatchDto> for ClosureRowMismatch {
+    fn from(mismatch: ClosureRowMismatchDto) -> Self {
+        Self {
+            stored: mismatch.stored.into(),
+            expected: mismatch.expected.into(),
+        }
+    }
+}
+
+/// How the stored ancestor/descendant rows differ from the ones implied by
+/// the directional links between live blocks.
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PathIntegrityReport {
+    pub consistent: bool,
+    /// Pairs reachable through links but absent from the table
+    pub missing: Vec<ClosureRow>,
+    /// Pairs in the table that no chain of links connects
+    pub extra: Vec<ClosureRow>,
+    /// Pairs present on both sides with a different count or depth
+    pub mismatched: Vec<ClosureRowMismatch>,
+    /// Blocks that reach themselves through links
+    pub cyclic_block_ids: Vec<Uuid>,
+}
+
+impl From<PathIntegrityReportDto> for PathIntegrityReport {
+    fn from(report: PathIntegrityReportDto) -> Self {
+        Self {
+            consistent: report.is_consistent(),
+            missing: report.missing.into_iter().map(Into::into).collect(),
+            extra: report.extra.into_iter().map(Into::into).collect(),
+            mismatched: report.mismatched.into_iter().map(Into::into).collect(),
+            cyclic_block_ids: report.cyclic_block_ids,
+        }
+    }
+}
+
+impl IntoResponse for PathIntegrityReport {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
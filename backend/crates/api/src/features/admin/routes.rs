@@ -0,0 +1,10 @@
+use std::sync::Arc;
+
+use crate::AppState;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(super::check_paths::check_path_integrity))
+        .routes(routes!(super::rebuild_paths::rebuild_paths))
+}
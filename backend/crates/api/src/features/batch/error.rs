@@ -0,0 +1,133 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use storage::repositories::block_directional_link_repository::BlockDirectionalLinkRepositoryError;
+use storage::repositories::block_related_link_repository::BlockRelatedLinkError;
+use storage::repositories::block_repository::BlockRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+    /// Index of the operation that failed; nothing was applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_index: Option<usize>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum BatchError {
+    #[error("A batch may contain at most {max} operations")]
+    TooManyOperations { max: usize },
+
+    #[error("Operation {index} failed: {source}")]
+    Operation {
+        index: usize,
+        source: BatchOperationError,
+    },
+
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum BatchOperationError {
+    #[error("Unknown block reference: {0}")]
+    UnknownReference(String),
+
+    #[error("Temp id used more than once: {0}")]
+    DuplicateTempId(String),
+
+    #[error("Block not found: {0}")]
+    BlockNotFound(Uuid),
+
+    #[error("Block was modified concurrently: {0}")]
+    Conflict(Uuid),
+
+    #[error(transparent)]
+    BlockRepository(#[from] BlockRepositoryError),
+
+    #[error(transparent)]
+    DirectionalLinkRepository(#[from] BlockDirectionalLinkRepositoryError),
+
+    #[error(transparent)]
+    RelatedLinkRepository(#[from] BlockRelatedLinkError),
+}
+
+impl BatchOperationError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::UnknownReference(_) | Self::DuplicateTempId(_) => StatusCode::BAD_REQUEST,
+            Self::BlockNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::BlockRepository(err) => match err {
+                BlockRepositoryError::NotFound { .. } => StatusCode::NOT_FOUND,
+                other => {
+                    error!(error = ?other, "Block repository failure");
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+            },
+            Self::DirectionalLinkRepository(err) => match err {
+                BlockDirectionalLinkRepositoryError::NotFoundByBlocks { .. } => {
+                    StatusCode::NOT_FOUND
+                }
+                BlockDirectionalLinkRepositoryError::BlocksNotFound { .. }
+                | BlockDirectionalLinkRepositoryError::CycleDetected { .. }
+                | BlockDirectionalLinkRepositoryError::AlreadyExists { .. } => {
+                    StatusCode::BAD_REQUEST
+                }
+                other => {
+                    error!(error = ?other, "Directional link repository failure");
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+            },
+            Self::RelatedLinkRepository(err) => match err {
+                BlockRelatedLinkError::NotFoundByBlocks { .. } => StatusCode::NOT_FOUND,
+                BlockRelatedLinkError::SelfLink { .. }
+                | BlockRelatedLinkError::BlocksNotFound { .. }
+                | BlockRelatedLinkError::AlreadyExists { .. } => StatusCode::BAD_REQUEST,
+                other => {
+                    error!(error = ?other, "Related link repository failure");
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+            },
+        }
+    }
+}
+
+impl IntoResponse for BatchError {
+    fn into_response(self) -> Response {
+        let (status, msg, operation_index) = match &self {
+            Self::TooManyOperations { .. } => (StatusCode::BAD_REQUEST, self.to_string(), None),
+            Self::Operation { index, source } => match source.status() {
+                StatusCode::INTERNAL_SERVER_ERROR => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                    Some(*index),
+                ),
+                status => (status, self.to_string(), Some(*index)),
+            },
+            Self::Database(err) => {
+                error!(error = ?err, "Batch transaction failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                    None,
+                )
+            }
+        };
+
+        let body = Json(ErrorResponse {
+            error: msg,
+            operation_index,
+        });
+
+        (status, body).into_response()
+    }
+}
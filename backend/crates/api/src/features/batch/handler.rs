@@ -0,0 +1,400 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{Json, extract::State};
+use chrono::Utc;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{BatchError, BatchOperationError, ErrorResponse},
+    request::{
+        BatchOperation, BatchRequest, CreateBlockOperation, CreateDirectionalLinkOperation,
+        CreateRelatedLinkOperation, UpdateBlockOperation,
+    },
+    response::{
+        BatchOperationResult, BatchResponse, BlockResult, DirectionalLinkResult, RelatedLinkResult,
+    },
+};
+use crate::AppState;
+use crate::app_state::DatabaseImpl;
+use domain::blocks::Block;
+use storage::Database;
+use storage::repositories::block_directional_link_repository::CreateBlockDirectionalLinkDto;
+use storage::repositories::block_related_link_repository::CreateBlockRelatedLinkDto;
+use storage::repositories::{
+    BlockDirectionalLinkRepository, BlockRelatedLinkRepository, BlockRepository,
+};
+
+const MAX_BATCH_OPERATIONS: usize = 1000;
+
+type Connection = <<DatabaseImpl as Database>::Provider as sqlx::Database>::Connection;
+
+/// Applies the operations in order inside one transaction. The first failing
+/// operation rolls back everything before it and is reported by index.
+#[utoipa::path(
+    post,
+    path = "/api/batch",
+    tag = "batch",
+    request_body = BatchRequest,
+    responses(
+        (status = 200, description = "All operations applied", body = BatchResponse),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Referenced block or link not found", body = ErrorResponse),
+        (status = 409, description = "Block was modified concurrently", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+#[instrument(err, skip(state, request))]
+pub async fn run_batch(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<BatchRequest>,
+) -> Result<BatchResponse, BatchError> {
+    if request.operations.len() > MAX_BATCH_OPERATIONS {
+        return Err(BatchError::TooManyOperations {
+            max: MAX_BATCH_OPERATIONS,
+        });
+    }
+
+    let mut tx = state.db.pool().begin().await?;
+    let mut temp_ids: HashMap<String, Uuid> = HashMap::new();
+    let mut results = Vec::with_capacity(request.operations.len());
+
+    for (index, operation) in request.operations.into_iter().enumerate() {
+        let result = apply_operation(operation, &mut temp_ids, &state, &mut tx)
+            .await
+            .map_err(|source| BatchError::Operation { index, source })?;
+        results.push(result);
+    }
+
+    tx.commit().await?;
+
+    Ok(BatchResponse { results })
+}
+
+async fn apply_operation(
+    operation: BatchOperation,
+    temp_ids: &mut HashMap<String, Uuid>,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<BatchOperationResult, BatchOperationError> {
+    match operation {
+        BatchOperation::CreateBlock(op) => create_block(op, temp_ids, state, conn).await,
+        BatchOperation::UpdateBlock(op) => update_block(op, temp_ids, state, conn).await,
+        BatchOperation::DeleteBlock(op) => {
+            let id = resolve(&op.id, temp_ids)?;
+            state.repos.blocks.trash(id, &mut *conn).await?;
+
+            Ok(BatchOperationResult::DeleteBlock(BlockResult {
+                temp_id: None,
+                id,
+                version: None,
+            }))
+        }
+        BatchOperation::CreateDirectionalLink(op) => {
+            create_directional_link(op, temp_ids, state, conn).await
+        }
+        BatchOperation::DeleteDirectionalLink(op) => {
+            let block_from_id = resolve(&op.from, temp_ids)?;
+            let block_to_id = resolve(&op.to, temp_ids)?;
+            state
+                .repos
+                .block_directional_links
+                .delete_by_block_ids(block_from_id, block_to_id, &mut *conn)
+                .await?;
+
+            Ok(BatchOperationResult::DeleteDirectionalLink(
+                DirectionalLinkResult {
+                    id: None,
+                    block_from_id,
+                    block_to_id,
+                    position: None,
+                },
+            ))
+        }
+        BatchOperation::CreateRelatedLink(op) => {
+            create_related_link(op, temp_ids, state, conn).await
+        }
+        BatchOperation::DeleteRelatedLink(op) => {
+            let block_a_id = resolve(&op.a, temp_ids)?;
+            let block_b_id = resolve(&op.b, temp_ids)?;
+            state
+                .repos
+                .block_related_links
+                .delete_by_block_ids(block_a_id, block_b_id, &mut *conn)
+                .await?;
+
+            Ok(BatchOperationResult::DeleteRelatedLink(RelatedLinkResult {
+                id: None,
+                block_a_id,
+                block_b_id,
+            }))
+        }
+    }
+}
+
+async fn create_block(
+    op: CreateBlockOperation,
+    temp_ids: &mut HashMap<String, Uuid>,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<BatchOperationResult, BatchOperationError> {
+    let block = Block::new(&op.title, &op.content);
+
+    if let Some(temp_id) = &op.temp_id {
+        if temp_ids.contains_key(temp_id) {
+            return Err(BatchOperationError::DuplicateTempId(temp_id.clone()));
+        }
+        temp_ids.insert(temp_id.clone(), block.id);
+    }
+
+    state.repos.blocks.save(&block, &mut *conn).await?;
+
+    Ok(BatchOperationResult::CreateBlock(BlockResult {
+        temp_id: op.temp_id,
+        id: block.id,
+        version: Some(block.version),
+    }))
+}
+
+async fn update_block(
+    op: UpdateBlockOperation,
+    temp_ids: &HashMap<String, Uuid>,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<BatchOperationResult, BatchOperationError> {
+    let id = resolve(&op.id, temp_ids)?;
+
+    let mut block = state
+        .repos
+        .blocks
+        .get_by_id(id, &mut *conn)
+        .await?
+        .ok_or(BatchOperationError::BlockNotFound(id))?;
+
+    if op.version.is_some_and(|version| version != block.version) {
+        return Err(BatchOperationError::Conflict(id));
+    }
+
+    let expected_version = block.version;
+    if let Some(title) = op.title {
+        block.title = title;
+    }
+    if let Some(content) = op.content {
+        block.content = content;
+    }
+    block.updated_at = Utc::now();
+
+    let updated = state
+        .repos
+        .blocks
+        .update_if_version(&block, expected_version, &mut *conn)
+        .await?
+        .ok_or(BatchOperationError::Conflict(id))?;
+
+    Ok(BatchOperationResult::UpdateBlock(BlockResult {
+        temp_id: None,
+        id,
+        version: Some(updated.version),
+    }))
+}
+
+async fn create_directional_link(
+    op: CreateDirectionalLinkOperation,
+    temp_ids: &HashMap<String, Uuid>,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<BatchOperationResult, BatchOperationError> {
+    let input = CreateBlockDirectionalLinkDto {
+        id: Uuid::new_v4(),
+        block_from_id: resolve(&op.from, temp_ids)?,
+        block_to_id: resolve(&op.to, temp_ids)?,
+        kind: op.kind,
+        label: op.label,
+        weight: op.weight,
+    };
+
+    let link = state
+        .repos
+        .block_directional_links
+        .create(&input, &mut *conn)
+        .await?;
+
+    Ok(BatchOperationResult::CreateDirectionalLink(
+        DirectionalLinkResult {
+            id: Some(link.id),
+            block_from_id: link.block_from_id,
+            block_to_id: link.block_to_id,
+            position: Some(link.position),
+        },
+    ))
+}
+
+async fn create_related_link(
+    op: CreateRelatedLinkOperation,
+    temp_ids: &HashMap<String, Uuid>,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<BatchOperationResult, BatchOperationError> {
+    let input = CreateBlockRelatedLinkDto {
+        id: Uuid::new_v4(),
+        block_a_id: resolve(&op.a, temp_ids)?,
+        block_b_id: resolve(&op.b, temp_ids)?,
+        label: op.label,
+        weight: op.weight,
+    };
+
+    let link = state
+        .repos
+        .block_related_links
+        .create(&input, &mut *conn)
+        .await?;
+
+    Ok(BatchOperationResult::CreateRelatedLink(RelatedLinkResult {
+        id: Some(link.id),
+        block_a_id: link.block_a_id,
+        block_b_id: link.block_b_id,
+    }))
+}
+
+/// Temp ids from earlier operations win over parsing the reference as a block id.
+fn resolve(reference: &str, temp_ids: &HashMap<String, Uuid>) -> Result<Uuid, BatchOperationError> {
+    if let Some(id) = temp_ids.get(reference) {
+        return Ok(*id);
+    }
+
+    Uuid::parse_str(reference)
+        .map_err(|_| BatchOperationError::UnknownReference(reference.to_string()))
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use axum::body::to_bytes;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use rstest::rstest;
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::test_utils::{TestApp, app};
+    use storage::query_services::{BlockLinkQueryService, BlockQueryService};
+
+    async fn run(app: &TestApp, operations: Value) -> Result<BatchResponse, BatchError> {
+        let request = serde_json::from_value(json!({ "operations": operations }))
+            .expect("invalid batch request");
+
+        run_batch(State(app.state()), Json(request)).await
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn resolves_temp_ids_from_earlier_operations(#[future] app: TestApp) {
+        let app = app.await;
+
+        let response = run(
+            &app,
+            json!([
+                { "op": "createBlock", "tempId": "parent", "title": "Parent", "content": "" },
+                { "op": "createBlock", "tempId": "child", "title": "Child", "content": "" },
+                { "op": "createDirectionalLink", "from": "parent", "to": "child" },
+                { "op": "updateBlock", "id": "child", "title": "Renamed", "version": 1 },
+            ]),
+        )
+        .await
+        .expect("batch should succeed");
+
+        let [
+            BatchOperationResult::CreateBlock(parent),
+            BatchOperationResult::CreateBlock(child),
+            BatchOperationResult::CreateDirectionalLink(link),
+            BatchOperationResult::UpdateBlock(update),
+        ] = &response.results[..]
+        else {
+            panic!("unexpected results");
+        };
+        assert_eq!(parent.temp_id.as_deref(), Some("parent"));
+        assert_eq!(link.block_from_id, parent.id);
+        assert_eq!(link.block_to_id, child.id);
+        assert_eq!(update.id, child.id);
+        assert_eq!(update.version, Some(2));
+
+        let children = app
+            .query_services
+            .block_links
+            .get_child_blocks(parent.id, app.db.pool())
+            .await
+            .unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].title, "Renamed");
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn failing_operation_rolls_back_the_batch(#[future] app: TestApp) {
+        let app = app.await;
+
+        let err = run(
+            &app,
+            json!([
+                { "op": "createBlock", "tempId": "a", "title": "A", "content": "" },
+                { "op": "createBlock", "tempId": "b", "title": "B", "content": "" },
+                { "op": "createDirectionalLink", "from": "a", "to": "b" },
+                { "op": "createDirectionalLink", "from": "b", "to": "a" },
+            ]),
+        )
+        .await
+        .err()
+        .expect("cycle should fail the batch");
+
+        assert!(matches!(
+            err,
+            BatchError::Operation {
+                index: 3,
+                source: BatchOperationError::DirectionalLinkRepository(_),
+            }
+        ));
+
+        let blocks = app
+            .query_services
+            .blocks
+            .get_all(app.db.pool())
+            .await
+            .unwrap();
+        assert!(blocks.is_empty(), "earlier operations should be rolled back");
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn error_response_reports_operation_index(#[future] app: TestApp) {
+        let app = app.await;
+
+        let err = run(
+            &app,
+            json!([
+                { "op": "createBlock", "tempId": "a", "title": "A", "content": "" },
+                { "op": "createBlock", "tempId": "a", "title": "Again", "content": "" },
+            ]),
+        )
+        .await
+        .err()
+        .expect("duplicate temp id should fail the batch");
+
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["operationIndex"], 1);
+
+        let err = run(&app, json!([{ "op": "deleteBlock", "id": "missing" }]))
+            .await
+            .err()
+            .expect("unknown reference should fail the batch");
+        assert!(matches!(
+            err,
+            BatchError::Operation {
+                index: 0,
+                source: BatchOperationError::UnknownReference(_),
+            }
+        ));
+    }
+}
@@ -0,0 +1,7 @@
+mod error;
+mod handler;
+mod request;
+mod response;
+mod routes;
+
+pub use routes::routes;
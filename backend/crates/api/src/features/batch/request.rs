@@ -0,0 +1,80 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchRequest {
+    pub operations: Vec<BatchOperation>,
+}
+
+/// Block references (`id`, `from`, `to`, `a`, `b`) take either a block id or
+/// the `tempId` of a block created earlier in the same batch.
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub enum BatchOperation {
+    CreateBlock(CreateBlockOperation),
+    UpdateBlock(UpdateBlockOperation),
+    DeleteBlock(DeleteBlockOperation),
+    CreateDirectionalLink(CreateDirectionalLinkOperation),
+    DeleteDirectionalLink(DeleteDirectionalLinkOperation),
+    CreateRelatedLink(CreateRelatedLinkOperation),
+    DeleteRelatedLink(DeleteRelatedLinkOperation),
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBlockOperation {
+    /// Client-side name later operations can use to refer to this block
+    pub temp_id: Option<String>,
+    pub title: String,
+    pub content: String,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBlockOperation {
+    pub id: String,
+    pub title: Option<String>,
+    pub content: Option<String>,
+    /// Version being edited; the operation fails if the block has moved on
+    pub version: Option<i64>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteBlockOperation {
+    pub id: String,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDirectionalLinkOperation {
+    pub from: String,
+    pub to: String,
+    pub kind: Option<String>,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDirectionalLinkOperation {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRelatedLinkOperation {
+    pub a: String,
+    pub b: String,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteRelatedLinkOperation {
+    pub a: String,
+    pub b: String,
+}
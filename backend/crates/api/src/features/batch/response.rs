@@ -0,0 +1,66 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// One result per operation, in request order.
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResponse {
+    pub results: Vec<BatchOperationResult>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub enum BatchOperationResult {
+    CreateBlock(BlockResult),
+    UpdateBlock(BlockResult),
+    DeleteBlock(BlockResult),
+    CreateDirectionalLink(DirectionalLinkResult),
+    DeleteDirectionalLink(DirectionalLinkResult),
+    CreateRelatedLink(RelatedLinkResult),
+    DeleteRelatedLink(RelatedLinkResult),
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_id: Option<String>,
+    pub id: Uuid,
+    /// Version after the operation; absent for deletes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectionalLinkResult {
+    /// Id of the created link; absent for deletes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Uuid>,
+    pub block_from_id: Uuid,
+    pub block_to_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RelatedLinkResult {
+    /// Id of the created link; absent for deletes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Uuid>,
+    pub block_a_id: Uuid,
+    pub block_b_id: Uuid,
+}
+
+impl IntoResponse for BatchResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
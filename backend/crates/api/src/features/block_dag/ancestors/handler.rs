@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, GetBlockAncestorsError},
+    response::GetBlockAncestorsResponse,
+};
+use crate::AppState;
+use storage::query_services::BlockDagQueryService;
+use storage::{Database, repositories::BlockRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/blocks/{id}/ancestors",
+    tag = "block_dag",
+    responses(
+        (status = 200, description = "List of ancestor blocks, nearest first", body = GetBlockAncestorsResponse),
+        (status = 404, description = "Block not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_block_ancestors(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<GetBlockAncestorsResponse, GetBlockAncestorsError> {
+    state
+        .repos
+        .blocks
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(GetBlockAncestorsError::NotFound)?;
+
+    let blocks = state
+        .query_services
+        .block_dag
+        .get_ancestors(id, state.db.pool())
+        .await?;
+
+    let response = GetBlockAncestorsResponse {
+        ancestor_blocks: blocks.into_iter().map(|b| b.into()).collect(),
+    };
+
+    Ok(response)
+}
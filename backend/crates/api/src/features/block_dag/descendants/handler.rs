@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, GetBlockDescendantsError},
+    response::GetBlockDescendantsResponse,
+};
+use crate::AppState;
+use storage::query_services::BlockDagQueryService;
+use storage::{Database, repositories::BlockRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/blocks/{id}/descendants",
+    tag = "block_dag",
+    responses(
+        (status = 200, description = "List of descendant blocks, nearest first", body = GetBlockDescendantsResponse),
+        (status = 404, description = "Block not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_block_descendants(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<GetBlockDescendantsResponse, GetBlockDescendantsError> {
+    state
+        .repos
+        .blocks
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(GetBlockDescendantsError::NotFound)?;
+
+    let blocks = state
+        .query_services
+        .block_dag
+        .get_descendants(id, state.db.pool())
+        .await?;
+
+    let response = GetBlockDescendantsResponse {
+        descendant_blocks: blocks.into_iter().map(|b| b.into()).collect(),
+    };
+
+    Ok(response)
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, GetBlockLeavesError},
+    response::GetBlockLeavesResponse,
+};
+use crate::AppState;
+use storage::query_services::BlockDagQueryService;
+use storage::{Database, repositories::BlockRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/blocks/{id}/leaves",
+    tag = "block_dag",
+    responses(
+        (status = 200, description = "List of leaf blocks below the block", body = GetBlockLeavesResponse),
+        (status = 404, description = "Block not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_block_leaves(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<GetBlockLeavesResponse, GetBlockLeavesError> {
+    state
+        .repos
+        .blocks
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(GetBlockLeavesError::NotFound)?;
+
+    let blocks = state
+        .query_services
+        .block_dag
+        .get_leaves(id, state.db.pool())
+        .await?;
+
+    let response = GetBlockLeavesResponse {
+        leaf_blocks: blocks.into_iter().map(|b| b.into()).collect(),
+    };
+
+    Ok(response)
+}
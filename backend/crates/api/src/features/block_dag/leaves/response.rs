@@ -0,0 +1,45 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use storage::query_services::block_dag_query_service::DagBlockDto;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LeafBlock {
+    pub block_id: Uuid,
+    pub title: String,
+    pub depth: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetBlockLeavesResponse {
+    pub leaf_blocks: Vec<LeafBlock>,
+}
+
+impl From<DagBlockDto> for LeafBlock {
+    fn from(block: DagBlockDto) -> Self {
+        Self {
+            block_id: block.id,
+            title: block.title,
+            depth: block.depth,
+            created_at: block.created_at,
+            updated_at: block.updated_at,
+        }
+    }
+}
+
+impl IntoResponse for GetBlockLeavesResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,9 @@
+mod ancestors;
+mod descendants;
+mod leaves;
+mod paths;
+mod roots;
+
+mod routes;
+
+pub use routes::routes;
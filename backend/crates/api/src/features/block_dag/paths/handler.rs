@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, GetBlockPathsError},
+    request::GetBlockPathsQuery,
+    response::GetBlockPathsResponse,
+};
+use crate::AppState;
+use storage::query_services::BlockDagQueryService;
+use storage::query_services::block_dag_query_service::BlockPathsDto;
+use storage::{Database, repositories::BlockRepository};
+
+const MAX_LIMIT: usize = 1000;
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/blocks/{id}/paths",
+    tag = "block_dag",
+    params(GetBlockPathsQuery),
+    responses(
+        (status = 200, description = "Paths between the two blocks, shortest first", body = GetBlockPathsResponse),
+        (status = 400, description = "Invalid limit", body = ErrorResponse),
+        (status = 404, description = "Block not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_block_paths(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<GetBlockPathsQuery>,
+) -> Result<GetBlockPathsResponse, GetBlockPathsError> {
+    let limit = query.limit.unwrap_or(BlockPathsDto::DEFAULT_LIMIT);
+    if !(1..=MAX_LIMIT).contains(&limit) {
+        return Err(GetBlockPathsError::InputValidation(format!(
+            "limit must be between 1 and {MAX_LIMIT}"
+        )));
+    }
+
+    for block_id in [id, query.to] {
+        state
+            .repos
+            .blocks
+            .get_by_id(block_id, state.db.pool())
+            .await?
+            .ok_or(GetBlockPathsError::NotFound)?;
+    }
+
+    let BlockPathsDto { paths, truncated } = state
+        .query_services
+        .block_dag
+        .get_paths_between(id, query.to, limit, state.db.pool())
+        .await?;
+
+    Ok(GetBlockPathsResponse { paths, truncated })
+}
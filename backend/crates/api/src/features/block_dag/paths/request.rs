@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct GetBlockPathsQuery {
+    /// Descendant block the paths should end at
+    pub to: Uuid,
+    /// Maximum number of paths to return, between 1 and 1000. Defaults to 100
+    pub limit: Option<usize>,
+}
@@ -0,0 +1,22 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetBlockPathsResponse {
+    pub paths: Vec<Vec<Uuid>>,
+    /// More paths exist than `limit` allowed
+    pub truncated: bool,
+}
+
+impl IntoResponse for GetBlockPathsResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,13 @@
+use std::sync::Arc;
+
+use crate::AppState;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(super::ancestors::get_block_ancestors))
+        .routes(routes!(super::descendants::get_block_descendants))
+        .routes(routes!(super::roots::get_block_roots))
+        .routes(routes!(super::leaves::get_block_leaves))
+        .routes(routes!(super::paths::get_block_paths))
+}
@@ -42,6 +42,9 @@ pub async fn create_block_child_link(
         id: link_id,
         block_from_id: id,
         block_to_id,
+        kind: request.kind,
+        label: request.label,
+        weight: request.weight,
     };
 
     let link = state
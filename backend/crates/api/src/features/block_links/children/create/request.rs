@@ -6,4 +6,9 @@ use uuid::Uuid;
 #[serde(rename_all = "camelCase")]
 pub struct CreateBlockChildLinkRequest {
     pub child_block_id: Uuid,
+    /// What the edge means, e.g. "supports", "example-of" or "part-of"
+    pub kind: Option<String>,
+    /// Free-form note on why the blocks are connected
+    pub label: Option<String>,
+    pub weight: Option<f64>,
 }
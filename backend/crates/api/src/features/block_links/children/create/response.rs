@@ -16,6 +16,9 @@ pub struct CreateBlockChildLinkResponse {
     pub id: Uuid,
     pub block_from_id: Uuid,
     pub block_to_id: Uuid,
+    pub kind: Option<String>,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -25,6 +28,9 @@ impl From<BlockDirectionalLink> for CreateBlockChildLinkResponse {
             id: link.id,
             block_from_id: link.block_from_id,
             block_to_id: link.block_to_id,
+            kind: link.kind,
+            label: link.label,
+            weight: link.weight,
             created_at: link.created_at,
         }
     }
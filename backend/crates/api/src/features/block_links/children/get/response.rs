@@ -14,6 +14,9 @@ use storage::query_services::block_link_query_service::LinkedBlockDto;
 pub(crate) struct ChildBlock {
     pub block_id: Uuid,
     pub title: String,
+    pub kind: Option<String>,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -27,6 +30,9 @@ impl From<LinkedBlockDto> for ChildBlock {
         Self {
             block_id: block.block_id,
             title: block.title,
+            kind: block.kind,
+            label: block.label,
+            weight: block.weight,
         }
     }
 }
@@ -1,6 +1,7 @@
 mod create;
 mod delete;
 mod get;
+mod reorder;
 
 mod routes;
 
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::instrument;
+use utoipa;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, ReorderBlockChildLinksError},
+    request::ReorderBlockChildLinksRequest,
+};
+use crate::AppState;
+use storage::Database;
+use storage::repositories::BlockDirectionalLinkRepository;
+
+#[instrument]
+#[utoipa::path(
+    put,
+    path = "/api/blocks/{id}/children/order",
+    tag = "block_links",
+    responses(
+        (status = 204, description = "Children reordered successfully"),
+        (status = 400, description = "Order does not list every child exactly once", body = ErrorResponse),
+        (status = 404, description = "Block not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn reorder_block_child_links(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ReorderBlockChildLinksRequest>,
+) -> Result<StatusCode, ReorderBlockChildLinksError> {
+    state
+        .repos
+        .block_directional_links
+        .reorder_children(id, &request.child_block_ids, state.db.pool())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
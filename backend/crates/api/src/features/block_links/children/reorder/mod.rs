@@ -0,0 +1,5 @@
+mod handler;
+mod error;
+mod request;
+
+pub(crate) use handler::*;
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderBlockChildLinksRequest {
+    /// Every child of the block, in the new order
+    pub child_block_ids: Vec<Uuid>,
+}
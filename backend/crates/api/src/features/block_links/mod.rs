@@ -1,6 +1,7 @@
 mod parents;
 mod children;
 mod related;
+mod reparent;
 
 mod routes;
 
@@ -42,6 +42,8 @@ pub async fn create_block_related_link(
         id: link_id,
         block_a_id: id,
         block_b_id: related_id,
+        label: request.label,
+        weight: request.weight,
     };
 
     let link = state
@@ -6,4 +6,7 @@ use uuid::Uuid;
 #[serde(rename_all = "camelCase")]
 pub struct CreateBlockRelatedLinkRequest {
     pub related_block_id: Uuid,
+    /// Free-form note on why the blocks are connected
+    pub label: Option<String>,
+    pub weight: Option<f64>,
 }
@@ -15,6 +15,8 @@ use domain::blocks::BlockRelatedLink;
 pub struct CreateBlockRelatedLinkResponse {
     pub id: Uuid,
     pub block_ids: [Uuid; 2],
+    pub label: Option<String>,
+    pub weight: Option<f64>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -23,6 +25,8 @@ impl From<BlockRelatedLink> for CreateBlockRelatedLinkResponse {
         Self {
             id: link.id,
             block_ids: [link.block_a_id, link.block_b_id],
+            label: link.label,
+            weight: link.weight,
             created_at: link.created_at,
         }
     }
@@ -14,6 +14,8 @@ use storage::query_services::block_link_query_service::LinkedBlockDto;
 pub(crate) struct RelatedBlock {
     pub block_id: Uuid,
     pub title: String,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -27,6 +29,8 @@ impl From<LinkedBlockDto> for RelatedBlock {
         Self {
             block_id: block.block_id,
             title: block.title,
+            label: block.label,
+            weight: block.weight,
         }
     }
 }
@@ -0,0 +1,50 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::block_directional_link_repository::BlockDirectionalLinkRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum MoveBlockError {
+    #[error(transparent)]
+    Repository(#[from] BlockDirectionalLinkRepositoryError),
+}
+
+impl IntoResponse for MoveBlockError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::Repository(err) => match err {
+                BlockDirectionalLinkRepositoryError::NotFoundByBlocks { .. } => {
+                    (StatusCode::NOT_FOUND, "Link not found".to_string())
+                }
+                BlockDirectionalLinkRepositoryError::BlocksNotFound { .. }
+                | BlockDirectionalLinkRepositoryError::CycleDetected { .. }
+                | BlockDirectionalLinkRepositoryError::AlreadyExists { .. } => {
+                    (StatusCode::BAD_REQUEST, err.to_string())
+                }
+                other => {
+                    error!(error = ?other, "Directional link repository failure");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal server error".to_string(),
+                    )
+                }
+            },
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
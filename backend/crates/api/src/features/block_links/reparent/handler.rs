@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use tracing::instrument;
+use utoipa;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, MoveBlockError},
+    request::MoveBlockRequest,
+    response::MoveBlockResponse,
+};
+use crate::AppState;
+use storage::{Database, repositories::BlockDirectionalLinkRepository};
+
+#[instrument]
+#[utoipa::path(
+    post,
+    path = "/api/blocks/{id}/move",
+    tag = "block_links",
+    responses(
+        (status = 200, description = "Block moved to the new parent", body = MoveBlockResponse),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Block is not a child of the old parent", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+pub async fn move_block(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<MoveBlockRequest>,
+) -> Result<MoveBlockResponse, MoveBlockError> {
+    let link = state
+        .repos
+        .block_directional_links
+        .move_link(id, request.from_parent, request.to_parent, state.db.pool())
+        .await?;
+
+    Ok(link.into())
+}
@@ -0,0 +1,6 @@
+mod handler;
+mod error;
+mod request;
+mod response;
+
+pub(crate) use handler::*;
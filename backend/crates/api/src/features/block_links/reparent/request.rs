@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveBlockRequest {
+    pub from_parent: Uuid,
+    pub to_parent: Uuid,
+}
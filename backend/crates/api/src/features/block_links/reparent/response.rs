@@ -0,0 +1,45 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use domain::blocks::BlockDirectionalLink;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveBlockResponse {
+    pub id: Uuid,
+    pub block_from_id: Uuid,
+    pub block_to_id: Uuid,
+    pub kind: Option<String>,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
+    pub position: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<BlockDirectionalLink> for MoveBlockResponse {
+    fn from(link: BlockDirectionalLink) -> Self {
+        Self {
+            id: link.id,
+            block_from_id: link.block_from_id,
+            block_to_id: link.block_to_id,
+            kind: link.kind,
+            label: link.label,
+            weight: link.weight,
+            position: link.position,
+            created_at: link.created_at,
+        }
+    }
+}
+
+impl IntoResponse for MoveBlockResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
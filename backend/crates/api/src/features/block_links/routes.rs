@@ -1,11 +1,12 @@
 use std::sync::Arc;
 
 use crate::AppState;
-use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::{router::OpenApiRouter, routes};
 
 pub fn routes() -> OpenApiRouter<Arc<AppState>> {
     OpenApiRouter::new()
         .merge(super::parents::routes())
         .merge(super::children::routes())
         .merge(super::related::routes())
+        .routes(routes!(super::reparent::move_block))
 }
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use similar::TextDiff;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, GetBlockRevisionDiffError},
+    request::GetBlockRevisionDiffQuery,
+    response::GetBlockRevisionDiffResponse,
+};
+use crate::AppState;
+use storage::query_services::BlockRevisionQueryService;
+use storage::{Database, repositories::BlockRepository};
+
+const CONTEXT_LINES: usize = 3;
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/blocks/{id}/revisions/diff",
+    tag = "block_revisions",
+    params(GetBlockRevisionDiffQuery),
+    responses(
+        (status = 200, description = "Unified diff between the two revisions", body = GetBlockRevisionDiffResponse),
+        (status = 404, description = "Block or revision not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_block_revision_diff(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<GetBlockRevisionDiffQuery>,
+) -> Result<GetBlockRevisionDiffResponse, GetBlockRevisionDiffError> {
+    state
+        .repos
+        .blocks
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(GetBlockRevisionDiffError::NotFound)?;
+
+    let mut revisions = Vec::with_capacity(2);
+    for revision in [query.from, query.to] {
+        let revision = state
+            .query_services
+            .block_revisions
+            .get_by_revision(id, revision, state.db.pool())
+            .await?
+            .ok_or(GetBlockRevisionDiffError::RevisionNotFound)?;
+        revisions.push(revision);
+    }
+    let (from, to) = (&revisions[0], &revisions[1]);
+
+    let diff = TextDiff::from_lines(&from.content, &to.content)
+        .unified_diff()
+        .context_radius(CONTEXT_LINES)
+        .header(
+            &format!("revision {}", from.revision),
+            &format!("revision {}", to.revision),
+        )
+        .to_string();
+
+    Ok(GetBlockRevisionDiffResponse {
+        from: from.revision,
+        to: to.revision,
+        from_title: from.title.clone(),
+        to_title: to.title.clone(),
+        diff,
+    })
+}
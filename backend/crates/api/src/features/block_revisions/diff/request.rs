@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct GetBlockRevisionDiffQuery {
+    /// Revision to diff from
+    pub from: i64,
+    /// Revision to diff to
+    pub to: i64,
+}
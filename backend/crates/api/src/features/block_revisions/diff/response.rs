@@ -0,0 +1,24 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetBlockRevisionDiffResponse {
+    pub from: i64,
+    pub to: i64,
+    pub from_title: String,
+    pub to_title: String,
+    /// Unified diff of the contents; empty when they are identical
+    pub diff: String,
+}
+
+impl IntoResponse for GetBlockRevisionDiffResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
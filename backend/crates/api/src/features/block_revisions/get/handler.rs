@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, GetBlockRevisionError},
+    response::GetBlockRevisionResponse,
+};
+use crate::AppState;
+use storage::query_services::BlockRevisionQueryService;
+use storage::{Database, repositories::BlockRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/blocks/{id}/revisions/{revision}",
+    tag = "block_revisions",
+    responses(
+        (status = 200, description = "Revision found", body = GetBlockRevisionResponse),
+        (status = 404, description = "Block or revision not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_block_revision(
+    State(state): State<Arc<AppState>>,
+    Path((id, revision)): Path<(Uuid, i64)>,
+) -> Result<GetBlockRevisionResponse, GetBlockRevisionError> {
+    state
+        .repos
+        .blocks
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(GetBlockRevisionError::NotFound)?;
+
+    let revision = state
+        .query_services
+        .block_revisions
+        .get_by_revision(id, revision, state.db.pool())
+        .await?
+        .ok_or(GetBlockRevisionError::RevisionNotFound)?;
+
+    Ok(revision.into())
+}
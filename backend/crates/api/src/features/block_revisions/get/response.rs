@@ -0,0 +1,39 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use storage::query_services::block_revision_query_service::BlockRevisionDto;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetBlockRevisionResponse {
+    pub block_id: Uuid,
+    pub revision: i64,
+    pub title: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<BlockRevisionDto> for GetBlockRevisionResponse {
+    fn from(revision: BlockRevisionDto) -> Self {
+        Self {
+            block_id: revision.block_id,
+            revision: revision.revision,
+            title: revision.title,
+            content: revision.content,
+            created_at: revision.created_at,
+        }
+    }
+}
+
+impl IntoResponse for GetBlockRevisionResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, GetBlockRevisionsError},
+    response::GetBlockRevisionsResponse,
+};
+use crate::AppState;
+use storage::query_services::BlockRevisionQueryService;
+use storage::{Database, repositories::BlockRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/blocks/{id}/revisions",
+    tag = "block_revisions",
+    responses(
+        (status = 200, description = "Revisions of the block, newest first", body = GetBlockRevisionsResponse),
+        (status = 404, description = "Block not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_block_revisions(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<GetBlockRevisionsResponse, GetBlockRevisionsError> {
+    state
+        .repos
+        .blocks
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(GetBlockRevisionsError::NotFound)?;
+
+    let revisions = state
+        .query_services
+        .block_revisions
+        .get_by_block_id(id, state.db.pool())
+        .await?;
+
+    Ok(GetBlockRevisionsResponse {
+        revisions: revisions.into_iter().map(Into::into).collect(),
+    })
+}
@@ -0,0 +1,42 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use storage::query_services::block_revision_query_service::BlockRevisionDto;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BlockRevisionSummary {
+    pub revision: i64,
+    pub title: String,
+    pub content_length: usize,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetBlockRevisionsResponse {
+    pub revisions: Vec<BlockRevisionSummary>,
+}
+
+impl From<BlockRevisionDto> for BlockRevisionSummary {
+    fn from(revision: BlockRevisionDto) -> Self {
+        Self {
+            revision: revision.revision,
+            title: revision.title,
+            content_length: revision.content.chars().count(),
+            created_at: revision.created_at,
+        }
+    }
+}
+
+impl IntoResponse for GetBlockRevisionsResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,8 @@
+mod diff;
+mod get;
+mod list;
+mod restore;
+
+mod routes;
+
+pub use routes::routes;
@@ -0,0 +1,89 @@
+use axum::{
+    Json,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use super::response::RestoreBlockRevisionResponse;
+use crate::features::blocks::etag::format_etag;
+use domain::blocks::Block;
+use storage::query_services::block_revision_query_service::BlockRevisionQueryServiceError;
+use storage::repositories::block_repository::BlockRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+/// Returned with `409`/`412` so the client can merge against the server copy.
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct VersionMismatchResponse {
+    pub error: String,
+    pub current: RestoreBlockRevisionResponse,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum RestoreBlockRevisionError {
+    #[error("{0}")]
+    InputValidation(String),
+
+    #[error("Block not found")]
+    NotFound,
+
+    #[error("Revision not found")]
+    RevisionNotFound,
+
+    #[error("Block version does not match If-Match")]
+    PreconditionFailed(Box<Block>),
+
+    #[error("Block was modified concurrently")]
+    Conflict(Box<Block>),
+
+    #[error(transparent)]
+    BlockRepository(#[from] BlockRepositoryError),
+
+    #[error(transparent)]
+    BlockRevisionQueryService(#[from] BlockRevisionQueryServiceError),
+}
+
+impl IntoResponse for RestoreBlockRevisionError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::InputValidation(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            Self::PreconditionFailed(_) => (StatusCode::PRECONDITION_FAILED, self.to_string()),
+            Self::Conflict(_) => (StatusCode::CONFLICT, self.to_string()),
+            Self::NotFound | Self::RevisionNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::BlockRepository(err) => {
+                error!(error = ?err, "Block repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+            Self::BlockRevisionQueryService(err) => {
+                error!(error = ?err, "Block revision query failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+        };
+
+        match self {
+            Self::PreconditionFailed(current) | Self::Conflict(current) => {
+                let etag = format_etag(current.version);
+                let body = Json(VersionMismatchResponse {
+                    error: msg,
+                    current: (*current).into(),
+                });
+                (status, [(header::ETAG, etag)], body).into_response()
+            }
+            _ => (status, Json(ErrorResponse { error: msg })).into_response(),
+        }
+    }
+}
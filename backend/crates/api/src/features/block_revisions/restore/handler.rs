@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+};
+use chrono::Utc;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, RestoreBlockRevisionError, VersionMismatchResponse},
+    response::RestoreBlockRevisionResponse,
+};
+use crate::AppState;
+use crate::features::blocks::etag::IfMatch;
+use storage::query_services::BlockRevisionQueryService;
+use storage::{Database, repositories::BlockRepository};
+
+/// Copies an old revision back onto the block. The restore is saved like any
+/// other edit, so it becomes the newest revision and can itself be undone.
+/// `If-Match` and concurrent writes are handled as in `update_block`.
+#[instrument]
+#[utoipa::path(
+    post,
+    path = "/api/blocks/{id}/revisions/{revision}/restore",
+    tag = "block_revisions",
+    params(
+        ("If-Match" = Option<String>, Header, description = "ETag of the version being replaced")
+    ),
+    responses(
+        (status = 200, description = "Block restored to the revision", body = RestoreBlockRevisionResponse),
+        (status = 400, description = "Invalid If-Match header", body = ErrorResponse),
+        (status = 404, description = "Block or revision not found", body = ErrorResponse),
+        (status = 409, description = "Block was modified concurrently", body = VersionMismatchResponse),
+        (status = 412, description = "If-Match does not match the current version", body = VersionMismatchResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn restore_block_revision(
+    State(state): State<Arc<AppState>>,
+    Path((id, revision)): Path<(Uuid, i64)>,
+    headers: HeaderMap,
+) -> Result<RestoreBlockRevisionResponse, RestoreBlockRevisionError> {
+    let if_match =
+        IfMatch::from_headers(&headers).map_err(RestoreBlockRevisionError::InputValidation)?;
+
+    let mut block = state
+        .repos
+        .blocks
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(RestoreBlockRevisionError::NotFound)?;
+
+    if let Some(if_match) = if_match
+        && !if_match.matches(block.version)
+    {
+        return Err(RestoreBlockRevisionError::PreconditionFailed(Box::new(
+            block,
+        )));
+    }
+
+    let revision = state
+        .query_services
+        .block_revisions
+        .get_by_revision(id, revision, state.db.pool())
+        .await?
+        .ok_or(RestoreBlockRevisionError::RevisionNotFound)?;
+
+    let expected_version = block.version;
+    block.title = revision.title;
+    block.content = revision.content;
+    block.updated_at = Utc::now();
+
+    let Some(restored) = state
+        .repos
+        .blocks
+        .update_if_version(&block, expected_version, state.db.pool())
+        .await?
+    else {
+        let current = state
+            .repos
+            .blocks
+            .get_by_id(id, state.db.pool())
+            .await?
+            .ok_or(RestoreBlockRevisionError::NotFound)?;
+        return Err(RestoreBlockRevisionError::Conflict(Box::new(current)));
+    };
+
+    Ok(restored.into())
+}
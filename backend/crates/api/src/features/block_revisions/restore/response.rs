@@ -0,0 +1,41 @@
+use axum::{
+    Json,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::features::blocks::etag::format_etag;
+use domain::blocks::Block;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RestoreBlockRevisionResponse {
+    pub id: Uuid,
+    pub title: String,
+    pub content: String,
+    pub updated_at: DateTime<Utc>,
+    pub version: i64,
+}
+
+impl From<Block> for RestoreBlockRevisionResponse {
+    fn from(block: Block) -> Self {
+        Self {
+            id: block.id,
+            title: block.title,
+            content: block.content,
+            updated_at: block.updated_at,
+            version: block.version,
+        }
+    }
+}
+
+impl IntoResponse for RestoreBlockRevisionResponse {
+    fn into_response(self) -> Response {
+        let etag = format_etag(self.version);
+        (StatusCode::OK, [(header::ETAG, etag)], Json(self)).into_response()
+    }
+}
@@ -0,0 +1,12 @@
+use std::sync::Arc;
+
+use crate::AppState;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(super::list::get_block_revisions))
+        .routes(routes!(super::diff::get_block_revision_diff))
+        .routes(routes!(super::get::get_block_revision))
+        .routes(routes!(super::restore::restore_block_revision))
+}
@@ -0,0 +1,61 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::block_repository::BlockRepositoryError;
+use storage::repositories::tag_repository::TagRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum TagBlockError {
+    #[error("Validation failed: {0}")]
+    InputValidation(String),
+
+    #[error("Block not found")]
+    NotFound,
+
+    #[error(transparent)]
+    BlockRepository(#[from] BlockRepositoryError),
+
+    #[error(transparent)]
+    TagRepository(#[from] TagRepositoryError),
+}
+
+impl IntoResponse for TagBlockError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::InputValidation(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            Self::NotFound | Self::TagRepository(TagRepositoryError::BlockNotFound { .. }) => {
+                (StatusCode::NOT_FOUND, "Block not found".to_string())
+            }
+            Self::BlockRepository(err) => {
+                error!(error = ?err, "Block repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+            Self::TagRepository(err) => {
+                error!(error = ?err, "Tag repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
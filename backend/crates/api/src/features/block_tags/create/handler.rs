@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, TagBlockError},
+    request::TagBlockRequest,
+    response::TagBlockResponse,
+};
+use crate::AppState;
+use domain::tags::Tag;
+use storage::Database;
+use storage::repositories::{BlockRepository, TagRepository};
+
+#[instrument]
+#[utoipa::path(
+    post,
+    path = "/api/blocks/{id}/tags",
+    tag = "tags",
+    request_body = TagBlockRequest,
+    responses(
+        (status = 201, description = "Tag added to the block", body = TagBlockResponse),
+        (status = 400, description = "Invalid tag name", body = ErrorResponse),
+        (status = 404, description = "Block not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn tag_block(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<TagBlockRequest>,
+) -> Result<TagBlockResponse, TagBlockError> {
+    let tag =
+        Tag::new(&request.name).map_err(|err| TagBlockError::InputValidation(err.to_string()))?;
+
+    state
+        .repos
+        .blocks
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(TagBlockError::NotFound)?;
+
+    let tag = state
+        .repos
+        .tags
+        .tag_block(id, &tag, state.db.pool())
+        .await?;
+
+    let response: TagBlockResponse = tag.into();
+
+    Ok(response)
+}
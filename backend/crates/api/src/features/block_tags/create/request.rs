@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TagBlockRequest {
+    /// Name of the tag. A tag with this name is created if none exists yet
+    pub name: String,
+}
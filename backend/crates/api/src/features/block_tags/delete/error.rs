@@ -0,0 +1,46 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::tag_repository::TagRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum UntagBlockError {
+    #[error(transparent)]
+    TagRepository(#[from] TagRepositoryError),
+}
+
+impl IntoResponse for UntagBlockError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::TagRepository(err) => match err {
+                TagRepositoryError::NotTagged { .. } => (
+                    StatusCode::NOT_FOUND,
+                    "Block does not have this tag".to_string(),
+                ),
+                other => {
+                    error!(error = ?other, "Tag repository failure");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal server error".to_string(),
+                    )
+                }
+            },
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
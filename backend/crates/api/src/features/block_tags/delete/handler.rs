@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::error::{ErrorResponse, UntagBlockError};
+use crate::AppState;
+use storage::{Database, repositories::TagRepository};
+
+#[instrument]
+#[utoipa::path(
+    delete,
+    path = "/api/blocks/{id}/tags/{tag_id}",
+    tag = "tags",
+    responses(
+        (status = 204, description = "Tag removed from the block"),
+        (status = 404, description = "Block does not have this tag", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn untag_block(
+    State(state): State<Arc<AppState>>,
+    Path((id, tag_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, UntagBlockError> {
+    state
+        .repos
+        .tags
+        .untag_block(id, tag_id, state.db.pool())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,6 @@
+mod create;
+mod delete;
+
+mod routes;
+
+pub use routes::routes;
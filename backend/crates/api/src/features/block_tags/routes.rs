@@ -0,0 +1,10 @@
+use std::sync::Arc;
+
+use crate::AppState;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(super::create::tag_block))
+        .routes(routes!(super::delete::untag_block))
+}
@@ -23,6 +23,7 @@ impl From<CreateBlockRequest> for Block {
             content: request.content,
             created_at: now,
             updated_at: now,
+            version: 1,
         }
     }
 }
@@ -1,6 +1,6 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{StatusCode, header},
     response::{IntoResponse, Response},
 };
 use chrono::{DateTime, Utc};
@@ -8,6 +8,7 @@ use serde::Serialize;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::features::blocks::etag::format_etag;
 use domain::blocks::Block;
 
 #[derive(Serialize, ToSchema)]
@@ -17,6 +18,7 @@ pub struct CreateBlockResponse {
     pub title: String,
     pub content: String,
     pub created_at: DateTime<Utc>,
+    pub version: i64,
 }
 
 impl From<Block> for CreateBlockResponse {
@@ -26,12 +28,14 @@ impl From<Block> for CreateBlockResponse {
             title: block.title,
             content: block.content,
             created_at: block.created_at,
+            version: block.version,
         }
     }
 }
 
 impl IntoResponse for CreateBlockResponse {
     fn into_response(self) -> Response {
-        (StatusCode::CREATED, Json(self)).into_response()
+        let etag = format_etag(self.version);
+        (StatusCode::CREATED, [(header::ETAG, etag)], Json(self)).into_response()
     }
 }
@@ -11,12 +11,14 @@ use super::error::DeleteBlockError;
 use crate::AppState;
 use storage::{Database, repositories::BlockRepository};
 
+/// Moves the block to the trash. It keeps its links and can be restored until
+/// it is purged.
 #[utoipa::path(
       delete,
       path = "/api/blocks/{id}",
       tag = "blocks",
     responses(
-        (status = 204, description = "Block deleted successfully"),
+        (status = 204, description = "Block moved to the trash"),
         (status = 404, description = "Block not found"),
         (status = 500, description = "Internal server error")
     )
@@ -26,7 +28,7 @@ pub async fn delete_block(
     State(state): State<Arc<AppState>>,
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, DeleteBlockError> {
-    state.repos.blocks.delete_by_id(id, state.db.pool()).await?;
+    state.repos.blocks.trash(id, state.db.pool()).await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -0,0 +1,95 @@
+use axum::http::{HeaderMap, HeaderValue, header};
+
+/// Formats a block version as a strong entity tag, e.g. `"3"`.
+pub(crate) fn format_etag(version: i64) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{version}\"")).expect("etag is always valid ASCII")
+}
+
+/// Parsed `If-Match` request header.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum IfMatch {
+    Any,
+    Versions(Vec<i64>),
+}
+
+impl IfMatch {
+    /// Reads `If-Match` from the request headers. Returns `Ok(None)` when the
+    /// header is absent and `Err` when it is present but not a list of
+    /// block versions. If-Match uses strong comparison (RFC 9110 §13.1.1), so
+    /// weak tags such as `W/"3"` are accepted but never match.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<Option<Self>, String> {
+        let Some(value) = headers.get(header::IF_MATCH) else {
+            return Ok(None);
+        };
+        let value = value
+            .to_str()
+            .map_err(|_| "If-Match header must be ASCII".to_string())?
+            .trim();
+
+        if value == "*" {
+            return Ok(Some(Self::Any));
+        }
+
+        let mut versions = Vec::new();
+        for tag in value.split(',').map(str::trim) {
+            let (weak, tag) = match tag.strip_prefix("W/") {
+                Some(tag) => (true, tag),
+                None => (false, tag),
+            };
+            let version =
+                parse_version(tag).ok_or_else(|| format!("Invalid If-Match header: {value}"))?;
+            if !weak {
+                versions.push(version);
+            }
+        }
+
+        Ok(Some(Self::Versions(versions)))
+    }
+
+    pub(crate) fn matches(&self, version: i64) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Versions(versions) => versions.contains(&version),
+        }
+    }
+}
+
+fn parse_version(tag: &str) -> Option<i64> {
+    tag.strip_prefix('"')?.strip_suffix('"')?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(value: &'static str) -> Result<Option<IfMatch>, String> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MATCH, HeaderValue::from_static(value));
+        IfMatch::from_headers(&headers)
+    }
+
+    #[test]
+    fn strong_tags_match_their_version() {
+        let if_match = parse(r#""2", "3""#).unwrap().unwrap();
+
+        assert!(if_match.matches(3));
+        assert!(!if_match.matches(4));
+    }
+
+    #[test]
+    fn weak_tags_never_match() {
+        let if_match = parse(r#"W/"3""#).unwrap().unwrap();
+        assert!(!if_match.matches(3));
+
+        let if_match = parse(r#"W/"3", "4""#).unwrap().unwrap();
+        assert!(!if_match.matches(3));
+        assert!(if_match.matches(4));
+    }
+
+    #[test]
+    fn rejects_malformed_tags() {
+        assert!(parse("3").is_err());
+        assert!(parse(r#"W/3"#).is_err());
+        assert_eq!(parse("*").unwrap(), Some(IfMatch::Any));
+    }
+}
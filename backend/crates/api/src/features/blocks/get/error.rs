@@ -8,7 +8,9 @@ use tracing::error;
 use utoipa::ToSchema;
 
 use storage::query_services::block_link_query_service::BlockLinkQueryServiceError;
+use storage::repositories::block_pin_repository::BlockPinRepositoryError;
 use storage::repositories::block_repository::BlockRepositoryError;
+use storage::repositories::tag_repository::TagRepositoryError;
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -26,6 +28,12 @@ pub(crate) enum GetBlockError {
 
     #[error(transparent)]
     BlockLinkQueryService(#[from] BlockLinkQueryServiceError),
+
+    #[error(transparent)]
+    BlockPinRepository(#[from] BlockPinRepositoryError),
+
+    #[error(transparent)]
+    TagRepository(#[from] TagRepositoryError),
 }
 
 impl IntoResponse for GetBlockError {
@@ -46,6 +54,20 @@ impl IntoResponse for GetBlockError {
                     "Internal server error".to_string(),
                 )
             }
+            Self::BlockPinRepository(err) => {
+                error!(error = ?err, "Block pin repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+            Self::TagRepository(err) => {
+                error!(error = ?err, "Tag repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
         };
 
         let body = Json(ErrorResponse { error: msg });
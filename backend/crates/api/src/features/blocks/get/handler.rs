@@ -7,7 +7,8 @@ use uuid::Uuid;
 use super::{error::GetBlockError, response::GetBlockResponse};
 use crate::AppState;
 use storage::query_services::BlockLinkQueryService;
-use storage::{Database, repositories::BlockRepository};
+use storage::Database;
+use storage::repositories::{BlockPinRepository, BlockRepository, TagRepository};
 
 #[instrument]
 #[utoipa::path(
@@ -37,7 +38,15 @@ pub async fn get_block(
         .get_linked_blocks(id, state.db.pool())
         .await?;
 
-    let response = GetBlockResponse::from_block_and_linked(block, linked_blocks);
+    let is_pinned = state
+        .repos
+        .block_pins
+        .is_pinned(id, state.db.pool())
+        .await?;
+
+    let tags = state.repos.tags.get_by_block(id, state.db.pool()).await?;
+
+    let response = GetBlockResponse::from_block_and_linked(block, linked_blocks, is_pinned, tags);
 
     Ok(response)
 }
@@ -1,21 +1,33 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{StatusCode, header},
     response::{IntoResponse, Response},
 };
 use serde::Serialize;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::features::blocks::etag::format_etag;
 use domain::blocks::Block;
+use domain::tags::Tag;
 use storage::query_services::block_link_query_service::{AllLinkedBlocksDto, LinkedBlockDto};
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct LinkedBlock {
+pub struct LinkedBlock {
     pub block_id: Uuid,
     pub link_id: Uuid,
     pub title: String,
+    pub kind: Option<String>,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTag {
+    pub id: Uuid,
+    pub name: String,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -27,6 +39,9 @@ pub struct GetBlockResponse {
     pub parent_blocks: Vec<LinkedBlock>,
     pub child_blocks: Vec<LinkedBlock>,
     pub related_blocks: Vec<LinkedBlock>,
+    pub is_pinned: bool,
+    pub tags: Vec<BlockTag>,
+    pub version: i64,
 }
 
 impl From<LinkedBlockDto> for LinkedBlock {
@@ -35,12 +50,29 @@ impl From<LinkedBlockDto> for LinkedBlock {
             block_id: dto.block_id,
             link_id: dto.link_id,
             title: dto.title,
+            kind: dto.kind,
+            label: dto.label,
+            weight: dto.weight,
+        }
+    }
+}
+
+impl From<Tag> for BlockTag {
+    fn from(tag: Tag) -> Self {
+        Self {
+            id: tag.id,
+            name: tag.name,
         }
     }
 }
 
 impl GetBlockResponse {
-    pub fn from_block_and_linked(block: Block, linked_blocks: AllLinkedBlocksDto) -> Self {
+    pub fn from_block_and_linked(
+        block: Block,
+        linked_blocks: AllLinkedBlocksDto,
+        is_pinned: bool,
+        tags: Vec<Tag>,
+    ) -> Self {
         let parent_blocks: Vec<LinkedBlock> = linked_blocks
             .parent_blocks
             .into_iter()
@@ -64,12 +96,16 @@ impl GetBlockResponse {
             parent_blocks,
             child_blocks,
             related_blocks,
+            is_pinned,
+            tags: tags.into_iter().map(|t| t.into()).collect(),
+            version: block.version,
         }
     }
 }
 
 impl IntoResponse for GetBlockResponse {
     fn into_response(self) -> Response {
-        (StatusCode::OK, Json(self)).into_response()
+        let etag = format_etag(self.version);
+        (StatusCode::OK, [(header::ETAG, etag)], Json(self)).into_response()
     }
 }
@@ -1,5 +1,6 @@
 mod create;
 mod delete;
+pub(crate) mod etag;
 mod get;
 mod update;
 
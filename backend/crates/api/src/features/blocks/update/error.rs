@@ -1,12 +1,15 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{StatusCode, header},
     response::{IntoResponse, Response},
 };
 use serde::Serialize;
 use tracing::error;
 use utoipa::ToSchema;
 
+use super::response::UpdateBlockResponse;
+use crate::features::blocks::etag::format_etag;
+use domain::blocks::Block;
 use storage::repositories::block_repository::BlockRepositoryError;
 
 #[derive(Serialize, ToSchema)]
@@ -15,11 +18,28 @@ pub(crate) struct ErrorResponse {
     pub error: String,
 }
 
+/// Returned with `409`/`412` so the client can merge against the server copy.
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct VersionMismatchResponse {
+    pub error: String,
+    pub current: UpdateBlockResponse,
+}
+
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum UpdateBlockError {
+    #[error("{0}")]
+    InputValidation(String),
+
     #[error("Block not found")]
     NotFound,
 
+    #[error("Block version does not match If-Match")]
+    PreconditionFailed(Box<Block>),
+
+    #[error("Block was modified concurrently")]
+    Conflict(Box<Block>),
+
     #[error(transparent)]
     BlockRepository(#[from] BlockRepositoryError),
 }
@@ -27,7 +47,10 @@ pub(crate) enum UpdateBlockError {
 impl IntoResponse for UpdateBlockError {
     fn into_response(self) -> Response {
         let (status, msg) = match &self {
+            Self::InputValidation(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
             Self::NotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::PreconditionFailed(_) => (StatusCode::PRECONDITION_FAILED, self.to_string()),
+            Self::Conflict(_) => (StatusCode::CONFLICT, self.to_string()),
             Self::BlockRepository(err) => {
                 error!(error = ?err, "Block repository failure");
                 (
@@ -37,8 +60,16 @@ impl IntoResponse for UpdateBlockError {
             }
         };
 
-        let body = Json(ErrorResponse { error: msg });
-
-        (status, body).into_response()
+        match self {
+            Self::PreconditionFailed(current) | Self::Conflict(current) => {
+                let etag = format_etag(current.version);
+                let body = Json(VersionMismatchResponse {
+                    error: msg,
+                    current: (*current).into(),
+                });
+                (status, [(header::ETAG, etag)], body).into_response()
+            }
+            _ => (status, Json(ErrorResponse { error: msg })).into_response(),
+        }
     }
 }
@@ -3,23 +3,38 @@ use std::sync::Arc;
 use axum::{
     Json,
     extract::{Path, State},
+    http::HeaderMap,
 };
 use chrono::Utc;
 use tracing::instrument;
 use uuid::Uuid;
 
-use super::{error::UpdateBlockError, request::UpdateBlockRequest, response::UpdateBlockResponse};
+use super::{
+    error::{UpdateBlockError, VersionMismatchResponse},
+    request::UpdateBlockRequest,
+    response::UpdateBlockResponse,
+};
 use crate::AppState;
+use crate::features::blocks::etag::IfMatch;
 use storage::{Database, repositories::BlockRepository};
 
+/// Updates a block. When `If-Match` is sent and does not name the current
+/// version the update is rejected with `412`; a concurrent write between the
+/// read and the update is reported as `409`. Both carry the server copy.
 #[utoipa::path(
       put,
       path = "/api/blocks/{id}",
       tag = "blocks",
       request_body = UpdateBlockRequest,
+      params(
+          ("If-Match" = Option<String>, Header, description = "ETag of the version being edited")
+      ),
     responses(
         (status = 200, description = "Block updated successfully", body = UpdateBlockResponse),
+        (status = 400, description = "Invalid If-Match header"),
         (status = 404, description = "Block not found"),
+        (status = 409, description = "Block was modified concurrently", body = VersionMismatchResponse),
+        (status = 412, description = "If-Match does not match the current version", body = VersionMismatchResponse),
         (status = 500, description = "Internal server error")
     )
 )]
@@ -27,8 +42,11 @@ use storage::{Database, repositories::BlockRepository};
 pub async fn update_block(
     State(state): State<Arc<AppState>>,
     Path(id): Path<Uuid>,
+    headers: HeaderMap,
     Json(request): Json<UpdateBlockRequest>,
 ) -> Result<UpdateBlockResponse, UpdateBlockError> {
+    let if_match = IfMatch::from_headers(&headers).map_err(UpdateBlockError::InputValidation)?;
+
     let mut block = state
         .repos
         .blocks
@@ -36,6 +54,13 @@ pub async fn update_block(
         .await?
         .ok_or(UpdateBlockError::NotFound)?;
 
+    if let Some(if_match) = if_match
+        && !if_match.matches(block.version)
+    {
+        return Err(UpdateBlockError::PreconditionFailed(Box::new(block)));
+    }
+
+    let expected_version = block.version;
     if let Some(title) = request.title {
         block.title = title;
     }
@@ -44,9 +69,69 @@ pub async fn update_block(
     }
     block.updated_at = Utc::now();
 
-    state.repos.blocks.save(&block, state.db.pool()).await?;
+    let Some(updated) = state
+        .repos
+        .blocks
+        .update_if_version(&block, expected_version, state.db.pool())
+        .await?
+    else {
+        let current = state
+            .repos
+            .blocks
+            .get_by_id(id, state.db.pool())
+            .await?
+            .ok_or(UpdateBlockError::NotFound)?;
+        return Err(UpdateBlockError::Conflict(Box::new(current)));
+    };
 
-    let response: UpdateBlockResponse = block.into();
+    let response: UpdateBlockResponse = updated.into();
 
     Ok(response)
 }
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use axum::http::{HeaderValue, StatusCode, header};
+    use axum::response::IntoResponse;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test_utils::{TestApp, app};
+    use domain::blocks::Block;
+
+    async fn update_with_if_match(
+        app: &TestApp,
+        id: Uuid,
+        if_match: &'static str,
+    ) -> Result<UpdateBlockResponse, UpdateBlockError> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MATCH, HeaderValue::from_static(if_match));
+        let request = UpdateBlockRequest {
+            title: Some("Renamed".to_string()),
+            content: None,
+        };
+
+        update_block(State(app.state()), Path(id), headers, Json(request)).await
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn weak_if_match_is_rejected_with_412(#[future] app: TestApp) {
+        let app = app.await;
+        let block = Block::new("Title", "");
+        app.repos.blocks.save(&block, app.db.pool()).await.unwrap();
+
+        let Err(error) = update_with_if_match(&app, block.id, r#"W/"1""#).await else {
+            panic!("a weak validator must not match");
+        };
+        assert_eq!(
+            error.into_response().status(),
+            StatusCode::PRECONDITION_FAILED
+        );
+
+        let updated = update_with_if_match(&app, block.id, r#""1""#)
+            .await
+            .expect("the strong validator should match");
+        assert_eq!(updated.version, 2);
+    }
+}
@@ -1,6 +1,6 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{StatusCode, header},
     response::{IntoResponse, Response},
 };
 use chrono::{DateTime, Utc};
@@ -8,6 +8,7 @@ use serde::Serialize;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::features::blocks::etag::format_etag;
 use domain::blocks::Block;
 
 #[derive(Serialize, ToSchema)]
@@ -17,6 +18,7 @@ pub struct UpdateBlockResponse {
     pub title: String,
     pub content: String,
     pub updated_at: DateTime<Utc>,
+    pub version: i64,
 }
 
 impl From<Block> for UpdateBlockResponse {
@@ -26,12 +28,14 @@ impl From<Block> for UpdateBlockResponse {
             title: block.title,
             content: block.content,
             updated_at: block.updated_at,
+            version: block.version,
         }
     }
 }
 
 impl IntoResponse for UpdateBlockResponse {
     fn into_response(self) -> Response {
-        (StatusCode::OK, Json(self)).into_response()
+        let etag = format_etag(self.version);
+        (StatusCode::OK, [(header::ETAG, etag)], Json(self)).into_response()
     }
 }
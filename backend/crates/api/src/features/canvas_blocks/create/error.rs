@@ -0,0 +1,78 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::block_repository::BlockRepositoryError;
+use storage::repositories::canvas_block_repository::CanvasBlockRepositoryError;
+use storage::repositories::canvas_repository::CanvasRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum CreateCanvasBlockError {
+    #[error("Validation failed: {0}")]
+    InputValidation(String),
+
+    #[error("Canvas not found")]
+    CanvasNotFound,
+
+    #[error("Block not found")]
+    BlockNotFound,
+
+    #[error("Block is already placed on this canvas")]
+    AlreadyPlaced,
+
+    #[error(transparent)]
+    CanvasRepository(#[from] CanvasRepositoryError),
+
+    #[error(transparent)]
+    BlockRepository(#[from] BlockRepositoryError),
+
+    #[error(transparent)]
+    CanvasBlockRepository(#[from] CanvasBlockRepositoryError),
+}
+
+impl IntoResponse for CreateCanvasBlockError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::InputValidation(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            Self::CanvasNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::BlockNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::AlreadyPlaced => (StatusCode::CONFLICT, self.to_string()),
+            Self::CanvasRepository(err) => {
+                error!(error = ?err, "Canvas repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+            Self::BlockRepository(err) => {
+                error!(error = ?err, "Block repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+            Self::CanvasBlockRepository(err) => {
+                error!(error = ?err, "Canvas block repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
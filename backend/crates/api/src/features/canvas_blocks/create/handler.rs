@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{CreateCanvasBlockError, ErrorResponse},
+    request::CreateCanvasBlockRequest,
+    response::CreateCanvasBlockResponse,
+};
+use crate::AppState;
+use domain::canvases::CanvasBlock;
+use storage::Database;
+use storage::repositories::{BlockRepository, CanvasBlockRepository, CanvasRepository};
+
+#[instrument]
+#[utoipa::path(
+    post,
+    path = "/api/canvases/{id}/blocks",
+    tag = "canvas_blocks",
+    request_body = CreateCanvasBlockRequest,
+    responses(
+        (status = 201, description = "Block placed on the canvas", body = CreateCanvasBlockResponse),
+        (status = 400, description = "Invalid placement", body = ErrorResponse),
+        (status = 404, description = "Canvas or block not found", body = ErrorResponse),
+        (status = 409, description = "Block is already placed on the canvas", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn create_canvas_block(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<CreateCanvasBlockRequest>,
+) -> Result<CreateCanvasBlockResponse, CreateCanvasBlockError> {
+    let mut canvas_block = CanvasBlock::new(
+        id,
+        request.block_id,
+        request.grid_x,
+        request.grid_y,
+        request.grid_width,
+        request.grid_height,
+    );
+    if let Some(z) = request.z {
+        canvas_block.z = z;
+    }
+    if let Some(scale) = request.scale {
+        canvas_block.scale = scale;
+    }
+    if let Some(content_visible) = request.content_visible {
+        canvas_block.content_visible = content_visible;
+    }
+    canvas_block
+        .validate()
+        .map_err(|err| CreateCanvasBlockError::InputValidation(err.to_string()))?;
+
+    state
+        .repos
+        .canvases
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(CreateCanvasBlockError::CanvasNotFound)?;
+
+    state
+        .repos
+        .blocks
+        .get_by_id(request.block_id, state.db.pool())
+        .await?
+        .ok_or(CreateCanvasBlockError::BlockNotFound)?;
+
+    let existing = state
+        .repos
+        .canvas_blocks
+        .get_by_canvas_and_block(id, request.block_id, state.db.pool())
+        .await?;
+    if existing.is_some() {
+        return Err(CreateCanvasBlockError::AlreadyPlaced);
+    }
+
+    state
+        .repos
+        .canvas_blocks
+        .save(&canvas_block, state.db.pool())
+        .await?;
+
+    let response: CreateCanvasBlockResponse = canvas_block.into();
+
+    Ok(response)
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCanvasBlockRequest {
+    pub block_id: Uuid,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub grid_width: i32,
+    pub grid_height: i32,
+    pub z: Option<i32>,
+    pub scale: Option<f64>,
+    pub content_visible: Option<bool>,
+}
@@ -0,0 +1,53 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use domain::canvases::CanvasBlock;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCanvasBlockResponse {
+    pub id: Uuid,
+    pub canvas_id: Uuid,
+    pub block_id: Uuid,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub grid_width: i32,
+    pub grid_height: i32,
+    pub z: i32,
+    pub scale: f64,
+    pub content_visible: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<CanvasBlock> for CreateCanvasBlockResponse {
+    fn from(canvas_block: CanvasBlock) -> Self {
+        Self {
+            id: canvas_block.id,
+            canvas_id: canvas_block.canvas_id,
+            block_id: canvas_block.block_id,
+            grid_x: canvas_block.grid_x,
+            grid_y: canvas_block.grid_y,
+            grid_width: canvas_block.grid_width,
+            grid_height: canvas_block.grid_height,
+            z: canvas_block.z,
+            scale: canvas_block.scale,
+            content_visible: canvas_block.content_visible,
+            created_at: canvas_block.created_at,
+            updated_at: canvas_block.updated_at,
+        }
+    }
+}
+
+impl IntoResponse for CreateCanvasBlockResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::CREATED, Json(self)).into_response()
+    }
+}
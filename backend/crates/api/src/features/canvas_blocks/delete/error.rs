@@ -0,0 +1,46 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::canvas_block_repository::CanvasBlockRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum DeleteCanvasBlockError {
+    #[error(transparent)]
+    CanvasBlockRepository(#[from] CanvasBlockRepositoryError),
+}
+
+impl IntoResponse for DeleteCanvasBlockError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::CanvasBlockRepository(err) => match err {
+                CanvasBlockRepositoryError::NotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    "Block is not placed on this canvas".to_string(),
+                ),
+                other => {
+                    error!(error = ?other, "Canvas block repository failure");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal server error".to_string(),
+                    )
+                }
+            },
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::error::{DeleteCanvasBlockError, ErrorResponse};
+use crate::AppState;
+use storage::{Database, repositories::CanvasBlockRepository};
+
+#[instrument]
+#[utoipa::path(
+    delete,
+    path = "/api/canvases/{id}/blocks/{block_id}",
+    tag = "canvas_blocks",
+    responses(
+        (status = 204, description = "Block removed from the canvas"),
+        (status = 404, description = "Block is not placed on the canvas", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn delete_canvas_block(
+    State(state): State<Arc<AppState>>,
+    Path((id, block_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, DeleteCanvasBlockError> {
+    state
+        .repos
+        .canvas_blocks
+        .delete(id, block_id, state.db.pool())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,4 @@
+mod error;
+mod handler;
+
+pub(crate) use handler::*;
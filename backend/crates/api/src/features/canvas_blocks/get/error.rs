@@ -0,0 +1,55 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::canvas_block_repository::CanvasBlockRepositoryError;
+use storage::repositories::canvas_repository::CanvasRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum GetCanvasBlocksError {
+    #[error("Canvas not found")]
+    NotFound,
+
+    #[error(transparent)]
+    CanvasRepository(#[from] CanvasRepositoryError),
+
+    #[error(transparent)]
+    CanvasBlockRepository(#[from] CanvasBlockRepositoryError),
+}
+
+impl IntoResponse for GetCanvasBlocksError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::NotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::CanvasRepository(err) => {
+                error!(error = ?err, "Canvas repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+            Self::CanvasBlockRepository(err) => {
+                error!(error = ?err, "Canvas block repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, GetCanvasBlocksError},
+    response::GetCanvasBlocksResponse,
+};
+use crate::AppState;
+use storage::Database;
+use storage::repositories::{CanvasBlockRepository, CanvasRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/canvases/{id}/blocks",
+    tag = "canvas_blocks",
+    responses(
+        (status = 200, description = "Blocks placed on the canvas, lowest z first", body = GetCanvasBlocksResponse),
+        (status = 404, description = "Canvas not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_canvas_blocks(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<GetCanvasBlocksResponse, GetCanvasBlocksError> {
+    state
+        .repos
+        .canvases
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(GetCanvasBlocksError::NotFound)?;
+
+    let canvas_blocks = state
+        .repos
+        .canvas_blocks
+        .get_by_canvas(id, state.db.pool())
+        .await?;
+
+    let response: GetCanvasBlocksResponse = canvas_blocks.into();
+
+    Ok(response)
+}
@@ -0,0 +1,111 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use chrono::Utc;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ApplyCanvasLayoutError, ErrorResponse},
+    request::ApplyCanvasLayoutRequest,
+    response::ApplyCanvasLayoutResponse,
+};
+use crate::AppState;
+use storage::Database;
+use storage::repositories::{CanvasBlockRepository, CanvasRepository};
+
+#[instrument]
+#[utoipa::path(
+    put,
+    path = "/api/canvases/{id}/layout",
+    tag = "canvas_blocks",
+    request_body = ApplyCanvasLayoutRequest,
+    responses(
+        (status = 200, description = "Layout applied; returns every placement on the canvas", body = ApplyCanvasLayoutResponse),
+        (status = 400, description = "Invalid or duplicate placement", body = ErrorResponse),
+        (status = 404, description = "Canvas not found or block not placed on it", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn apply_canvas_layout(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ApplyCanvasLayoutRequest>,
+) -> Result<ApplyCanvasLayoutResponse, ApplyCanvasLayoutError> {
+    let mut seen = HashSet::with_capacity(request.placements.len());
+    if let Some(placement) = request
+        .placements
+        .iter()
+        .find(|placement| !seen.insert(placement.block_id))
+    {
+        return Err(ApplyCanvasLayoutError::InputValidation(format!(
+            "block {} is listed more than once",
+            placement.block_id
+        )));
+    }
+
+    state
+        .repos
+        .canvases
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(ApplyCanvasLayoutError::CanvasNotFound)?;
+
+    let mut placed: HashMap<Uuid, _> = state
+        .repos
+        .canvas_blocks
+        .get_by_canvas(id, state.db.pool())
+        .await?
+        .into_iter()
+        .map(|canvas_block| (canvas_block.block_id, canvas_block))
+        .collect();
+
+    let now = Utc::now();
+    let mut updated = Vec::with_capacity(request.placements.len());
+
+    for placement in request.placements {
+        let mut canvas_block = placed
+            .remove(&placement.block_id)
+            .ok_or(ApplyCanvasLayoutError::NotPlaced(placement.block_id))?;
+
+        canvas_block.grid_x = placement.grid_x;
+        canvas_block.grid_y = placement.grid_y;
+        canvas_block.grid_width = placement.grid_width;
+        canvas_block.grid_height = placement.grid_height;
+        if let Some(z) = placement.z {
+            canvas_block.z = z;
+        }
+        if let Some(scale) = placement.scale {
+            canvas_block.scale = scale;
+        }
+        if let Some(content_visible) = placement.content_visible {
+            canvas_block.content_visible = content_visible;
+        }
+        canvas_block
+            .validate()
+            .map_err(|err| ApplyCanvasLayoutError::InputValidation(err.to_string()))?;
+        canvas_block.updated_at = now;
+
+        updated.push(canvas_block);
+    }
+
+    state
+        .repos
+        .canvas_blocks
+        .save_many(&updated, state.db.pool())
+        .await?;
+
+    let canvas_blocks = state
+        .repos
+        .canvas_blocks
+        .get_by_canvas(id, state.db.pool())
+        .await?;
+
+    let response: ApplyCanvasLayoutResponse = canvas_blocks.into();
+
+    Ok(response)
+}
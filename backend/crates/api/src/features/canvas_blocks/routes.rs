@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use crate::AppState;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(
+            super::get::get_canvas_blocks,
+            super::create::create_canvas_block
+        ))
+        .routes(routes!(
+            super::update::update_canvas_block,
+            super::delete::delete_canvas_block
+        ))
+        .routes(routes!(super::layout::apply_canvas_layout))
+}
@@ -0,0 +1,48 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::canvas_block_repository::CanvasBlockRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum UpdateCanvasBlockError {
+    #[error("Validation failed: {0}")]
+    InputValidation(String),
+
+    #[error("Block is not placed on this canvas")]
+    NotFound,
+
+    #[error(transparent)]
+    CanvasBlockRepository(#[from] CanvasBlockRepositoryError),
+}
+
+impl IntoResponse for UpdateCanvasBlockError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::InputValidation(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            Self::NotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::CanvasBlockRepository(err) => {
+                error!(error = ?err, "Canvas block repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use chrono::Utc;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, UpdateCanvasBlockError},
+    request::UpdateCanvasBlockRequest,
+    response::UpdateCanvasBlockResponse,
+};
+use crate::AppState;
+use storage::{Database, repositories::CanvasBlockRepository};
+
+#[instrument]
+#[utoipa::path(
+    patch,
+    path = "/api/canvases/{id}/blocks/{block_id}",
+    tag = "canvas_blocks",
+    request_body = UpdateCanvasBlockRequest,
+    responses(
+        (status = 200, description = "Placement updated", body = UpdateCanvasBlockResponse),
+        (status = 400, description = "Invalid placement", body = ErrorResponse),
+        (status = 404, description = "Block is not placed on the canvas", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn update_canvas_block(
+    State(state): State<Arc<AppState>>,
+    Path((id, block_id)): Path<(Uuid, Uuid)>,
+    Json(request): Json<UpdateCanvasBlockRequest>,
+) -> Result<UpdateCanvasBlockResponse, UpdateCanvasBlockError> {
+    let mut canvas_block = state
+        .repos
+        .canvas_blocks
+        .get_by_canvas_and_block(id, block_id, state.db.pool())
+        .await?
+        .ok_or(UpdateCanvasBlockError::NotFound)?;
+
+    if let Some(grid_x) = request.grid_x {
+        canvas_block.grid_x = grid_x;
+    }
+    if let Some(grid_y) = request.grid_y {
+        canvas_block.grid_y = grid_y;
+    }
+    if let Some(grid_width) = request.grid_width {
+        canvas_block.grid_width = grid_width;
+    }
+    if let Some(grid_height) = request.grid_height {
+        canvas_block.grid_height = grid_height;
+    }
+    if let Some(z) = request.z {
+        canvas_block.z = z;
+    }
+    if let Some(scale) = request.scale {
+        canvas_block.scale = scale;
+    }
+    if let Some(content_visible) = request.content_visible {
+        canvas_block.content_visible = content_visible;
+    }
+    canvas_block
+        .validate()
+        .map_err(|err| UpdateCanvasBlockError::InputValidation(err.to_string()))?;
+    canvas_block.updated_at = Utc::now();
+
+    state
+        .repos
+        .canvas_blocks
+        .save(&canvas_block, state.db.pool())
+        .await?;
+
+    let response: UpdateCanvasBlockResponse = canvas_block.into();
+
+    Ok(response)
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCanvasBlockRequest {
+    pub grid_x: Option<i32>,
+    pub grid_y: Option<i32>,
+    pub grid_width: Option<i32>,
+    pub grid_height: Option<i32>,
+    pub z: Option<i32>,
+    pub scale: Option<f64>,
+    pub content_visible: Option<bool>,
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State};
+use tracing::instrument;
+
+use super::{
+    error::{CreateCanvasError, ErrorResponse},
+    request::CreateCanvasRequest,
+    response::CreateCanvasResponse,
+};
+use crate::AppState;
+use domain::canvases::Canvas;
+use storage::{Database, repositories::CanvasRepository};
+
+#[instrument]
+#[utoipa::path(
+    post,
+    path = "/api/canvases",
+    tag = "canvases",
+    request_body = CreateCanvasRequest,
+    responses(
+        (status = 201, description = "Canvas created successfully", body = CreateCanvasResponse),
+        (status = 400, description = "Invalid canvas name", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn create_canvas(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CreateCanvasRequest>,
+) -> Result<CreateCanvasResponse, CreateCanvasError> {
+    if request.name.trim().is_empty() {
+        return Err(CreateCanvasError::InputValidation(
+            "Canvas name must not be empty".to_string(),
+        ));
+    }
+
+    let canvas = Canvas::new(&request.name);
+    state.repos.canvases.save(&canvas, state.db.pool()).await?;
+
+    let response: CreateCanvasResponse = canvas.into();
+
+    Ok(response)
+}
@@ -0,0 +1,45 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::canvas_repository::CanvasRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum DeleteCanvasError {
+    #[error(transparent)]
+    CanvasRepository(#[from] CanvasRepositoryError),
+}
+
+impl IntoResponse for DeleteCanvasError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::CanvasRepository(err) => match err {
+                CanvasRepositoryError::NotFound { .. } => {
+                    (StatusCode::NOT_FOUND, "Canvas not found".to_string())
+                }
+                other => {
+                    error!(error = ?other, "Canvas repository failure");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal server error".to_string(),
+                    )
+                }
+            },
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
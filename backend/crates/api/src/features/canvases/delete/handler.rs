@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::error::DeleteCanvasError;
+use crate::AppState;
+use storage::{Database, repositories::CanvasRepository};
+
+#[instrument]
+#[utoipa::path(
+    delete,
+    path = "/api/canvases/{id}",
+    tag = "canvases",
+    responses(
+        (status = 204, description = "Canvas deleted successfully"),
+        (status = 404, description = "Canvas not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn delete_canvas(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, DeleteCanvasError> {
+    state
+        .repos
+        .canvases
+        .delete_by_id(id, state.db.pool())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, GetCanvasError},
+    response::GetCanvasResponse,
+};
+use crate::AppState;
+use storage::{Database, repositories::CanvasRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/canvases/{id}",
+    tag = "canvases",
+    responses(
+        (status = 200, description = "Canvas found", body = GetCanvasResponse),
+        (status = 404, description = "Canvas not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_canvas(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<GetCanvasResponse, GetCanvasError> {
+    let canvas = state
+        .repos
+        .canvases
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(GetCanvasError::NotFound)?;
+
+    let response: GetCanvasResponse = canvas.into();
+
+    Ok(response)
+}
@@ -0,0 +1,43 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use domain::canvases::Canvas;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCanvasResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub viewport_x: f64,
+    pub viewport_y: f64,
+    pub zoom_level: f64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Canvas> for GetCanvasResponse {
+    fn from(canvas: Canvas) -> Self {
+        Self {
+            id: canvas.id,
+            name: canvas.name,
+            viewport_x: canvas.viewport_x,
+            viewport_y: canvas.viewport_y,
+            zoom_level: canvas.zoom_level,
+            created_at: canvas.created_at,
+            updated_at: canvas.updated_at,
+        }
+    }
+}
+
+impl IntoResponse for GetCanvasResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use tracing::instrument;
+
+use super::{
+    error::{ErrorResponse, ListCanvasesError},
+    response::ListCanvasesResponse,
+};
+use crate::AppState;
+use storage::{Database, repositories::CanvasRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/canvases",
+    tag = "canvases",
+    responses(
+        (status = 200, description = "List of canvases, most recently updated first", body = ListCanvasesResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn list_canvases(
+    State(state): State<Arc<AppState>>,
+) -> Result<ListCanvasesResponse, ListCanvasesError> {
+    let canvases = state.repos.canvases.get_all(state.db.pool()).await?;
+
+    let response: ListCanvasesResponse = canvases.into();
+
+    Ok(response)
+}
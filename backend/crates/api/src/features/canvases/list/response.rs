@@ -0,0 +1,51 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use domain::canvases::Canvas;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CanvasSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCanvasesResponse {
+    pub canvases: Vec<CanvasSummary>,
+}
+
+impl From<Canvas> for CanvasSummary {
+    fn from(canvas: Canvas) -> Self {
+        Self {
+            id: canvas.id,
+            name: canvas.name,
+            created_at: canvas.created_at,
+            updated_at: canvas.updated_at,
+        }
+    }
+}
+
+impl From<Vec<Canvas>> for ListCanvasesResponse {
+    fn from(canvases: Vec<Canvas>) -> Self {
+        Self {
+            canvases: canvases.into_iter().map(CanvasSummary::from).collect(),
+        }
+    }
+}
+
+impl IntoResponse for ListCanvasesResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
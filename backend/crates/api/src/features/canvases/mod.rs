@@ -0,0 +1,9 @@
+mod create;
+mod delete;
+mod get;
+mod list;
+mod update;
+
+mod routes;
+
+pub use routes::routes;
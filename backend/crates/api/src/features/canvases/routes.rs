@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use crate::AppState;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(
+            super::list::list_canvases,
+            super::create::create_canvas
+        ))
+        .routes(routes!(
+            super::get::get_canvas,
+            super::update::update_canvas,
+            super::delete::delete_canvas
+        ))
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use chrono::Utc;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, UpdateCanvasError},
+    request::UpdateCanvasRequest,
+    response::UpdateCanvasResponse,
+};
+use crate::AppState;
+use storage::{Database, repositories::CanvasRepository};
+
+#[instrument]
+#[utoipa::path(
+    put,
+    path = "/api/canvases/{id}",
+    tag = "canvases",
+    request_body = UpdateCanvasRequest,
+    responses(
+        (status = 200, description = "Canvas updated successfully", body = UpdateCanvasResponse),
+        (status = 400, description = "Invalid canvas update", body = ErrorResponse),
+        (status = 404, description = "Canvas not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn update_canvas(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateCanvasRequest>,
+) -> Result<UpdateCanvasResponse, UpdateCanvasError> {
+    if request
+        .name
+        .as_ref()
+        .is_some_and(|name| name.trim().is_empty())
+    {
+        return Err(UpdateCanvasError::InputValidation(
+            "Canvas name must not be empty".to_string(),
+        ));
+    }
+    if request
+        .zoom_level
+        .is_some_and(|zoom| !zoom.is_finite() || zoom <= 0.0)
+    {
+        return Err(UpdateCanvasError::InputValidation(
+            "Zoom level must be a positive number".to_string(),
+        ));
+    }
+
+    let mut canvas = state
+        .repos
+        .canvases
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(UpdateCanvasError::NotFound)?;
+
+    if let Some(name) = request.name {
+        canvas.name = name;
+    }
+    if let Some(viewport_x) = request.viewport_x {
+        canvas.viewport_x = viewport_x;
+    }
+    if let Some(viewport_y) = request.viewport_y {
+        canvas.viewport_y = viewport_y;
+    }
+    if let Some(zoom_level) = request.zoom_level {
+        canvas.zoom_level = zoom_level;
+    }
+    canvas.updated_at = Utc::now();
+
+    state.repos.canvases.save(&canvas, state.db.pool()).await?;
+
+    let response: UpdateCanvasResponse = canvas.into();
+
+    Ok(response)
+}
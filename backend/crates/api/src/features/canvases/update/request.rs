@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCanvasRequest {
+    pub name: Option<String>,
+    pub viewport_x: Option<f64>,
+    pub viewport_y: Option<f64>,
+    pub zoom_level: Option<f64>,
+}
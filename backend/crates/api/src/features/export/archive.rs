@@ -0,0 +1,108 @@
+//! Records stored in the export archive. `import` reads the same records back,
+//! so changes here need a new `ARCHIVE_FORMAT_VERSION` and an upgrade path
+//! in the importer.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Version 1 archives have no manifest and hold only blocks and links.
+/// Version 2 adds the manifest, link ids and timestamps, open tabs, pins and
+/// canvases.
+pub(crate) const ARCHIVE_FORMAT_VERSION: u32 = 2;
+
+pub(crate) const MANIFEST_FILE: &str = "manifest.json";
+pub(crate) const BLOCKS_FILE: &str = "blocks.jsonl";
+pub(crate) const DIRECTIONAL_LINKS_FILE: &str = "directional_links.json";
+pub(crate) const RELATED_LINKS_FILE: &str = "related_links.json";
+pub(crate) const BLOCK_OPENS_FILE: &str = "block_opens.json";
+pub(crate) const BLOCK_PINS_FILE: &str = "block_pins.json";
+pub(crate) const CANVASES_FILE: &str = "canvases.json";
+pub(crate) const CANVAS_BLOCKS_FILE: &str = "canvas_blocks.json";
+pub(crate) const CANVAS_PINS_FILE: &str = "canvas_pins.json";
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ManifestRecord {
+    pub format_version: u32,
+    pub source: SourceRecord,
+    pub exported_at: DateTime<Utc>,
+    /// Set when only a subtree was exported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<Uuid>,
+    pub counts: ArchiveCountsRecord,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SourceRecord {
+    /// `sqlite` or `postgres`
+    pub backend: String,
+    pub app_version: String,
+}
+
+/// Number of records in each file, so a truncated archive is caught before
+/// anything is written.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ArchiveCountsRecord {
+    pub blocks: usize,
+    pub directional_links: usize,
+    pub related_links: usize,
+    pub block_opens: usize,
+    pub block_pins: usize,
+    pub canvases: usize,
+    pub canvas_blocks: usize,
+    pub canvas_pins: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BlockOpenRecord {
+    pub block_id: Uuid,
+    pub opened_at: DateTime<Utc>,
+    pub tab_index: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BlockPinRecord {
+    pub block_id: Uuid,
+    pub pinned_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CanvasRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub viewport_x: f64,
+    pub viewport_y: f64,
+    pub zoom_level: f64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CanvasBlockRecord {
+    pub id: Uuid,
+    pub canvas_id: Uuid,
+    pub block_id: Uuid,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub grid_width: i32,
+    pub grid_height: i32,
+    pub z: i32,
+    pub scale: f64,
+    pub content_visible: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CanvasPinRecord {
+    pub canvas_id: Uuid,
+    pub pinned_at: DateTime<Utc>,
+}
@@ -4,9 +4,17 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use serde::Serialize;
+use tracing::error;
 
+use storage::query_services::block_dag_query_service::BlockDagQueryServiceError;
 use storage::query_services::block_link_query_service::BlockLinkQueryServiceError;
 use storage::query_services::block_query_service::BlockQueryServiceError;
+use storage::repositories::block_pin_repository::BlockPinRepositoryError;
+use storage::repositories::canvas_block_repository::CanvasBlockRepositoryError;
+use storage::repositories::canvas_pin_repository::CanvasPinRepositoryError;
+use storage::repositories::canvas_repository::CanvasRepositoryError;
+use storage::repositories::tag_repository::TagRepositoryError;
+use storage::repositories::workspace_repository::WorkspaceRepositoryError;
 
 #[derive(Serialize)]
 struct ErrorResponse {
@@ -15,6 +23,9 @@ struct ErrorResponse {
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ExportError {
+    #[error("Root block not found")]
+    RootNotFound,
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
@@ -27,6 +38,27 @@ pub(crate) enum ExportError {
     #[error(transparent)]
     BlockLinkQueryService(#[from] BlockLinkQueryServiceError),
 
+    #[error(transparent)]
+    BlockDagQueryService(#[from] BlockDagQueryServiceError),
+
+    #[error(transparent)]
+    TagRepository(#[from] TagRepositoryError),
+
+    #[error(transparent)]
+    WorkspaceRepository(#[from] WorkspaceRepositoryError),
+
+    #[error(transparent)]
+    BlockPinRepository(#[from] BlockPinRepositoryError),
+
+    #[error(transparent)]
+    CanvasRepository(#[from] CanvasRepositoryError),
+
+    #[error(transparent)]
+    CanvasBlockRepository(#[from] CanvasBlockRepositoryError),
+
+    #[error(transparent)]
+    CanvasPinRepository(#[from] CanvasPinRepositoryError),
+
     #[error("Failed to build export archive")]
     Zip(#[from] zip::result::ZipError),
 
@@ -36,12 +68,17 @@ pub(crate) enum ExportError {
 
 impl IntoResponse for ExportError {
     fn into_response(self) -> Response {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: "Export failed".to_string(),
-            }),
-        )
-            .into_response()
+        let (status, msg) = match &self {
+            Self::RootNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            _ => {
+                error!(error = ?self, "Export failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Export failed".to_string(),
+                )
+            }
+        };
+
+        (status, Json(ErrorResponse { error: msg })).into_response()
     }
 }
@@ -1,16 +1,31 @@
-use std::io::{Cursor, Write};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use axum::extract::State;
+use axum::extract::{Query, State};
 use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
 use serde::Serialize;
 use tracing::instrument;
 use uuid::Uuid;
 
-use super::{error::ExportError, response::ExportResponse};
+use super::archive::{
+    ARCHIVE_FORMAT_VERSION, ArchiveCountsRecord, BLOCK_OPENS_FILE, BLOCK_PINS_FILE, BLOCKS_FILE,
+    BlockOpenRecord, BlockPinRecord, CANVAS_BLOCKS_FILE, CANVAS_PINS_FILE, CANVASES_FILE,
+    CanvasBlockRecord, CanvasPinRecord, CanvasRecord, DIRECTIONAL_LINKS_FILE, MANIFEST_FILE,
+    ManifestRecord, RELATED_LINKS_FILE, SourceRecord,
+};
+use super::spool::SpooledFile;
+use super::writer::ArchiveWriter;
+use super::{error::ExportError, request::ExportQuery, response::ExportResponse};
 use crate::AppState;
-use storage::query_services::{BlockLinkQueryService, BlockQueryService};
+use crate::app_state::DATABASE_BACKEND;
 use storage::Database;
+use storage::query_services::block_dag_query_service::BlockDagQueryServiceError;
+use storage::query_services::{BlockDagQueryService, BlockLinkQueryService, BlockQueryService};
+use storage::repositories::{
+    BlockPinRepository, CanvasBlockRepository, CanvasPinRepository, CanvasRepository,
+    TagRepository, WorkspaceRepository,
+};
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -20,61 +35,456 @@ struct BlockRecord {
     content: String,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
+    tags: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DirectionalLinkRecord {
+    id: Uuid,
+    block_from_id: Uuid,
+    block_to_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<f64>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RelatedLinkRecord {
+    id: Uuid,
+    block_a_id: Uuid,
+    block_b_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<f64>,
+    created_at: DateTime<Utc>,
 }
 
 #[utoipa::path(
     get,
     path = "/api/export",
     tag = "export",
+    params(ExportQuery),
     responses(
-        (status = 200, description = "ZIP archive of all blocks and relations", content_type = "application/zip"),
+        (status = 200, description = "ZIP archive of all blocks and relations, or of one subtree", content_type = "application/zip"),
+        (status = 404, description = "Root block not found"),
         (status = 500, description = "Internal server error"),
     )
 )]
 #[instrument(err, skip(state))]
 pub async fn export(
     State(state): State<Arc<AppState>>,
+    Query(query): Query<ExportQuery>,
 ) -> Result<ExportResponse, ExportError> {
+    let archive = build_archive(&state, &query).await?;
+
+    ExportResponse::new(archive)
+}
+
+/// Builds the ZIP archive served by `GET /api/export`. A subtree export has
+/// the same layout, so it imports like a full one. Open tabs, pins and canvas
+/// placements are kept only for exported blocks; a subtree export leaves out
+/// canvases that place none of them. Blocks are streamed from the database
+/// and the archive spills to a temp file, so memory stays flat however large
+/// the export is.
+///
+/// The ZIP writer seeks back to patch entry headers, so the whole archive is
+/// built before anything is returned. Time to first byte therefore grows with
+/// the size of the export.
+pub(crate) async fn build_archive(
+    state: &AppState,
+    query: &ExportQuery,
+) -> Result<SpooledFile, ExportError> {
     let pool = state.db.pool();
 
-    let blocks = state.query_services.blocks.get_all(pool).await?;
-    let directional = state.query_services.block_links.get_all_directional(pool).await?;
-    let related = state.query_services.block_links.get_all_related(pool).await?;
+    let subtree = match query.root {
+        Some(root) => Some(subtree_ids(state, root).await?),
+        None => None,
+    };
 
-    let cursor = Cursor::new(Vec::new());
-    let mut zip = zip::ZipWriter::new(cursor);
-    let options = zip::write::SimpleFileOptions::default()
-        .compression_method(zip::CompressionMethod::Deflated);
+    let mut tags_by_block: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for block_tag in state.repos.tags.get_all_block_tags(pool).await? {
+        tags_by_block
+            .entry(block_tag.block_id)
+            .or_default()
+            .push(block_tag.tag_name);
+    }
+
+    let mut zip = ArchiveWriter::new();
+
+    zip.start_file(BLOCKS_FILE).await?;
+    let mut block_ids = HashSet::new();
+    let mut blocks = state.query_services.blocks.stream_all(pool);
+    while let Some(block) = blocks.try_next().await? {
+        if subtree.as_ref().is_some_and(|ids| !ids.contains(&block.id)) {
+            continue;
+        }
 
-    zip.start_file("blocks.jsonl", options)?;
-    for block in &blocks {
         let record = BlockRecord {
             id: block.id,
-            title: block.title.clone(),
-            content: block.content.clone(),
+            title: block.title,
+            content: block.content,
             created_at: block.created_at,
             updated_at: block.updated_at,
+            tags: tags_by_block.remove(&block.id).unwrap_or_default(),
         };
         let line = serde_json::to_string(&record)?;
-        zip.write_all(line.as_bytes())?;
-        zip.write_all(b"\n")?;
+        zip.write_all(line.as_bytes()).await?;
+        zip.write_all(b"\n").await?;
+        block_ids.insert(block.id);
+    }
+    // Give the connection back before the next queries
+    drop(blocks);
+
+    if query.root.is_some_and(|root| !block_ids.contains(&root)) {
+        return Err(ExportError::RootNotFound);
     }
 
-    zip.start_file("directional_links.json", options)?;
-    let dir_pairs: Vec<[Uuid; 2]> = directional
-        .iter()
-        .map(|l| [l.block_from_id, l.block_to_id])
+    let mut directional = state
+        .query_services
+        .block_links
+        .get_all_directional(pool)
+        .await?;
+    let mut related = if query.include_related {
+        state
+            .query_services
+            .block_links
+            .get_all_related(pool)
+            .await?
+    } else {
+        Vec::new()
+    };
+
+    if subtree.is_some() {
+        directional
+            .retain(|l| block_ids.contains(&l.block_from_id) && block_ids.contains(&l.block_to_id));
+        related.retain(|l| block_ids.contains(&l.block_a_id) && block_ids.contains(&l.block_b_id));
+    }
+
+    let mut opened_blocks = state.repos.workspaces.get(pool).await?.opened_blocks;
+    opened_blocks.sort_by_key(|b| b.tab_index);
+    let open_records: Vec<BlockOpenRecord> = opened_blocks
+        .into_iter()
+        .filter(|b| block_ids.contains(&b.block_id))
+        .map(|b| BlockOpenRecord {
+            block_id: b.block_id,
+            opened_at: b.opened_at,
+            tab_index: b.tab_index,
+        })
+        .collect();
+
+    let pin_records: Vec<BlockPinRecord> = state
+        .repos
+        .block_pins
+        .get_all(pool)
+        .await?
+        .into_iter()
+        .filter(|p| block_ids.contains(&p.block_id))
+        .map(|p| BlockPinRecord {
+            block_id: p.block_id,
+            pinned_at: p.pinned_at,
+        })
+        .collect();
+
+    let mut canvas_records = Vec::new();
+    let mut canvas_block_records = Vec::new();
+    for canvas in state.repos.canvases.get_all(pool).await? {
+        let placements: Vec<CanvasBlockRecord> = state
+            .repos
+            .canvas_blocks
+            .get_by_canvas(canvas.id, pool)
+            .await?
+            .into_iter()
+            .filter(|cb| block_ids.contains(&cb.block_id))
+            .map(|cb| CanvasBlockRecord {
+                id: cb.id,
+                canvas_id: cb.canvas_id,
+                block_id: cb.block_id,
+                grid_x: cb.grid_x,
+                grid_y: cb.grid_y,
+                grid_width: cb.grid_width,
+                grid_height: cb.grid_height,
+                z: cb.z,
+                scale: cb.scale,
+                content_visible: cb.content_visible,
+                created_at: cb.created_at,
+                updated_at: cb.updated_at,
+            })
+            .collect();
+        if query.root.is_some() && placements.is_empty() {
+            continue;
+        }
+
+        canvas_records.push(CanvasRecord {
+            id: canvas.id,
+            name: canvas.name,
+            viewport_x: canvas.viewport_x,
+            viewport_y: canvas.viewport_y,
+            zoom_level: canvas.zoom_level,
+            created_at: canvas.created_at,
+            updated_at: canvas.updated_at,
+        });
+        canvas_block_records.extend(placements);
+    }
+    let canvas_ids: HashSet<Uuid> = canvas_records.iter().map(|c| c.id).collect();
+
+    let canvas_pin_records: Vec<CanvasPinRecord> = state
+        .repos
+        .canvas_pins
+        .get_all(pool)
+        .await?
+        .into_iter()
+        .filter(|p| canvas_ids.contains(&p.canvas_id))
+        .map(|p| CanvasPinRecord {
+            canvas_id: p.canvas_id,
+            pinned_at: p.pinned_at,
+        })
+        .collect();
+
+    let manifest = ManifestRecord {
+        format_version: ARCHIVE_FORMAT_VERSION,
+        source: SourceRecord {
+            backend: DATABASE_BACKEND.to_string(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+        },
+        exported_at: Utc::now(),
+        root: query.root,
+        counts: ArchiveCountsRecord {
+            blocks: block_ids.len(),
+            directional_links: directional.len(),
+            related_links: related.len(),
+            block_opens: open_records.len(),
+            block_pins: pin_records.len(),
+            canvases: canvas_records.len(),
+            canvas_blocks: canvas_block_records.len(),
+            canvas_pins: canvas_pin_records.len(),
+        },
+    };
+
+    zip.start_file(DIRECTIONAL_LINKS_FILE).await?;
+    let dir_records: Vec<DirectionalLinkRecord> = directional
+        .into_iter()
+        .map(|l| DirectionalLinkRecord {
+            id: l.id,
+            block_from_id: l.block_from_id,
+            block_to_id: l.block_to_id,
+            kind: l.kind,
+            label: l.label,
+            weight: l.weight,
+            created_at: l.created_at,
+        })
+        .collect();
+    zip.write_all(serde_json::to_string(&dir_records)?.as_bytes())
+        .await?;
+
+    zip.start_file(RELATED_LINKS_FILE).await?;
+    let rel_records: Vec<RelatedLinkRecord> = related
+        .into_iter()
+        .map(|l| RelatedLinkRecord {
+            id: l.id,
+            block_a_id: l.block_a_id,
+            block_b_id: l.block_b_id,
+            label: l.label,
+            weight: l.weight,
+            created_at: l.created_at,
+        })
         .collect();
-    zip.write_all(serde_json::to_string(&dir_pairs)?.as_bytes())?;
+    zip.write_all(serde_json::to_string(&rel_records)?.as_bytes())
+        .await?;
+
+    zip.start_file(BLOCK_OPENS_FILE).await?;
+    zip.write_all(serde_json::to_string(&open_records)?.as_bytes())
+        .await?;
+
+    zip.start_file(BLOCK_PINS_FILE).await?;
+    zip.write_all(serde_json::to_string(&pin_records)?.as_bytes())
+        .await?;
+
+    zip.start_file(CANVASES_FILE).await?;
+    zip.write_all(serde_json::to_string(&canvas_records)?.as_bytes())
+        .await?;
+
+    zip.start_file(CANVAS_BLOCKS_FILE).await?;
+    zip.write_all(serde_json::to_string(&canvas_block_records)?.as_bytes())
+        .await?;
 
-    zip.start_file("related_links.json", options)?;
-    let rel_pairs: Vec<[Uuid; 2]> = related
-        .iter()
-        .map(|l| [l.block_a_id, l.block_b_id])
+    zip.start_file(CANVAS_PINS_FILE).await?;
+    zip.write_all(serde_json::to_string(&canvas_pin_records)?.as_bytes())
+        .await?;
+
+    // Written last, once the block count is known
+    zip.start_file(MANIFEST_FILE).await?;
+    zip.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())
+        .await?;
+
+    zip.finish().await
+}
+
+/// Ids of `root` and every block below it.
+pub(crate) async fn subtree_ids(
+    state: &AppState,
+    root: Uuid,
+) -> Result<HashSet<Uuid>, BlockDagQueryServiceError> {
+    let mut ids: HashSet<Uuid> = state
+        .query_services
+        .block_dag
+        .get_descendants(root, state.db.pool())
+        .await?
+        .into_iter()
+        .map(|b| b.id)
         .collect();
-    zip.write_all(serde_json::to_string(&rel_pairs)?.as_bytes())?;
+    ids.insert(root);
 
-    let bytes = zip.finish()?.into_inner();
+    Ok(ids)
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use rstest::rstest;
 
-    ExportResponse::new(bytes)
+    use super::*;
+    use crate::features::import::{ImportOptions, import_archive};
+    use crate::test_utils::{TestApp, app};
+    use domain::blocks::Block;
+    use domain::canvases::{Canvas, CanvasBlock};
+    use storage::repositories::block_directional_link_repository::CreateBlockDirectionalLinkDto;
+    use storage::repositories::block_related_link_repository::CreateBlockRelatedLinkDto;
+    use storage::repositories::{
+        BlockDirectionalLinkRepository, BlockRelatedLinkRepository, BlockRepository,
+    };
+
+    async fn seed_block(app: &TestApp, title: &str) -> Uuid {
+        let block = Block::new(title, "");
+        app.repos.blocks.save(&block, app.db.pool()).await.unwrap();
+        block.id
+    }
+
+    async fn link(app: &TestApp, from: Uuid, to: Uuid) {
+        let dto = CreateBlockDirectionalLinkDto {
+            id: Uuid::new_v4(),
+            block_from_id: from,
+            block_to_id: to,
+            kind: None,
+            label: None,
+            weight: None,
+        };
+        app.repos
+            .block_directional_links
+            .create(&dto, app.db.pool())
+            .await
+            .unwrap();
+    }
+
+    async fn relate(app: &TestApp, a: Uuid, b: Uuid) {
+        let dto = CreateBlockRelatedLinkDto {
+            id: Uuid::new_v4(),
+            block_a_id: a,
+            block_b_id: b,
+            label: None,
+            weight: None,
+        };
+        app.repos
+            .block_related_links
+            .create(&dto, app.db.pool())
+            .await
+            .unwrap();
+    }
+
+    #[rstest]
+    #[case::with_related(true)]
+    #[case::without_related(false)]
+    #[tokio::test]
+    async fn subtree_export_stops_at_the_subtree_and_imports_cleanly(
+        #[future] app: TestApp,
+        #[future]
+        #[from(app)]
+        target: TestApp,
+        #[case] include_related: bool,
+    ) {
+        let (source, target) = (app.await, target.await);
+        let pool = source.db.pool();
+
+        // `outside` is a second parent of `root`, so its link crosses the
+        // boundary, as does the related link from `child`
+        let outside = seed_block(&source, "Outside").await;
+        let root = seed_block(&source, "Root").await;
+        let child = seed_block(&source, "Child").await;
+        link(&source, outside, root).await;
+        link(&source, root, child).await;
+        relate(&source, root, child).await;
+        relate(&source, child, outside).await;
+        source.repos.block_pins.pin(outside, pool).await.unwrap();
+        source.repos.block_pins.pin(child, pool).await.unwrap();
+        let canvas = Canvas::new("Outside only");
+        source.repos.canvases.save(&canvas, pool).await.unwrap();
+        source
+            .repos
+            .canvas_blocks
+            .save(&CanvasBlock::new(canvas.id, outside, 0, 0, 1, 1), pool)
+            .await
+            .unwrap();
+
+        let query = ExportQuery {
+            root: Some(root),
+            include_related,
+        };
+        let archive = build_archive(&source, &query).await.unwrap();
+
+        let response = import_archive(archive, &ImportOptions::default(), &target)
+            .await
+            .expect("a subtree export should import");
+        assert!(response.conflicts.is_empty());
+        assert_eq!(response.blocks_inserted, 2);
+        assert_eq!(response.dir_links_inserted, 1);
+        assert_eq!(
+            response.related_links_inserted,
+            usize::from(include_related)
+        );
+        assert_eq!(response.block_pins_inserted, 1);
+        assert_eq!(response.canvases_inserted, 0);
+
+        let pool = target.db.pool();
+        let blocks: HashSet<Uuid> = target
+            .query_services
+            .blocks
+            .get_all(pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|b| b.id)
+            .collect();
+        assert_eq!(blocks, HashSet::from([root, child]));
+
+        let directional: Vec<(Uuid, Uuid)> = target
+            .query_services
+            .block_links
+            .get_all_directional(pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|l| (l.block_from_id, l.block_to_id))
+            .collect();
+        assert_eq!(directional, [(root, child)]);
+
+        let related: Vec<(Uuid, Uuid)> = target
+            .query_services
+            .block_links
+            .get_all_related(pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|l| (l.block_a_id, l.block_b_id))
+            .collect();
+        if include_related {
+            assert_eq!(related, [(root.min(child), root.max(child))]);
+        } else {
+            assert!(related.is_empty());
+        }
+    }
 }
@@ -1,6 +1,13 @@
+pub(crate) mod archive;
 mod error;
 mod handler;
+mod request;
 mod response;
 mod routes;
+pub(crate) mod spool;
+mod writer;
 
+pub(crate) use error::ExportError;
+pub(crate) use handler::{build_archive, subtree_ids};
+pub(crate) use request::ExportQuery;
 pub use routes::routes;
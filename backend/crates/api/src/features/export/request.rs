@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub(crate) struct ExportQuery {
+    /// Only export this block, its descendants and the directional links
+    /// among them
+    pub root: Option<Uuid>,
+    /// Keep related links whose blocks are both exported
+    #[serde(default = "include_related_default", alias = "include_related")]
+    #[param(default = true)]
+    pub include_related: bool,
+}
+
+fn include_related_default() -> bool {
+    true
+}
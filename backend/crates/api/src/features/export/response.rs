@@ -4,19 +4,21 @@ use axum::{
 };
 
 use super::error::ExportError;
+use super::spool::SpooledFile;
 
 pub(crate) struct ExportResponse(Response);
 
 impl ExportResponse {
-    pub(crate) fn new(bytes: Vec<u8>) -> Result<Self, ExportError> {
+    pub(crate) fn new(archive: SpooledFile) -> Result<Self, ExportError> {
         let response = Response::builder()
             .status(StatusCode::OK)
             .header(header::CONTENT_TYPE, "application/zip")
+            .header(header::CONTENT_LENGTH, archive.len()?)
             .header(
                 header::CONTENT_DISPOSITION,
                 "attachment; filename=\"modunote-export.zip\"",
             )
-            .body(axum::body::Body::from(bytes))?;
+            .body(axum::body::Body::from_stream(archive.into_stream()))?;
 
         Ok(Self(response))
     }
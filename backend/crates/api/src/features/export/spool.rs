@@ -0,0 +1,161 @@
+//! Buffer for archives that may be too large to hold in memory. The ZIP
+//! writer seeks back to patch each entry's header, so an export can't be
+//! written straight to the response; it is assembled here and then streamed.
+
+use std::fs::File;
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+use axum::body::Bytes;
+use futures_util::{Stream, StreamExt, stream};
+use uuid::Uuid;
+
+/// Data above this size moves from memory to a temp file.
+const MAX_MEMORY_BYTES: u64 = 8 * 1024 * 1024;
+
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// Read/write buffer that starts in memory and moves to a temp file once it
+/// outgrows `MAX_MEMORY_BYTES`. The temp file is removed on drop.
+pub(crate) struct SpooledFile {
+    inner: Inner,
+}
+
+enum Inner {
+    Memory(Cursor<Vec<u8>>),
+    File { file: File, path: PathBuf },
+}
+
+impl SpooledFile {
+    pub(crate) fn new() -> Self {
+        Self {
+            inner: Inner::Memory(Cursor::new(Vec::new())),
+        }
+    }
+
+    /// Total bytes written.
+    pub(crate) fn len(&self) -> io::Result<u64> {
+        match &self.inner {
+            Inner::Memory(cursor) => Ok(cursor.get_ref().len() as u64),
+            Inner::File { file, .. } => Ok(file.metadata()?.len()),
+        }
+    }
+
+    /// Appends `chunk`, writing to the temp file off the async runtime.
+    pub(crate) async fn append(mut self, chunk: Bytes) -> io::Result<Self> {
+        let fits_in_memory = match &self.inner {
+            Inner::Memory(cursor) => cursor.position() + chunk.len() as u64 <= MAX_MEMORY_BYTES,
+            Inner::File { .. } => false,
+        };
+        if fits_in_memory {
+            self.write_all(&chunk)?;
+            return Ok(self);
+        }
+
+        tokio::task::spawn_blocking(move || {
+            self.write_all(&chunk)?;
+            Ok(self)
+        })
+        .await
+        .map_err(io::Error::other)?
+    }
+
+    /// Streams the whole contents, reading the temp file off the async
+    /// runtime.
+    pub(crate) fn into_stream(mut self) -> impl Stream<Item = io::Result<Bytes>> + Send {
+        if let Inner::Memory(cursor) = &mut self.inner {
+            let bytes = Bytes::from(std::mem::take(cursor.get_mut()));
+            return stream::iter([Ok(bytes)]).left_stream();
+        }
+
+        stream::try_unfold((self, false), |(mut spool, rewound)| async move {
+            let (spool, chunk) = tokio::task::spawn_blocking(move || {
+                if !rewound {
+                    spool.rewind()?;
+                }
+                let mut chunk = vec![0; CHUNK_BYTES];
+                let read = spool.read(&mut chunk)?;
+                chunk.truncate(read);
+                Ok::<_, io::Error>((spool, chunk))
+            })
+            .await
+            .map_err(io::Error::other)??;
+
+            Ok((!chunk.is_empty()).then(|| (Bytes::from(chunk), (spool, true))))
+        })
+        .right_stream()
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        let Inner::Memory(cursor) = &self.inner else {
+            return Ok(());
+        };
+
+        let path = std::env::temp_dir().join(format!("modunote-{}.spool", Uuid::new_v4()));
+        let mut file = File::options()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        file.write_all(cursor.get_ref())?;
+        file.seek(SeekFrom::Start(cursor.position()))?;
+
+        self.inner = Inner::File { file, path };
+        Ok(())
+    }
+}
+
+impl Write for SpooledFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let outgrown = match &self.inner {
+            Inner::Memory(cursor) => cursor.position() + buf.len() as u64 > MAX_MEMORY_BYTES,
+            Inner::File { .. } => false,
+        };
+        if outgrown {
+            self.spill()?;
+        }
+
+        match &mut self.inner {
+            Inner::Memory(cursor) => cursor.write(buf),
+            Inner::File { file, .. } => file.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.inner {
+            Inner::Memory(_) => Ok(()),
+            Inner::File { file, .. } => file.flush(),
+        }
+    }
+}
+
+impl Read for SpooledFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match &mut self.inner {
+            Inner::Memory(cursor) => cursor.read(buf),
+            Inner::File { file, .. } => file.read(buf),
+        }
+    }
+}
+
+impl Seek for SpooledFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match &mut self.inner {
+            Inner::Memory(cursor) => cursor.seek(pos),
+            Inner::File { file, .. } => file.seek(pos),
+        }
+    }
+}
+
+impl Drop for SpooledFile {
+    fn drop(&mut self) {
+        // The file is closed first; Windows won't remove an open file
+        let inner = std::mem::replace(&mut self.inner, Inner::Memory(Cursor::default()));
+        if let Inner::File { file, path } = inner {
+            drop(file);
+            if let Err(e) = std::fs::remove_file(&path) {
+                tracing::warn!(path = %path.display(), error = %e, "Failed to remove spool file");
+            }
+        }
+    }
+}
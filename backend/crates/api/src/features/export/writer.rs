@@ -0,0 +1,98 @@
+//! Writes the export ZIP on a blocking thread. The async side queries the
+//! database and hands entries over a channel, so compression and spool file
+//! writes never run on the async runtime.
+
+use std::io::{self, Seek, Write};
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use super::error::ExportError;
+use super::spool::SpooledFile;
+
+/// Bytes buffered before they are sent to the writer thread.
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// Chunks that may wait for the writer thread before sends wait too.
+const CHANNEL_CAPACITY: usize = 16;
+
+enum Message {
+    StartFile(&'static str),
+    Data(Vec<u8>),
+}
+
+pub(super) struct ArchiveWriter {
+    sender: mpsc::Sender<Message>,
+    buffer: Vec<u8>,
+    task: JoinHandle<Result<SpooledFile, ExportError>>,
+}
+
+impl ArchiveWriter {
+    pub(super) fn new() -> Self {
+        let (sender, mut receiver) = mpsc::channel(CHANNEL_CAPACITY);
+
+        let task = tokio::task::spawn_blocking(move || {
+            let mut zip = ZipWriter::new(SpooledFile::new());
+            let options =
+                SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+            while let Some(message) = receiver.blocking_recv() {
+                match message {
+                    Message::StartFile(name) => zip.start_file(name, options)?,
+                    Message::Data(data) => zip.write_all(&data)?,
+                }
+            }
+
+            let mut archive = zip.finish()?;
+            archive.rewind()?;
+            Ok(archive)
+        });
+
+        Self {
+            sender,
+            buffer: Vec::with_capacity(CHUNK_BYTES),
+            task,
+        }
+    }
+
+    /// Starts a new entry; later writes go into it.
+    pub(super) async fn start_file(&mut self, name: &'static str) -> Result<(), ExportError> {
+        self.flush().await?;
+        self.send(Message::StartFile(name)).await
+    }
+
+    pub(super) async fn write_all(&mut self, data: &[u8]) -> Result<(), ExportError> {
+        self.buffer.extend_from_slice(data);
+        if self.buffer.len() >= CHUNK_BYTES {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// Writes the central directory and returns the archive, rewound.
+    pub(super) async fn finish(mut self) -> Result<SpooledFile, ExportError> {
+        self.flush().await?;
+        drop(self.sender);
+
+        self.task.await.map_err(io::Error::other)?
+    }
+
+    async fn flush(&mut self) -> Result<(), ExportError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let data = std::mem::replace(&mut self.buffer, Vec::with_capacity(CHUNK_BYTES));
+        self.send(Message::Data(data)).await
+    }
+
+    async fn send(&mut self, message: Message) -> Result<(), ExportError> {
+        if self.sender.send(message).await.is_err() {
+            // The writer thread only hangs up when it fails
+            (&mut self.task).await.map_err(io::Error::other)??;
+            return Err(io::Error::other("Archive writer stopped").into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+
+use storage::query_services::block_dag_query_service::BlockDagQueryServiceError;
+use storage::query_services::block_link_query_service::BlockLinkQueryServiceError;
+use storage::query_services::block_query_service::BlockQueryServiceError;
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum ExportGraphError {
+    #[error("Root block not found")]
+    RootNotFound,
+
+    #[error(transparent)]
+    Http(#[from] axum::http::Error),
+
+    #[error(transparent)]
+    BlockQueryService(#[from] BlockQueryServiceError),
+
+    #[error(transparent)]
+    BlockLinkQueryService(#[from] BlockLinkQueryServiceError),
+
+    #[error(transparent)]
+    BlockDagQueryService(#[from] BlockDagQueryServiceError),
+}
+
+impl IntoResponse for ExportGraphError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::RootNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            _ => {
+                error!(error = ?self, "Graph export failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Export failed".to_string(),
+                )
+            }
+        };
+
+        (status, Json(ErrorResponse { error: msg })).into_response()
+    }
+}
@@ -0,0 +1,315 @@
+//! Writes blocks and links as GraphML, GEXF or DOT. Node ids are block ids,
+//! so files exported at different times line up in other tools.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use storage::query_services::block_link_query_service::{
+    DirectionalLinkExportDto, RelatedLinkExportDto,
+};
+use storage::query_services::block_query_service::BlockExportDto;
+
+pub(super) fn render_graphml(
+    blocks: &[BlockExportDto],
+    directional: &[DirectionalLinkExportDto],
+    related: &[RelatedLinkExportDto],
+) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+    for (id, domain, kind) in [
+        ("title", "node", "string"),
+        ("created_at", "node", "string"),
+        ("updated_at", "node", "string"),
+        ("link", "edge", "string"),
+        ("kind", "edge", "string"),
+        ("label", "edge", "string"),
+        ("weight", "edge", "double"),
+    ] {
+        let _ = writeln!(
+            out,
+            "  <key id=\"{id}\" for=\"{domain}\" attr.name=\"{id}\" attr.type=\"{kind}\"/>"
+        );
+    }
+    out.push_str("  <graph id=\"modunote\" edgedefault=\"directed\">\n");
+
+    for block in blocks {
+        let _ = writeln!(out, "    <node id=\"{}\">", block.id);
+        let _ = writeln!(
+            out,
+            "      <data key=\"title\">{}</data>",
+            xml(&block.title)
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"created_at\">{}</data>",
+            timestamp(block.created_at)
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"updated_at\">{}</data>",
+            timestamp(block.updated_at)
+        );
+        out.push_str("    </node>\n");
+    }
+
+    for link in directional {
+        let _ = writeln!(
+            out,
+            "    <edge source=\"{}\" target=\"{}\">",
+            link.block_from_id, link.block_to_id
+        );
+        out.push_str("      <data key=\"link\">directional</data>\n");
+        graphml_data(&mut out, "kind", link.kind.as_deref());
+        graphml_data(&mut out, "label", link.label.as_deref());
+        graphml_data(
+            &mut out,
+            "weight",
+            link.weight.map(|w| w.to_string()).as_deref(),
+        );
+        out.push_str("    </edge>\n");
+    }
+
+    for link in related {
+        let _ = writeln!(
+            out,
+            "    <edge source=\"{}\" target=\"{}\" directed=\"false\">",
+            link.block_a_id, link.block_b_id
+        );
+        out.push_str("      <data key=\"link\">related</data>\n");
+        graphml_data(&mut out, "label", link.label.as_deref());
+        graphml_data(
+            &mut out,
+            "weight",
+            link.weight.map(|w| w.to_string()).as_deref(),
+        );
+        out.push_str("    </edge>\n");
+    }
+
+    out.push_str("  </graph>\n");
+    out.push_str("</graphml>\n");
+    out
+}
+
+fn graphml_data(out: &mut String, key: &str, value: Option<&str>) {
+    if let Some(value) = value {
+        let _ = writeln!(out, "      <data key=\"{key}\">{}</data>", xml(value));
+    }
+}
+
+/// Related links are written as undirected edges in an otherwise directed
+/// graph, which Gephi shows as a mixed graph.
+pub(super) fn render_gexf(
+    blocks: &[BlockExportDto],
+    directional: &[DirectionalLinkExportDto],
+    related: &[RelatedLinkExportDto],
+) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n");
+    out.push_str("  <meta>\n    <creator>Modunote</creator>\n  </meta>\n");
+    out.push_str("  <graph defaultedgetype=\"directed\" mode=\"static\">\n");
+    out.push_str("    <attributes class=\"node\">\n");
+    out.push_str("      <attribute id=\"created_at\" title=\"created_at\" type=\"string\"/>\n");
+    out.push_str("      <attribute id=\"updated_at\" title=\"updated_at\" type=\"string\"/>\n");
+    out.push_str("    </attributes>\n");
+    out.push_str("    <attributes class=\"edge\">\n");
+    out.push_str("      <attribute id=\"link\" title=\"link\" type=\"string\"/>\n");
+    out.push_str("      <attribute id=\"kind\" title=\"kind\" type=\"string\"/>\n");
+    out.push_str("    </attributes>\n");
+
+    out.push_str("    <nodes>\n");
+    for block in blocks {
+        let _ = writeln!(
+            out,
+            "      <node id=\"{}\" label=\"{}\">",
+            block.id,
+            xml(&block.title)
+        );
+        out.push_str("        <attvalues>\n");
+        gexf_attvalue(&mut out, "created_at", Some(&timestamp(block.created_at)));
+        gexf_attvalue(&mut out, "updated_at", Some(&timestamp(block.updated_at)));
+        out.push_str("        </attvalues>\n");
+        out.push_str("      </node>\n");
+    }
+    out.push_str("    </nodes>\n");
+
+    out.push_str("    <edges>\n");
+    let mut edge_id = 0;
+    for link in directional {
+        let _ = write!(
+            out,
+            "      <edge id=\"{edge_id}\" source=\"{}\" target=\"{}\"",
+            link.block_from_id, link.block_to_id
+        );
+        gexf_edge_tail(&mut out, link.label.as_deref(), link.weight);
+        gexf_attvalue(&mut out, "link", Some("directional"));
+        gexf_attvalue(&mut out, "kind", link.kind.as_deref());
+        out.push_str("        </attvalues>\n      </edge>\n");
+        edge_id += 1;
+    }
+    for link in related {
+        let _ = write!(
+            out,
+            "      <edge id=\"{edge_id}\" source=\"{}\" target=\"{}\" type=\"undirected\"",
+            link.block_a_id, link.block_b_id
+        );
+        gexf_edge_tail(&mut out, link.label.as_deref(), link.weight);
+        gexf_attvalue(&mut out, "link", Some("related"));
+        out.push_str("        </attvalues>\n      </edge>\n");
+        edge_id += 1;
+    }
+    out.push_str("    </edges>\n");
+
+    out.push_str("  </graph>\n");
+    out.push_str("</gexf>\n");
+    out
+}
+
+/// Finishes an `<edge` start tag and opens its `<attvalues>`.
+fn gexf_edge_tail(out: &mut String, label: Option<&str>, weight: Option<f64>) {
+    if let Some(label) = label {
+        let _ = write!(out, " label=\"{}\"", xml(label));
+    }
+    if let Some(weight) = weight {
+        let _ = write!(out, " weight=\"{weight}\"");
+    }
+    out.push_str(">\n        <attvalues>\n");
+}
+
+fn gexf_attvalue(out: &mut String, id: &str, value: Option<&str>) {
+    if let Some(value) = value {
+        let _ = writeln!(
+            out,
+            "          <attvalue for=\"{id}\" value=\"{}\"/>",
+            xml(value)
+        );
+    }
+}
+
+/// Related links are drawn as dashed lines without arrowheads. Link weights
+/// are left out because Graphviz reads `weight` as a layout hint that must be
+/// an integer.
+pub(super) fn render_dot(
+    blocks: &[BlockExportDto],
+    directional: &[DirectionalLinkExportDto],
+    related: &[RelatedLinkExportDto],
+) -> String {
+    let mut out = String::new();
+    out.push_str("digraph modunote {\n");
+    out.push_str("  node [shape=box];\n");
+
+    for block in blocks {
+        let _ = writeln!(
+            out,
+            "  \"{}\" [label={}, created_at=\"{}\", updated_at=\"{}\"];",
+            block.id,
+            dot(&block.title),
+            timestamp(block.created_at),
+            timestamp(block.updated_at)
+        );
+    }
+
+    for link in directional {
+        let _ = write!(
+            out,
+            "  \"{}\" -> \"{}\"",
+            link.block_from_id, link.block_to_id
+        );
+        let mut attrs = Vec::new();
+        if let Some(label) = &link.label {
+            attrs.push(format!("label={}", dot(label)));
+        }
+        if let Some(kind) = &link.kind {
+            attrs.push(format!("kind={}", dot(kind)));
+        }
+        if !attrs.is_empty() {
+            let _ = write!(out, " [{}]", attrs.join(", "));
+        }
+        out.push_str(";\n");
+    }
+
+    for link in related {
+        let _ = write!(
+            out,
+            "  \"{}\" -> \"{}\" [dir=none, style=dashed",
+            link.block_a_id, link.block_b_id
+        );
+        if let Some(label) = &link.label {
+            let _ = write!(out, ", label={}", dot(label));
+        }
+        out.push_str("];\n");
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// Escapes text for element content and attribute values. Control
+/// characters XML 1.0 cannot hold become spaces.
+fn xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => escaped.push(' '),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Double-quoted DOT string. Line breaks become centred `\n` breaks.
+fn dot(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => {}
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xml_escapes_markup_and_blanks_control_characters() {
+        assert_eq!(
+            xml(r#"<a href="x">Tom & Jerry's</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s&lt;/a&gt;"
+        );
+        assert_eq!(xml("tab\tnew\nline\r"), "tab\tnew\nline\r");
+        assert_eq!(
+            xml("bell\u{7}nul\u{0}esc\u{1b}del\u{7f}"),
+            "bell nul esc del "
+        );
+        assert_eq!(xml("ünïcode ✓"), "ünïcode ✓");
+    }
+
+    #[test]
+    fn dot_quotes_escape_quotes_backslashes_and_line_breaks() {
+        assert_eq!(dot("plain"), r#""plain""#);
+        assert_eq!(dot(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(dot(r"C:\path\"), r#""C:\\path\\""#);
+        assert_eq!(dot("one\r\ntwo\nthree"), r#""one\ntwo\nthree""#);
+        assert_eq!(dot(""), r#""""#);
+    }
+}
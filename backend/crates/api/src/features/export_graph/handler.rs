@@ -0,0 +1,183 @@
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::ExportGraphError,
+    graph::{render_dot, render_gexf, render_graphml},
+    request::{ExportGraphQuery, GraphFormat},
+    response::ExportGraphResponse,
+};
+use crate::AppState;
+use crate::features::export::subtree_ids;
+use storage::Database;
+use storage::query_services::{BlockLinkQueryService, BlockQueryService};
+
+/// Blocks become nodes, directional links directed edges and related links
+/// undirected edges. With `root`, only that block, its descendants and the
+/// links between them are exported.
+#[utoipa::path(
+    get,
+    path = "/api/export/graph",
+    tag = "export",
+    params(ExportGraphQuery),
+    responses(
+        (status = 200, description = "Graph of live blocks and their links", content_type = "application/xml"),
+        (status = 404, description = "Root block not found"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
+#[instrument(err, skip(state))]
+pub async fn export_graph(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ExportGraphQuery>,
+) -> Result<ExportGraphResponse, ExportGraphError> {
+    let text = build_graph(&state, query.format, query.root).await?;
+
+    ExportGraphResponse::new(query.format, text)
+}
+
+pub(crate) async fn build_graph(
+    state: &AppState,
+    format: GraphFormat,
+    root: Option<Uuid>,
+) -> Result<String, ExportGraphError> {
+    let pool = state.db.pool();
+
+    let mut blocks = state.query_services.blocks.get_all(pool).await?;
+    let mut directional = state
+        .query_services
+        .block_links
+        .get_all_directional(pool)
+        .await?;
+    let mut related = state
+        .query_services
+        .block_links
+        .get_all_related(pool)
+        .await?;
+
+    if let Some(root) = root {
+        if !blocks.iter().any(|b| b.id == root) {
+            return Err(ExportGraphError::RootNotFound);
+        }
+
+        let subtree = subtree_ids(state, root).await?;
+        blocks.retain(|b| subtree.contains(&b.id));
+        directional
+            .retain(|l| subtree.contains(&l.block_from_id) && subtree.contains(&l.block_to_id));
+        related.retain(|l| subtree.contains(&l.block_a_id) && subtree.contains(&l.block_b_id));
+    }
+
+    let text = match format {
+        GraphFormat::Graphml => render_graphml(&blocks, &directional, &related),
+        GraphFormat::Gexf => render_gexf(&blocks, &directional, &related),
+        GraphFormat::Dot => render_dot(&blocks, &directional, &related),
+    };
+
+    Ok(text)
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test_utils::{TestApp, app};
+    use domain::blocks::Block;
+    use storage::repositories::block_directional_link_repository::CreateBlockDirectionalLinkDto;
+    use storage::repositories::block_related_link_repository::CreateBlockRelatedLinkDto;
+    use storage::repositories::{
+        BlockDirectionalLinkRepository, BlockRelatedLinkRepository, BlockRepository,
+    };
+
+    async fn seed_block(app: &TestApp, title: &str) -> Uuid {
+        let block = Block::new(title, "");
+        app.repos.blocks.save(&block, app.db.pool()).await.unwrap();
+        block.id
+    }
+
+    async fn link(app: &TestApp, from: Uuid, to: Uuid) {
+        let dto = CreateBlockDirectionalLinkDto {
+            id: Uuid::new_v4(),
+            block_from_id: from,
+            block_to_id: to,
+            kind: None,
+            label: None,
+            weight: None,
+        };
+        app.repos
+            .block_directional_links
+            .create(&dto, app.db.pool())
+            .await
+            .unwrap();
+    }
+
+    async fn relate(app: &TestApp, a: Uuid, b: Uuid) {
+        let dto = CreateBlockRelatedLinkDto {
+            id: Uuid::new_v4(),
+            block_a_id: a,
+            block_b_id: b,
+            label: None,
+            weight: None,
+        };
+        app.repos
+            .block_related_links
+            .create(&dto, app.db.pool())
+            .await
+            .unwrap();
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn root_limits_the_graph_to_its_subtree(#[future] app: TestApp) {
+        let app = app.await;
+        let root = seed_block(&app, "Root").await;
+        let child = seed_block(&app, "Child").await;
+        let grandchild = seed_block(&app, "Grandchild").await;
+        let outside = seed_block(&app, "Outside").await;
+        link(&app, root, child).await;
+        link(&app, child, grandchild).await;
+        link(&app, outside, child).await;
+        relate(&app, root, grandchild).await;
+        relate(&app, child, outside).await;
+
+        let full = build_graph(&app, GraphFormat::Dot, None).await.unwrap();
+        assert_eq!(full.matches(" -> ").count(), 5);
+
+        let dot = build_graph(&app, GraphFormat::Dot, Some(child))
+            .await
+            .unwrap();
+        assert!(dot.contains(&format!("\"{child}\" [label=\"Child\"")));
+        assert!(dot.contains(&format!("\"{grandchild}\" [label=\"Grandchild\"")));
+        assert!(!dot.contains(&root.to_string()));
+        assert!(!dot.contains(&outside.to_string()));
+        assert_eq!(dot.matches(" -> ").count(), 1);
+        assert!(dot.contains(&format!("\"{child}\" -> \"{grandchild}\";")));
+
+        let dot = build_graph(&app, GraphFormat::Dot, Some(root))
+            .await
+            .unwrap();
+        assert!(!dot.contains(&outside.to_string()));
+        // root -> child, child -> grandchild and the related root ~ grandchild
+        assert_eq!(dot.matches(" -> ").count(), 3);
+        assert_eq!(dot.matches("dir=none").count(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn unknown_root_is_not_found(#[future] app: TestApp) {
+        let app = app.await;
+        seed_block(&app, "Block").await;
+
+        let error = build_graph(&app, GraphFormat::Graphml, Some(Uuid::new_v4()))
+            .await
+            .expect_err("an unknown root should fail");
+
+        assert!(matches!(error, ExportGraphError::RootNotFound));
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+    }
+}
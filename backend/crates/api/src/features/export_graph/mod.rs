@@ -0,0 +1,11 @@
+mod error;
+mod graph;
+mod handler;
+mod request;
+mod response;
+mod routes;
+
+pub(crate) use error::ExportGraphError;
+pub(crate) use handler::build_graph;
+pub(crate) use request::GraphFormat;
+pub use routes::routes;
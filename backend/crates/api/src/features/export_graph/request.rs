@@ -0,0 +1,42 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum GraphFormat {
+    /// GraphML, read by Gephi, yEd and most graph libraries
+    Graphml,
+    /// GEXF 1.3, Gephi's native format
+    Gexf,
+    /// Graphviz DOT
+    Dot,
+}
+
+impl GraphFormat {
+    pub(crate) fn file_name(self) -> &'static str {
+        match self {
+            Self::Graphml => "modunote.graphml",
+            Self::Gexf => "modunote.gexf",
+            Self::Dot => "modunote.dot",
+        }
+    }
+
+    pub(crate) fn content_type(self) -> &'static str {
+        match self {
+            Self::Graphml => "application/graphml+xml",
+            Self::Gexf => "application/xml",
+            Self::Dot => "text/vnd.graphviz",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub(crate) struct ExportGraphQuery {
+    #[param(inline)]
+    pub format: GraphFormat,
+    /// Only export this block and its descendants
+    pub root: Option<Uuid>,
+}
@@ -0,0 +1,29 @@
+use axum::{
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+
+use super::{error::ExportGraphError, request::GraphFormat};
+
+pub(crate) struct ExportGraphResponse(Response);
+
+impl ExportGraphResponse {
+    pub(crate) fn new(format: GraphFormat, text: String) -> Result<Self, ExportGraphError> {
+        let response = Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, format.content_type())
+            .header(
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", format.file_name()),
+            )
+            .body(axum::body::Body::from(text))?;
+
+        Ok(Self(response))
+    }
+}
+
+impl IntoResponse for ExportGraphResponse {
+    fn into_response(self) -> Response {
+        self.0
+    }
+}
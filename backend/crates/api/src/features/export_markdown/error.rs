@@ -0,0 +1,54 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+
+use storage::query_services::block_link_query_service::BlockLinkQueryServiceError;
+use storage::query_services::block_query_service::BlockQueryServiceError;
+use storage::repositories::tag_repository::TagRepositoryError;
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum ExportMarkdownError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Http(#[from] axum::http::Error),
+
+    #[error(transparent)]
+    BlockQueryService(#[from] BlockQueryServiceError),
+
+    #[error(transparent)]
+    BlockLinkQueryService(#[from] BlockLinkQueryServiceError),
+
+    #[error(transparent)]
+    TagRepository(#[from] TagRepositoryError),
+
+    #[error("Failed to build export archive")]
+    Zip(#[from] zip::result::ZipError),
+}
+
+impl IntoResponse for ExportMarkdownError {
+    fn into_response(self) -> Response {
+        error!(error = ?self, "Markdown export failure");
+
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: "Export failed".to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
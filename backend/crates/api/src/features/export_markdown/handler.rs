@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Write};
+use std::sync::Arc;
+
+use axum::extract::State;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{error::ExportMarkdownError, response::ExportMarkdownResponse, vault::render_vault};
+use crate::AppState;
+use storage::Database;
+use storage::query_services::{BlockLinkQueryService, BlockQueryService};
+use storage::repositories::TagRepository;
+
+#[utoipa::path(
+    get,
+    path = "/api/export/markdown",
+    tag = "export",
+    responses(
+        (status = 200, description = "ZIP of one Markdown file per live block, with YAML front matter", content_type = "application/zip"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
+#[instrument(err, skip(state))]
+pub async fn export_markdown(
+    State(state): State<Arc<AppState>>,
+) -> Result<ExportMarkdownResponse, ExportMarkdownError> {
+    let bytes = build_vault(&state).await?;
+
+    ExportMarkdownResponse::new(bytes)
+}
+
+/// Builds the ZIP served by `GET /api/export/markdown`. Each note carries
+/// its id, timestamps, tags, parent ids and related ids in front matter, and
+/// links to its children and related notes after the content.
+pub(crate) async fn build_vault(state: &AppState) -> Result<Vec<u8>, ExportMarkdownError> {
+    // One transaction, so links and tags match the blocks they were read with
+    let mut tx = state.db.pool().begin().await?;
+
+    let blocks = state.query_services.blocks.get_all(&mut *tx).await?;
+    let directional = state
+        .query_services
+        .block_links
+        .get_all_directional(&mut *tx)
+        .await?;
+    let related = state
+        .query_services
+        .block_links
+        .get_all_related(&mut *tx)
+        .await?;
+
+    let mut tags_by_block: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for block_tag in state.repos.tags.get_all_block_tags(&mut *tx).await? {
+        tags_by_block
+            .entry(block_tag.block_id)
+            .or_default()
+            .push(block_tag.tag_name);
+    }
+
+    tx.commit().await?;
+
+    let cursor = Cursor::new(Vec::new());
+    let mut zip = zip::ZipWriter::new(cursor);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    for note in render_vault(&blocks, &directional, &related, &tags_by_block) {
+        zip.start_file(note.file_name, options)?;
+        zip.write_all(note.text.as_bytes())?;
+    }
+
+    let bytes = zip.finish()?.into_inner();
+
+    Ok(bytes)
+}
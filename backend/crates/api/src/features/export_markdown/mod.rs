@@ -0,0 +1,9 @@
+mod error;
+mod handler;
+mod response;
+mod routes;
+mod vault;
+
+pub(crate) use error::ExportMarkdownError;
+pub(crate) use handler::build_vault;
+pub use routes::routes;
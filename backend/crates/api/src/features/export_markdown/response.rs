@@ -0,0 +1,29 @@
+use axum::{
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+
+use super::error::ExportMarkdownError;
+
+pub(crate) struct ExportMarkdownResponse(Response);
+
+impl ExportMarkdownResponse {
+    pub(crate) fn new(bytes: Vec<u8>) -> Result<Self, ExportMarkdownError> {
+        let response = Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/zip")
+            .header(
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"modunote-markdown.zip\"",
+            )
+            .body(axum::body::Body::from(bytes))?;
+
+        Ok(Self(response))
+    }
+}
+
+impl IntoResponse for ExportMarkdownResponse {
+    fn into_response(self) -> Response {
+        self.0
+    }
+}
@@ -0,0 +1,311 @@
+//! Renders blocks as Markdown notes with YAML front matter, one file per
+//! block in a flat folder.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use uuid::Uuid;
+
+use storage::query_services::block_link_query_service::{
+    DirectionalLinkExportDto, RelatedLinkExportDto,
+};
+use storage::query_services::block_query_service::BlockExportDto;
+
+const MAX_FILE_STEM_CHARS: usize = 100;
+
+/// Names Windows refuses as file names, whatever the extension
+const RESERVED_STEMS: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+pub(super) struct Note {
+    pub file_name: String,
+    pub text: String,
+}
+
+/// Links to blocks missing from `blocks` are left out.
+pub(super) fn render_vault(
+    blocks: &[BlockExportDto],
+    directional: &[DirectionalLinkExportDto],
+    related: &[RelatedLinkExportDto],
+    tags_by_block: &HashMap<Uuid, Vec<String>>,
+) -> Vec<Note> {
+    let file_names = assign_file_names(blocks);
+    let titles: HashMap<Uuid, &str> = blocks.iter().map(|b| (b.id, b.title.as_str())).collect();
+
+    let mut parents: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    let mut children: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    let exported = |id: &Uuid| titles.contains_key(id);
+
+    for link in directional {
+        if !exported(&link.block_from_id) || !exported(&link.block_to_id) {
+            continue;
+        }
+        parents
+            .entry(link.block_to_id)
+            .or_default()
+            .push(link.block_from_id);
+        children
+            .entry(link.block_from_id)
+            .or_default()
+            .push(link.block_to_id);
+    }
+
+    let mut related_ids: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for link in related {
+        if !exported(&link.block_a_id) || !exported(&link.block_b_id) {
+            continue;
+        }
+        related_ids
+            .entry(link.block_a_id)
+            .or_default()
+            .push(link.block_b_id);
+        related_ids
+            .entry(link.block_b_id)
+            .or_default()
+            .push(link.block_a_id);
+    }
+
+    let no_ids = Vec::new();
+    let no_tags = Vec::new();
+
+    blocks
+        .iter()
+        .map(|block| {
+            let block_parents = parents.get(&block.id).unwrap_or(&no_ids);
+            let block_related = related_ids.get(&block.id).unwrap_or(&no_ids);
+            let block_children = children.get(&block.id).unwrap_or(&no_ids);
+            let tags = tags_by_block.get(&block.id).unwrap_or(&no_tags);
+
+            let mut text = String::new();
+            text.push_str("---\n");
+            let _ = writeln!(text, "id: {}", block.id);
+            let _ = writeln!(text, "title: {}", quote(&block.title));
+            let _ = writeln!(text, "created_at: {}", timestamp(block.created_at));
+            let _ = writeln!(text, "updated_at: {}", timestamp(block.updated_at));
+            if !tags.is_empty() {
+                let tags: Vec<String> = tags.iter().map(|t| quote(t)).collect();
+                let _ = writeln!(text, "tags: [{}]", tags.join(", "));
+            }
+            let _ = writeln!(text, "parents: [{}]", id_list(block_parents));
+            let _ = writeln!(text, "related: [{}]", id_list(block_related));
+            text.push_str("---\n");
+
+            if !block.content.is_empty() {
+                text.push('\n');
+                text.push_str(&block.content);
+                if !block.content.ends_with('\n') {
+                    text.push('\n');
+                }
+            }
+
+            for (heading, ids) in [("Children", block_children), ("Related", block_related)] {
+                if ids.is_empty() {
+                    continue;
+                }
+                let _ = write!(text, "\n## {heading}\n\n");
+                for id in ids {
+                    let (Some(title), Some(file_name)) = (titles.get(id), file_names.get(id))
+                    else {
+                        continue;
+                    };
+                    let _ = writeln!(text, "- [{}](<{file_name}>)", link_text(title));
+                }
+            }
+
+            Note {
+                file_name: file_names
+                    .get(&block.id)
+                    .cloned()
+                    .unwrap_or_else(|| format!("{}.md", block.id)),
+                text,
+            }
+        })
+        .collect()
+}
+
+/// Gives every block a file name derived from its title that is safe on
+/// common file systems. Later blocks with the same name, compared
+/// case-insensitively, get a ` 2`, ` 3`, ... suffix.
+fn assign_file_names(blocks: &[BlockExportDto]) -> HashMap<Uuid, String> {
+    let mut taken: HashSet<String> = HashSet::new();
+    let mut file_names = HashMap::new();
+
+    for block in blocks {
+        let stem = safe_file_stem(&block.title);
+        let mut candidate = stem.clone();
+        let mut suffix = 2;
+        while !taken.insert(candidate.to_lowercase()) {
+            candidate = format!("{stem} {suffix}");
+            suffix += 1;
+        }
+        file_names.insert(block.id, format!("{candidate}.md"));
+    }
+
+    file_names
+}
+
+fn safe_file_stem(title: &str) -> String {
+    // Path separators and characters Windows, or wikilinks, cannot hold
+    let replaced: String = title
+        .chars()
+        .map(|c| {
+            if c.is_control() || r#"/\:*?"<>|#^[]"#.contains(c) {
+                '-'
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    let mut stem: String = replaced
+        .trim_matches(|c: char| c.is_whitespace() || c == '.')
+        .chars()
+        .take(MAX_FILE_STEM_CHARS)
+        .collect();
+    stem.truncate(stem.trim_end().len());
+
+    if stem.is_empty() {
+        stem = "Untitled".to_string();
+    }
+    if RESERVED_STEMS.contains(&stem.to_uppercase().as_str()) {
+        stem.push('_');
+    }
+
+    stem
+}
+
+/// Double-quoted scalar. JSON string escapes are valid YAML.
+fn quote(value: &str) -> String {
+    serde_json::Value::String(value.to_string()).to_string()
+}
+
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+fn id_list(ids: &[Uuid]) -> String {
+    ids.iter()
+        .map(Uuid::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn link_text(title: &str) -> String {
+    let title = title.replace(['[', ']'], "");
+    if title.trim().is_empty() {
+        "Untitled".to_string()
+    } else {
+        title
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(title: &str) -> BlockExportDto {
+        BlockExportDto {
+            id: Uuid::new_v4(),
+            title: title.to_string(),
+            content: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn file_names_of(titles: &[&str]) -> Vec<String> {
+        let blocks: Vec<_> = titles.iter().map(|title| block(title)).collect();
+        let file_names = assign_file_names(&blocks);
+        blocks.iter().map(|b| file_names[&b.id].clone()).collect()
+    }
+
+    #[test]
+    fn file_stems_replace_unsafe_characters() {
+        assert_eq!(
+            safe_file_stem("a/b\\c:d*e?f\"g<h>i|j#k^l[m]n"),
+            "a-b-c-d-e-f-g-h-i-j-k-l-m-n"
+        );
+        assert_eq!(safe_file_stem("tab\there"), "tab-here");
+        assert_eq!(safe_file_stem("  .hidden. "), "hidden");
+        assert_eq!(safe_file_stem(" .. "), "Untitled");
+    }
+
+    #[test]
+    fn file_stems_are_truncated_without_trailing_space() {
+        let long = format!("{} tail", "x".repeat(MAX_FILE_STEM_CHARS - 1));
+        assert_eq!(safe_file_stem(&long), "x".repeat(MAX_FILE_STEM_CHARS - 1));
+
+        let wide = "é".repeat(MAX_FILE_STEM_CHARS + 10);
+        assert_eq!(safe_file_stem(&wide).chars().count(), MAX_FILE_STEM_CHARS);
+    }
+
+    #[test]
+    fn reserved_names_get_an_underscore() {
+        assert_eq!(safe_file_stem("con"), "con_");
+        assert_eq!(safe_file_stem("LPT9"), "LPT9_");
+        assert_eq!(safe_file_stem("Console"), "Console");
+    }
+
+    #[test]
+    fn clashing_names_are_numbered_case_insensitively() {
+        assert_eq!(
+            file_names_of(&["Note", "note", "NOTE", "Note 2", "Other"]),
+            [
+                "Note.md",
+                "note 2.md",
+                "NOTE 3.md",
+                "Note 2 2.md",
+                "Other.md"
+            ]
+        );
+        assert_eq!(file_names_of(&["nul", "NUL"]), ["nul_.md", "NUL_ 2.md"]);
+        assert_eq!(file_names_of(&["", " . "]), ["Untitled.md", "Untitled 2.md"]);
+    }
+
+    #[test]
+    fn quoted_scalars_escape_quotes_and_control_characters() {
+        assert_eq!(quote("plain"), r#""plain""#);
+        assert_eq!(quote("say \"hi\": yes"), r#""say \"hi\": yes""#);
+        assert_eq!(
+            quote("back\\slash\nline\u{7}"),
+            r#""back\\slash\nline\u0007""#
+        );
+        assert_eq!(quote("# not a comment"), r##""# not a comment""##);
+    }
+
+    #[test]
+    fn links_to_blocks_outside_the_export_are_left_out() {
+        let (parent, child) = (block("Parent"), block("Child"));
+        let missing = Uuid::new_v4();
+        let link = |from: Uuid, to: Uuid| DirectionalLinkExportDto {
+            id: Uuid::new_v4(),
+            block_from_id: from,
+            block_to_id: to,
+            kind: None,
+            label: None,
+            weight: None,
+            created_at: Utc::now(),
+        };
+        let directional = [link(parent.id, child.id), link(parent.id, missing)];
+        let (a, b) = (parent.id.min(missing), parent.id.max(missing));
+        let related = [RelatedLinkExportDto {
+            id: Uuid::new_v4(),
+            block_a_id: a,
+            block_b_id: b,
+            label: None,
+            weight: None,
+            created_at: Utc::now(),
+        }];
+
+        let notes = render_vault(&[parent, child], &directional, &related, &HashMap::new());
+
+        let parent_note = &notes[0].text;
+        assert!(parent_note.contains("- [Child](<Child.md>)"));
+        assert!(parent_note.contains("related: []"));
+        assert!(!parent_note.contains(&missing.to_string()));
+        assert!(!parent_note.contains("## Related"));
+    }
+}
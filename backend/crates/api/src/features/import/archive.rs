@@ -0,0 +1,228 @@
+//! Reads an export archive, upgrading older format versions so the importer
+//! only deals with the current layout. Blocks are read one line at a time;
+//! links, tabs, pins and canvases are small enough to load whole. Every entry
+//! is read through a `DecompressionBudget`, and all of it is blocking I/O, so
+//! callers run it off the async runtime.
+
+use std::io::{self, BufRead, BufReader, Read, Seek};
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+use zip::ZipArchive;
+use zip::result::ZipError;
+
+use super::error::ImportError;
+use super::limits::DecompressionBudget;
+use crate::features::export::archive::{
+    ARCHIVE_FORMAT_VERSION, ArchiveCountsRecord, BLOCK_OPENS_FILE, BLOCK_PINS_FILE, BLOCKS_FILE,
+    BlockOpenRecord, BlockPinRecord, CANVAS_BLOCKS_FILE, CANVAS_PINS_FILE, CANVASES_FILE,
+    CanvasBlockRecord, CanvasPinRecord, CanvasRecord, DIRECTIONAL_LINKS_FILE, MANIFEST_FILE,
+    ManifestRecord, RELATED_LINKS_FILE,
+};
+use crate::features::export::spool::SpooledFile;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ImportedBlock {
+    pub id: Uuid,
+    pub title: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    // Archives exported before tags existed have no tags field
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+// Archives exported before link annotations existed store bare id pairs.
+// Link ids and timestamps were added in format version 2.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(super) enum ImportedDirectionalLink {
+    Pair([Uuid; 2]),
+    #[serde(rename_all = "camelCase")]
+    Annotated {
+        id: Option<Uuid>,
+        block_from_id: Uuid,
+        block_to_id: Uuid,
+        kind: Option<String>,
+        label: Option<String>,
+        weight: Option<f64>,
+        created_at: Option<DateTime<Utc>>,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(super) enum ImportedRelatedLink {
+    Pair([Uuid; 2]),
+    #[serde(rename_all = "camelCase")]
+    Annotated {
+        id: Option<Uuid>,
+        block_a_id: Uuid,
+        block_b_id: Uuid,
+        label: Option<String>,
+        weight: Option<f64>,
+        created_at: Option<DateTime<Utc>>,
+    },
+}
+
+/// Blocks read from `blocks.jsonl` per batch.
+const BLOCK_BATCH_SIZE: usize = 256;
+
+/// Blocks from `blocks.jsonl`, parsed as they are read.
+pub(super) struct ImportedBlocks {
+    /// `None` once every line has been read
+    lines: Option<io::Lines<BufReader<SpooledFile>>>,
+    batch: std::vec::IntoIter<ImportedBlock>,
+}
+
+impl ImportedBlocks {
+    /// Returns the next block. Lines are read and parsed a batch at a time,
+    /// off the async runtime.
+    pub(super) async fn next(&mut self) -> Result<Option<ImportedBlock>, ImportError> {
+        if let Some(block) = self.batch.next() {
+            return Ok(Some(block));
+        }
+        let Some(mut lines) = self.lines.take() else {
+            return Ok(None);
+        };
+
+        let (lines, batch) = tokio::task::spawn_blocking(move || {
+            let mut batch = Vec::with_capacity(BLOCK_BATCH_SIZE);
+            while batch.len() < BLOCK_BATCH_SIZE {
+                let Some(line) = lines.next() else {
+                    return Ok((None, batch));
+                };
+                let line = line?;
+                if !line.trim().is_empty() {
+                    batch.push(serde_json::from_str(&line)?);
+                }
+            }
+            Ok::<_, ImportError>((Some(lines), batch))
+        })
+        .await
+        .map_err(io::Error::other)??;
+
+        self.lines = lines;
+        self.batch = batch.into_iter();
+        Ok(self.batch.next())
+    }
+}
+
+pub(super) struct Archive {
+    pub format_version: u32,
+    pub blocks: ImportedBlocks,
+    pub directional_links: Vec<ImportedDirectionalLink>,
+    pub related_links: Vec<ImportedRelatedLink>,
+    pub block_opens: Vec<BlockOpenRecord>,
+    pub block_pins: Vec<BlockPinRecord>,
+    pub canvases: Vec<CanvasRecord>,
+    pub canvas_blocks: Vec<CanvasBlockRecord>,
+    pub canvas_pins: Vec<CanvasPinRecord>,
+}
+
+/// Parses every file in the archive. An archive without a manifest is
+/// version 1, which has no open tabs, pins or canvases. Newer archives must
+/// hold exactly the number of records their manifest lists.
+///
+/// `blocks.jsonl` is decompressed into its own spool file and checked line by
+/// line here, so a malformed block fails the import before anything is written.
+/// Entries that decompress past the budget's limits fail the import too.
+pub(super) fn read_archive<R: Read + Seek>(reader: R) -> Result<Archive, ImportError> {
+    let mut zip = ZipArchive::new(reader)?;
+    let mut budget = DecompressionBudget::new();
+
+    let manifest: Option<ManifestRecord> = match read_entry(&mut zip, MANIFEST_FILE, &mut budget) {
+        Ok(content) => Some(serde_json::from_slice(&content)?),
+        Err(ImportError::Zip(ZipError::FileNotFound)) => None,
+        Err(e) => return Err(e),
+    };
+    let format_version = manifest.as_ref().map_or(1, |m| m.format_version);
+    if format_version > ARCHIVE_FORMAT_VERSION {
+        return Err(ImportError::UnsupportedVersion {
+            found: format_version,
+            supported: ARCHIVE_FORMAT_VERSION,
+        });
+    }
+
+    let mut blocks_file = SpooledFile::new();
+    budget.copy(BLOCKS_FILE, zip.by_name(BLOCKS_FILE)?, &mut blocks_file)?;
+    blocks_file.rewind()?;
+    let mut block_count = 0;
+    for line in BufReader::new(&mut blocks_file).lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            serde_json::from_str::<ImportedBlock>(&line)?;
+            block_count += 1;
+        }
+    }
+    blocks_file.rewind()?;
+    let blocks = ImportedBlocks {
+        lines: Some(BufReader::new(blocks_file).lines()),
+        batch: Vec::new().into_iter(),
+    };
+
+    let directional_links = read_json(&mut zip, DIRECTIONAL_LINKS_FILE, &mut budget)?;
+    let related_links = read_json(&mut zip, RELATED_LINKS_FILE, &mut budget)?;
+
+    let Some(manifest) = manifest else {
+        return Ok(Archive {
+            format_version,
+            blocks,
+            directional_links,
+            related_links,
+            block_opens: Vec::new(),
+            block_pins: Vec::new(),
+            canvases: Vec::new(),
+            canvas_blocks: Vec::new(),
+            canvas_pins: Vec::new(),
+        });
+    };
+
+    let archive = Archive {
+        format_version,
+        blocks,
+        directional_links,
+        related_links,
+        block_opens: read_json(&mut zip, BLOCK_OPENS_FILE, &mut budget)?,
+        block_pins: read_json(&mut zip, BLOCK_PINS_FILE, &mut budget)?,
+        canvases: read_json(&mut zip, CANVASES_FILE, &mut budget)?,
+        canvas_blocks: read_json(&mut zip, CANVAS_BLOCKS_FILE, &mut budget)?,
+        canvas_pins: read_json(&mut zip, CANVAS_PINS_FILE, &mut budget)?,
+    };
+
+    let counts = ArchiveCountsRecord {
+        blocks: block_count,
+        directional_links: archive.directional_links.len(),
+        related_links: archive.related_links.len(),
+        block_opens: archive.block_opens.len(),
+        block_pins: archive.block_pins.len(),
+        canvases: archive.canvases.len(),
+        canvas_blocks: archive.canvas_blocks.len(),
+        canvas_pins: archive.canvas_pins.len(),
+    };
+    if counts != manifest.counts {
+        return Err(ImportError::CountMismatch);
+    }
+
+    Ok(archive)
+}
+
+fn read_json<T: DeserializeOwned, R: Read + Seek>(
+    zip: &mut ZipArchive<R>,
+    name: &str,
+    budget: &mut DecompressionBudget,
+) -> Result<T, ImportError> {
+    Ok(serde_json::from_slice(&read_entry(zip, name, budget)?)?)
+}
+
+fn read_entry<R: Read + Seek>(
+    zip: &mut ZipArchive<R>,
+    name: &str,
+    budget: &mut DecompressionBudget,
+) -> Result<Vec<u8>, ImportError> {
+    Ok(budget.read(name, zip.by_name(name)?)?)
+}
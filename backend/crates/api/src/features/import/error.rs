@@ -5,11 +5,21 @@ use axum::{
 };
 use serde::Serialize;
 
+use super::limits::DecompressionError;
 use storage::query_services::block_link_query_service::BlockLinkQueryServiceError;
 use storage::query_services::block_query_service::BlockQueryServiceError;
 use storage::repositories::block_directional_link_repository::BlockDirectionalLinkRepositoryError;
+use storage::repositories::block_pin_repository::BlockPinRepositoryError;
 use storage::repositories::block_related_link_repository::BlockRelatedLinkError;
 use storage::repositories::block_repository::BlockRepositoryError;
+use storage::repositories::canvas_block_repository::CanvasBlockRepositoryError;
+use storage::repositories::canvas_pin_repository::CanvasPinRepositoryError;
+use storage::repositories::canvas_repository::CanvasRepositoryError;
+use storage::repositories::tag_repository::TagRepositoryError;
+use storage::repositories::workspace_repository::WorkspaceRepositoryError;
+
+/// Shown when an upload is cut off by the body size limit
+pub(crate) const UPLOAD_TOO_LARGE: &str = "Upload exceeds the size limit set by MAX_UPLOAD_BYTES";
 
 #[derive(Serialize)]
 struct ErrorResponse {
@@ -18,6 +28,9 @@ struct ErrorResponse {
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ImportError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
@@ -36,6 +49,27 @@ pub(crate) enum ImportError {
     #[error(transparent)]
     BlockRelatedLinkRepository(#[from] BlockRelatedLinkError),
 
+    #[error(transparent)]
+    TagRepository(#[from] TagRepositoryError),
+
+    #[error(transparent)]
+    WorkspaceRepository(#[from] WorkspaceRepositoryError),
+
+    #[error(transparent)]
+    BlockPinRepository(#[from] BlockPinRepositoryError),
+
+    #[error(transparent)]
+    CanvasRepository(#[from] CanvasRepositoryError),
+
+    #[error(transparent)]
+    CanvasBlockRepository(#[from] CanvasBlockRepositoryError),
+
+    #[error(transparent)]
+    CanvasPinRepository(#[from] CanvasPinRepositoryError),
+
+    #[error(transparent)]
+    Decompression(#[from] DecompressionError),
+
     #[error("Failed to read archive")]
     Zip(#[from] zip::result::ZipError),
 
@@ -47,12 +81,28 @@ pub(crate) enum ImportError {
 
     #[error("No file field found in multipart request")]
     MissingFile,
+
+    #[error("Archive format version {found} is newer than the supported version {supported}")]
+    UnsupportedVersion { found: u32, supported: u32 },
+
+    #[error("Archive contents do not match its manifest counts")]
+    CountMismatch,
 }
 
 impl IntoResponse for ImportError {
     fn into_response(self) -> Response {
         let (status, msg) = match &self {
-            Self::MissingFile => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::MissingFile | Self::UnsupportedVersion { .. } | Self::CountMismatch => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
+            Self::Decompression(
+                DecompressionError::EntryTooLarge { .. }
+                | DecompressionError::ArchiveTooLarge { .. },
+            ) => (StatusCode::PAYLOAD_TOO_LARGE, self.to_string()),
+            Self::Multipart(e) if e.status() == StatusCode::PAYLOAD_TOO_LARGE => {
+                (StatusCode::PAYLOAD_TOO_LARGE, UPLOAD_TOO_LARGE.to_string())
+            }
+            Self::Multipart(e) => (e.status(), e.body_text()),
             _ => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Import failed".to_string(),
@@ -1,19 +1,32 @@
 use std::collections::{HashMap, HashSet};
-use std::io::{Cursor, Read};
+use std::io::{Read, Seek};
 use std::sync::Arc;
 
-use axum::extract::{Multipart, State};
+use axum::extract::{Multipart, Query, State};
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use sqlx::Connection as _;
 use tracing::instrument;
 use uuid::Uuid;
-use zip::ZipArchive;
 
-use super::{error::ImportError, response::ImportResponse};
+use super::archive::{
+    Archive, ImportedBlocks, ImportedDirectionalLink, ImportedRelatedLink, read_archive,
+};
+use super::{
+    error::ImportError,
+    request::{ImportOptions, ImportStrategy},
+    response::{ConflictReason, ConflictResolution, ImportConflict, ImportResponse},
+};
 use crate::AppState;
+use crate::app_state::DatabaseImpl;
+use crate::features::export::archive::{
+    BlockOpenRecord, BlockPinRecord, CanvasBlockRecord, CanvasPinRecord, CanvasRecord,
+};
+use crate::features::export::spool::SpooledFile;
 use domain::blocks::Block;
+use domain::canvases::{Canvas, CanvasBlock};
+use domain::tags::Tag;
+use domain::workspaces::OpenedBlock;
 use storage::Database;
-use storage::query_services::block_query_service::BlockExportDto;
 use storage::query_services::{BlockLinkQueryService, BlockQueryService};
 use storage::repositories::block_directional_link_repository::{
     BlockDirectionalLinkRepositoryError, CreateBlockDirectionalLinkDto,
@@ -22,24 +35,86 @@ use storage::repositories::block_related_link_repository::{
     BlockRelatedLinkError, CreateBlockRelatedLinkDto,
 };
 use storage::repositories::{
-    BlockDirectionalLinkRepository, BlockRelatedLinkRepository, BlockRepository,
+    BlockDirectionalLinkRepository, BlockPinRepository, BlockRelatedLinkRepository,
+    BlockRepository, CanvasBlockRepository, CanvasPinRepository, CanvasRepository, TagRepository,
+    WorkspaceRepository,
 };
 
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct ImportedBlock {
-    id: Uuid,
-    title: String,
-    content: String,
-    created_at: DateTime<Utc>,
-    updated_at: DateTime<Utc>,
+type Connection = <<DatabaseImpl as Database>::Provider as sqlx::Database>::Connection;
+
+impl ImportedDirectionalLink {
+    /// Splits the link into a create dto and its archived `created_at`.
+    /// Links from version 1 archives get a fresh id.
+    fn into_parts(self) -> (CreateBlockDirectionalLinkDto, Option<DateTime<Utc>>) {
+        let (id, block_from_id, block_to_id, kind, label, weight, created_at) = match self {
+            Self::Pair([from, to]) => (None, from, to, None, None, None, None),
+            Self::Annotated {
+                id,
+                block_from_id,
+                block_to_id,
+                kind,
+                label,
+                weight,
+                created_at,
+            } => (
+                id,
+                block_from_id,
+                block_to_id,
+                kind,
+                label,
+                weight,
+                created_at,
+            ),
+        };
+
+        let dto = CreateBlockDirectionalLinkDto {
+            id: id.unwrap_or_else(Uuid::new_v4),
+            block_from_id,
+            block_to_id,
+            kind,
+            label,
+            weight,
+        };
+        (dto, created_at)
+    }
+}
+
+impl ImportedRelatedLink {
+    /// Splits the link into a create dto and its archived `created_at`.
+    /// Links from version 1 archives get a fresh id.
+    fn into_parts(self) -> (CreateBlockRelatedLinkDto, Option<DateTime<Utc>>) {
+        let (id, a, b, label, weight, created_at) = match self {
+            Self::Pair([a, b]) => (None, a, b, None, None, None),
+            Self::Annotated {
+                id,
+                block_a_id,
+                block_b_id,
+                label,
+                weight,
+                created_at,
+            } => (id, block_a_id, block_b_id, label, weight, created_at),
+        };
+        let (block_a_id, block_b_id) = if a < b { (a, b) } else { (b, a) };
+
+        let dto = CreateBlockRelatedLinkDto {
+            id: id.unwrap_or_else(Uuid::new_v4),
+            block_a_id,
+            block_b_id,
+            label,
+            weight,
+        };
+        (dto, created_at)
+    }
 }
 
 struct BlockImportResult {
     inserted: usize,
     updated: usize,
     skipped: usize,
+    copied: usize,
     new_ids: HashSet<Uuid>,
+    /// Archived ids of blocks imported as copies, mapped to their new ids
+    copied_ids: HashMap<Uuid, Uuid>,
 }
 
 struct LinkImportResult {
@@ -47,40 +122,106 @@ struct LinkImportResult {
     skipped: usize,
 }
 
+#[derive(Default)]
+struct ExtrasImportResult {
+    block_opens_inserted: usize,
+    block_pins_inserted: usize,
+    canvases_inserted: usize,
+    canvases_skipped: usize,
+    canvas_blocks_inserted: usize,
+    canvas_pins_inserted: usize,
+}
+
 #[utoipa::path(
     post,
     path = "/api/import",
     tag = "import",
+    params(ImportOptions),
     responses(
         (status = 200, description = "Import summary", body = ImportResponse),
         (status = 400, description = "Bad request"),
+        (status = 413, description = "Upload exceeds the size limit"),
         (status = 500, description = "Internal server error"),
     )
 )]
 #[instrument(err, skip(state, multipart))]
 pub async fn import(
     State(state): State<Arc<AppState>>,
+    Query(options): Query<ImportOptions>,
     mut multipart: Multipart,
 ) -> Result<ImportResponse, ImportError> {
-    let zip_bytes = loop {
+    // Spooled chunk by chunk so a large upload never sits in memory whole
+    let mut upload = SpooledFile::new();
+    loop {
         match multipart.next_field().await? {
             None => return Err(ImportError::MissingFile),
-            Some(field) if field.name() == Some("file") => {
-                break field.bytes().await?.to_vec();
+            Some(mut field) if field.name() == Some("file") => {
+                while let Some(chunk) = field.chunk().await? {
+                    upload = upload.append(chunk).await?;
+                }
+                break;
             }
             Some(_) => continue,
         }
-    };
+    }
+    upload.rewind()?;
 
-    let pool = state.db.pool();
+    import_archive(upload, &options, &state).await
+}
 
-    let existing_blocks: HashMap<Uuid, _> = state
+/// Merges an archive produced by `GET /api/export` into the database. A dry
+/// run applies the archive inside a transaction and rolls it back, so the
+/// summary and conflicts are exactly what a real run would produce.
+pub(crate) async fn import_archive<R: Read + Seek + Send + 'static>(
+    reader: R,
+    options: &ImportOptions,
+    state: &AppState,
+) -> Result<ImportResponse, ImportError> {
+    let archive = tokio::task::spawn_blocking(move || read_archive(reader))
+        .await
+        .map_err(std::io::Error::other)??;
+
+    if options.dry_run || options.atomic {
+        let mut tx = state.db.pool().begin().await?;
+        let mut response = apply_archive(archive, options.strategy, state, &mut tx).await?;
+
+        if options.dry_run {
+            tx.rollback().await?;
+            response.dry_run = true;
+        } else {
+            tx.commit().await?;
+        }
+
+        Ok(response)
+    } else {
+        let mut conn = state.db.pool().acquire().await?;
+        apply_archive(archive, options.strategy, state, &mut conn).await
+    }
+}
+
+async fn apply_archive(
+    archive: Archive,
+    strategy: ImportStrategy,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<ImportResponse, ImportError> {
+    let existing_blocks: HashMap<Uuid, DateTime<Utc>> = state
         .query_services
         .blocks
-        .get_all(pool)
+        .get_all_updated_at(&mut *conn)
         .await?
         .into_iter()
-        .map(|b| (b.id, b))
+        .map(|b| (b.id, b.updated_at))
+        .collect();
+
+    // Trashed blocks keep their ids, so archived blocks can clash with them too
+    let trashed_block_ids: HashSet<Uuid> = state
+        .query_services
+        .blocks
+        .get_trashed(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|b| b.id)
         .collect();
 
     let mut known_block_ids: HashSet<Uuid> = existing_blocks.keys().copied().collect();
@@ -88,7 +229,7 @@ pub async fn import(
     let dir_set: HashSet<(Uuid, Uuid)> = state
         .query_services
         .block_links
-        .get_all_directional(pool)
+        .get_all_directional(&mut *conn)
         .await?
         .into_iter()
         .map(|l| (l.block_from_id, l.block_to_id))
@@ -97,120 +238,348 @@ pub async fn import(
     let rel_set: HashSet<(Uuid, Uuid)> = state
         .query_services
         .block_links
-        .get_all_related(pool)
+        .get_all_related(&mut *conn)
         .await?
         .into_iter()
         .map(|l| (l.block_a_id, l.block_b_id))
         .collect();
 
-    let mut archive = ZipArchive::new(Cursor::new(zip_bytes))?;
+    let mut conflicts = Vec::new();
 
-    let blocks_content = read_zip_entry(&mut archive, "blocks.jsonl")?;
-    let blocks = process_blocks(&blocks_content, &existing_blocks, &state).await?;
+    let blocks = process_blocks(
+        archive.blocks,
+        &existing_blocks,
+        &trashed_block_ids,
+        strategy,
+        &mut conflicts,
+        state,
+        conn,
+    )
+    .await?;
     known_block_ids.extend(&blocks.new_ids);
 
-    let dir_content = read_zip_entry(&mut archive, "directional_links.json")?;
-    let dir_links =
-        process_directional_links(&dir_content, &dir_set, &known_block_ids, &state).await?;
+    let dir_links = process_directional_links(
+        archive.directional_links,
+        &dir_set,
+        &known_block_ids,
+        &blocks.copied_ids,
+        &mut conflicts,
+        state,
+        conn,
+    )
+    .await?;
 
-    let rel_content = read_zip_entry(&mut archive, "related_links.json")?;
-    let rel_links = process_related_links(&rel_content, &rel_set, &known_block_ids, &state).await?;
+    let rel_links = process_related_links(
+        archive.related_links,
+        &rel_set,
+        &known_block_ids,
+        &blocks.copied_ids,
+        &mut conflicts,
+        state,
+        conn,
+    )
+    .await?;
+
+    let mut extras = ExtrasImportResult::default();
+    process_block_opens(
+        archive.block_opens,
+        &known_block_ids,
+        &blocks.copied_ids,
+        &mut extras,
+        state,
+        conn,
+    )
+    .await?;
+    process_block_pins(
+        archive.block_pins,
+        &known_block_ids,
+        &blocks.copied_ids,
+        &mut extras,
+        state,
+        conn,
+    )
+    .await?;
+
+    // Canvases go in together with their placements and pins
+    let mut canvas_tx = conn.begin().await?;
+    let new_canvas_ids = process_canvases(
+        archive.canvases,
+        &mut extras,
+        &mut conflicts,
+        state,
+        &mut canvas_tx,
+    )
+    .await?;
+    process_canvas_blocks(
+        archive.canvas_blocks,
+        &new_canvas_ids,
+        &known_block_ids,
+        &blocks.copied_ids,
+        &mut extras,
+        state,
+        &mut canvas_tx,
+    )
+    .await?;
+    process_canvas_pins(
+        archive.canvas_pins,
+        &new_canvas_ids,
+        &mut extras,
+        state,
+        &mut canvas_tx,
+    )
+    .await?;
+    canvas_tx.commit().await?;
 
     Ok(ImportResponse {
+        dry_run: false,
+        format_version: archive.format_version,
         blocks_inserted: blocks.inserted,
         blocks_updated: blocks.updated,
         blocks_skipped: blocks.skipped,
+        blocks_copied: blocks.copied,
         dir_links_inserted: dir_links.inserted,
         dir_links_skipped: dir_links.skipped,
         related_links_inserted: rel_links.inserted,
         related_links_skipped: rel_links.skipped,
+        block_opens_inserted: extras.block_opens_inserted,
+        block_pins_inserted: extras.block_pins_inserted,
+        canvases_inserted: extras.canvases_inserted,
+        canvases_skipped: extras.canvases_skipped,
+        canvas_blocks_inserted: extras.canvas_blocks_inserted,
+        canvas_pins_inserted: extras.canvas_pins_inserted,
+        conflicts,
     })
 }
 
 async fn process_blocks(
-    content: &str,
-    existing_blocks: &HashMap<Uuid, BlockExportDto>,
+    mut imported_blocks: ImportedBlocks,
+    existing_blocks: &HashMap<Uuid, DateTime<Utc>>,
+    trashed_block_ids: &HashSet<Uuid>,
+    strategy: ImportStrategy,
+    conflicts: &mut Vec<ImportConflict>,
     state: &AppState,
+    conn: &mut Connection,
 ) -> Result<BlockImportResult, ImportError> {
     let mut result = BlockImportResult {
         inserted: 0,
         updated: 0,
         skipped: 0,
+        copied: 0,
         new_ids: HashSet::new(),
+        copied_ids: HashMap::new(),
     };
 
-    for line in content.lines().filter(|l| !l.trim().is_empty()) {
-        let imported: ImportedBlock = serde_json::from_str(line)?;
+    while let Some(imported) = imported_blocks.next().await? {
+        let existing = existing_blocks.get(&imported.id);
+        let trashed = trashed_block_ids.contains(&imported.id);
+        if existing.is_none() && !trashed {
+            let block = Block {
+                id: imported.id,
+                title: imported.title,
+                content: imported.content,
+                created_at: imported.created_at,
+                updated_at: imported.updated_at,
+                version: 1,
+            };
+            save_block(&block, &imported.tags, state, conn).await?;
+            result.new_ids.insert(imported.id);
+            result.inserted += 1;
+            continue;
+        }
 
-        match existing_blocks.get(&imported.id) {
-            None => {
+        let resolution = match (existing, strategy) {
+            (_, ImportStrategy::Copy) => ConflictResolution::Copied,
+            // A block in the local trash is never overwritten; restore it to merge
+            (None, _) => ConflictResolution::Skipped,
+            (Some(&updated_at), ImportStrategy::NewerWins) if imported.updated_at > updated_at => {
+                ConflictResolution::Overwritten
+            }
+            (Some(_), ImportStrategy::NewerWins | ImportStrategy::KeepLocal) => {
+                ConflictResolution::Skipped
+            }
+            (Some(_), ImportStrategy::Overwrite) => ConflictResolution::Overwritten,
+        };
+
+        let mut new_id = None;
+        match resolution {
+            ConflictResolution::Overwritten => {
                 let block = Block {
                     id: imported.id,
                     title: imported.title,
                     content: imported.content,
                     created_at: imported.created_at,
                     updated_at: imported.updated_at,
+                    // Overwriting bumps the stored version
+                    version: 1,
                 };
-                state.repos.blocks.save(&block, state.db.pool()).await?;
-                result.new_ids.insert(imported.id);
-                result.inserted += 1;
+                overwrite_block(&block, &imported.tags, state, conn).await?;
+                result.updated += 1;
             }
-            Some(existing) if imported.updated_at > existing.updated_at => {
+            ConflictResolution::Copied => {
                 let block = Block {
-                    id: imported.id,
+                    id: Uuid::new_v4(),
                     title: imported.title,
                     content: imported.content,
-                    created_at: existing.created_at,
+                    created_at: imported.created_at,
                     updated_at: imported.updated_at,
+                    version: 1,
                 };
-                state.repos.blocks.save(&block, state.db.pool()).await?;
-                result.updated += 1;
+                save_block(&block, &imported.tags, state, conn).await?;
+                result.new_ids.insert(block.id);
+                result.copied_ids.insert(imported.id, block.id);
+                result.copied += 1;
+                new_id = Some(block.id);
             }
-            Some(_) => {
+            ConflictResolution::Skipped => {
                 result.skipped += 1;
             }
         }
+
+        conflicts.push(ImportConflict::Block {
+            id: imported.id,
+            reason: if trashed {
+                ConflictReason::Trashed
+            } else {
+                ConflictReason::AlreadyExists
+            },
+            resolution,
+            new_id,
+        });
     }
 
     Ok(result)
 }
 
+/// Writes a block together with its tags, so an import that stops half way
+/// never leaves a block without them.
+async fn save_block(
+    block: &Block,
+    tags: &[String],
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<(), ImportError> {
+    let mut tx = conn.begin().await?;
+    state
+        .repos
+        .blocks
+        .save_with_timestamps(block, &mut *tx)
+        .await?;
+    import_tags(block.id, tags, state, &mut tx).await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Like `save_block`, but the block ends up with only the archive's tags.
+async fn overwrite_block(
+    block: &Block,
+    tags: &[String],
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<(), ImportError> {
+    let mut tx = conn.begin().await?;
+    state
+        .repos
+        .blocks
+        .save_with_timestamps(block, &mut *tx)
+        .await?;
+    for tag in state.repos.tags.get_by_block(block.id, &mut *tx).await? {
+        state
+            .repos
+            .tags
+            .untag_block(block.id, tag.id, &mut *tx)
+            .await?;
+    }
+    import_tags(block.id, tags, state, &mut tx).await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+async fn import_tags(
+    block_id: Uuid,
+    names: &[String],
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<(), ImportError> {
+    for name in names {
+        let Ok(tag) = Tag::new(name) else {
+            tracing::warn!(%block_id, "Skipping blank tag name");
+            continue;
+        };
+        state
+            .repos
+            .tags
+            .tag_block(block_id, &tag, &mut *conn)
+            .await?;
+    }
+
+    Ok(())
+}
+
 async fn process_directional_links(
-    content: &str,
+    links: Vec<ImportedDirectionalLink>,
     dir_set: &HashSet<(Uuid, Uuid)>,
     known_block_ids: &HashSet<Uuid>,
+    copied_ids: &HashMap<Uuid, Uuid>,
+    conflicts: &mut Vec<ImportConflict>,
     state: &AppState,
+    conn: &mut Connection,
 ) -> Result<LinkImportResult, ImportError> {
     let mut result = LinkImportResult {
         inserted: 0,
         skipped: 0,
     };
 
-    let pairs: Vec<[Uuid; 2]> = serde_json::from_str(content)?;
-    for [from_id, to_id] in pairs {
-        if dir_set.contains(&(from_id, to_id)) {
-            result.skipped += 1;
-            continue;
+    for link in links {
+        let (mut dto, created_at) = link.into_parts();
+        let (from_id, to_id) = (
+            remap(dto.block_from_id, copied_ids),
+            remap(dto.block_to_id, copied_ids),
+        );
+        // A link between copies is a new link, so it must not reuse the archived id
+        if (from_id, to_id) != (dto.block_from_id, dto.block_to_id) {
+            dto.id = Uuid::new_v4();
         }
-        if !known_block_ids.contains(&from_id) || !known_block_ids.contains(&to_id) {
+        (dto.block_from_id, dto.block_to_id) = (from_id, to_id);
+
+        let reason = if dir_set.contains(&(from_id, to_id)) {
+            Some(ConflictReason::AlreadyExists)
+        } else if !known_block_ids.contains(&from_id) || !known_block_ids.contains(&to_id) {
             tracing::warn!(%from_id, %to_id, "Skipping directional link: block not found");
-            result.skipped += 1;
-            continue;
-        }
-        let dto = CreateBlockDirectionalLinkDto {
-            id: Uuid::new_v4(),
-            block_from_id: from_id,
-            block_to_id: to_id,
+            Some(ConflictReason::MissingBlock)
+        } else {
+            match state
+                .repos
+                .block_directional_links
+                .create_at(&dto, created_at.unwrap_or_else(Utc::now), &mut *conn)
+                .await
+            {
+                Ok(_) => None,
+                Err(BlockDirectionalLinkRepositoryError::AlreadyExists { .. }) => {
+                    Some(ConflictReason::AlreadyExists)
+                }
+                Err(BlockDirectionalLinkRepositoryError::BlocksNotFound { .. }) => {
+                    Some(ConflictReason::MissingBlock)
+                }
+                Err(BlockDirectionalLinkRepositoryError::CycleDetected { .. }) => {
+                    Some(ConflictReason::Cycle)
+                }
+                Err(e) => return Err(e.into()),
+            }
         };
-        match state
-            .repos
-            .block_directional_links
-            .create(&dto, state.db.pool())
-            .await
-        {
-            Ok(_) => result.inserted += 1,
-            Err(BlockDirectionalLinkRepositoryError::AlreadyExists { .. }) => result.skipped += 1,
-            Err(e) => return Err(e.into()),
+
+        match reason {
+            None => result.inserted += 1,
+            Some(reason) => {
+                result.skipped += 1;
+                conflicts.push(ImportConflict::DirectionalLink {
+                    block_from_id: from_id,
+                    block_to_id: to_id,
+                    reason,
+                    resolution: ConflictResolution::Skipped,
+                });
+            }
         }
     }
 
@@ -218,54 +587,818 @@ async fn process_directional_links(
 }
 
 async fn process_related_links(
-    content: &str,
+    links: Vec<ImportedRelatedLink>,
     rel_set: &HashSet<(Uuid, Uuid)>,
     known_block_ids: &HashSet<Uuid>,
+    copied_ids: &HashMap<Uuid, Uuid>,
+    conflicts: &mut Vec<ImportConflict>,
     state: &AppState,
+    conn: &mut Connection,
 ) -> Result<LinkImportResult, ImportError> {
     let mut result = LinkImportResult {
         inserted: 0,
         skipped: 0,
     };
 
-    let pairs: Vec<[Uuid; 2]> = serde_json::from_str(content)?;
-    for [a, b] in pairs {
-        let (a_id, b_id) = if a < b { (a, b) } else { (b, a) };
-        if rel_set.contains(&(a_id, b_id)) {
-            result.skipped += 1;
-            continue;
+    for link in links {
+        let (mut dto, created_at) = link.into_parts();
+        let a = remap(dto.block_a_id, copied_ids);
+        let b = remap(dto.block_b_id, copied_ids);
+        if a != dto.block_a_id || b != dto.block_b_id {
+            dto.id = Uuid::new_v4();
         }
-        if !known_block_ids.contains(&a_id) || !known_block_ids.contains(&b_id) {
+        // Copies get fresh ids, so the pair may need reordering
+        (dto.block_a_id, dto.block_b_id) = if a < b { (a, b) } else { (b, a) };
+        let (a_id, b_id) = (dto.block_a_id, dto.block_b_id);
+
+        let reason = if rel_set.contains(&(a_id, b_id)) {
+            Some(ConflictReason::AlreadyExists)
+        } else if !known_block_ids.contains(&a_id) || !known_block_ids.contains(&b_id) {
             tracing::warn!(%a_id, %b_id, "Skipping related link: block not found");
-            result.skipped += 1;
+            Some(ConflictReason::MissingBlock)
+        } else {
+            match state
+                .repos
+                .block_related_links
+                .create_at(&dto, created_at.unwrap_or_else(Utc::now), &mut *conn)
+                .await
+            {
+                Ok(_) => None,
+                Err(BlockRelatedLinkError::AlreadyExists { .. }) => {
+                    Some(ConflictReason::AlreadyExists)
+                }
+                Err(e) => return Err(e.into()),
+            }
+        };
+
+        match reason {
+            None => result.inserted += 1,
+            Some(reason) => {
+                result.skipped += 1;
+                conflicts.push(ImportConflict::RelatedLink {
+                    block_a_id: a_id,
+                    block_b_id: b_id,
+                    reason,
+                    resolution: ConflictResolution::Skipped,
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Appends the archived tabs after the local ones, in archived order. Blocks
+/// that are already open keep their local tab.
+async fn process_block_opens(
+    opens: Vec<BlockOpenRecord>,
+    known_block_ids: &HashSet<Uuid>,
+    copied_ids: &HashMap<Uuid, Uuid>,
+    result: &mut ExtrasImportResult,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<(), ImportError> {
+    let mut workspace = state.repos.workspaces.get(&mut *conn).await?;
+    let mut next_tab_index = workspace
+        .opened_blocks
+        .iter()
+        .map(|b| b.tab_index + 1)
+        .max()
+        .unwrap_or(0);
+
+    let mut opens = opens;
+    opens.sort_by_key(|o| o.tab_index);
+    for open in opens {
+        let block_id = remap(open.block_id, copied_ids);
+        if !known_block_ids.contains(&block_id) || workspace.is_block_opened(block_id) {
+            continue;
+        }
+
+        workspace.opened_blocks.push(OpenedBlock {
+            block_id,
+            opened_at: open.opened_at,
+            tab_index: next_tab_index,
+        });
+        next_tab_index += 1;
+        result.block_opens_inserted += 1;
+    }
+
+    if result.block_opens_inserted > 0 {
+        state.repos.workspaces.save(&workspace, &mut *conn).await?;
+    }
+
+    Ok(())
+}
+
+async fn process_block_pins(
+    pins: Vec<BlockPinRecord>,
+    known_block_ids: &HashSet<Uuid>,
+    copied_ids: &HashMap<Uuid, Uuid>,
+    result: &mut ExtrasImportResult,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<(), ImportError> {
+    let pinned: HashSet<Uuid> = state
+        .repos
+        .block_pins
+        .get_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|p| p.block_id)
+        .collect();
+
+    for pin in pins {
+        let block_id = remap(pin.block_id, copied_ids);
+        if !known_block_ids.contains(&block_id) || pinned.contains(&block_id) {
+            continue;
+        }
+
+        state
+            .repos
+            .block_pins
+            .pin_at(block_id, pin.pinned_at, &mut *conn)
+            .await?;
+        result.block_pins_inserted += 1;
+    }
+
+    Ok(())
+}
+
+/// Inserts canvases whose id is not in use locally and returns their ids. A
+/// canvas that already exists is left as it is, placements and pins included.
+async fn process_canvases(
+    canvases: Vec<CanvasRecord>,
+    result: &mut ExtrasImportResult,
+    conflicts: &mut Vec<ImportConflict>,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<HashSet<Uuid>, ImportError> {
+    let existing: HashSet<Uuid> = state
+        .repos
+        .canvases
+        .get_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|c| c.id)
+        .collect();
+
+    let mut inserted = HashSet::new();
+    for record in canvases {
+        if existing.contains(&record.id) {
+            result.canvases_skipped += 1;
+            conflicts.push(ImportConflict::Canvas {
+                id: record.id,
+                reason: ConflictReason::AlreadyExists,
+                resolution: ConflictResolution::Skipped,
+            });
+            continue;
+        }
+
+        let canvas = Canvas {
+            id: record.id,
+            name: record.name,
+            viewport_x: record.viewport_x,
+            viewport_y: record.viewport_y,
+            zoom_level: record.zoom_level,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        };
+        state.repos.canvases.save(&canvas, &mut *conn).await?;
+        inserted.insert(canvas.id);
+        result.canvases_inserted += 1;
+    }
+
+    Ok(inserted)
+}
+
+async fn process_canvas_blocks(
+    canvas_blocks: Vec<CanvasBlockRecord>,
+    new_canvas_ids: &HashSet<Uuid>,
+    known_block_ids: &HashSet<Uuid>,
+    copied_ids: &HashMap<Uuid, Uuid>,
+    result: &mut ExtrasImportResult,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<(), ImportError> {
+    let placements: Vec<CanvasBlock> = canvas_blocks
+        .into_iter()
+        .filter(|cb| new_canvas_ids.contains(&cb.canvas_id))
+        .map(|cb| CanvasBlock {
+            id: cb.id,
+            canvas_id: cb.canvas_id,
+            block_id: remap(cb.block_id, copied_ids),
+            grid_x: cb.grid_x,
+            grid_y: cb.grid_y,
+            grid_width: cb.grid_width,
+            grid_height: cb.grid_height,
+            z: cb.z,
+            scale: cb.scale,
+            content_visible: cb.content_visible,
+            created_at: cb.created_at,
+            updated_at: cb.updated_at,
+        })
+        .filter(|cb| known_block_ids.contains(&cb.block_id))
+        .collect();
+    state
+        .repos
+        .canvas_blocks
+        .save_many(&placements, &mut *conn)
+        .await?;
+    result.canvas_blocks_inserted = placements.len();
+
+    Ok(())
+}
+
+async fn process_canvas_pins(
+    canvas_pins: Vec<CanvasPinRecord>,
+    new_canvas_ids: &HashSet<Uuid>,
+    result: &mut ExtrasImportResult,
+    state: &AppState,
+    conn: &mut Connection,
+) -> Result<(), ImportError> {
+    for pin in canvas_pins {
+        if !new_canvas_ids.contains(&pin.canvas_id) {
             continue;
         }
+
+        state
+            .repos
+            .canvas_pins
+            .pin_at(pin.canvas_id, pin.pinned_at, &mut *conn)
+            .await?;
+        result.canvas_pins_inserted += 1;
+    }
+
+    Ok(())
+}
+
+fn remap(id: Uuid, copied_ids: &HashMap<Uuid, Uuid>) -> Uuid {
+    copied_ids.get(&id).copied().unwrap_or(id)
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use chrono::Duration;
+    use rstest::rstest;
+    use serde_json::{Value, json};
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    use super::*;
+    use crate::features::export::archive::{
+        BLOCKS_FILE, DIRECTIONAL_LINKS_FILE, RELATED_LINKS_FILE,
+    };
+    use crate::features::export::{ExportQuery, build_archive};
+    use crate::test_utils::{TestApp, app};
+    use domain::workspaces::Workspace;
+    use storage::repositories::{
+        BlockDirectionalLinkRepository, BlockRelatedLinkRepository, CanvasBlockRepository,
+    };
+
+    type BlockRow = (Uuid, String, String, DateTime<Utc>, DateTime<Utc>);
+    type LinkRow = (Uuid, Uuid, Uuid, Option<String>, DateTime<Utc>);
+    type CanvasBlockRow = (Uuid, Uuid, Uuid, i32, i32, DateTime<Utc>);
+
+    /// Everything an archive carries, sorted so two databases compare equal
+    /// when they hold the same data. Tabs are compared by order, since import
+    /// renumbers them after the local ones.
+    #[derive(Debug, PartialEq)]
+    struct Snapshot {
+        blocks: Vec<BlockRow>,
+        tags: Vec<(Uuid, String)>,
+        directional_links: Vec<LinkRow>,
+        related_links: Vec<LinkRow>,
+        tabs: Vec<(Uuid, DateTime<Utc>)>,
+        block_pins: Vec<(Uuid, DateTime<Utc>)>,
+        canvases: Vec<(Uuid, String, DateTime<Utc>, DateTime<Utc>)>,
+        canvas_blocks: Vec<CanvasBlockRow>,
+        canvas_pins: Vec<(Uuid, DateTime<Utc>)>,
+    }
+
+    async fn snapshot(app: &TestApp) -> Snapshot {
+        let pool = app.db.pool();
+
+        let mut blocks: Vec<_> = app
+            .query_services
+            .blocks
+            .get_all(pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|b| (b.id, b.title, b.content, b.created_at, b.updated_at))
+            .collect();
+        blocks.sort_by_key(|b| b.0);
+
+        let mut tags: Vec<_> = app
+            .repos
+            .tags
+            .get_all_block_tags(pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| (t.block_id, t.tag_name))
+            .collect();
+        tags.sort();
+
+        let mut directional_links: Vec<_> = app
+            .query_services
+            .block_links
+            .get_all_directional(pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|l| (l.id, l.block_from_id, l.block_to_id, l.label, l.created_at))
+            .collect();
+        directional_links.sort_by_key(|l| l.0);
+
+        let mut related_links: Vec<_> = app
+            .query_services
+            .block_links
+            .get_all_related(pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|l| (l.id, l.block_a_id, l.block_b_id, l.label, l.created_at))
+            .collect();
+        related_links.sort_by_key(|l| l.0);
+
+        let mut opened = app.repos.workspaces.get(pool).await.unwrap().opened_blocks;
+        opened.sort_by_key(|o| o.tab_index);
+        let tabs = opened
+            .into_iter()
+            .map(|o| (o.block_id, o.opened_at))
+            .collect();
+
+        let mut block_pins: Vec<_> = app
+            .repos
+            .block_pins
+            .get_all(pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|p| (p.block_id, p.pinned_at))
+            .collect();
+        block_pins.sort();
+
+        let mut canvases = Vec::new();
+        let mut canvas_blocks = Vec::new();
+        for canvas in app.repos.canvases.get_all(pool).await.unwrap() {
+            for cb in app
+                .repos
+                .canvas_blocks
+                .get_by_canvas(canvas.id, pool)
+                .await
+                .unwrap()
+            {
+                canvas_blocks.push((
+                    cb.id,
+                    cb.canvas_id,
+                    cb.block_id,
+                    cb.grid_x,
+                    cb.grid_y,
+                    cb.created_at,
+                ));
+            }
+            canvases.push((canvas.id, canvas.name, canvas.created_at, canvas.updated_at));
+        }
+        canvases.sort_by_key(|c| c.0);
+        canvas_blocks.sort_by_key(|cb| cb.0);
+
+        let mut canvas_pins: Vec<_> = app
+            .repos
+            .canvas_pins
+            .get_all(pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|p| (p.canvas_id, p.pinned_at))
+            .collect();
+        canvas_pins.sort();
+
+        Snapshot {
+            blocks,
+            tags,
+            directional_links,
+            related_links,
+            tabs,
+            block_pins,
+            canvases,
+            canvas_blocks,
+            canvas_pins,
+        }
+    }
+
+    /// Saves a block last edited a week ago, so a timestamp that import
+    /// replaced with the current time would show.
+    async fn seed_block(app: &TestApp, title: &str) -> Block {
+        let mut block = Block::new(title, &format!("{title} content"));
+        block.created_at = Utc::now() - Duration::days(30);
+        block.updated_at = Utc::now() - Duration::days(7);
+        app.repos
+            .blocks
+            .save_with_timestamps(&block, app.db.pool())
+            .await
+            .unwrap();
+        block
+    }
+
+    /// A parent with two children, one of them related to the parent, with
+    /// tags, tabs, a pin and a pinned canvas placing a child.
+    async fn seed(app: &TestApp) -> [Block; 3] {
+        let pool = app.db.pool();
+        let parent = seed_block(app, "Parent").await;
+        let first = seed_block(app, "First").await;
+        let second = seed_block(app, "Second").await;
+
+        for child in [&first, &second] {
+            let dto = CreateBlockDirectionalLinkDto {
+                id: Uuid::new_v4(),
+                block_from_id: parent.id,
+                block_to_id: child.id,
+                kind: None,
+                label: Some(format!("to {}", child.title)),
+                weight: None,
+            };
+            app.repos
+                .block_directional_links
+                .create_at(&dto, Utc::now() - Duration::days(3), pool)
+                .await
+                .unwrap();
+        }
         let dto = CreateBlockRelatedLinkDto {
             id: Uuid::new_v4(),
-            block_a_id: a_id,
-            block_b_id: b_id,
+            block_a_id: parent.id,
+            block_b_id: second.id,
+            label: Some("see also".to_string()),
+            weight: None,
         };
-        match state
-            .repos
+        app.repos
             .block_related_links
-            .create(&dto, state.db.pool())
+            .create_at(&dto, Utc::now() - Duration::days(2), pool)
+            .await
+            .unwrap();
+
+        let tag = Tag::new("project").unwrap();
+        app.repos
+            .tags
+            .tag_block(parent.id, &tag, pool)
+            .await
+            .unwrap();
+
+        let mut workspace = Workspace::new();
+        workspace.open_block(second.id);
+        workspace.open_block(parent.id);
+        app.repos.workspaces.save(&workspace, pool).await.unwrap();
+        app.repos
+            .block_pins
+            .pin_at(first.id, Utc::now() - Duration::days(1), pool)
+            .await
+            .unwrap();
+
+        let mut canvas = Canvas::new("Board");
+        canvas.created_at = Utc::now() - Duration::days(5);
+        canvas.updated_at = Utc::now() - Duration::days(4);
+        app.repos.canvases.save(&canvas, pool).await.unwrap();
+        app.repos
+            .canvas_blocks
+            .save(&CanvasBlock::new(canvas.id, first.id, 1, 2, 3, 4), pool)
+            .await
+            .unwrap();
+        app.repos
+            .canvas_pins
+            .pin_at(canvas.id, Utc::now() - Duration::hours(1), pool)
+            .await
+            .unwrap();
+
+        [parent, first, second]
+    }
+
+    async fn export(app: &TestApp) -> SpooledFile {
+        let query = ExportQuery {
+            root: None,
+            include_related: true,
+        };
+        build_archive(app, &query)
+            .await
+            .expect("export should succeed")
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn round_trip_into_a_fresh_database_is_faithful(
+        #[future] app: TestApp,
+        #[future]
+        #[from(app)]
+        fresh: TestApp,
+    ) {
+        let (source, fresh) = (app.await, fresh.await);
+        seed(&source).await;
+
+        let response = import_archive(export(&source).await, &ImportOptions::default(), &fresh)
+            .await
+            .expect("import should succeed");
+        assert_eq!(response.blocks_inserted, 3);
+        assert!(response.conflicts.is_empty());
+
+        assert_eq!(snapshot(&fresh).await, snapshot(&source).await);
+
+        // Every block is now as new locally as in the archive
+        let response = import_archive(export(&source).await, &ImportOptions::default(), &fresh)
+            .await
+            .expect("second import should succeed");
+        assert_eq!(response.blocks_updated, 0);
+        assert_eq!(response.blocks_skipped, 3);
+        assert_eq!(snapshot(&fresh).await, snapshot(&source).await);
+    }
+
+    async fn import(
+        app: &TestApp,
+        archive: SpooledFile,
+        options: ImportOptions,
+    ) -> Result<ImportResponse, ImportError> {
+        import_archive(archive, &options, app).await
+    }
+
+    /// Saves a local edit of `block` made at `updated_at`.
+    async fn edit_locally(app: &TestApp, block: &Block, title: &str, updated_at: DateTime<Utc>) {
+        let edited = Block {
+            title: title.to_string(),
+            updated_at,
+            ..block.clone()
+        };
+        app.repos
+            .blocks
+            .save_with_timestamps(&edited, app.db.pool())
             .await
-        {
-            Ok(_) => result.inserted += 1,
-            Err(BlockRelatedLinkError::AlreadyExists { .. }) => result.skipped += 1,
-            Err(e) => return Err(e.into()),
+            .unwrap();
+    }
+
+    async fn titles(app: &TestApp, blocks: &[&Block]) -> Vec<String> {
+        let mut titles = Vec::new();
+        for block in blocks {
+            let stored = app
+                .repos
+                .blocks
+                .get_by_id(block.id, app.db.pool())
+                .await
+                .unwrap()
+                .expect("block should exist");
+            titles.push(stored.title);
         }
+        titles
     }
 
-    Ok(result)
-}
+    /// A format version 1 archive, which needs no manifest.
+    fn legacy_archive(blocks: &[Value], related_links: Value) -> Cursor<Vec<u8>> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default();
+
+        zip.start_file(BLOCKS_FILE, options).unwrap();
+        for block in blocks {
+            writeln!(zip, "{block}").unwrap();
+        }
+        zip.start_file(DIRECTIONAL_LINKS_FILE, options).unwrap();
+        zip.write_all(b"[]").unwrap();
+        zip.start_file(RELATED_LINKS_FILE, options).unwrap();
+        zip.write_all(related_links.to_string().as_bytes()).unwrap();
+
+        let mut archive = zip.finish().unwrap();
+        archive.rewind().unwrap();
+        archive
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn dry_run_reports_a_real_run_and_writes_nothing(
+        #[future] app: TestApp,
+        #[future]
+        #[from(app)]
+        target: TestApp,
+    ) {
+        let (source, target) = (app.await, target.await);
+        let [parent, ..] = seed(&source).await;
+        edit_locally(
+            &target,
+            &parent,
+            "Local parent",
+            Utc::now() - Duration::days(8),
+        )
+        .await;
+        let before = snapshot(&target).await;
+
+        let dry_run = ImportOptions {
+            dry_run: true,
+            ..ImportOptions::default()
+        };
+        let preview = import(&target, export(&source).await, dry_run)
+            .await
+            .expect("dry run should succeed");
+        assert!(preview.dry_run);
+        assert_eq!(snapshot(&target).await, before);
+
+        let applied = import(&target, export(&source).await, ImportOptions::default())
+            .await
+            .expect("import should succeed");
+        assert!(!applied.dry_run);
+        assert_eq!(applied.blocks_inserted, 2);
+        assert_eq!(applied.blocks_updated, 1);
 
-fn read_zip_entry(
-    archive: &mut ZipArchive<Cursor<Vec<u8>>>,
-    name: &str,
-) -> Result<String, ImportError> {
-    let mut file = archive.by_name(name)?;
-    let mut content = String::new();
-    file.read_to_string(&mut content)?;
-    Ok(content)
+        let without_flag = |response: &ImportResponse| {
+            let mut value = serde_json::to_value(response).unwrap();
+            value.as_object_mut().unwrap().remove("dryRun");
+            value
+        };
+        assert_eq!(without_flag(&preview), without_flag(&applied));
+    }
+
+    #[rstest]
+    #[case::keep_local(ImportStrategy::KeepLocal, ["Local parent", "Local first"], 0)]
+    #[case::overwrite(ImportStrategy::Overwrite, ["Parent", "First"], 3)]
+    #[case::newer_wins(ImportStrategy::NewerWins, ["Parent", "Local first"], 1)]
+    #[tokio::test]
+    async fn strategies_resolve_existing_blocks(
+        #[future] app: TestApp,
+        #[future]
+        #[from(app)]
+        target: TestApp,
+        #[case] strategy: ImportStrategy,
+        #[case] expected_titles: [&str; 2],
+        #[case] expected_updated: usize,
+    ) {
+        let (source, target) = (app.await, target.await);
+        let [parent, first, second] = seed(&source).await;
+
+        // The archived parent is newer than the local one, the archived first
+        // child older; the second child is the same on both sides
+        import(&target, export(&source).await, ImportOptions::default())
+            .await
+            .unwrap();
+        edit_locally(
+            &target,
+            &parent,
+            "Local parent",
+            parent.updated_at - Duration::days(1),
+        )
+        .await;
+        edit_locally(&target, &first, "Local first", Utc::now()).await;
+
+        let options = ImportOptions {
+            strategy,
+            ..ImportOptions::default()
+        };
+        let response = import(&target, export(&source).await, options)
+            .await
+            .expect("import should succeed");
+
+        assert_eq!(titles(&target, &[&parent, &first]).await, expected_titles);
+        assert_eq!(titles(&target, &[&second]).await, ["Second"]);
+        assert_eq!(response.blocks_inserted, 0);
+        assert_eq!(response.blocks_updated, expected_updated);
+        assert_eq!(response.blocks_skipped, 3 - expected_updated);
+
+        let overwritten = response
+            .conflicts
+            .iter()
+            .filter(|c| {
+                matches!(
+                    c,
+                    ImportConflict::Block {
+                        resolution: ConflictResolution::Overwritten,
+                        ..
+                    }
+                )
+            })
+            .count();
+        assert_eq!(overwritten, expected_updated);
+
+        // An overwritten block takes the archive's timestamps
+        if strategy == ImportStrategy::Overwrite {
+            let stored = target
+                .repos
+                .blocks
+                .get_by_id(first.id, target.db.pool())
+                .await
+                .unwrap()
+                .unwrap();
+            let archived = source
+                .repos
+                .blocks
+                .get_by_id(first.id, source.db.pool())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(stored.updated_at, archived.updated_at);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn copies_take_links_pins_and_tabs_with_them(
+        #[future] app: TestApp,
+        #[future]
+        #[from(app)]
+        target: TestApp,
+    ) {
+        let (source, target) = (app.await, target.await);
+        let [parent, first, second] = seed(&source).await;
+        import(&target, export(&source).await, ImportOptions::default())
+            .await
+            .unwrap();
+        let before = snapshot(&target).await;
+
+        let options = ImportOptions {
+            strategy: ImportStrategy::Copy,
+            ..ImportOptions::default()
+        };
+        let response = import(&target, export(&source).await, options)
+            .await
+            .expect("import should succeed");
+        assert_eq!(response.blocks_copied, 3);
+
+        let copies: HashMap<Uuid, Uuid> = response
+            .conflicts
+            .iter()
+            .filter_map(|c| match c {
+                ImportConflict::Block {
+                    id,
+                    resolution: ConflictResolution::Copied,
+                    new_id: Some(new_id),
+                    ..
+                } => Some((*id, *new_id)),
+                _ => None,
+            })
+            .collect();
+        let copy = |block: &Block| copies[&block.id];
+        assert_eq!(copies.len(), 3);
+
+        let after = snapshot(&target).await;
+        let links: HashSet<(Uuid, Uuid)> =
+            after.directional_links.iter().map(|l| (l.1, l.2)).collect();
+        assert!(links.contains(&(copy(&parent), copy(&first))));
+        assert!(links.contains(&(copy(&parent), copy(&second))));
+        assert_eq!(after.directional_links.len(), 4);
+
+        let (a, b) = (
+            copy(&parent).min(copy(&second)),
+            copy(&parent).max(copy(&second)),
+        );
+        assert!(after.related_links.iter().any(|l| (l.1, l.2) == (a, b)));
+        assert_eq!(after.related_links.len(), 2);
+
+        let pinned: Vec<Uuid> = after.block_pins.iter().map(|p| p.0).collect();
+        assert!(pinned.contains(&first.id));
+        assert!(pinned.contains(&copy(&first)));
+
+        // The copies' tabs follow the original ones, in archived order
+        let tabs: Vec<Uuid> = after.tabs.iter().map(|t| t.0).collect();
+        assert_eq!(tabs, [second.id, parent.id, copy(&second), copy(&parent)]);
+
+        // The canvas already exists, so it and its placements are left alone
+        assert_eq!(after.canvases, before.canvases);
+        assert_eq!(after.canvas_blocks, before.canvas_blocks);
+    }
+
+    #[rstest]
+    #[case::atomic(true)]
+    #[case::incremental(false)]
+    #[tokio::test]
+    async fn atomic_import_rolls_back_a_failure_mid_archive(
+        #[future] app: TestApp,
+        #[case] atomic: bool,
+    ) {
+        let app = app.await;
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let block = |id: Uuid, title: &str| {
+            json!({
+                "id": id,
+                "title": title,
+                "content": "",
+                "createdAt": Utc::now(),
+                "updatedAt": Utc::now(),
+            })
+        };
+        // The second related link joins a block to itself, which fails once
+        // both blocks and the first link are written
+        let archive = legacy_archive(&[block(a, "A"), block(b, "B")], json!([[a, b], [a, a]]));
+
+        let options = ImportOptions {
+            atomic,
+            ..ImportOptions::default()
+        };
+        import_archive(archive, &options, &app)
+            .await
+            .err()
+            .expect("the self link should fail the import");
+
+        let after = snapshot(&app).await;
+        if atomic {
+            assert!(after.blocks.is_empty());
+            assert!(after.related_links.is_empty());
+        } else {
+            assert_eq!(after.blocks.len(), 2);
+            assert_eq!(after.related_links.len(), 1);
+        }
+    }
 }
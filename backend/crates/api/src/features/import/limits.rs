@@ -0,0 +1,121 @@
+//! Caps on how far an uploaded ZIP may inflate. The upload itself is bounded
+//! by `max_upload_bytes`, but a few megabytes of deflated data can expand to
+//! gigabytes, so every entry is read through a `DecompressionBudget`.
+
+use std::io::{self, Read, Write};
+
+/// Largest size a single archive entry may decompress to.
+pub(crate) const MAX_ENTRY_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Largest size all entries of an archive may decompress to together.
+pub(crate) const MAX_ARCHIVE_BYTES: u64 = 4 * 1024 * 1024 * 1024;
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum DecompressionError {
+    #[error("{name} decompresses to more than {limit} bytes")]
+    EntryTooLarge { name: String, limit: u64 },
+
+    #[error("Archive decompresses to more than {limit} bytes")]
+    ArchiveTooLarge { limit: u64 },
+
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// Tracks the bytes left to decompress from one archive. Entries are read
+/// through `Read::take`, so an entry whose header understates its size is
+/// still cut off one byte past the limit.
+pub(crate) struct DecompressionBudget {
+    entry_limit: u64,
+    total_limit: u64,
+    remaining: u64,
+}
+
+impl DecompressionBudget {
+    pub(crate) fn new() -> Self {
+        Self::with_limits(MAX_ENTRY_BYTES, MAX_ARCHIVE_BYTES)
+    }
+
+    fn with_limits(entry_limit: u64, total_limit: u64) -> Self {
+        Self {
+            entry_limit,
+            total_limit,
+            remaining: total_limit,
+        }
+    }
+
+    /// Copies one entry into `writer`, failing as soon as it passes either
+    /// limit.
+    pub(crate) fn copy<R: Read, W: Write + ?Sized>(
+        &mut self,
+        name: &str,
+        entry: R,
+        writer: &mut W,
+    ) -> Result<u64, DecompressionError> {
+        let limit = self.entry_limit.min(self.remaining);
+        let written = io::copy(&mut entry.take(limit + 1), writer)?;
+
+        if written > limit {
+            return Err(if limit == self.entry_limit {
+                DecompressionError::EntryTooLarge {
+                    name: name.to_string(),
+                    limit,
+                }
+            } else {
+                DecompressionError::ArchiveTooLarge {
+                    limit: self.total_limit,
+                }
+            });
+        }
+
+        self.remaining -= written;
+        Ok(written)
+    }
+
+    /// Reads one entry into memory, with the same limits as `copy`.
+    pub(crate) fn read<R: Read>(
+        &mut self,
+        name: &str,
+        entry: R,
+    ) -> Result<Vec<u8>, DecompressionError> {
+        let mut content = Vec::new();
+        self.copy(name, entry, &mut content)?;
+        Ok(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_within_both_limits_are_read_whole() {
+        let mut budget = DecompressionBudget::with_limits(4, 8);
+
+        assert_eq!(budget.read("a", &b"abcd"[..]).unwrap(), b"abcd");
+        assert_eq!(budget.read("b", &b"efgh"[..]).unwrap(), b"efgh");
+    }
+
+    #[test]
+    fn an_entry_past_the_entry_limit_is_rejected() {
+        let mut budget = DecompressionBudget::with_limits(4, 8);
+
+        let error = budget.read("big.json", &b"abcde"[..]).unwrap_err();
+        assert!(matches!(
+            error,
+            DecompressionError::EntryTooLarge { ref name, limit: 4 } if name == "big.json"
+        ));
+    }
+
+    #[test]
+    fn entries_past_the_total_limit_are_rejected() {
+        let mut budget = DecompressionBudget::with_limits(4, 6);
+
+        budget.read("a", &b"abcd"[..]).unwrap();
+        let error = budget.read("b", &b"efg"[..]).unwrap_err();
+        assert!(matches!(
+            error,
+            DecompressionError::ArchiveTooLarge { limit: 6 }
+        ));
+    }
+}
@@ -1,6 +1,14 @@
+mod archive;
 mod error;
 mod handler;
+mod limits;
+mod request;
 mod response;
 mod routes;
 
+pub(crate) use error::{ImportError, UPLOAD_TOO_LARGE};
+pub(crate) use handler::import_archive;
+pub(crate) use limits::{DecompressionBudget, DecompressionError};
+pub(crate) use request::{ImportOptions, ImportStrategy};
+pub(crate) use response::{ConflictReason, ConflictResolution, ImportConflict};
 pub use routes::routes;
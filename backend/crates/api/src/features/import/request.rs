@@ -0,0 +1,36 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+/// What to do with an archived block whose id already exists locally.
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ImportStrategy {
+    /// Take the archived block if its `updatedAt` is later than the local one
+    #[default]
+    NewerWins,
+    /// Leave the local block alone
+    KeepLocal,
+    /// Replace the local block with the archived one
+    Overwrite,
+    /// Insert the archived block under a new id; its links follow it
+    Copy,
+}
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub(crate) struct ImportOptions {
+    /// Run the import and report what it would do, then roll it back
+    #[serde(default)]
+    pub dry_run: bool,
+    /// How to resolve blocks that already exist
+    #[serde(default)]
+    #[param(inline)]
+    pub strategy: ImportStrategy,
+    /// Apply the whole archive in one transaction, so any failure leaves the
+    /// database untouched. Otherwise each block with its tags, each link and
+    /// pin, and the canvases with their placements are committed as they go,
+    /// so a failure keeps what came before it but never half an item
+    #[serde(default)]
+    pub atomic: bool,
+}
@@ -5,17 +5,86 @@ use axum::{
 };
 use serde::Serialize;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 #[derive(Serialize, ToSchema, Default)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ImportResponse {
+    /// True when nothing was written
+    pub dry_run: bool,
+    /// Format version of the archive; 1 for archives without a manifest
+    pub format_version: u32,
     pub blocks_inserted: usize,
     pub blocks_updated: usize,
     pub blocks_skipped: usize,
+    pub blocks_copied: usize,
     pub dir_links_inserted: usize,
     pub dir_links_skipped: usize,
     pub related_links_inserted: usize,
     pub related_links_skipped: usize,
+    pub block_opens_inserted: usize,
+    pub block_pins_inserted: usize,
+    pub canvases_inserted: usize,
+    pub canvases_skipped: usize,
+    pub canvas_blocks_inserted: usize,
+    pub canvas_pins_inserted: usize,
+    /// Archived items that clashed with local data, in archive order
+    pub conflicts: Vec<ImportConflict>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ConflictReason {
+    /// An item with the same id or the same endpoints exists locally
+    AlreadyExists,
+    /// A block with the same id is in the local trash
+    Trashed,
+    /// An endpoint is neither local nor in the archive
+    MissingBlock,
+    /// The link would close a cycle
+    Cycle,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ConflictResolution {
+    Skipped,
+    Overwritten,
+    Copied,
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+#[serde(tag = "item", rename_all = "camelCase")]
+pub(crate) enum ImportConflict {
+    #[serde(rename_all = "camelCase")]
+    Block {
+        id: Uuid,
+        reason: ConflictReason,
+        resolution: ConflictResolution,
+        /// Id the archived block was inserted under, for copies
+        #[serde(skip_serializing_if = "Option::is_none")]
+        new_id: Option<Uuid>,
+    },
+    #[serde(rename_all = "camelCase")]
+    DirectionalLink {
+        block_from_id: Uuid,
+        block_to_id: Uuid,
+        reason: ConflictReason,
+        resolution: ConflictResolution,
+    },
+    #[serde(rename_all = "camelCase")]
+    RelatedLink {
+        block_a_id: Uuid,
+        block_b_id: Uuid,
+        reason: ConflictReason,
+        resolution: ConflictResolution,
+    },
+    #[serde(rename_all = "camelCase")]
+    Canvas {
+        id: Uuid,
+        reason: ConflictReason,
+        resolution: ConflictResolution,
+    },
 }
 
 impl IntoResponse for ImportResponse {
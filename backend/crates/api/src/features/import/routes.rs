@@ -1,8 +1,12 @@
 use std::sync::Arc;
 
+use axum::extract::DefaultBodyLimit;
+
 use crate::AppState;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
-pub fn routes() -> OpenApiRouter<Arc<AppState>> {
-    OpenApiRouter::new().routes(routes!(super::handler::import))
+pub fn routes(max_upload_bytes: usize) -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(super::handler::import))
+        .layer(DefaultBodyLimit::max(max_upload_bytes))
 }
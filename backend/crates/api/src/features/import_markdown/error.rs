@@ -0,0 +1,81 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::features::import::{DecompressionError, UPLOAD_TOO_LARGE};
+use storage::repositories::block_directional_link_repository::BlockDirectionalLinkRepositoryError;
+use storage::repositories::block_related_link_repository::BlockRelatedLinkError;
+use storage::repositories::block_repository::BlockRepositoryError;
+use storage::repositories::tag_repository::TagRepositoryError;
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum ImportMarkdownError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error(transparent)]
+    BlockRepository(#[from] BlockRepositoryError),
+
+    #[error(transparent)]
+    BlockDirectionalLinkRepository(#[from] BlockDirectionalLinkRepositoryError),
+
+    #[error(transparent)]
+    BlockRelatedLinkRepository(#[from] BlockRelatedLinkError),
+
+    #[error(transparent)]
+    TagRepository(#[from] TagRepositoryError),
+
+    #[error("Failed to read archive: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    #[error(transparent)]
+    Decompression(#[from] DecompressionError),
+
+    #[error("{path} is not valid UTF-8")]
+    NotUtf8 { path: String },
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Multipart(#[from] axum::extract::multipart::MultipartError),
+
+    #[error("No file field found in multipart request")]
+    MissingFile,
+}
+
+impl IntoResponse for ImportMarkdownError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::MissingFile
+            | Self::Zip(_)
+            | Self::NotUtf8 { .. }
+            | Self::Decompression(DecompressionError::Io(_)) => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
+            Self::Decompression(_) => (StatusCode::PAYLOAD_TOO_LARGE, self.to_string()),
+            Self::Multipart(e) if e.status() == StatusCode::PAYLOAD_TOO_LARGE => {
+                (StatusCode::PAYLOAD_TOO_LARGE, UPLOAD_TOO_LARGE.to_string())
+            }
+            Self::Multipart(e) => (e.status(), e.body_text()),
+            _ => {
+                error!(error = ?self, "Markdown import failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Import failed".to_string(),
+                )
+            }
+        };
+
+        (status, Json(ErrorResponse { error: msg })).into_response()
+    }
+}
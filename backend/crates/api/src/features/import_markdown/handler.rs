@@ -0,0 +1,135 @@
+use std::io::{Read, Seek};
+use std::sync::Arc;
+
+use axum::extract::{Multipart, Query, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::ImportMarkdownError, request::ImportMarkdownQuery, response::ImportMarkdownResponse,
+    vault::plan_vault,
+};
+use crate::AppState;
+use crate::features::export::spool::SpooledFile;
+use domain::tags::Tag;
+use storage::Database;
+use storage::repositories::block_directional_link_repository::CreateBlockDirectionalLinkDto;
+use storage::repositories::block_related_link_repository::CreateBlockRelatedLinkDto;
+use storage::repositories::{
+    BlockDirectionalLinkRepository, BlockRelatedLinkRepository, BlockRepository, TagRepository,
+};
+
+/// Imports a ZIP of Markdown notes. Every note becomes a block titled by its
+/// front matter `title` or file name; folders become parent blocks and
+/// `[[wikilinks]]` become related links. The vault is written in one
+/// transaction, so a failure leaves nothing behind.
+#[utoipa::path(
+    post,
+    path = "/api/import/markdown",
+    tag = "import",
+    params(ImportMarkdownQuery),
+    responses(
+        (status = 200, description = "Import summary with unresolved links and name collisions", body = ImportMarkdownResponse),
+        (status = 400, description = "Bad request"),
+        (status = 413, description = "Upload exceeds the size limit"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
+#[instrument(err, skip(state, multipart))]
+pub async fn import_markdown(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ImportMarkdownQuery>,
+    mut multipart: Multipart,
+) -> Result<ImportMarkdownResponse, ImportMarkdownError> {
+    // Spooled chunk by chunk so a large upload never sits in memory whole
+    let mut upload = SpooledFile::new();
+    loop {
+        match multipart.next_field().await? {
+            None => return Err(ImportMarkdownError::MissingFile),
+            Some(mut field) if field.name() == Some("file") => {
+                while let Some(chunk) = field.chunk().await? {
+                    upload = upload.append(chunk).await?;
+                }
+                break;
+            }
+            Some(_) => continue,
+        }
+    }
+    upload.rewind()?;
+
+    import_vault(upload, query.dry_run, &state).await
+}
+
+pub(crate) async fn import_vault<R: Read + Seek + Send + 'static>(
+    reader: R,
+    dry_run: bool,
+    state: &AppState,
+) -> Result<ImportMarkdownResponse, ImportMarkdownError> {
+    let plan = tokio::task::spawn_blocking(move || plan_vault(reader))
+        .await
+        .map_err(std::io::Error::other)??;
+
+    let mut tx = state.db.pool().begin().await?;
+
+    for planned in &plan.blocks {
+        state.repos.blocks.save(&planned.block, &mut *tx).await?;
+        for name in &planned.tags {
+            let Ok(tag) = Tag::new(name) else {
+                continue;
+            };
+            state
+                .repos
+                .tags
+                .tag_block(planned.block.id, &tag, &mut *tx)
+                .await?;
+        }
+    }
+
+    for &(block_from_id, block_to_id) in &plan.directional_links {
+        let dto = CreateBlockDirectionalLinkDto {
+            id: Uuid::new_v4(),
+            block_from_id,
+            block_to_id,
+            kind: None,
+            label: None,
+            weight: None,
+        };
+        state
+            .repos
+            .block_directional_links
+            .create(&dto, &mut *tx)
+            .await?;
+    }
+
+    for &(block_a_id, block_b_id) in &plan.related_links {
+        let dto = CreateBlockRelatedLinkDto {
+            id: Uuid::new_v4(),
+            block_a_id,
+            block_b_id,
+            label: None,
+            weight: None,
+        };
+        state
+            .repos
+            .block_related_links
+            .create(&dto, &mut *tx)
+            .await?;
+    }
+
+    if dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+    }
+
+    Ok(ImportMarkdownResponse {
+        dry_run,
+        notes_imported: plan.notes,
+        folders_created: plan.folders,
+        dir_links_created: plan.directional_links.len(),
+        related_links_created: plan.related_links.len(),
+        files_skipped: plan.files_skipped,
+        unresolved_links: plan.unresolved_links,
+        collisions: plan.collisions,
+    })
+}
@@ -0,0 +1,11 @@
+mod error;
+mod handler;
+mod request;
+mod response;
+mod routes;
+mod vault;
+
+pub(crate) use error::ImportMarkdownError;
+pub(crate) use handler::import_vault;
+pub(crate) use response::UnresolvedReason;
+pub use routes::routes;
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub(crate) struct ImportMarkdownQuery {
+    /// Build the blocks and links and report them, then roll back
+    #[serde(default)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,56 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ImportMarkdownResponse {
+    /// True when nothing was written
+    pub dry_run: bool,
+    pub notes_imported: usize,
+    /// Blocks created for folders that have no note of their own
+    pub folders_created: usize,
+    pub dir_links_created: usize,
+    pub related_links_created: usize,
+    /// Files that are not Markdown notes, or are hidden
+    pub files_skipped: usize,
+    pub unresolved_links: Vec<UnresolvedLink>,
+    pub collisions: Vec<NameCollision>,
+}
+
+#[derive(Serialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum UnresolvedReason {
+    /// No note has that path or name
+    NotFound,
+    /// Several notes match; see `collisions`
+    Ambiguous,
+}
+
+/// A `[[wikilink]]` that did not become a related link.
+#[derive(Serialize, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UnresolvedLink {
+    /// Archive path of the note containing the link
+    pub note: String,
+    pub target: String,
+    pub reason: UnresolvedReason,
+}
+
+/// Notes that share a file name, compared case-insensitively.
+#[derive(Serialize, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NameCollision {
+    pub name: String,
+    pub paths: Vec<String>,
+}
+
+impl IntoResponse for ImportMarkdownResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
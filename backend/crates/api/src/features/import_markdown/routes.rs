@@ -0,0 +1,12 @@
+use std::sync::Arc;
+
+use axum::extract::DefaultBodyLimit;
+
+use crate::AppState;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+pub fn routes(max_upload_bytes: usize) -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(super::handler::import_markdown))
+        .layer(DefaultBodyLimit::max(max_upload_bytes))
+}
@@ -0,0 +1,612 @@
+//! Turns a ZIP of Markdown notes into blocks and links without touching the
+//! database, so the handler only has to write the plan out.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::io::{Read, Seek};
+use std::path::Component;
+
+use uuid::Uuid;
+use zip::ZipArchive;
+
+use super::error::ImportMarkdownError;
+use super::response::{NameCollision, UnresolvedLink, UnresolvedReason};
+use crate::features::import::DecompressionBudget;
+use domain::blocks::Block;
+
+pub(super) struct VaultPlan {
+    /// Folders without a note of their own, then notes
+    pub blocks: Vec<PlannedBlock>,
+    /// Parent and child ids, siblings in path order
+    pub directional_links: Vec<(Uuid, Uuid)>,
+    /// Deduplicated pairs with the smaller id first
+    pub related_links: Vec<(Uuid, Uuid)>,
+    pub notes: usize,
+    pub folders: usize,
+    pub files_skipped: usize,
+    pub unresolved_links: Vec<UnresolvedLink>,
+    pub collisions: Vec<NameCollision>,
+}
+
+pub(super) struct PlannedBlock {
+    pub block: Block,
+    pub tags: Vec<String>,
+}
+
+struct Note {
+    /// Path inside the archive without the `.md` extension
+    path: String,
+    block: Block,
+    tags: Vec<String>,
+    wikilinks: Vec<String>,
+}
+
+struct FrontMatter<'a> {
+    title: Option<String>,
+    tags: Vec<String>,
+    body: &'a str,
+}
+
+/// Notes are decompressed through a `DecompressionBudget`; files that are
+/// skipped are never read. All of it is blocking I/O, so callers run it off
+/// the async runtime.
+pub(super) fn plan_vault<R: Read + Seek>(reader: R) -> Result<VaultPlan, ImportMarkdownError> {
+    let mut archive = ZipArchive::new(reader)?;
+    let mut budget = DecompressionBudget::new();
+    let mut notes: BTreeMap<String, Note> = BTreeMap::new();
+    let mut files_skipped = 0;
+
+    for index in 0..archive.len() {
+        let file = archive.by_index(index)?;
+        if file.is_dir() {
+            continue;
+        }
+        let Some(path) = note_path(file.enclosed_name()) else {
+            files_skipped += 1;
+            continue;
+        };
+
+        let name = format!("{path}.md");
+        let bytes = budget.read(&name, file)?;
+        let text =
+            String::from_utf8(bytes).map_err(|_| ImportMarkdownError::NotUtf8 { path: name })?;
+
+        notes.insert(path.clone(), parse_note(path, &text));
+    }
+
+    Ok(build_plan(notes, files_skipped))
+}
+
+/// Archive path of a Markdown note without its extension. Other files,
+/// hidden files and anything under a hidden folder (`.obsidian`, `.trash`)
+/// are left out.
+fn note_path(name: Option<std::path::PathBuf>) -> Option<String> {
+    let name = name?;
+    let mut parts = Vec::new();
+    for component in name.components() {
+        let Component::Normal(part) = component else {
+            return None;
+        };
+        let part = part.to_str()?;
+        if part.starts_with('.') {
+            return None;
+        }
+        parts.push(part);
+    }
+
+    let file = parts.pop()?;
+    let (stem, extension) = file.rsplit_once('.')?;
+    if !extension.eq_ignore_ascii_case("md") || stem.is_empty() {
+        return None;
+    }
+    parts.push(stem);
+
+    Some(parts.join("/"))
+}
+
+fn parse_note(path: String, text: &str) -> Note {
+    let front_matter = parse_front_matter(text);
+    let title = front_matter
+        .title
+        .unwrap_or_else(|| file_name(&path).to_string());
+    let content = front_matter.body.trim_start_matches(['\r', '\n']);
+
+    Note {
+        block: Block::new(&title, content),
+        tags: front_matter.tags,
+        wikilinks: extract_wikilinks(content),
+        path,
+    }
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit_once('/').map_or(path, |(_, name)| name)
+}
+
+fn parent_path(path: &str) -> Option<&str> {
+    path.rsplit_once('/').map(|(parent, _)| parent)
+}
+
+/// Reads `title` and `tags` from a leading `---` block. Only the flat YAML
+/// notes apps write is understood: `key: value`, `tags: [a, b]` and `tags:`
+/// followed by `- a` lines. Anything else is ignored.
+fn parse_front_matter(text: &str) -> FrontMatter<'_> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut front_matter = FrontMatter {
+        title: None,
+        tags: Vec::new(),
+        body: text,
+    };
+
+    let Some(rest) = text
+        .strip_prefix("---\n")
+        .or_else(|| text.strip_prefix("---\r\n"))
+    else {
+        return front_matter;
+    };
+
+    let mut offset = 0;
+    let mut end = None;
+    for line in rest.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed == "---" || trimmed == "..." {
+            end = Some((offset, offset + line.len()));
+            break;
+        }
+        offset += line.len();
+    }
+    let Some((header_end, body_start)) = end else {
+        return front_matter;
+    };
+
+    let mut in_tag_list = false;
+    for line in rest[..header_end].lines() {
+        if in_tag_list {
+            if let Some(item) = line.trim_start().strip_prefix("- ") {
+                push_tag(&mut front_matter.tags, item);
+                continue;
+            }
+            in_tag_list = false;
+        }
+
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "title" if !value.is_empty() => front_matter.title = Some(unquote(value).to_string()),
+            "tags" | "tag" => {
+                if value.is_empty() {
+                    in_tag_list = true;
+                } else {
+                    let value = value
+                        .strip_prefix('[')
+                        .and_then(|v| v.strip_suffix(']'))
+                        .unwrap_or(value);
+                    for item in value.split(',') {
+                        push_tag(&mut front_matter.tags, item);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    front_matter.body = &rest[body_start..];
+    front_matter
+}
+
+fn push_tag(tags: &mut Vec<String>, item: &str) {
+    let tag = unquote(item.trim()).trim_start_matches('#').trim();
+    if !tag.is_empty() {
+        tags.push(tag.to_string());
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|quote| {
+            value
+                .strip_prefix(*quote)
+                .and_then(|v| v.strip_suffix(*quote))
+        })
+        .unwrap_or(value)
+}
+
+/// Targets of `[[target]]`, `[[target|alias]]` and `[[target#heading]]`
+/// outside fenced code blocks, with the alias and heading cut off.
+/// Embeds of attachments (`![[diagram.png]]`) are skipped.
+fn extract_wikilinks(content: &str) -> Vec<String> {
+    let mut targets = Vec::new();
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some(start) = rest.find("[[") {
+            let after = &rest[start + 2..];
+            let Some(end) = after.find("]]") else {
+                break;
+            };
+            let inner = &after[..end];
+            rest = &after[end + 2..];
+
+            let target = inner
+                .split(['|', '#', '^'])
+                .next()
+                .unwrap_or_default()
+                .trim();
+            if target.is_empty() {
+                continue;
+            }
+            match target.rsplit_once('.') {
+                Some((stem, extension)) if extension.eq_ignore_ascii_case("md") => {
+                    targets.push(stem.to_string());
+                }
+                Some((_, extension)) if !extension.contains([' ', '/']) => {}
+                _ => targets.push(target.to_string()),
+            }
+        }
+    }
+
+    targets
+}
+
+fn build_plan(notes: BTreeMap<String, Note>, files_skipped: usize) -> VaultPlan {
+    // Every folder that holds a note, directly or further down
+    let mut folder_paths: BTreeSet<&str> = BTreeSet::new();
+    for path in notes.keys() {
+        let mut current = parent_path(path);
+        while let Some(folder) = current {
+            folder_paths.insert(folder);
+            current = parent_path(folder);
+        }
+    }
+
+    let mut blocks = Vec::new();
+    // Keyed apart from notes, since `X/A.md` and the folder `X/A` share a path
+    let mut folder_ids: HashMap<&str, Uuid> = HashMap::new();
+    // A note named after its folder stands for it, whether it sits inside
+    // (`Projects/Projects.md`) or next to it (`Projects.md`)
+    let mut folder_notes: HashSet<&str> = HashSet::new();
+
+    for folder in &folder_paths {
+        let inner_note = format!("{folder}/{}", file_name(folder));
+        let note = notes
+            .get_key_value(&inner_note)
+            .or_else(|| notes.get_key_value(*folder));
+        let id = match note {
+            Some((note_path, note)) => {
+                folder_notes.insert(note_path.as_str());
+                note.block.id
+            }
+            None => {
+                let block = Block::new(file_name(folder), "");
+                let id = block.id;
+                blocks.push(PlannedBlock {
+                    block,
+                    tags: Vec::new(),
+                });
+                id
+            }
+        };
+        folder_ids.insert(folder, id);
+    }
+    let folders = blocks.len();
+
+    for note in notes.values() {
+        blocks.push(PlannedBlock {
+            block: note.block.clone(),
+            tags: note.tags.clone(),
+        });
+    }
+
+    let mut children: Vec<(&str, Uuid)> = folder_paths
+        .iter()
+        .map(|&folder| (folder, folder_ids[folder]))
+        .collect();
+    children.extend(
+        notes
+            .iter()
+            .filter(|(path, _)| !folder_notes.contains(path.as_str()))
+            .map(|(path, note)| (path.as_str(), note.block.id)),
+    );
+    children.sort();
+    let directional_links = children
+        .into_iter()
+        .filter_map(|(path, id)| Some((folder_ids[parent_path(path)?], id)))
+        .collect();
+
+    let (related_links, unresolved_links) = resolve_wikilinks(&notes);
+
+    VaultPlan {
+        blocks,
+        directional_links,
+        related_links,
+        notes: notes.len(),
+        folders,
+        files_skipped,
+        unresolved_links,
+        collisions: find_collisions(&notes),
+    }
+}
+
+/// Matches a target against the whole path or a trailing part of it, then
+/// against file names, case-insensitively. Targets that match several notes
+/// are reported rather than guessed.
+fn resolve_wikilinks(notes: &BTreeMap<String, Note>) -> (Vec<(Uuid, Uuid)>, Vec<UnresolvedLink>) {
+    let lowered: Vec<(String, &Note)> = notes
+        .iter()
+        .map(|(path, note)| (path.to_lowercase(), note))
+        .collect();
+
+    let mut related: BTreeSet<(Uuid, Uuid)> = BTreeSet::new();
+    let mut unresolved = Vec::new();
+
+    for note in notes.values() {
+        for target in &note.wikilinks {
+            let wanted = target.trim_start_matches('/').to_lowercase();
+            let suffix = format!("/{wanted}");
+            let matches: Vec<&Note> = lowered
+                .iter()
+                .filter(|(path, _)| *path == wanted || path.ends_with(&suffix))
+                .map(|(_, note)| *note)
+                .collect();
+
+            let reason = match matches.as_slice() {
+                [linked] => {
+                    let (a, b) = (note.block.id, linked.block.id);
+                    if a != b {
+                        related.insert(if a < b { (a, b) } else { (b, a) });
+                    }
+                    continue;
+                }
+                [] => UnresolvedReason::NotFound,
+                _ => UnresolvedReason::Ambiguous,
+            };
+            unresolved.push(UnresolvedLink {
+                note: format!("{}.md", note.path),
+                target: target.clone(),
+                reason,
+            });
+        }
+    }
+
+    (related.into_iter().collect(), unresolved)
+}
+
+/// Notes sharing a file name, which makes bare `[[name]]` links to them
+/// ambiguous.
+fn find_collisions(notes: &BTreeMap<String, Note>) -> Vec<NameCollision> {
+    let mut by_name: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for path in notes.keys() {
+        by_name
+            .entry(file_name(path).to_lowercase())
+            .or_default()
+            .push(format!("{path}.md"));
+    }
+
+    by_name
+        .into_iter()
+        .filter(|(_, paths)| paths.len() > 1)
+        .map(|(name, paths)| NameCollision { name, paths })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+    use std::path::PathBuf;
+
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    use super::*;
+
+    fn vault(files: &[(&str, &[u8])]) -> Cursor<Vec<u8>> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in files {
+            zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(content).unwrap();
+        }
+        let mut archive = zip.finish().unwrap();
+        archive.rewind().unwrap();
+        archive
+    }
+
+    fn note_path_of(name: &str) -> Option<String> {
+        note_path(Some(PathBuf::from(name)))
+    }
+
+    #[test]
+    fn note_paths_drop_the_extension() {
+        assert_eq!(note_path_of("Inbox.md").as_deref(), Some("Inbox"));
+        assert_eq!(
+            note_path_of("Projects/Alpha.MD").as_deref(),
+            Some("Projects/Alpha")
+        );
+        assert_eq!(note_path_of("v1.2 notes.md").as_deref(), Some("v1.2 notes"));
+    }
+
+    #[test]
+    fn note_paths_skip_other_and_hidden_files() {
+        assert_eq!(note_path_of("diagram.png"), None);
+        assert_eq!(note_path_of(".md"), None);
+        assert_eq!(note_path_of(".obsidian/workspace.md"), None);
+        assert_eq!(note_path_of("Projects/.draft.md"), None);
+        assert_eq!(note_path_of("../outside.md"), None);
+        assert_eq!(note_path(None), None);
+    }
+
+    #[test]
+    fn front_matter_after_a_byte_order_mark_is_read() {
+        let front_matter = parse_front_matter("\u{feff}---\ntitle: \"Hello\"\n---\nBody\n");
+
+        assert_eq!(front_matter.title.as_deref(), Some("Hello"));
+        assert_eq!(front_matter.body, "Body\n");
+    }
+
+    #[test]
+    fn byte_order_mark_is_dropped_without_front_matter() {
+        let front_matter = parse_front_matter("\u{feff}Body\n");
+
+        assert_eq!(front_matter.title, None);
+        assert_eq!(front_matter.body, "Body\n");
+    }
+
+    #[test]
+    fn tags_are_read_from_lists_and_inline_arrays() {
+        let front_matter =
+            parse_front_matter("---\r\ntags:\r\n  - a\r\n  - '#b'\r\ntitle: T\r\n---\r\nBody");
+        assert_eq!(front_matter.tags, ["a", "b"]);
+        assert_eq!(front_matter.title.as_deref(), Some("T"));
+        assert_eq!(front_matter.body, "Body");
+
+        let front_matter = parse_front_matter("---\ntags: [a, \"b\", ]\n---\n");
+        assert_eq!(front_matter.tags, ["a", "b"]);
+    }
+
+    #[test]
+    fn unclosed_front_matter_is_left_in_the_body() {
+        let text = "---\ntitle: T\nBody\n";
+        let front_matter = parse_front_matter(text);
+
+        assert_eq!(front_matter.title, None);
+        assert_eq!(front_matter.body, text);
+    }
+
+    #[test]
+    fn wikilinks_lose_their_alias_and_heading() {
+        let links = extract_wikilinks("See [[a|alias]], [[b#h]], [[c#^block]] and [[d.md]].");
+
+        assert_eq!(links, ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn wikilinks_skip_fenced_code_and_attachment_embeds() {
+        let content = "[[a]]\n```\n[[in code]]\n```\n![[img.png]] ![[b]] [[v1.2 notes]] [[]]";
+
+        assert_eq!(extract_wikilinks(content), ["a", "b", "v1.2 notes"]);
+    }
+
+    #[test]
+    fn plans_folders_notes_and_links() {
+        let plan = plan_vault(vault(&[
+            ("Projects/Projects.md", b"Folder note"),
+            (
+                "Projects/Alpha.md",
+                b"---\ntags: [work]\n---\n[[Beta]] [[Missing]]",
+            ),
+            ("Projects/Sub/Beta.md", b"[[Alpha|back]]"),
+            ("Projects/diagram.png", b"png"),
+            (".obsidian/app.md", b"{}"),
+        ]))
+        .unwrap();
+
+        assert_eq!(plan.notes, 3);
+        assert_eq!(plan.folders, 1);
+        assert_eq!(plan.files_skipped, 2);
+        assert_eq!(plan.blocks.len(), 4);
+        // Projects -> Alpha, Projects -> Sub, Sub -> Beta
+        assert_eq!(plan.directional_links.len(), 3);
+        assert_eq!(plan.related_links.len(), 1);
+        assert!(plan.collisions.is_empty());
+
+        let alpha = plan
+            .blocks
+            .iter()
+            .find(|planned| planned.block.title == "Alpha")
+            .unwrap();
+        assert_eq!(alpha.tags, ["work"]);
+        assert_eq!(alpha.block.content, "[[Beta]] [[Missing]]");
+
+        let [unresolved] = plan.unresolved_links.as_slice() else {
+            panic!("expected one unresolved link");
+        };
+        assert_eq!(unresolved.note, "Projects/Alpha.md");
+        assert_eq!(unresolved.target, "Missing");
+        assert_eq!(unresolved.reason, UnresolvedReason::NotFound);
+    }
+
+    fn id_of(plan: &VaultPlan, title: &str, content: &str) -> Uuid {
+        let [planned] = plan
+            .blocks
+            .iter()
+            .filter(|p| p.block.title == title && p.block.content == content)
+            .collect::<Vec<_>>()[..]
+        else {
+            panic!("expected one {title:?} block with {content:?}");
+        };
+        planned.block.id
+    }
+
+    #[test]
+    fn a_note_next_to_its_folder_stands_for_it() {
+        let plan =
+            plan_vault(vault(&[("X/A.md", b"Folder note"), ("X/A/B.md", b"Child")])).unwrap();
+
+        // Only X needs a block of its own; no empty block is left for A
+        assert_eq!(plan.folders, 1);
+        assert_eq!(plan.blocks.len(), 3);
+        let x = id_of(&plan, "X", "");
+        let a = id_of(&plan, "A", "Folder note");
+        let b = id_of(&plan, "B", "Child");
+        assert_eq!(plan.directional_links, [(x, a), (a, b)]);
+    }
+
+    #[test]
+    fn a_note_inside_its_folder_wins_over_one_next_to_it() {
+        let plan = plan_vault(vault(&[
+            ("X/A.md", b"Sibling"),
+            ("X/A/A.md", b"Inner"),
+            ("X/A/B.md", b"Child"),
+        ]))
+        .unwrap();
+
+        assert_eq!(plan.folders, 1);
+        assert_eq!(plan.blocks.len(), 4);
+        let x = id_of(&plan, "X", "");
+        let sibling = id_of(&plan, "A", "Sibling");
+        let inner = id_of(&plan, "A", "Inner");
+        let b = id_of(&plan, "B", "Child");
+        assert_eq!(plan.directional_links.len(), 3);
+        for link in [(x, inner), (x, sibling), (inner, b)] {
+            assert!(plan.directional_links.contains(&link), "missing {link:?}");
+        }
+    }
+
+    #[test]
+    fn ambiguous_links_are_reported() {
+        let plan = plan_vault(vault(&[
+            ("a/Note.md", b""),
+            ("b/Note.md", b""),
+            ("Index.md", b"[[Note]] [[a/Note]]"),
+        ]))
+        .unwrap();
+
+        assert_eq!(plan.related_links.len(), 1);
+        assert_eq!(plan.collisions.len(), 1);
+        assert_eq!(plan.collisions[0].paths, ["a/Note.md", "b/Note.md"]);
+        let [unresolved] = plan.unresolved_links.as_slice() else {
+            panic!("expected one unresolved link");
+        };
+        assert_eq!(unresolved.reason, UnresolvedReason::Ambiguous);
+    }
+
+    #[test]
+    fn rejects_notes_that_are_not_utf8() {
+        let result = plan_vault(vault(&[("Bad.md", &[0xff, 0xfe, 0x00])]));
+
+        assert!(matches!(
+            result,
+            Err(ImportMarkdownError::NotUtf8 { ref path }) if path == "Bad.md"
+        ));
+    }
+}
@@ -1,6 +1,19 @@
+pub mod admin;
+pub mod batch;
+pub mod block_dag;
 pub mod block_links;
+pub mod block_revisions;
+pub mod block_tags;
 pub mod blocks;
+pub mod canvas_blocks;
+pub mod canvases;
 pub mod export;
+pub mod export_graph;
+pub mod export_markdown;
 pub mod import;
+pub mod import_markdown;
+pub mod pins;
 pub mod search;
+pub mod tags;
+pub mod trash;
 pub mod workspace;
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State, http::StatusCode};
+use tracing::instrument;
+
+use super::{
+    error::{ErrorResponse, PinBlockError},
+    request::PinBlockRequest,
+};
+use crate::AppState;
+use storage::Database;
+use storage::repositories::{BlockPinRepository, BlockRepository};
+
+#[instrument]
+#[utoipa::path(
+    post,
+    path = "/api/pins/blocks",
+    tag = "pins",
+    request_body = PinBlockRequest,
+    responses(
+        (status = 204, description = "Block pinned"),
+        (status = 404, description = "Block not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn pin_block(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<PinBlockRequest>,
+) -> Result<StatusCode, PinBlockError> {
+    state
+        .repos
+        .blocks
+        .get_by_id(request.block_id, state.db.pool())
+        .await?
+        .ok_or(PinBlockError::NotFound)?;
+
+    state
+        .repos
+        .block_pins
+        .pin(request.block_id, state.db.pool())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,45 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::block_pin_repository::BlockPinRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum UnpinBlockError {
+    #[error(transparent)]
+    BlockPinRepository(#[from] BlockPinRepositoryError),
+}
+
+impl IntoResponse for UnpinBlockError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::BlockPinRepository(err) => match err {
+                BlockPinRepositoryError::NotPinned { .. } => {
+                    (StatusCode::NOT_FOUND, "Block is not pinned".to_string())
+                }
+                other => {
+                    error!(error = ?other, "Block pin repository failure");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal server error".to_string(),
+                    )
+                }
+            },
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
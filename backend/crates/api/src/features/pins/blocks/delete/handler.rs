@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::error::{ErrorResponse, UnpinBlockError};
+use crate::AppState;
+use storage::{Database, repositories::BlockPinRepository};
+
+#[instrument]
+#[utoipa::path(
+    delete,
+    path = "/api/pins/blocks/{id}",
+    tag = "pins",
+    responses(
+        (status = 204, description = "Block unpinned"),
+        (status = 404, description = "Block is not pinned", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn unpin_block(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, UnpinBlockError> {
+    state.repos.block_pins.unpin(id, state.db.pool()).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
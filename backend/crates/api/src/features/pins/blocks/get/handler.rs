@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use tracing::instrument;
+
+use super::{
+    error::{ErrorResponse, GetPinnedBlocksError},
+    response::GetPinnedBlocksResponse,
+};
+use crate::AppState;
+use storage::{Database, repositories::BlockPinRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/pins/blocks",
+    tag = "pins",
+    responses(
+        (status = 200, description = "Pinned blocks, most recently pinned first", body = GetPinnedBlocksResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_pinned_blocks(
+    State(state): State<Arc<AppState>>,
+) -> Result<GetPinnedBlocksResponse, GetPinnedBlocksError> {
+    let pinned = state.repos.block_pins.get_all(state.db.pool()).await?;
+
+    let response = GetPinnedBlocksResponse {
+        pinned_blocks: pinned.into_iter().map(|p| p.into()).collect(),
+    };
+
+    Ok(response)
+}
@@ -0,0 +1,41 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use storage::repositories::block_pin_repository::PinnedBlockDto;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PinnedBlock {
+    pub block_id: Uuid,
+    pub title: String,
+    pub pinned_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetPinnedBlocksResponse {
+    pub pinned_blocks: Vec<PinnedBlock>,
+}
+
+impl From<PinnedBlockDto> for PinnedBlock {
+    fn from(dto: PinnedBlockDto) -> Self {
+        Self {
+            block_id: dto.block_id,
+            title: dto.title,
+            pinned_at: dto.pinned_at,
+        }
+    }
+}
+
+impl IntoResponse for GetPinnedBlocksResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
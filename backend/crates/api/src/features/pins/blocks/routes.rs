@@ -0,0 +1,13 @@
+use std::sync::Arc;
+
+use crate::AppState;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(
+            super::get::get_pinned_blocks,
+            super::create::pin_block
+        ))
+        .routes(routes!(super::delete::unpin_block))
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State, http::StatusCode};
+use tracing::instrument;
+
+use super::{
+    error::{ErrorResponse, PinCanvasError},
+    request::PinCanvasRequest,
+};
+use crate::AppState;
+use storage::Database;
+use storage::repositories::{CanvasPinRepository, CanvasRepository};
+
+#[instrument]
+#[utoipa::path(
+    post,
+    path = "/api/pins/canvases",
+    tag = "pins",
+    request_body = PinCanvasRequest,
+    responses(
+        (status = 204, description = "Canvas pinned"),
+        (status = 404, description = "Canvas not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn pin_canvas(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<PinCanvasRequest>,
+) -> Result<StatusCode, PinCanvasError> {
+    state
+        .repos
+        .canvases
+        .get_by_id(request.canvas_id, state.db.pool())
+        .await?
+        .ok_or(PinCanvasError::NotFound)?;
+
+    state
+        .repos
+        .canvas_pins
+        .pin(request.canvas_id, state.db.pool())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,5 @@
+mod error;
+mod handler;
+mod request;
+
+pub(crate) use handler::*;
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PinCanvasRequest {
+    pub canvas_id: Uuid,
+}
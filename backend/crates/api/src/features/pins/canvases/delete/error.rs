@@ -0,0 +1,45 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::canvas_pin_repository::CanvasPinRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum UnpinCanvasError {
+    #[error(transparent)]
+    CanvasPinRepository(#[from] CanvasPinRepositoryError),
+}
+
+impl IntoResponse for UnpinCanvasError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::CanvasPinRepository(err) => match err {
+                CanvasPinRepositoryError::NotPinned { .. } => {
+                    (StatusCode::NOT_FOUND, "Canvas is not pinned".to_string())
+                }
+                other => {
+                    error!(error = ?other, "Canvas pin repository failure");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal server error".to_string(),
+                    )
+                }
+            },
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
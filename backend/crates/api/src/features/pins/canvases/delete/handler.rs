@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::error::{ErrorResponse, UnpinCanvasError};
+use crate::AppState;
+use storage::{Database, repositories::CanvasPinRepository};
+
+#[instrument]
+#[utoipa::path(
+    delete,
+    path = "/api/pins/canvases/{id}",
+    tag = "pins",
+    responses(
+        (status = 204, description = "Canvas unpinned"),
+        (status = 404, description = "Canvas is not pinned", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn unpin_canvas(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, UnpinCanvasError> {
+    state.repos.canvas_pins.unpin(id, state.db.pool()).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
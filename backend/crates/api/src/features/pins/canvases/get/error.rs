@@ -0,0 +1,40 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::canvas_pin_repository::CanvasPinRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum GetPinnedCanvassError {
+    #[error(transparent)]
+    CanvasPinRepository(#[from] CanvasPinRepositoryError),
+}
+
+impl IntoResponse for GetPinnedCanvassError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::CanvasPinRepository(err) => {
+                error!(error = ?err, "Canvas pin repository failure");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
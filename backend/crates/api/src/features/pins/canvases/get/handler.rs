@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use tracing::instrument;
+
+use super::{
+    error::{ErrorResponse, GetPinnedCanvassError},
+    response::GetPinnedCanvassResponse,
+};
+use crate::AppState;
+use storage::{Database, repositories::CanvasPinRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/pins/canvases",
+    tag = "pins",
+    responses(
+        (status = 200, description = "Pinned canvases, most recently pinned first", body = GetPinnedCanvassResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_pinned_canvases(
+    State(state): State<Arc<AppState>>,
+) -> Result<GetPinnedCanvassResponse, GetPinnedCanvassError> {
+    let pinned = state.repos.canvas_pins.get_all(state.db.pool()).await?;
+
+    let response = GetPinnedCanvassResponse {
+        pinned_canvases: pinned.into_iter().map(|p| p.into()).collect(),
+    };
+
+    Ok(response)
+}
@@ -0,0 +1,41 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use storage::repositories::canvas_pin_repository::PinnedCanvasDto;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PinnedCanvas {
+    pub canvas_id: Uuid,
+    pub name: String,
+    pub pinned_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetPinnedCanvassResponse {
+    pub pinned_canvases: Vec<PinnedCanvas>,
+}
+
+impl From<PinnedCanvasDto> for PinnedCanvas {
+    fn from(dto: PinnedCanvasDto) -> Self {
+        Self {
+            canvas_id: dto.canvas_id,
+            name: dto.name,
+            pinned_at: dto.pinned_at,
+        }
+    }
+}
+
+impl IntoResponse for GetPinnedCanvassResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,7 @@
+mod create;
+mod delete;
+mod get;
+
+mod routes;
+
+pub(crate) use routes::routes;
@@ -0,0 +1,6 @@
+mod blocks;
+mod canvases;
+
+mod routes;
+
+pub use routes::routes;
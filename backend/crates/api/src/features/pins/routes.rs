@@ -0,0 +1,10 @@
+use std::sync::Arc;
+
+use crate::AppState;
+use utoipa_axum::router::OpenApiRouter;
+
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .merge(super::blocks::routes())
+        .merge(super::canvases::routes())
+}
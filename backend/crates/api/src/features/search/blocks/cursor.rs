@@ -0,0 +1,111 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use storage::query_services::block_query_service::{BlockSearchDto, BlockSearchPosition};
+
+#[derive(Serialize, Deserialize)]
+struct Cursor {
+    /// `f64::to_bits` of the rank, so it compares equal after the round trip
+    rank: Option<u64>,
+    updated_at: DateTime<Utc>,
+    id: Uuid,
+    /// Fingerprint of the search the cursor was issued for
+    search: u64,
+}
+
+/// Encodes the sort key of the last result on a page as an opaque string.
+/// The next page starts after that key rather than at an offset, so rows
+/// added, edited or trashed in between don't shift it. The cursor carries a
+/// fingerprint of the query and filters so it can't be replayed against a
+/// different search.
+pub(super) fn encode(search: &BlockSearchDto, last: BlockSearchPosition) -> String {
+    let cursor = Cursor {
+        rank: last.rank.map(f64::to_bits),
+        updated_at: last.updated_at,
+        id: last.id,
+        search: fingerprint(search),
+    };
+
+    URL_SAFE_NO_PAD.encode(serde_json::to_vec(&cursor).expect("cursors always serialise"))
+}
+
+/// Returns the position to continue after, or `None` if the cursor is
+/// malformed or was issued for another query or filter set.
+pub(super) fn decode(cursor: &str, search: &BlockSearchDto) -> Option<BlockSearchPosition> {
+    let bytes = URL_SAFE_NO_PAD.decode(cursor).ok()?;
+    let cursor: Cursor = serde_json::from_slice(&bytes).ok()?;
+
+    (cursor.search == fingerprint(search)).then_some(BlockSearchPosition {
+        rank: cursor.rank.map(f64::from_bits),
+        updated_at: cursor.updated_at,
+        id: cursor.id,
+    })
+}
+
+/// FNV-1a over the JSON encoding of the query and filters. Unlike std's
+/// `DefaultHasher`, both are fixed, so cursors survive a server rebuild.
+fn fingerprint(search: &BlockSearchDto) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let canonical = serde_json::to_vec(&(
+        &search.query,
+        search.created_after,
+        search.created_before,
+        search.updated_after,
+        search.updated_before,
+        search.descendant_of,
+        search.only_opened,
+        search.only_pinned,
+        &search.tag,
+    ))
+    .expect("search filters always serialise");
+
+    canonical.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(rank: Option<f64>) -> BlockSearchPosition {
+        BlockSearchPosition {
+            rank,
+            updated_at: Utc::now(),
+            id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn round_trips_the_position_exactly() {
+        let search = BlockSearchDto::new("needle");
+        for last in [position(Some(-0.1 - 0.2)), position(None)] {
+            assert_eq!(decode(&encode(&search, last), &search), Some(last));
+        }
+    }
+
+    #[test]
+    fn rejects_cursors_from_another_search() {
+        let search = BlockSearchDto::new("needle");
+        let cursor = encode(&search, position(Some(-1.0)));
+
+        let other = BlockSearchDto {
+            only_pinned: true,
+            ..BlockSearchDto::new("needle")
+        };
+        assert_eq!(decode(&cursor, &other), None);
+        assert_eq!(decode(&cursor, &BlockSearchDto::new("other")), None);
+    }
+
+    #[test]
+    fn rejects_malformed_cursors() {
+        let search = BlockSearchDto::new("needle");
+
+        assert_eq!(decode("not a cursor", &search), None);
+        assert_eq!(decode(&URL_SAFE_NO_PAD.encode("offset:50"), &search), None);
+    }
+}
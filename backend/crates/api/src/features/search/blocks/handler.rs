@@ -3,17 +3,25 @@ use std::sync::Arc;
 use axum::{Json, extract::State};
 use tracing::instrument;
 
-use super::{error::SearchBlocksError, request::BlockSearchRequest, response::BlockSearchResponse};
+use super::{
+    cursor, error::SearchBlocksError, request::BlockSearchRequest, response::BlockSearchResponse,
+};
 use crate::AppState;
+use domain::tags::Tag;
 use storage::Database;
 use storage::query_services::BlockQueryService;
+use storage::query_services::block_query_service::BlockSearchDto;
+
+const MAX_LIMIT: i64 = 100;
 
 #[utoipa::path(
     post,
     path = "/api/search/blocks",
     tag = "search",
+    request_body = BlockSearchRequest,
     responses(
         (status = 200, description = "Success", body = BlockSearchResponse),
+        (status = 400, description = "Invalid limit, or cursor issued for a different query or filters"),
         (status = 500, description = "Internal server error")
     )
 )]
@@ -22,13 +30,57 @@ pub async fn search_blocks(
     State(state): State<Arc<AppState>>,
     Json(request): Json<BlockSearchRequest>,
 ) -> Result<BlockSearchResponse, SearchBlocksError> {
-    let blocks = state
+    let limit = request.limit.unwrap_or(BlockSearchDto::DEFAULT_LIMIT);
+    if !(1..=MAX_LIMIT).contains(&limit) {
+        return Err(SearchBlocksError::InputValidation(format!(
+            "limit must be between 1 and {MAX_LIMIT}"
+        )));
+    }
+
+    let tag = request
+        .tag
+        .as_deref()
+        .map(Tag::normalize_name)
+        .transpose()
+        .map_err(|err| SearchBlocksError::InputValidation(err.to_string()))?;
+
+    // Fetch one extra row to learn whether another page follows
+    let mut search = BlockSearchDto {
+        created_after: request.created_after,
+        created_before: request.created_before,
+        updated_after: request.updated_after,
+        updated_before: request.updated_before,
+        descendant_of: request.descendant_of,
+        only_opened: request.only_opened,
+        only_pinned: request.only_pinned,
+        tag,
+        limit: limit + 1,
+        ..BlockSearchDto::new(&request.query)
+    };
+
+    if let Some(value) = request.cursor.as_deref() {
+        let after = cursor::decode(value, &search).ok_or_else(|| {
+            SearchBlocksError::InputValidation(
+                "cursor is invalid or belongs to a different search".to_string(),
+            )
+        })?;
+        search.after = Some(after);
+    }
+
+    let mut blocks = state
         .query_services
         .blocks
-        .search(&request.query, state.db.pool())
+        .search(&search, state.db.pool())
         .await?;
 
-    let response: BlockSearchResponse = blocks.into();
+    let next_cursor = if blocks.len() as i64 > limit {
+        blocks.truncate(limit as usize);
+        blocks
+            .last()
+            .map(|last| cursor::encode(&search, last.position()))
+    } else {
+        None
+    };
 
-    Ok(response)
+    Ok(BlockSearchResponse::from_page(blocks, next_cursor))
 }
@@ -1,3 +1,4 @@
+mod cursor;
 mod handler;
 mod error;
 mod request;
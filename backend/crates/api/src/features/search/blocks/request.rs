@@ -1,9 +1,30 @@
-use serde;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 #[derive(Deserialize, Debug, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockSearchRequest {
     pub query: String,
+    /// Inclusive lower bound on the creation time
+    pub created_after: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on the creation time
+    pub created_before: Option<DateTime<Utc>>,
+    /// Inclusive lower bound on the last update time
+    pub updated_after: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on the last update time
+    pub updated_before: Option<DateTime<Utc>>,
+    /// Only return blocks below this block in the directional hierarchy
+    pub descendant_of: Option<Uuid>,
+    #[serde(default)]
+    pub only_opened: bool,
+    #[serde(default)]
+    pub only_pinned: bool,
+    /// Only return blocks with this tag, ignoring case
+    pub tag: Option<String>,
+    /// Page size, between 1 and 100. Defaults to 50
+    pub limit: Option<i64>,
+    /// `nextCursor` from a previous response with the same query and filters
+    pub cursor: Option<String>,
 }
@@ -14,12 +14,17 @@ use storage::query_services::block_query_service::BlockSummaryDto;
 pub struct BlockSummary {
     pub id: Uuid,
     pub title: String,
+    pub is_pinned: bool,
+    /// HTML-escaped content excerpt with matches wrapped in `<mark>` tags
+    pub snippet: Option<String>,
 }
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockSearchResponse {
     pub blocks: Vec<BlockSummary>,
+    /// Pass back as `cursor` to fetch the next page; absent on the last page
+    pub next_cursor: Option<String>,
 }
 
 impl From<BlockSummaryDto> for BlockSummary {
@@ -27,14 +32,17 @@ impl From<BlockSummaryDto> for BlockSummary {
         Self {
             id: dto.id,
             title: dto.title,
+            is_pinned: dto.is_pinned,
+            snippet: dto.snippet,
         }
     }
 }
 
-impl From<Vec<BlockSummaryDto>> for BlockSearchResponse {
-    fn from(blocks: Vec<BlockSummaryDto>) -> Self {
+impl BlockSearchResponse {
+    pub fn from_page(blocks: Vec<BlockSummaryDto>, next_cursor: Option<String>) -> Self {
         Self {
             blocks: blocks.into_iter().map(|b| b.into()).collect(),
+            next_cursor,
         }
     }
 }
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use tracing::instrument;
+
+use super::{
+    error::{ErrorResponse, GetTagsError},
+    response::GetTagsResponse,
+};
+use crate::AppState;
+use storage::{Database, repositories::TagRepository};
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/tags",
+    tag = "tags",
+    responses(
+        (status = 200, description = "All tags with their block counts, ordered by name", body = GetTagsResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_tags(State(state): State<Arc<AppState>>) -> Result<GetTagsResponse, GetTagsError> {
+    let tags = state
+        .repos
+        .tags
+        .get_all_with_counts(state.db.pool())
+        .await?;
+
+    let response = GetTagsResponse {
+        tags: tags.into_iter().map(|t| t.into()).collect(),
+    };
+
+    Ok(response)
+}
@@ -0,0 +1,40 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use storage::repositories::tag_repository::TagCountDto;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TagSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub block_count: i64,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetTagsResponse {
+    pub tags: Vec<TagSummary>,
+}
+
+impl From<TagCountDto> for TagSummary {
+    fn from(dto: TagCountDto) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            block_count: dto.block_count,
+        }
+    }
+}
+
+impl IntoResponse for GetTagsResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,49 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::tag_repository::TagRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum MergeTagError {
+    #[error(transparent)]
+    TagRepository(#[from] TagRepositoryError),
+}
+
+impl IntoResponse for MergeTagError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::TagRepository(err) => match err {
+                TagRepositoryError::NotFound { .. } => {
+                    (StatusCode::NOT_FOUND, "Tag not found".to_string())
+                }
+                TagRepositoryError::SelfMerge { .. } => (
+                    StatusCode::BAD_REQUEST,
+                    "Cannot merge a tag into itself".to_string(),
+                ),
+                other => {
+                    error!(error = ?other, "Tag repository failure");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal server error".to_string(),
+                    )
+                }
+            },
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
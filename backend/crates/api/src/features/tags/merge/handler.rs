@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, MergeTagError},
+    request::MergeTagRequest,
+};
+use crate::AppState;
+use storage::{Database, repositories::TagRepository};
+
+/// Moves every block tagged `{id}` onto the target tag, then deletes `{id}`.
+#[instrument]
+#[utoipa::path(
+    post,
+    path = "/api/tags/{id}/merge",
+    tag = "tags",
+    request_body = MergeTagRequest,
+    responses(
+        (status = 204, description = "Tag merged into the target"),
+        (status = 400, description = "Source and target are the same tag", body = ErrorResponse),
+        (status = 404, description = "Tag not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn merge_tag(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<MergeTagRequest>,
+) -> Result<StatusCode, MergeTagError> {
+    state
+        .repos
+        .tags
+        .merge(id, request.target_tag_id, state.db.pool())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeTagRequest {
+    /// Tag that receives the blocks of the merged tag
+    pub target_tag_id: Uuid,
+}
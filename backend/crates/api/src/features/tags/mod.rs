@@ -0,0 +1,7 @@
+mod list;
+mod merge;
+mod rename;
+
+mod routes;
+
+pub use routes::routes;
@@ -0,0 +1,50 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::tag_repository::TagRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum RenameTagError {
+    #[error("Validation failed: {0}")]
+    InputValidation(String),
+
+    #[error(transparent)]
+    TagRepository(#[from] TagRepositoryError),
+}
+
+impl IntoResponse for RenameTagError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::InputValidation(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            Self::TagRepository(err) => match err {
+                TagRepositoryError::NotFound { .. } => {
+                    (StatusCode::NOT_FOUND, "Tag not found".to_string())
+                }
+                TagRepositoryError::AlreadyExists { .. } => (StatusCode::CONFLICT, err.to_string()),
+                other => {
+                    error!(error = ?other, "Tag repository failure");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal server error".to_string(),
+                    )
+                }
+            },
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
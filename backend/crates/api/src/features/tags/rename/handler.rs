@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, RenameTagError},
+    request::RenameTagRequest,
+    response::RenameTagResponse,
+};
+use crate::AppState;
+use domain::tags::Tag;
+use storage::{Database, repositories::TagRepository};
+
+#[instrument]
+#[utoipa::path(
+    put,
+    path = "/api/tags/{id}",
+    tag = "tags",
+    request_body = RenameTagRequest,
+    responses(
+        (status = 200, description = "Tag renamed", body = RenameTagResponse),
+        (status = 400, description = "Invalid tag name", body = ErrorResponse),
+        (status = 404, description = "Tag not found", body = ErrorResponse),
+        (status = 409, description = "Another tag already has this name", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn rename_tag(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<RenameTagRequest>,
+) -> Result<RenameTagResponse, RenameTagError> {
+    let name = Tag::normalize_name(&request.name)
+        .map_err(|err| RenameTagError::InputValidation(err.to_string()))?;
+
+    let tag = state.repos.tags.rename(id, &name, state.db.pool()).await?;
+
+    let response: RenameTagResponse = tag.into();
+
+    Ok(response)
+}
@@ -0,0 +1,11 @@
+use std::sync::Arc;
+
+use crate::AppState;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(super::list::get_tags))
+        .routes(routes!(super::rename::rename_tag))
+        .routes(routes!(super::merge::merge_tag))
+}
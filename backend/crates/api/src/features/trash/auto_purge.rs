@@ -0,0 +1,41 @@
+use std::{sync::Arc, time::Duration as StdDuration};
+
+use chrono::{Duration, Utc};
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::AppState;
+use storage::{Database, repositories::BlockRepository};
+
+const PURGE_INTERVAL: StdDuration = StdDuration::from_secs(60 * 60);
+
+/// Periodically purges blocks that have been in the trash for longer than
+/// `retention_days`. A retention of zero keeps trashed blocks until they are
+/// purged by hand.
+pub fn spawn_auto_purge(state: Arc<AppState>, retention_days: u32) -> Option<JoinHandle<()>> {
+    if retention_days == 0 {
+        return None;
+    }
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let cutoff = Utc::now() - Duration::days(i64::from(retention_days));
+            match state
+                .repos
+                .blocks
+                .purge_trashed_before(cutoff, state.db.pool())
+                .await
+            {
+                Ok(0) => {}
+                Ok(purged) => info!(purged, "Purged expired blocks from the trash"),
+                Err(err) => error!(error = ?err, "Trash auto-purge failure"),
+            }
+        }
+    });
+
+    Some(handle)
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use tracing::instrument;
+
+use super::{
+    error::{ErrorResponse, GetTrashedBlocksError},
+    response::GetTrashedBlocksResponse,
+};
+use crate::AppState;
+use storage::Database;
+use storage::query_services::BlockQueryService;
+
+#[instrument]
+#[utoipa::path(
+    get,
+    path = "/api/trash",
+    tag = "trash",
+    responses(
+        (status = 200, description = "Trashed blocks, most recently trashed first", body = GetTrashedBlocksResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn get_trashed_blocks(
+    State(state): State<Arc<AppState>>,
+) -> Result<GetTrashedBlocksResponse, GetTrashedBlocksError> {
+    let blocks = state
+        .query_services
+        .blocks
+        .get_trashed(state.db.pool())
+        .await?;
+
+    Ok(GetTrashedBlocksResponse {
+        blocks: blocks.into_iter().map(Into::into).collect(),
+    })
+}
@@ -0,0 +1,5 @@
+mod error;
+mod handler;
+mod response;
+
+pub(crate) use handler::*;
@@ -0,0 +1,41 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use storage::query_services::block_query_service::TrashedBlockDto;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TrashedBlock {
+    pub id: Uuid,
+    pub title: String,
+    pub trashed_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetTrashedBlocksResponse {
+    pub blocks: Vec<TrashedBlock>,
+}
+
+impl From<TrashedBlockDto> for TrashedBlock {
+    fn from(dto: TrashedBlockDto) -> Self {
+        Self {
+            id: dto.id,
+            title: dto.title,
+            trashed_at: dto.trashed_at,
+        }
+    }
+}
+
+impl IntoResponse for GetTrashedBlocksResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
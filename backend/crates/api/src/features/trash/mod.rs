@@ -0,0 +1,9 @@
+mod auto_purge;
+mod list;
+mod purge;
+mod restore;
+
+mod routes;
+
+pub use auto_purge::spawn_auto_purge;
+pub use routes::routes;
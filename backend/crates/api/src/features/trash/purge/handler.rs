@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::error::{ErrorResponse, PurgeTrashedBlockError};
+use crate::AppState;
+use storage::{Database, repositories::BlockRepository};
+
+/// Permanently deletes a trashed block together with its links, pins,
+/// placements and revisions.
+#[instrument]
+#[utoipa::path(
+    delete,
+    path = "/api/trash/{id}",
+    tag = "trash",
+    responses(
+        (status = 204, description = "Block purged"),
+        (status = 404, description = "Block not found", body = ErrorResponse),
+        (status = 409, description = "Block is not in the trash", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn purge_trashed_block(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, PurgeTrashedBlockError> {
+    state.repos.blocks.purge(id, state.db.pool()).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
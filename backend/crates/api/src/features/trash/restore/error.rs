@@ -0,0 +1,53 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+use utoipa::ToSchema;
+
+use storage::repositories::block_repository::BlockRepositoryError;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum RestoreTrashedBlockError {
+    #[error(transparent)]
+    BlockRepository(#[from] BlockRepositoryError),
+}
+
+impl IntoResponse for RestoreTrashedBlockError {
+    fn into_response(self) -> Response {
+        let (status, msg) = match &self {
+            Self::BlockRepository(err) => match err {
+                BlockRepositoryError::NotFound { .. } => {
+                    (StatusCode::NOT_FOUND, "Block not found".to_string())
+                }
+                BlockRepositoryError::NotTrashed { .. } => (
+                    StatusCode::CONFLICT,
+                    "Block is not in the trash".to_string(),
+                ),
+                BlockRepositoryError::RestoreCycle { .. } => (
+                    StatusCode::CONFLICT,
+                    "Restoring the block would create a cycle".to_string(),
+                ),
+                other => {
+                    error!(error = ?other, "Block repository failure");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal server error".to_string(),
+                    )
+                }
+            },
+        };
+
+        let body = Json(ErrorResponse { error: msg });
+
+        (status, body).into_response()
+    }
+}
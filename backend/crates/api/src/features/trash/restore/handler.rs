@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::{
+    error::{ErrorResponse, RestoreTrashedBlockError},
+    response::RestoreTrashedBlockResponse,
+};
+use crate::AppState;
+use storage::repositories::block_repository::BlockRepositoryError;
+use storage::{Database, repositories::BlockRepository};
+
+#[instrument]
+#[utoipa::path(
+    post,
+    path = "/api/trash/{id}/restore",
+    tag = "trash",
+    responses(
+        (status = 200, description = "Block restored with its links", body = RestoreTrashedBlockResponse),
+        (status = 404, description = "Block not found", body = ErrorResponse),
+        (status = 409, description = "Block is not in the trash or restoring it would create a cycle", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn restore_trashed_block(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<RestoreTrashedBlockResponse, RestoreTrashedBlockError> {
+    state.repos.blocks.restore(id, state.db.pool()).await?;
+
+    let block = state
+        .repos
+        .blocks
+        .get_by_id(id, state.db.pool())
+        .await?
+        .ok_or(BlockRepositoryError::NotFound { id })?;
+
+    Ok(block.into())
+}
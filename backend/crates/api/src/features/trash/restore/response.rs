@@ -0,0 +1,39 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use domain::blocks::Block;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RestoreTrashedBlockResponse {
+    pub id: Uuid,
+    pub title: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Block> for RestoreTrashedBlockResponse {
+    fn from(block: Block) -> Self {
+        Self {
+            id: block.id,
+            title: block.title,
+            content: block.content,
+            created_at: block.created_at,
+            updated_at: block.updated_at,
+        }
+    }
+}
+
+impl IntoResponse for RestoreTrashedBlockResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,11 @@
+use std::sync::Arc;
+
+use crate::AppState;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(super::list::get_trashed_blocks))
+        .routes(routes!(super::restore::restore_trashed_block))
+        .routes(routes!(super::purge::purge_trashed_block))
+}
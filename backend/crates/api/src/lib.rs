@@ -1,9 +1,13 @@
 pub mod app_state;
+pub mod cli;
 pub mod config;
 pub mod error;
 pub mod features;
 pub mod telemetry;
 
+#[cfg(all(test, feature = "native"))]
+pub(crate) mod test_utils;
+
 pub use app_state::AppState;
 pub use config::AppConfig;
 pub use error::{AppError, AppResult};
@@ -45,7 +45,13 @@ fn configure_cors(config: &AppConfig) -> Result<CorsLayer> {
             Method::PATCH,
             Method::OPTIONS,
         ])
-        .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION, header::ACCEPT])
+        .allow_headers([
+            header::CONTENT_TYPE,
+            header::AUTHORIZATION,
+            header::ACCEPT,
+            header::IF_MATCH,
+        ])
+        .expose_headers([header::ETAG])
         .allow_credentials(true);
 
     Ok(cors)
@@ -62,13 +68,28 @@ async fn main() -> Result<()> {
 
     let state = Arc::new(AppState::new(db));
 
+    features::trash::spawn_auto_purge(state.clone(), config.trash_retention_days);
+
     let (router, openapi) = OpenApiRouter::new()
         .merge(features::blocks::routes())
         .merge(features::block_links::routes())
+        .merge(features::block_dag::routes())
+        .merge(features::block_revisions::routes())
+        .merge(features::block_tags::routes())
+        .merge(features::canvases::routes())
+        .merge(features::canvas_blocks::routes())
+        .merge(features::pins::routes())
+        .merge(features::tags::routes())
         .merge(features::workspace::routes())
         .merge(features::search::routes())
+        .merge(features::trash::routes())
         .merge(features::export::routes())
-        .merge(features::import::routes())
+        .merge(features::export_graph::routes())
+        .merge(features::export_markdown::routes())
+        .merge(features::import::routes(config.max_upload_bytes))
+        .merge(features::import_markdown::routes(config.max_upload_bytes))
+        .merge(features::batch::routes())
+        .merge(features::admin::routes())
         .split_for_parts();
 
     let cors_layer = configure_cors(&config)?;
@@ -0,0 +1,60 @@
+//! Fixtures for handler tests. Every test gets its own SQLite database file,
+//! so they need the `native` feature and no external database.
+
+use std::ops::Deref;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use rstest::fixture;
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::app_state::DatabaseImpl;
+use storage::Database;
+
+pub(crate) struct TestApp {
+    state: Arc<AppState>,
+    path: PathBuf,
+}
+
+impl TestApp {
+    pub(crate) async fn new() -> Self {
+        let path = std::env::temp_dir().join(format!("modunote-test-{}.sqlite", Uuid::new_v4()));
+        let db = DatabaseImpl::connect(&format!("sqlite://{}?mode=rwc", path.display()))
+            .await
+            .expect("Failed to create test database");
+        db.run_migration().await.expect("Failed to run migrations");
+
+        Self {
+            state: Arc::new(AppState::new(db)),
+            path,
+        }
+    }
+
+    pub(crate) fn state(&self) -> Arc<AppState> {
+        self.state.clone()
+    }
+}
+
+impl Deref for TestApp {
+    type Target = AppState;
+
+    fn deref(&self) -> &AppState {
+        &self.state
+    }
+}
+
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        for suffix in ["", "-wal", "-shm"] {
+            let mut path = self.path.clone().into_os_string();
+            path.push(suffix);
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+#[fixture]
+pub(crate) async fn app() -> TestApp {
+    TestApp::new().await
+}
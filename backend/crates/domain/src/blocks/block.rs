@@ -8,6 +8,8 @@ pub struct Block {
     pub content: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Starts at 1 and goes up by one every time the block is saved
+    pub version: i64,
 }
 
 impl Block {
@@ -20,6 +22,7 @@ impl Block {
             content: content.to_string(),
             created_at: now,
             updated_at: now,
+            version: 1,
         }
     }
 }
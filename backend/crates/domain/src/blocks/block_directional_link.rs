@@ -14,6 +14,12 @@ pub struct BlockDirectionalLink {
     pub id: Uuid,
     pub block_from_id: Uuid,
     pub block_to_id: Uuid,
+    /// What the edge means, e.g. "supports" or "part-of"
+    pub kind: Option<String>,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
+    /// Ordinal among the children of `block_from_id`
+    pub position: i64,
     pub created_at: DateTime<Utc>,
 }
 
@@ -32,6 +38,10 @@ impl BlockDirectionalLink {
             id,
             block_from_id,
             block_to_id,
+            kind: None,
+            label: None,
+            weight: None,
+            position: 0,
             created_at: now,
         })
     }
@@ -14,6 +14,8 @@ pub struct BlockRelatedLink {
     pub id: Uuid,
     pub block_a_id: Uuid,
     pub block_b_id: Uuid,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -40,6 +42,8 @@ impl BlockRelatedLink {
             id,
             block_a_id,
             block_b_id,
+            label: None,
+            weight: None,
             created_at,
         })
     }
@@ -12,3 +12,19 @@ pub struct Canvas {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+impl Canvas {
+    pub fn new(name: &str) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            viewport_x: 0.0,
+            viewport_y: 0.0,
+            zoom_level: 1.0,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum CanvasBlockError {
+    #[error("Grid width and height must be at least 1")]
+    InvalidSize,
+
+    #[error("Scale must be a positive number")]
+    InvalidScale,
+}
+
+/// Placement of a block on a canvas. Position and size are in grid cells.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct CanvasBlock {
+    pub id: Uuid,
+    pub canvas_id: Uuid,
+    pub block_id: Uuid,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub grid_width: i32,
+    pub grid_height: i32,
+    pub z: i32,
+    pub scale: f64,
+    pub content_visible: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CanvasBlock {
+    pub fn new(
+        canvas_id: Uuid,
+        block_id: Uuid,
+        grid_x: i32,
+        grid_y: i32,
+        grid_width: i32,
+        grid_height: i32,
+    ) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            canvas_id,
+            block_id,
+            grid_x,
+            grid_y,
+            grid_width,
+            grid_height,
+            z: 0,
+            scale: 1.0,
+            content_visible: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), CanvasBlockError> {
+        if self.grid_width < 1 || self.grid_height < 1 {
+            return Err(CanvasBlockError::InvalidSize);
+        }
+        if !self.scale.is_finite() || self.scale <= 0.0 {
+            return Err(CanvasBlockError::InvalidScale);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CanvasBlock, CanvasBlockError};
+    use uuid::Uuid;
+
+    #[test]
+    fn validate_rejects_empty_size() {
+        let placement = CanvasBlock::new(Uuid::new_v4(), Uuid::new_v4(), 0, 0, 0, 2);
+
+        assert_eq!(placement.validate(), Err(CanvasBlockError::InvalidSize));
+    }
+
+    #[test]
+    fn validate_rejects_non_positive_scale() {
+        let mut placement = CanvasBlock::new(Uuid::new_v4(), Uuid::new_v4(), 0, 0, 2, 2);
+        placement.scale = 0.0;
+
+        assert_eq!(placement.validate(), Err(CanvasBlockError::InvalidScale));
+    }
+
+    #[test]
+    fn validate_accepts_new_placement() {
+        let placement = CanvasBlock::new(Uuid::new_v4(), Uuid::new_v4(), -3, 4, 2, 2);
+
+        assert!(placement.validate().is_ok());
+    }
+}
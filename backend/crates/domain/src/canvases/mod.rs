@@ -1 +1,5 @@
 pub mod canvas;
+pub mod canvas_block;
+
+pub use canvas::Canvas;
+pub use canvas_block::{CanvasBlock, CanvasBlockError};
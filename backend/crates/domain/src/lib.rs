@@ -1,3 +1,4 @@
 pub mod blocks;
 pub mod canvases;
+pub mod tags;
 pub mod workspaces;
@@ -0,0 +1,3 @@
+pub mod tag;
+
+pub use tag::{Tag, TagError};
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum TagError {
+    #[error("Tag name must not be empty")]
+    EmptyName,
+}
+
+/// Label attached to blocks. Names are stored lowercase, so they are unique
+/// ignoring case on every backend.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Tag {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Tag {
+    pub fn new(name: &str) -> Result<Self, TagError> {
+        Ok(Self {
+            id: Uuid::new_v4(),
+            name: Self::normalize_name(name)?,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Trims surrounding whitespace, lowercases and rejects blank names. Use
+    /// it on names both before storing and before looking tags up.
+    pub fn normalize_name(name: &str) -> Result<String, TagError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(TagError::EmptyName);
+        }
+
+        Ok(name.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Tag, TagError};
+
+    #[test]
+    fn new_trims_name() {
+        let tag = Tag::new("  rust ").unwrap();
+
+        assert_eq!(tag.name, "rust");
+    }
+
+    #[test]
+    fn new_lowercases_name() {
+        assert_eq!(Tag::new("Rust").unwrap().name, "rust");
+        assert_eq!(Tag::new("ÉCOLE").unwrap().name, "école");
+    }
+
+    #[test]
+    fn new_rejects_blank_name() {
+        assert_eq!(Tag::new(" \t").unwrap_err(), TagError::EmptyName);
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use uuid::Uuid;
+
+use super::dtos::{ClosureRowDto, ClosureRowMismatchDto, PathIntegrityReportDto};
+
+/// The closure implied by a set of directional links.
+#[derive(Clone, Debug, Default)]
+pub struct DerivedClosure {
+    /// One row per reachable pair whose ancestor does not reach a cycle
+    pub rows: Vec<ClosureRowDto>,
+    /// Blocks that reach themselves through the links
+    pub cyclic_block_ids: Vec<Uuid>,
+    /// Blocks that reach a cycle and therefore have no finite path counts
+    unresolved_block_ids: HashSet<Uuid>,
+}
+
+/// Recomputes the closure from `(from, to)` link pairs. Blocks are settled
+/// children first, so each one merges the already settled closures of its
+/// children; whatever is left over reaches a cycle.
+pub fn derive_closure(links: &[(Uuid, Uuid)]) -> DerivedClosure {
+    let mut children: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    let mut parents: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    let mut unsettled_children: HashMap<Uuid, usize> = HashMap::new();
+
+    for &(from, to) in links {
+        children.entry(from).or_default().push(to);
+        parents.entry(to).or_default().push(from);
+        *unsettled_children.entry(from).or_default() += 1;
+        unsettled_children.entry(to).or_default();
+    }
+
+    let mut queue: VecDeque<Uuid> = unsettled_children
+        .iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(id, _)| *id)
+        .collect();
+
+    // descendant -> (path_count, min_depth), per settled block
+    let mut settled: HashMap<Uuid, HashMap<Uuid, (i64, i64)>> = HashMap::new();
+
+    while let Some(id) = queue.pop_front() {
+        let mut reachable: HashMap<Uuid, (i64, i64)> = HashMap::new();
+        for child in children.get(&id).into_iter().flatten() {
+            let through_child = std::iter::once((*child, (1, 0))).chain(
+                settled[child]
+                    .iter()
+                    .map(|(descendant, (count, depth))| (*descendant, (*count, *depth))),
+            );
+            for (descendant, (count, depth)) in through_child {
+                let entry = reachable.entry(descendant).or_insert((0, i64::MAX));
+                entry.0 = entry.0.saturating_add(count);
+                entry.1 = entry.1.min(depth + 1);
+            }
+        }
+        settled.insert(id, reachable);
+
+        for parent in parents.get(&id).into_iter().flatten() {
+            let count = unsettled_children
+                .get_mut(parent)
+                .expect("every linked block is counted");
+            *count -= 1;
+            if *count == 0 {
+                queue.push_back(*parent);
+            }
+        }
+    }
+
+    let unresolved_block_ids: HashSet<Uuid> = unsettled_children
+        .keys()
+        .filter(|id| !settled.contains_key(id))
+        .copied()
+        .collect();
+
+    let mut cyclic_block_ids: Vec<Uuid> = unresolved_block_ids
+        .iter()
+        .filter(|id| reaches_itself(**id, &children, &unresolved_block_ids))
+        .copied()
+        .collect();
+    cyclic_block_ids.sort();
+
+    let mut rows: Vec<ClosureRowDto> = settled
+        .into_iter()
+        .flat_map(|(ancestor, reachable)| {
+            reachable
+                .into_iter()
+                .map(move |(descendant, (path_count, min_depth))| ClosureRowDto {
+                    block_ancestor_id: ancestor,
+                    block_descendant_id: descendant,
+                    path_count,
+                    min_depth,
+                })
+        })
+        .collect();
+    rows.sort_by_key(|row| (row.block_ancestor_id, row.block_descendant_id));
+
+    DerivedClosure {
+        rows,
+        cyclic_block_ids,
+        unresolved_block_ids,
+    }
+}
+
+/// Compares stored closure rows with the closure derived from the links.
+pub fn compare_closure(
+    derived: &DerivedClosure,
+    stored: Vec<ClosureRowDto>,
+) -> PathIntegrityReportDto {
+    let mut expected: HashMap<(Uuid, Uuid), &ClosureRowDto> = derived
+        .rows
+        .iter()
+        .map(|row| ((row.block_ancestor_id, row.block_descendant_id), row))
+        .collect();
+
+    let mut report = PathIntegrityReportDto {
+        cyclic_block_ids: derived.cyclic_block_ids.clone(),
+        ..Default::default()
+    };
+
+    for row in stored {
+        if derived
+            .unresolved_block_ids
+            .contains(&row.block_ancestor_id)
+        {
+            continue;
+        }
+        match expected.remove(&(row.block_ancestor_id, row.block_descendant_id)) {
+            None => report.extra.push(row),
+            Some(want) if *want != row => report.mismatched.push(ClosureRowMismatchDto {
+                stored: row,
+                expected: want.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+
+    report.missing = expected.into_values().cloned().collect();
+    report
+        .missing
+        .sort_by_key(|row| (row.block_ancestor_id, row.block_descendant_id));
+    report
+        .extra
+        .sort_by_key(|row| (row.block_ancestor_id, row.block_descendant_id));
+    report.mismatched.sort_by_key(|mismatch| {
+        (
+            mismatch.stored.block_ancestor_id,
+            mismatch.stored.block_descendant_id,
+        )
+    });
+
+    report
+}
+
+fn reaches_itself(
+    start: Uuid,
+    children: &HashMap<Uuid, Vec<Uuid>>,
+    candidates: &HashSet<Uuid>,
+) -> bool {
+    let mut seen = HashSet::new();
+    let mut stack = vec![start];
+
+    while let Some(id) = stack.pop() {
+        for child in children.get(&id).into_iter().flatten() {
+            if *child == start {
+                return true;
+            }
+            if candidates.contains(child) && seen.insert(*child) {
+                stack.push(*child);
+            }
+        }
+    }
+
+    false
+}
@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClosureRowDto {
+    pub block_ancestor_id: Uuid,
+    pub block_descendant_id: Uuid,
+    /// Number of distinct paths, saturating at `i64::MAX`
+    pub path_count: i64,
+    pub min_depth: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClosureRowMismatchDto {
+    pub stored: ClosureRowDto,
+    pub expected: ClosureRowDto,
+}
+
+/// Differences between the stored closure and the one implied by the links
+/// between live blocks. Ancestors that reach a cycle have no well-defined
+/// closure, so their rows are only reported through `cyclic_block_ids`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PathIntegrityReportDto {
+    pub missing: Vec<ClosureRowDto>,
+    pub extra: Vec<ClosureRowDto>,
+    pub mismatched: Vec<ClosureRowMismatchDto>,
+    pub cyclic_block_ids: Vec<Uuid>,
+}
+
+impl PathIntegrityReportDto {
+    pub fn is_consistent(&self) -> bool {
+        self.missing.is_empty()
+            && self.extra.is_empty()
+            && self.mismatched.is_empty()
+            && self.cyclic_block_ids.is_empty()
+    }
+}
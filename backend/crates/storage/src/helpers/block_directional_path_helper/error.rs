@@ -8,20 +8,17 @@ pub enum BlockDirectionalPathHelperError {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
-    #[error("Empty path provided - cannot create path with no blocks")]
-    EmptyPathCreation,
-
-    #[error("Cyclic path provided - cannot create")]
+    #[error("Link would close a cycle - cannot create paths")]
     CyclicPathCreation,
 
-    #[error("Path already exists: {from} -> {to}")]
-    AlreadyExists { from: Uuid, to: Uuid },
-
     #[error("Block not found: {from} or {to}")]
     PathBlocksNotFound { from: Uuid, to: Uuid },
 
     #[error("Block not found: {block_id}")]
     BlockNotFound { block_id: Uuid },
+
+    #[error("Links form a cycle through blocks {block_ids:?} - cannot rebuild paths")]
+    CyclicLinks { block_ids: Vec<Uuid> },
 }
 
 pub type BlockDirectionalPathHelperResult<T> = Result<T, BlockDirectionalPathHelperError>;
@@ -1,8 +1,12 @@
+mod closure;
+mod dtos;
 mod error;
 mod traits;
 
 #[cfg(feature = "test-utils")]
 pub mod test_utils;
 
+pub use closure::{DerivedClosure, compare_closure, derive_closure};
+pub use dtos::*;
 pub use error::{BlockDirectionalPathHelperError, BlockDirectionalPathHelperResult};
 pub use traits::BlockDirectionalPathHelper;
@@ -1,9 +1,14 @@
 use crate::helpers::block_directional_path_helper::{
-    BlockDirectionalPathHelper, BlockDirectionalPathHelperResult as Result,
+    BlockDirectionalPathHelper, BlockDirectionalPathHelperError,
+    BlockDirectionalPathHelperResult as Result,
+};
+use crate::repositories::block_directional_link_repository::{
+    BlockDirectionalLinkRepository, CreateBlockDirectionalLinkDto,
 };
 use crate::repositories::block_repository::BlockRepository;
 use domain::blocks::Block;
 use sqlx::{Acquire, Database, Executor};
+use uuid::Uuid;
 
 async fn seed_block<'e, E, R, DB>(repo: &R, title: &str, executor: E) -> Block
 where
@@ -18,6 +23,34 @@ where
     block
 }
 
+async fn seed_link<'e, E, L, DB>(repo: &L, from: &Block, to: &Block, executor: E)
+where
+    DB: Database,
+    L: BlockDirectionalLinkRepository<DB>,
+    E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>,
+{
+    let input = CreateBlockDirectionalLinkDto {
+        id: Uuid::new_v4(),
+        block_from_id: from.id,
+        block_to_id: to.id,
+        kind: None,
+        label: None,
+        weight: None,
+    };
+    repo.create(&input, executor)
+        .await
+        .expect("failed to seed link for path helper tests");
+}
+
+fn pairs<'r>(rows: impl IntoIterator<Item = &'r super::ClosureRowDto>) -> Vec<(Uuid, Uuid)> {
+    let mut pairs: Vec<_> = rows
+        .into_iter()
+        .map(|row| (row.block_ancestor_id, row.block_descendant_id))
+        .collect();
+    pairs.sort();
+    pairs
+}
+
 pub async fn assert_create_paths_for_link_transitive<'e, A, H, R, DB>(
     helper: &H,
     block_repo: &R,
@@ -120,3 +153,210 @@ where
 
     Ok(())
 }
+
+pub async fn assert_check_integrity_and_rebuild<'e, A, H, R, L, DB>(
+    helper: &H,
+    block_repo: &R,
+    link_repo: &L,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    H: BlockDirectionalPathHelper<DB>,
+    R: BlockRepository<DB>,
+    L: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'e, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    // The check covers the whole table, so start from a known-good one
+    helper.rebuild(&mut *tx).await?;
+
+    let a = seed_block(block_repo, "a", &mut *tx).await;
+    let b = seed_block(block_repo, "b", &mut *tx).await;
+    let c = seed_block(block_repo, "c", &mut *tx).await;
+    let d = seed_block(block_repo, "d", &mut *tx).await;
+
+    seed_link(link_repo, &a, &b, &mut *tx).await;
+    seed_link(link_repo, &b, &c, &mut *tx).await;
+
+    assert!(helper.check_integrity(&mut *tx).await?.is_consistent());
+
+    // Forget a link that still exists and remember one that never did
+    helper.delete_paths_using_link(a.id, b.id, &mut *tx).await?;
+    helper.create_paths_for_link(c.id, d.id, &mut *tx).await?;
+
+    let report = helper.check_integrity(&mut *tx).await?;
+    assert_eq!(pairs(&report.missing), {
+        let mut expected = vec![(a.id, b.id), (a.id, c.id)];
+        expected.sort();
+        expected
+    });
+    assert_eq!(pairs(&report.extra), {
+        let mut expected = vec![(b.id, d.id), (c.id, d.id)];
+        expected.sort();
+        expected
+    });
+    assert!(report.mismatched.is_empty());
+    assert!(report.cyclic_block_ids.is_empty());
+
+    let repaired = helper.rebuild(&mut *tx).await?;
+    assert_eq!(repaired, report);
+    assert!(helper.check_integrity(&mut *tx).await?.is_consistent());
+    assert!(helper.is_ancestor_descendant(a.id, c.id, &mut *tx).await?);
+    assert!(!helper.is_ancestor_descendant(c.id, d.id, &mut *tx).await?);
+
+    // Counting the same link twice leaves the right pairs with wrong counts
+    helper.create_paths_for_link(b.id, c.id, &mut *tx).await?;
+
+    let report = helper.check_integrity(&mut *tx).await?;
+    assert!(report.missing.is_empty());
+    assert!(report.extra.is_empty());
+    assert_eq!(
+        pairs(report.mismatched.iter().map(|mismatch| &mismatch.stored)),
+        {
+            let mut expected = vec![(a.id, c.id), (b.id, c.id)];
+            expected.sort();
+            expected
+        }
+    );
+    for mismatch in &report.mismatched {
+        assert_eq!(mismatch.stored.path_count, 2);
+        assert_eq!(mismatch.expected.path_count, 1);
+    }
+
+    helper.rebuild(&mut *tx).await?;
+    assert!(helper.check_integrity(&mut *tx).await?.is_consistent());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_path_counts_saturate<'e, A, H, R, L, DB>(
+    helper: &H,
+    block_repo: &R,
+    link_repo: &L,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    H: BlockDirectionalPathHelper<DB>,
+    R: BlockRepository<DB>,
+    L: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'e, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    helper.rebuild(&mut *tx).await?;
+
+    // Every diamond doubles the paths from the top, so 64 of them overflow i64
+    let top = seed_block(block_repo, "top", &mut *tx).await;
+    let mut tip = top.clone();
+    let mut first_diamond = Vec::new();
+    for i in 0..64 {
+        let left = seed_block(block_repo, &format!("left {i}"), &mut *tx).await;
+        let right = seed_block(block_repo, &format!("right {i}"), &mut *tx).await;
+        let bottom = seed_block(block_repo, &format!("bottom {i}"), &mut *tx).await;
+        for (from, to) in [
+            (&tip, &left),
+            (&tip, &right),
+            (&left, &bottom),
+            (&right, &bottom),
+        ] {
+            seed_link(link_repo, from, to, &mut *tx).await;
+        }
+        if i == 0 {
+            first_diamond = vec![left, right];
+        }
+        tip = bottom;
+    }
+
+    assert!(helper.check_integrity(&mut *tx).await?.is_consistent());
+    assert!(
+        helper
+            .is_ancestor_descendant(top.id, tip.id, &mut *tx)
+            .await?
+    );
+
+    // The top still has 2^63 paths to the last tip, but only 2^62 to the one
+    // above it, which must come back as an exact count
+    link_repo
+        .delete_by_block_ids(top.id, first_diamond[0].id, &mut *tx)
+        .await
+        .expect("failed to delete link");
+
+    assert!(helper.check_integrity(&mut *tx).await?.is_consistent());
+    assert!(
+        helper
+            .is_ancestor_descendant(top.id, tip.id, &mut *tx)
+            .await?
+    );
+
+    block_repo
+        .trash(first_diamond[1].id, &mut *tx)
+        .await
+        .expect("failed to trash block");
+
+    assert!(helper.check_integrity(&mut *tx).await?.is_consistent());
+    assert!(
+        !helper
+            .is_ancestor_descendant(top.id, tip.id, &mut *tx)
+            .await?
+    );
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_check_integrity_reports_cycles<'e, A, H, R, L, DB>(
+    helper: &H,
+    block_repo: &R,
+    link_repo: &L,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    H: BlockDirectionalPathHelper<DB>,
+    R: BlockRepository<DB>,
+    L: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'e, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let a = seed_block(block_repo, "a", &mut *tx).await;
+    let b = seed_block(block_repo, "b", &mut *tx).await;
+    let c = seed_block(block_repo, "c", &mut *tx).await;
+    let root = seed_block(block_repo, "root", &mut *tx).await;
+
+    seed_link(link_repo, &root, &a, &mut *tx).await;
+    seed_link(link_repo, &a, &b, &mut *tx).await;
+    seed_link(link_repo, &b, &c, &mut *tx).await;
+
+    // Once the closure forgets a -> b, cycle detection lets c -> a through
+    helper.delete_paths_using_link(a.id, b.id, &mut *tx).await?;
+    seed_link(link_repo, &c, &a, &mut *tx).await;
+
+    let report = helper.check_integrity(&mut *tx).await?;
+    let mut expected = vec![a.id, b.id, c.id];
+    expected.sort();
+    assert_eq!(report.cyclic_block_ids, expected);
+    assert!(!report.is_consistent());
+
+    let result = helper.rebuild(&mut *tx).await;
+    assert!(matches!(
+        result,
+        Err(BlockDirectionalPathHelperError::CyclicLinks { block_ids }) if block_ids == expected
+    ));
+
+    tx.rollback().await?;
+
+    Ok(())
+}
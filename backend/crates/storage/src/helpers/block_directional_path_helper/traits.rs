@@ -2,8 +2,13 @@ use async_trait::async_trait;
 use sqlx::{Acquire, Database, Executor};
 use uuid::Uuid;
 
+use super::dtos::PathIntegrityReportDto;
 use super::error::BlockDirectionalPathHelperResult as Result;
 
+/// Maintains `block_directional_closure`, which holds one row per reachable
+/// (ancestor, descendant) pair with the number of paths between them and the
+/// length of the shortest one. Removals read the link table to find the
+/// remaining shortest routes, so they must run before the link rows go away.
 #[async_trait]
 pub trait BlockDirectionalPathHelper<DB: Database>: Send + Sync {
     async fn is_ancestor_descendant<'e, E>(
@@ -15,6 +20,8 @@ pub trait BlockDirectionalPathHelper<DB: Database>: Send + Sync {
     where
         E: Executor<'e, Database = DB>;
 
+    /// Adds every path through the link. Fails with `CyclicPathCreation` if
+    /// `to_id` already reaches `from_id`.
     async fn create_paths_for_link<'e, E>(
         &self,
         from_id: Uuid,
@@ -24,6 +31,8 @@ pub trait BlockDirectionalPathHelper<DB: Database>: Send + Sync {
     where
         E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
 
+    /// Removes every path through the link. Does nothing if the link is
+    /// dormant because one of its blocks is in the trash.
     async fn delete_paths_using_link<'e, E>(
         &self,
         from_id: Uuid,
@@ -31,9 +40,23 @@ pub trait BlockDirectionalPathHelper<DB: Database>: Send + Sync {
         executor: E,
     ) -> Result<()>
     where
-        E: Executor<'e, Database = DB>;
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
 
+    /// Removes every path that starts at, ends at or passes through the block.
     async fn delete_paths_using_block<'e, E>(&self, block_id: Uuid, executor: E) -> Result<()>
     where
-        E: Executor<'e, Database = DB>;
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
+    /// Recomputes the closure from the links between live blocks and reports
+    /// how the stored rows differ from it. Changes nothing.
+    async fn check_integrity<'e, E>(&self, executor: E) -> Result<PathIntegrityReportDto>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
+    /// Replaces every stored row with the closure recomputed from the links
+    /// between live blocks, and returns what was wrong beforehand. Fails with
+    /// `CyclicLinks` and leaves the table alone if those links form a cycle.
+    async fn rebuild<'e, E>(&self, executor: E) -> Result<PathIntegrityReportDto>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
 }
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct DagBlockDto {
+    pub id: Uuid,
+    pub title: String,
+    pub depth: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Paths between two blocks, shortest first. `truncated` is set when more
+/// paths exist than the requested limit.
+#[derive(Clone, Debug, Default)]
+pub struct BlockPathsDto {
+    pub paths: Vec<Vec<Uuid>>,
+    pub truncated: bool,
+}
+
+impl BlockPathsDto {
+    pub const DEFAULT_LIMIT: usize = 100;
+}
@@ -1,7 +1,12 @@
-mod dto;
+mod dtos;
 mod error;
+mod paths;
 mod traits;
 
-pub use dto::*;
+pub use dtos::*;
 pub use error::{BlockDagQueryServiceError, BlockDagQueryServiceResult};
+pub use paths::enumerate_paths;
 pub use traits::BlockDagQueryService;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
@@ -0,0 +1,133 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use uuid::Uuid;
+
+use super::dtos::BlockPathsDto;
+
+/// Walks the routes from `ancestor_id` to `descendant_id` over `links`,
+/// shortest first, stopping after `limit` paths.
+///
+/// Partial paths are expanded in order of the shortest route they can still
+/// complete, so the work done is bounded by `limit` rather than by the total
+/// number of paths, which grows exponentially on diamond-shaped DAGs.
+pub fn enumerate_paths(
+    ancestor_id: Uuid,
+    descendant_id: Uuid,
+    links: impl IntoIterator<Item = (Uuid, Uuid)>,
+    limit: usize,
+) -> BlockPathsDto {
+    let mut children: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    let mut parents: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (block_from_id, block_to_id) in links {
+        children.entry(block_from_id).or_default().push(block_to_id);
+        parents.entry(block_to_id).or_default().push(block_from_id);
+    }
+
+    // Links remaining to `descendant_id` from every block that can reach it
+    let mut remaining = HashMap::from([(descendant_id, 0usize)]);
+    let mut queue = VecDeque::from([descendant_id]);
+    while let Some(block_id) = queue.pop_front() {
+        let distance = remaining[&block_id] + 1;
+        for parent_id in parents.get(&block_id).into_iter().flatten() {
+            if !remaining.contains_key(parent_id) {
+                remaining.insert(*parent_id, distance);
+                queue.push_back(*parent_id);
+            }
+        }
+    }
+
+    let mut result = BlockPathsDto::default();
+    let Some(&shortest) = remaining.get(&ancestor_id) else {
+        return result;
+    };
+    if ancestor_id == descendant_id {
+        return result;
+    }
+
+    // Among routes of equal length the deepest partial path goes first, so
+    // complete paths are reached without expanding whole levels of the DAG.
+    // Remaining ties keep link position order.
+    let mut sequence = 0usize;
+    let mut pending =
+        BinaryHeap::from([Reverse((shortest, Reverse(1), sequence, vec![ancestor_id]))]);
+    while let Some(Reverse((_, _, _, path))) = pending.pop() {
+        let last = path[path.len() - 1];
+        if last == descendant_id {
+            if result.paths.len() == limit {
+                result.truncated = true;
+                break;
+            }
+            result.paths.push(path);
+            continue;
+        }
+        for child_id in children.get(&last).into_iter().flatten() {
+            let Some(distance) = remaining.get(child_id) else {
+                continue;
+            };
+            let mut extended = path.clone();
+            extended.push(*child_id);
+            sequence += 1;
+            let length = extended.len() - 1 + distance;
+            pending.push(Reverse((
+                length,
+                Reverse(extended.len()),
+                sequence,
+                extended,
+            )));
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A chain of `diamonds` diamonds has 2^diamonds paths end to end.
+    fn diamond_chain(diamonds: usize) -> (Uuid, Uuid, Vec<(Uuid, Uuid)>) {
+        let start = Uuid::new_v4();
+        let mut links = Vec::new();
+        let mut top = start;
+        for _ in 0..diamonds {
+            let (left, right, bottom) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+            links.extend([(top, left), (top, right), (left, bottom), (right, bottom)]);
+            top = bottom;
+        }
+        (start, top, links)
+    }
+
+    #[test]
+    fn stops_at_limit_on_diamond_chains() {
+        let (start, end, links) = diamond_chain(40);
+
+        let result = enumerate_paths(start, end, links, 5);
+
+        assert_eq!(result.paths.len(), 5);
+        assert!(result.truncated);
+        assert!(result.paths.iter().all(|path| path.len() == 81));
+    }
+
+    #[test]
+    fn returns_shortest_paths_first() {
+        let (start, end, mut links) = diamond_chain(2);
+        links.push((start, end));
+
+        let result = enumerate_paths(start, end, links, 10);
+
+        assert_eq!(result.paths.len(), 5);
+        assert!(!result.truncated);
+        assert_eq!(result.paths[0], vec![start, end]);
+    }
+
+    #[test]
+    fn ignores_links_that_do_not_lead_to_the_descendant() {
+        let (start, end) = (Uuid::new_v4(), Uuid::new_v4());
+        let links = [(start, Uuid::new_v4()), (start, end)];
+
+        let result = enumerate_paths(start, end, links, 10);
+
+        assert_eq!(result.paths, vec![vec![start, end]]);
+    }
+}
@@ -0,0 +1,394 @@
+use sqlx::{Acquire, Database, Executor};
+use uuid::Uuid;
+
+use domain::blocks::Block;
+
+use super::{BlockDagQueryServiceResult as Result, BlockPathsDto};
+use crate::query_services::BlockDagQueryService;
+use crate::repositories::{
+    BlockDirectionalLinkRepository, BlockRepository,
+    block_directional_link_repository::CreateBlockDirectionalLinkDto,
+};
+
+/// root -> left -> bottom -> leaf
+/// root -> right -> bottom
+struct SeededDag {
+    root_id: Uuid,
+    left_id: Uuid,
+    right_id: Uuid,
+    bottom_id: Uuid,
+    leaf_id: Uuid,
+}
+
+async fn seed_dag<'a, A, BR, DR, DB>(block_repo: &BR, directional_repo: &DR, conn: A) -> SeededDag
+where
+    DB: Database,
+    BR: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let root = Block::new("root", "content");
+    let left = Block::new("left", "content");
+    let right = Block::new("right", "content");
+    let bottom = Block::new("bottom", "content");
+    let leaf = Block::new("leaf", "content");
+
+    let mut conn = conn.acquire().await.expect("Failed to get connection");
+
+    for block in [&root, &left, &right, &bottom, &leaf] {
+        block_repo
+            .save(block, &mut *conn)
+            .await
+            .expect("failed to seed dag block");
+    }
+
+    let edges = [
+        (root.id, left.id),
+        (root.id, right.id),
+        (left.id, bottom.id),
+        (right.id, bottom.id),
+        (bottom.id, leaf.id),
+    ];
+
+    for (block_from_id, block_to_id) in edges {
+        directional_repo
+            .create(
+                &CreateBlockDirectionalLinkDto {
+                    id: Uuid::new_v4(),
+                    block_from_id,
+                    block_to_id,
+                    kind: None,
+                    label: None,
+                    weight: None,
+                },
+                &mut *conn,
+            )
+            .await
+            .expect("failed to seed dag link");
+    }
+
+    SeededDag {
+        root_id: root.id,
+        left_id: left.id,
+        right_id: right.id,
+        bottom_id: bottom.id,
+        leaf_id: leaf.id,
+    }
+}
+
+pub async fn assert_get_descendants<'a, A, Q, BR, DR, DB>(
+    query_service: &Q,
+    block_repo: &BR,
+    directional_repo: &DR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockDagQueryService<DB>,
+    BR: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let seeded = seed_dag(block_repo, directional_repo, &mut tx).await;
+    let descendants = query_service
+        .get_descendants(seeded.root_id, &mut *tx)
+        .await?;
+
+    assert_eq!(descendants.len(), 4);
+    let depth_of = |id: Uuid| descendants.iter().find(|b| b.id == id).map(|b| b.depth);
+    assert_eq!(depth_of(seeded.left_id), Some(1));
+    assert_eq!(depth_of(seeded.right_id), Some(1));
+    assert_eq!(depth_of(seeded.bottom_id), Some(2));
+    assert_eq!(depth_of(seeded.leaf_id), Some(3));
+    assert!(descendants.windows(2).all(|w| w[0].depth <= w[1].depth));
+
+    let descendants = query_service
+        .get_descendants(seeded.leaf_id, &mut *tx)
+        .await?;
+    assert!(descendants.is_empty());
+
+    tx.rollback().await?;
+    Ok(())
+}
+
+pub async fn assert_get_ancestors<'a, A, Q, BR, DR, DB>(
+    query_service: &Q,
+    block_repo: &BR,
+    directional_repo: &DR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockDagQueryService<DB>,
+    BR: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let seeded = seed_dag(block_repo, directional_repo, &mut tx).await;
+    let ancestors = query_service
+        .get_ancestors(seeded.leaf_id, &mut *tx)
+        .await?;
+
+    assert_eq!(ancestors.len(), 4);
+    assert_eq!(ancestors[0].id, seeded.bottom_id);
+    assert_eq!(ancestors[0].depth, 1);
+    let root = ancestors
+        .iter()
+        .find(|b| b.id == seeded.root_id)
+        .expect("root should be an ancestor of leaf");
+    assert_eq!(root.depth, 3);
+
+    tx.rollback().await?;
+    Ok(())
+}
+
+pub async fn assert_get_roots_and_leaves<'a, A, Q, BR, DR, DB>(
+    query_service: &Q,
+    block_repo: &BR,
+    directional_repo: &DR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockDagQueryService<DB>,
+    BR: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let seeded = seed_dag(block_repo, directional_repo, &mut tx).await;
+
+    let roots = query_service.get_roots(seeded.bottom_id, &mut *tx).await?;
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0].id, seeded.root_id);
+
+    let leaves = query_service.get_leaves(seeded.root_id, &mut *tx).await?;
+    assert_eq!(leaves.len(), 1);
+    assert_eq!(leaves[0].id, seeded.leaf_id);
+
+    assert!(
+        query_service
+            .get_roots(seeded.root_id, &mut *tx)
+            .await?
+            .is_empty()
+    );
+    assert!(
+        query_service
+            .get_leaves(seeded.leaf_id, &mut *tx)
+            .await?
+            .is_empty()
+    );
+
+    tx.rollback().await?;
+    Ok(())
+}
+
+pub async fn assert_get_paths_between<'a, A, Q, BR, DR, DB>(
+    query_service: &Q,
+    block_repo: &BR,
+    directional_repo: &DR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockDagQueryService<DB>,
+    BR: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let seeded = seed_dag(block_repo, directional_repo, &mut tx).await;
+
+    let paths = query_service
+        .get_paths_between(
+            seeded.root_id,
+            seeded.leaf_id,
+            BlockPathsDto::DEFAULT_LIMIT,
+            &mut *tx,
+        )
+        .await?
+        .paths;
+
+    assert_eq!(paths.len(), 2);
+    assert!(paths.contains(&vec![
+        seeded.root_id,
+        seeded.left_id,
+        seeded.bottom_id,
+        seeded.leaf_id
+    ]));
+    assert!(paths.contains(&vec![
+        seeded.root_id,
+        seeded.right_id,
+        seeded.bottom_id,
+        seeded.leaf_id
+    ]));
+
+    let reversed = query_service
+        .get_paths_between(
+            seeded.leaf_id,
+            seeded.root_id,
+            BlockPathsDto::DEFAULT_LIMIT,
+            &mut *tx,
+        )
+        .await?
+        .paths;
+    assert!(reversed.is_empty());
+
+    let first = query_service
+        .get_paths_between(seeded.root_id, seeded.leaf_id, 1, &mut *tx)
+        .await?;
+    assert_eq!(first.paths.len(), 1);
+    assert!(first.truncated);
+
+    let exact = query_service
+        .get_paths_between(seeded.root_id, seeded.leaf_id, 2, &mut *tx)
+        .await?;
+    assert_eq!(exact.paths.len(), 2);
+    assert!(!exact.truncated);
+
+    tx.rollback().await?;
+    Ok(())
+}
+
+async fn link<DR, DB>(directional_repo: &DR, from: Uuid, to: Uuid, conn: &mut DB::Connection)
+where
+    DB: Database,
+    DR: BlockDirectionalLinkRepository<DB>,
+    for<'x> &'x mut DB::Connection: Executor<'x, Database = DB> + Acquire<'x, Database = DB>,
+{
+    directional_repo
+        .create(
+            &CreateBlockDirectionalLinkDto {
+                id: Uuid::new_v4(),
+                block_from_id: from,
+                block_to_id: to,
+                kind: None,
+                label: None,
+                weight: None,
+            },
+            conn,
+        )
+        .await
+        .expect("failed to create link");
+}
+
+pub async fn assert_closure_follows_link_changes<'a, A, Q, BR, DR, DB>(
+    query_service: &Q,
+    block_repo: &BR,
+    directional_repo: &DR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockDagQueryService<DB>,
+    BR: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let seeded = seed_dag(block_repo, directional_repo, &mut tx).await;
+    let (root, left, right, bottom, leaf) = (
+        seeded.root_id,
+        seeded.left_id,
+        seeded.right_id,
+        seeded.bottom_id,
+        seeded.leaf_id,
+    );
+
+    // A shortcut adds a path and becomes the shortest route
+    link(directional_repo, root, leaf, &mut tx).await;
+    let paths = query_service
+        .get_paths_between(root, leaf, BlockPathsDto::DEFAULT_LIMIT, &mut *tx)
+        .await?
+        .paths;
+    assert_eq!(paths.len(), 3);
+    assert_eq!(paths[0], vec![root, leaf]);
+    let descendants = query_service.get_descendants(root, &mut *tx).await?;
+    let depth_of = |id: Uuid| descendants.iter().find(|b| b.id == id).map(|b| b.depth);
+    assert_eq!(depth_of(leaf), Some(1));
+
+    // Removing it falls back to the longer routes
+    directional_repo
+        .delete_by_block_ids(root, leaf, &mut *tx)
+        .await
+        .expect("failed to delete link");
+    let paths = query_service
+        .get_paths_between(root, leaf, BlockPathsDto::DEFAULT_LIMIT, &mut *tx)
+        .await?
+        .paths;
+    assert_eq!(paths.len(), 2);
+    let descendants = query_service.get_descendants(root, &mut *tx).await?;
+    let depth_of = |id: Uuid| descendants.iter().find(|b| b.id == id).map(|b| b.depth);
+    assert_eq!(depth_of(leaf), Some(3));
+
+    // Trashing a block on the shortest route drops only the paths through it
+    link(directional_repo, right, leaf, &mut tx).await;
+    block_repo
+        .trash(right, &mut *tx)
+        .await
+        .expect("failed to trash block");
+    let paths = query_service
+        .get_paths_between(root, leaf, BlockPathsDto::DEFAULT_LIMIT, &mut *tx)
+        .await?
+        .paths;
+    assert_eq!(paths, vec![vec![root, left, bottom, leaf]]);
+    let descendants = query_service.get_descendants(root, &mut *tx).await?;
+    let depth_of = |id: Uuid| descendants.iter().find(|b| b.id == id).map(|b| b.depth);
+    assert_eq!(descendants.len(), 3);
+    assert_eq!(depth_of(bottom), Some(2));
+    assert_eq!(depth_of(leaf), Some(3));
+
+    block_repo
+        .restore(right, &mut *tx)
+        .await
+        .expect("failed to restore block");
+    let paths = query_service
+        .get_paths_between(root, leaf, BlockPathsDto::DEFAULT_LIMIT, &mut *tx)
+        .await?
+        .paths;
+    assert_eq!(paths.len(), 3);
+    assert_eq!(paths[0], vec![root, right, leaf]);
+
+    // Losing one of two routes keeps the block reachable
+    directional_repo
+        .delete_by_block_ids(left, bottom, &mut *tx)
+        .await
+        .expect("failed to delete link");
+    assert!(
+        query_service
+            .get_paths_between(left, leaf, BlockPathsDto::DEFAULT_LIMIT, &mut *tx)
+            .await?
+            .paths
+            .is_empty()
+    );
+    let paths = query_service
+        .get_paths_between(root, leaf, BlockPathsDto::DEFAULT_LIMIT, &mut *tx)
+        .await?
+        .paths;
+    assert_eq!(paths.len(), 2);
+    let descendants = query_service.get_descendants(root, &mut *tx).await?;
+    let depth_of = |id: Uuid| descendants.iter().find(|b| b.id == id).map(|b| b.depth);
+    assert_eq!(depth_of(bottom), Some(2));
+    assert_eq!(depth_of(leaf), Some(2));
+
+    tx.rollback().await?;
+    Ok(())
+}
@@ -1,38 +1,46 @@
-use std::collections::HashSet;
-
 use async_trait::async_trait;
+use sqlx::{Database, Executor};
 use uuid::Uuid;
 
-use super::dto::BlockSummaryDto;
+use super::dtos::{BlockPathsDto, DagBlockDto};
 use super::error::BlockDagQueryServiceResult as Result;
 
+/// Read-only queries over the directional link DAG, answered from the
+/// precomputed `block_directional_closure` table. Paths between two blocks are
+/// enumerated on demand from the links.
 #[async_trait]
-pub trait BlockDagQueryService: Send + Sync {
-    // node queries
-    async fn is_ancestor_descendant(&self, ancestor_id: Uuid, descendant_id: Uuid) -> Result<bool>;
-    async fn get_descendants_ids(&self, block_id: Uuid) -> Result<Vec<Uuid>>;
-    async fn get_ancestors_ids(&self, block_id: Uuid) -> Result<Vec<Uuid>>;
-    async fn get_root_ids(&self) -> Result<Vec<Uuid>>;
-    async fn get_leaf_ids(&self) -> Result<Vec<Uuid>>;
+pub trait BlockDagQueryService<DB: Database>: Send + Sync {
+    /// Returns every block reachable from `block_id`, nearest first.
+    /// `depth` is the length of the shortest path in links.
+    async fn get_descendants<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<DagBlockDto>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Returns every block that can reach `block_id`, nearest first.
+    async fn get_ancestors<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<DagBlockDto>>
+    where
+        E: Executor<'e, Database = DB>;
 
-    async fn get_descendants(&self, block_id: Uuid) -> Result<BlockSummaryDto>;
-    async fn get_ancestors(&self, block_id: Uuid) -> Result<BlockSummaryDto>;
-    async fn get_roots(&self, block_id: Uuid) -> Result<BlockSummaryDto>;
-    async fn get_leaves(&self, block_id: Uuid) -> Result<BlockSummaryDto>;
+    /// Returns the ancestors of `block_id` that have no parents themselves.
+    async fn get_roots<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<DagBlockDto>>
+    where
+        E: Executor<'e, Database = DB>;
 
-    // path queries
-    async fn get_paths_from_id(&self, ancestor_id: Uuid) -> Result<Vec<Vec<Uuid>>>;
-    async fn get_paths_to_id(&self, descendant_id: Uuid) -> Result<Vec<Vec<Uuid>>>;
+    /// Returns the descendants of `block_id` that have no children themselves.
+    async fn get_leaves<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<DagBlockDto>>
+    where
+        E: Executor<'e, Database = DB>;
 
-    async fn get_paths_between_ids(
+    /// Returns up to `limit` paths from `ancestor_id` to `descendant_id`,
+    /// shortest first, and whether more exist. Each path lists block ids and
+    /// includes both endpoints.
+    async fn get_paths_between<'e, E>(
         &self,
         ancestor_id: Uuid,
         descendant_id: Uuid,
-    ) -> Result<Vec<Vec<Uuid>>>;
-
-    async fn get_paths_between_id_sets(
-        &self,
-        ancestor_ids: HashSet<Uuid>,
-        descendant_ids: HashSet<Uuid>,
-    ) -> Result<Vec<Vec<Uuid>>>;
+        limit: usize,
+        executor: E,
+    ) -> Result<BlockPathsDto>
+    where
+        E: Executor<'e, Database = DB>;
 }
@@ -6,6 +6,10 @@ pub struct LinkedBlockDto {
     pub link_id: Uuid,
     pub block_id: Uuid,
     pub title: String,
+    /// Always `None` for related links, which have no direction to qualify
+    pub kind: Option<String>,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -19,12 +23,21 @@ pub struct AllLinkedBlocksDto {
 
 #[derive(sqlx::FromRow, Clone, Debug)]
 pub struct DirectionalLinkExportDto {
+    pub id: Uuid,
     pub block_from_id: Uuid,
     pub block_to_id: Uuid,
+    pub kind: Option<String>,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
+    pub created_at: DateTime<Utc>,
 }
 
 #[derive(sqlx::FromRow, Clone, Debug)]
 pub struct RelatedLinkExportDto {
+    pub id: Uuid,
     pub block_a_id: Uuid,
     pub block_b_id: Uuid,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
+    pub created_at: DateTime<Utc>,
 }
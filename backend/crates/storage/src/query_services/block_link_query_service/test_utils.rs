@@ -77,6 +77,9 @@ where
                 id: Uuid::new_v4(),
                 block_from_id: parent_one.id,
                 block_to_id: target.id,
+                kind: Some("supports".to_string()),
+                label: Some("main premise".to_string()),
+                weight: Some(1.5),
             },
             &mut *conn,
         )
@@ -88,6 +91,9 @@ where
                 id: Uuid::new_v4(),
                 block_from_id: parent_two.id,
                 block_to_id: target.id,
+                kind: None,
+                label: None,
+                weight: None,
             },
             &mut *conn,
         )
@@ -100,6 +106,9 @@ where
                 id: Uuid::new_v4(),
                 block_from_id: target.id,
                 block_to_id: child_one.id,
+                kind: None,
+                label: None,
+                weight: None,
             },
             &mut *conn,
         )
@@ -111,6 +120,9 @@ where
                 id: Uuid::new_v4(),
                 block_from_id: target.id,
                 block_to_id: child_two.id,
+                kind: None,
+                label: None,
+                weight: None,
             },
             &mut *conn,
         )
@@ -123,6 +135,8 @@ where
                 id: Uuid::new_v4(),
                 block_a_id: target.id,
                 block_b_id: related_one.id,
+                label: Some("same source".to_string()),
+                weight: Some(0.5),
             },
             &mut *conn,
         )
@@ -134,6 +148,8 @@ where
                 id: Uuid::new_v4(),
                 block_a_id: target.id,
                 block_b_id: related_two.id,
+                label: None,
+                weight: None,
             },
             &mut *conn,
         )
@@ -177,6 +193,19 @@ where
         assert!(parents.iter().any(|block| block.block_id == parent_id));
     }
 
+    let annotated = parents
+        .iter()
+        .find(|block| block.block_id == seeded.parent_ids[0])
+        .expect("annotated parent should be returned");
+    assert_eq!(annotated.kind.as_deref(), Some("supports"));
+    assert_eq!(annotated.label.as_deref(), Some("main premise"));
+    assert_eq!(annotated.weight, Some(1.5));
+    let plain = parents
+        .iter()
+        .find(|block| block.block_id == seeded.parent_ids[1])
+        .expect("plain parent should be returned");
+    assert!(plain.kind.is_none() && plain.label.is_none() && plain.weight.is_none());
+
     tx.rollback().await?;
     Ok(())
 }
@@ -205,10 +234,31 @@ where
         .get_child_blocks(seeded.target_id, &mut *tx)
         .await?;
 
-    assert_eq!(children.len(), 2);
-    for child_id in seeded.child_ids {
-        assert!(children.iter().any(|block| block.block_id == child_id));
-    }
+    // Children come back in insertion order until reordered
+    let ids: Vec<Uuid> = children.iter().map(|block| block.block_id).collect();
+    assert_eq!(ids, seeded.child_ids.to_vec());
+
+    let reversed = [seeded.child_ids[1], seeded.child_ids[0]];
+    directional_repo
+        .reorder_children(seeded.target_id, &reversed, &mut *tx)
+        .await
+        .expect("failed to reorder children");
+
+    let children = query_service
+        .get_child_blocks(seeded.target_id, &mut *tx)
+        .await?;
+    let ids: Vec<Uuid> = children.iter().map(|block| block.block_id).collect();
+    assert_eq!(ids, reversed.to_vec());
+
+    let linked = query_service
+        .get_linked_blocks(seeded.target_id, &mut *tx)
+        .await?;
+    let ids: Vec<Uuid> = linked
+        .child_blocks
+        .iter()
+        .map(|block| block.block_id)
+        .collect();
+    assert_eq!(ids, reversed.to_vec());
 
     tx.rollback().await?;
     Ok(())
@@ -243,6 +293,14 @@ where
         assert!(related.iter().any(|block| block.block_id == related_id));
     }
 
+    let annotated = related
+        .iter()
+        .find(|block| block.block_id == seeded.related_ids[0])
+        .expect("annotated related block should be returned");
+    assert!(annotated.kind.is_none());
+    assert_eq!(annotated.label.as_deref(), Some("same source"));
+    assert_eq!(annotated.weight, Some(0.5));
+
     tx.rollback().await?;
     Ok(())
 }
@@ -300,6 +358,20 @@ where
         );
     }
 
+    let annotated = linked
+        .parent_blocks
+        .iter()
+        .find(|block| block.block_id == seeded.parent_ids[0])
+        .expect("annotated parent should be returned");
+    assert_eq!(annotated.kind.as_deref(), Some("supports"));
+    let annotated = linked
+        .related_blocks
+        .iter()
+        .find(|block| block.block_id == seeded.related_ids[0])
+        .expect("annotated related block should be returned");
+    assert!(annotated.kind.is_none());
+    assert_eq!(annotated.label.as_deref(), Some("same source"));
+
     tx.rollback().await?;
     Ok(())
 }
@@ -340,6 +412,10 @@ where
             .any(|l| l.block_from_id == seeded.target_id
                 && seeded.child_ids.contains(&l.block_to_id))
     );
+    assert!(links.iter().any(|l| l.block_from_id == seeded.parent_ids[0]
+        && l.kind.as_deref() == Some("supports")
+        && l.label.as_deref() == Some("main premise")
+        && l.weight == Some(1.5)));
 
     tx.rollback().await?;
     Ok(())
@@ -375,7 +451,78 @@ where
                 || (l.block_b_id == seeded.target_id && l.block_a_id == related_id)
         }));
     }
+    assert!(
+        links
+            .iter()
+            .any(|l| l.label.as_deref() == Some("same source") && l.weight == Some(0.5))
+    );
 
     tx.rollback().await?;
     Ok(())
 }
+
+pub async fn assert_linked_blocks_skip_trashed<'a, A, Q, BR, DR, RR, DB>(
+    query_service: &Q,
+    block_repo: &BR,
+    directional_repo: &DR,
+    related_repo: &RR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockLinkQueryService<DB>,
+    BR: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    RR: BlockRelatedLinkRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let seeded = seed_link_graph(block_repo, directional_repo, related_repo, &mut tx).await;
+    let trashed_ids = [
+        seeded.parent_ids[0],
+        seeded.child_ids[0],
+        seeded.related_ids[0],
+    ];
+    for id in trashed_ids {
+        block_repo
+            .trash(id, &mut *tx)
+            .await
+            .expect("failed to trash block");
+    }
+
+    let linked = query_service
+        .get_linked_blocks(seeded.target_id, &mut *tx)
+        .await?;
+
+    assert_eq!(linked.parent_blocks.len(), 1);
+    assert_eq!(linked.child_blocks.len(), 1);
+    assert_eq!(linked.related_blocks.len(), 1);
+
+    let children = query_service
+        .get_child_blocks(seeded.target_id, &mut *tx)
+        .await?;
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].block_id, seeded.child_ids[1]);
+
+    let directional = query_service.get_all_directional(&mut *tx).await?;
+    let related = query_service.get_all_related(&mut *tx).await?;
+    for id in trashed_ids {
+        assert!(
+            !directional
+                .iter()
+                .any(|l| l.block_from_id == id || l.block_to_id == id)
+        );
+        assert!(
+            !related
+                .iter()
+                .any(|l| l.block_a_id == id || l.block_b_id == id)
+        );
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
@@ -17,6 +17,25 @@ pub struct BlockSummaryDto {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub opened_at: Option<DateTime<Utc>>,
+    pub is_pinned: bool,
+    /// HTML-escaped content excerpt around the match with hits wrapped in
+    /// `<mark>` tags.
+    /// `None` when the query was blank.
+    pub snippet: Option<String>,
+    /// Relevance score; lower scores rank first. `None` when the query was
+    /// blank.
+    pub rank: Option<f64>,
+}
+
+impl BlockSummaryDto {
+    /// Sort key of this result, for fetching the page that follows it.
+    pub fn position(&self) -> BlockSearchPosition {
+        BlockSearchPosition {
+            rank: self.rank,
+            updated_at: self.updated_at,
+            id: self.id,
+        }
+    }
 }
 
 #[derive(FromRow, Clone, Debug)]
@@ -27,3 +46,65 @@ pub struct BlockExportDto {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+/// Just enough of a block to tell whether an imported copy is newer.
+#[derive(FromRow, Clone, Debug)]
+pub struct BlockUpdatedAtDto {
+    pub id: Uuid,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Parameters for `BlockQueryService::search`. Date ranges include the lower
+/// bound and exclude the upper bound.
+#[derive(Clone, Debug)]
+pub struct BlockSearchDto {
+    pub query: String,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub updated_after: Option<DateTime<Utc>>,
+    pub updated_before: Option<DateTime<Utc>>,
+    /// Only return blocks reachable from this block through directional links
+    pub descendant_of: Option<Uuid>,
+    pub only_opened: bool,
+    pub only_pinned: bool,
+    /// Only return blocks carrying a tag with this name, ignoring case
+    pub tag: Option<String>,
+    pub limit: i64,
+    /// Only return results that sort after this one
+    pub after: Option<BlockSearchPosition>,
+}
+
+/// Where a result sorts: by `rank`, then most recently updated, then by id.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlockSearchPosition {
+    pub rank: Option<f64>,
+    pub updated_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl BlockSearchDto {
+    pub const DEFAULT_LIMIT: i64 = 50;
+
+    pub fn new(query: &str) -> Self {
+        Self {
+            query: query.to_string(),
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+            updated_before: None,
+            descendant_of: None,
+            only_opened: false,
+            only_pinned: false,
+            tag: None,
+            limit: Self::DEFAULT_LIMIT,
+            after: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TrashedBlockDto {
+    pub id: Uuid,
+    pub title: String,
+    pub trashed_at: DateTime<Utc>,
+}
@@ -1,9 +1,11 @@
 mod dtos;
 mod error;
+mod snippet;
 mod traits;
 
 pub use dtos::*;
 pub use error::*;
+pub use snippet::*;
 pub use traits::*;
 
 #[cfg(feature = "test-utils")]
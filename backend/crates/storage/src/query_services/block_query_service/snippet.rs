@@ -0,0 +1,39 @@
+/// Marks the start of a search hit in the raw snippet the database returns.
+/// Private-use characters are used instead of markup so the block content
+/// around them can be escaped first.
+pub const SNIPPET_MATCH_START: char = '\u{E000}';
+
+/// Marks the end of a search hit in the raw snippet.
+pub const SNIPPET_MATCH_END: char = '\u{E001}';
+
+/// HTML-escapes a raw snippet and wraps each hit in `<mark>` tags. Stray
+/// markers coming from the content itself never produce unbalanced tags.
+pub fn render_snippet(raw: &str) -> String {
+    let mut html = String::with_capacity(raw.len() + 16);
+    let mut in_mark = false;
+
+    for c in raw.chars() {
+        match c {
+            SNIPPET_MATCH_START if !in_mark => {
+                html.push_str("<mark>");
+                in_mark = true;
+            }
+            SNIPPET_MATCH_END if in_mark => {
+                html.push_str("</mark>");
+                in_mark = false;
+            }
+            SNIPPET_MATCH_START | SNIPPET_MATCH_END => {}
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+    if in_mark {
+        html.push_str("</mark>");
+    }
+
+    html
+}
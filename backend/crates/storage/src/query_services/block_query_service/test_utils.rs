@@ -1,12 +1,18 @@
 use chrono::{Duration, Utc};
+use futures_util::TryStreamExt;
 use sqlx::{Acquire, Database, Executor};
+use uuid::Uuid;
 
 use domain::blocks::Block;
+use domain::tags::Tag;
 use domain::workspaces::{OpenedBlock, Workspace};
 
-use super::BlockQueryServiceResult as Result;
+use super::{BlockQueryServiceResult as Result, BlockSearchDto, BlockSummaryDto};
 use crate::query_services::BlockQueryService;
-use crate::repositories::{BlockRepository, WorkspaceRepository};
+use crate::repositories::{
+    BlockDirectionalLinkRepository, BlockPinRepository, BlockRepository, TagRepository,
+    WorkspaceRepository, block_directional_link_repository::CreateBlockDirectionalLinkDto,
+};
 
 pub async fn assert_get_all_returns_all_blocks<'a, A, Q, R, DB>(
     query_service: &Q,
@@ -50,6 +56,59 @@ where
     Ok(())
 }
 
+pub async fn assert_stream_all_matches_get_all<'a, A, Q, R, DB>(
+    query_service: &Q,
+    block_repo: &R,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockQueryService<DB>,
+    R: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let block_a = Block::new("alpha", "content alpha");
+    let block_b = Block::new("beta", "content beta");
+    let trashed = Block::new("gamma", "content gamma");
+    for block in [&block_a, &block_b, &trashed] {
+        block_repo
+            .save(block, &mut *tx)
+            .await
+            .expect("failed to save block");
+    }
+    block_repo
+        .trash(trashed.id, &mut *tx)
+        .await
+        .expect("failed to trash block");
+
+    let all = query_service.get_all(&mut *tx).await?;
+    let streamed: Vec<_> = query_service.stream_all(&mut *tx).try_collect().await?;
+
+    let ids = |blocks: &[super::BlockExportDto]| blocks.iter().map(|b| b.id).collect::<Vec<_>>();
+    assert_eq!(ids(&streamed), ids(&all));
+    assert!(streamed.iter().any(|b| b.id == block_a.id));
+    assert!(streamed.iter().any(|b| b.id == block_b.id));
+    assert!(!streamed.iter().any(|b| b.id == trashed.id));
+
+    let mut updated_at: Vec<_> = query_service
+        .get_all_updated_at(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|b| (b.id, b.updated_at))
+        .collect();
+    let mut expected: Vec<_> = all.iter().map(|b| (b.id, b.updated_at)).collect();
+    updated_at.sort();
+    expected.sort();
+    assert_eq!(updated_at, expected);
+
+    tx.rollback().await?;
+    Ok(())
+}
+
 pub async fn assert_get_opened_orders_by_tab_index<'a, A, Q, BR, WR, DB>(
     query_service: &Q,
     block_repo: &BR,
@@ -113,7 +172,7 @@ where
     Ok(())
 }
 
-pub async fn assert_search_matches_title_or_content<'a, A, Q, R, DB>(
+pub async fn assert_search_ranks_title_above_content<'a, A, Q, R, DB>(
     query_service: &Q,
     block_repo: &R,
     conn: A,
@@ -153,11 +212,486 @@ where
         .await
         .expect("failed to save other block");
 
-    let results = query_service.search("find", &mut *tx).await?;
+    let results = query_service
+        .search(&BlockSearchDto::new("find"), &mut *tx)
+        .await?;
 
     assert_eq!(results.len(), 2);
-    assert_eq!(results[0].id, block_content.id);
-    assert_eq!(results[1].id, block_title.id);
+    assert_eq!(results[0].id, block_title.id);
+    assert_eq!(results[1].id, block_content.id);
+    let snippet = results[1]
+        .snippet
+        .as_deref()
+        .expect("content match should have a snippet");
+    assert!(snippet.contains("<mark>find</mark>"));
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_search_escapes_snippet<'a, A, Q, R, DB>(
+    query_service: &Q,
+    block_repo: &R,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockQueryService<DB>,
+    R: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let block = Block::new(
+        "untrusted",
+        "<img src=x onerror=alert(1)> Tom & \"Jerry\" needle <script>",
+    );
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to save block");
+
+    let results = query_service
+        .search(&BlockSearchDto::new("needle"), &mut *tx)
+        .await?;
+
+    assert_eq!(results.len(), 1);
+    let snippet = results[0]
+        .snippet
+        .as_deref()
+        .expect("content match should have a snippet");
+    assert!(snippet.contains("<mark>needle</mark>"));
+    assert!(snippet.contains("Tom &amp;"));
+    let without_marks = snippet.replace("<mark>", "").replace("</mark>", "");
+    assert!(!without_marks.contains('<'));
+    assert!(!without_marks.contains('>'));
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_search_reports_pinned<'a, A, Q, R, PR, DB>(
+    query_service: &Q,
+    block_repo: &R,
+    pin_repo: &PR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockQueryService<DB>,
+    R: BlockRepository<DB>,
+    PR: BlockPinRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let pinned = Block::new("pinned needle", "content");
+    let unpinned = Block::new("unpinned needle", "content");
+
+    block_repo
+        .save(&pinned, &mut *tx)
+        .await
+        .expect("failed to save pinned block");
+    block_repo
+        .save(&unpinned, &mut *tx)
+        .await
+        .expect("failed to save unpinned block");
+    pin_repo
+        .pin(pinned.id, &mut *tx)
+        .await
+        .expect("failed to pin block");
+
+    let results = query_service
+        .search(&BlockSearchDto::new("needle"), &mut *tx)
+        .await?;
+
+    assert_eq!(results.len(), 2);
+    for result in results {
+        assert_eq!(result.is_pinned, result.id == pinned.id);
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_search_filters_by_tag<'a, A, Q, R, TR, DB>(
+    query_service: &Q,
+    block_repo: &R,
+    tag_repo: &TR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockQueryService<DB>,
+    R: BlockRepository<DB>,
+    TR: TagRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let tagged = Block::new("tagged needle", "content");
+    let untagged = Block::new("untagged needle", "content");
+    for block in [&tagged, &untagged] {
+        block_repo
+            .save(block, &mut *tx)
+            .await
+            .expect("failed to save block");
+    }
+
+    let tag_name = format!("needle-tag-{}", Uuid::new_v4());
+    tag_repo
+        .tag_block(tagged.id, &Tag::new(&tag_name).unwrap(), &mut *tx)
+        .await
+        .expect("failed to tag block");
+
+    for query in ["needle", ""] {
+        let search = BlockSearchDto {
+            tag: Some(tag_name.to_uppercase()),
+            ..BlockSearchDto::new(query)
+        };
+        let ids: Vec<Uuid> = query_service
+            .search(&search, &mut *tx)
+            .await?
+            .iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, [tagged.id], "query {query:?} should match the tag");
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_search_blank_query_returns_recent<'a, A, Q, R, DB>(
+    query_service: &Q,
+    block_repo: &R,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockQueryService<DB>,
+    R: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let block = Block::new("recent", "content");
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to save block");
+
+    let results = query_service
+        .search(&BlockSearchDto::new("  "), &mut *tx)
+        .await?;
+
+    assert!(results.iter().any(|r| r.id == block.id));
+    assert!(results.iter().all(|r| r.snippet.is_none()));
+
+    let results = query_service
+        .search(&BlockSearchDto::new("\"*("), &mut *tx)
+        .await?;
+    assert!(results.iter().all(|r| r.snippet.is_none()));
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_search_applies_filters<'a, A, Q, R, DR, PR, WR, DB>(
+    query_service: &Q,
+    block_repo: &R,
+    directional_repo: &DR,
+    pin_repo: &PR,
+    workspace_repo: &WR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockQueryService<DB>,
+    R: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    PR: BlockPinRepository<DB>,
+    WR: WorkspaceRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let base_time = Utc::now() - Duration::days(10);
+
+    let mut parent = Block::new("filter parent", "filterneedle");
+    parent.created_at = base_time;
+
+    let mut child = Block::new("filter child", "filterneedle");
+    child.created_at = base_time + Duration::days(2);
+
+    let mut other = Block::new("filter other", "filterneedle");
+    other.created_at = base_time + Duration::days(4);
+
+    for block in [&parent, &child, &other] {
+        block_repo
+            .save(block, &mut *tx)
+            .await
+            .expect("failed to save block");
+    }
+    directional_repo
+        .create(
+            &CreateBlockDirectionalLinkDto {
+                id: Uuid::new_v4(),
+                block_from_id: parent.id,
+                block_to_id: child.id,
+                kind: None,
+                label: None,
+                weight: None,
+            },
+            &mut *tx,
+        )
+        .await
+        .expect("failed to link blocks");
+    pin_repo
+        .pin(other.id, &mut *tx)
+        .await
+        .expect("failed to pin block");
+    workspace_repo
+        .save(
+            &Workspace {
+                opened_blocks: vec![OpenedBlock {
+                    block_id: child.id,
+                    opened_at: Utc::now(),
+                    tab_index: 0,
+                }],
+            },
+            &mut *tx,
+        )
+        .await
+        .expect("failed to save workspace");
+
+    let ids = |results: Vec<BlockSummaryDto>| {
+        let mut ids: Vec<Uuid> = results.into_iter().map(|r| r.id).collect();
+        ids.sort();
+        ids
+    };
+    let sorted = |mut expected: Vec<Uuid>| {
+        expected.sort();
+        expected
+    };
+
+    let search = BlockSearchDto::new("filterneedle");
+    let results = query_service.search(&search, &mut *tx).await?;
+    assert_eq!(ids(results), sorted(vec![parent.id, child.id, other.id]));
+
+    let search = BlockSearchDto {
+        created_after: Some(base_time + Duration::days(1)),
+        created_before: Some(base_time + Duration::days(4)),
+        ..BlockSearchDto::new("filterneedle")
+    };
+    let results = query_service.search(&search, &mut *tx).await?;
+    assert_eq!(ids(results), vec![child.id]);
+
+    // Saving stamps updated_at with the current time
+    let search = BlockSearchDto {
+        updated_after: Some(base_time),
+        ..BlockSearchDto::new("filterneedle")
+    };
+    let results = query_service.search(&search, &mut *tx).await?;
+    assert_eq!(results.len(), 3);
+
+    let search = BlockSearchDto {
+        updated_before: Some(base_time),
+        ..BlockSearchDto::new("filterneedle")
+    };
+    let results = query_service.search(&search, &mut *tx).await?;
+    assert!(results.is_empty());
+
+    let search = BlockSearchDto {
+        descendant_of: Some(parent.id),
+        ..BlockSearchDto::new("filterneedle")
+    };
+    let results = query_service.search(&search, &mut *tx).await?;
+    assert_eq!(ids(results), vec![child.id]);
+
+    let search = BlockSearchDto {
+        only_opened: true,
+        ..BlockSearchDto::new("filterneedle")
+    };
+    let results = query_service.search(&search, &mut *tx).await?;
+    assert_eq!(ids(results), vec![child.id]);
+
+    let search = BlockSearchDto {
+        only_pinned: true,
+        ..BlockSearchDto::new("filterneedle")
+    };
+    let results = query_service.search(&search, &mut *tx).await?;
+    assert_eq!(ids(results), vec![other.id]);
+
+    let search = BlockSearchDto {
+        descendant_of: Some(parent.id),
+        only_opened: true,
+        ..BlockSearchDto::new("")
+    };
+    let results = query_service.search(&search, &mut *tx).await?;
+    assert_eq!(ids(results), vec![child.id]);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_search_paginates<'a, A, Q, R, DB>(
+    query_service: &Q,
+    block_repo: &R,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockQueryService<DB>,
+    R: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    // Identical rank and timestamps, so only the id tiebreaker orders them
+    let now = Utc::now();
+    let mut saved = Vec::new();
+    for i in 0..5 {
+        let mut block = Block::new(&format!("page {i}"), "pageneedle");
+        block.created_at = now;
+        block.updated_at = now;
+        block_repo
+            .save(&block, &mut *tx)
+            .await
+            .expect("failed to save block");
+        saved.push(block.id);
+    }
+
+    let mut seen = Vec::new();
+    let mut after = None;
+    loop {
+        let search = BlockSearchDto {
+            limit: 2,
+            after,
+            ..BlockSearchDto::new("pageneedle")
+        };
+        let results = query_service.search(&search, &mut *tx).await?;
+        assert!(results.len() <= 2);
+        let Some(last) = results.last() else {
+            break;
+        };
+        after = Some(last.position());
+
+        // Trashing rows already seen would shift an offset past unseen ones
+        for result in &results {
+            block_repo
+                .trash(result.id, &mut *tx)
+                .await
+                .expect("failed to trash block");
+        }
+        seen.extend(results.into_iter().map(|r| r.id));
+    }
+
+    // Pages neither overlap nor skip results
+    seen.sort();
+    saved.sort();
+    assert_eq!(seen, saved);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_trashed_blocks_are_hidden<'a, A, Q, R, WR, DB>(
+    query_service: &Q,
+    block_repo: &R,
+    workspace_repo: &WR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockQueryService<DB>,
+    R: BlockRepository<DB>,
+    WR: WorkspaceRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let trashed = Block::new("trashed trashneedle", "content");
+    block_repo
+        .save(&trashed, &mut *tx)
+        .await
+        .expect("failed to save block");
+    workspace_repo
+        .save(
+            &Workspace {
+                opened_blocks: vec![OpenedBlock {
+                    block_id: trashed.id,
+                    opened_at: Utc::now(),
+                    tab_index: 0,
+                }],
+            },
+            &mut *tx,
+        )
+        .await
+        .expect("failed to save workspace");
+    block_repo
+        .trash(trashed.id, &mut *tx)
+        .await
+        .expect("failed to trash block");
+
+    let results = query_service
+        .search(&BlockSearchDto::new("trashneedle"), &mut *tx)
+        .await?;
+    assert!(results.is_empty());
+
+    let results = query_service
+        .search(&BlockSearchDto::new(""), &mut *tx)
+        .await?;
+    assert!(results.iter().all(|r| r.id != trashed.id));
+
+    let opened = query_service.get_opened(&mut *tx).await?;
+    assert!(opened.iter().all(|o| o.id != trashed.id));
+
+    let all = query_service.get_all(&mut *tx).await?;
+    assert!(all.iter().all(|b| b.id != trashed.id));
+
+    let in_trash = query_service.get_trashed(&mut *tx).await?;
+    assert!(in_trash.iter().any(|b| b.id == trashed.id));
+    assert_eq!(
+        query_service.count_trashed(&mut *tx).await?,
+        in_trash.len() as i64
+    );
+
+    block_repo
+        .restore(trashed.id, &mut *tx)
+        .await
+        .expect("failed to restore block");
+
+    let opened = query_service.get_opened(&mut *tx).await?;
+    assert!(opened.iter().any(|o| o.id == trashed.id));
+
+    let in_trash = query_service.get_trashed(&mut *tx).await?;
+    assert!(in_trash.iter().all(|b| b.id != trashed.id));
+    assert_eq!(
+        query_service.count_trashed(&mut *tx).await?,
+        in_trash.len() as i64
+    );
 
     tx.rollback().await?;
 
@@ -1,8 +1,12 @@
 use async_trait::async_trait;
+use futures_util::stream::BoxStream;
 use sqlx::{Database, Executor};
 
 use super::{
-    dtos::{BlockExportDto, BlockSummaryDto, OpenedBlockDto},
+    dtos::{
+        BlockExportDto, BlockSearchDto, BlockSummaryDto, BlockUpdatedAtDto, OpenedBlockDto,
+        TrashedBlockDto,
+    },
     error::BlockQueryServiceResult as Result,
 };
 
@@ -12,11 +16,41 @@ pub trait BlockQueryService<DB: Database>: Send + Sync {
     where
         E: Executor<'e, Database = DB>;
 
-    async fn search<'e, E>(&self, query: &str, executor: E) -> Result<Vec<BlockSummaryDto>>
+    /// Full-text search over titles and contents, best match first with title
+    /// hits ranked above content hits. A blank query returns the most recently
+    /// updated blocks. Returns at most `search.limit` results that sort after
+    /// `search.after`, so pages stay consistent when rows before them change.
+    async fn search<'e, E>(
+        &self,
+        search: &BlockSearchDto,
+        executor: E,
+    ) -> Result<Vec<BlockSummaryDto>>
     where
         E: Executor<'e, Database = DB>;
 
     async fn get_all<'e, E>(&self, executor: E) -> Result<Vec<BlockExportDto>>
     where
         E: Executor<'e, Database = DB>;
+
+    /// Same rows as `get_all`, fetched one at a time so exports of large
+    /// databases don't hold every block in memory.
+    fn stream_all<'e, E>(&self, executor: E) -> BoxStream<'e, Result<BlockExportDto>>
+    where
+        E: Executor<'e, Database = DB> + 'e;
+
+    /// Ids and update times of the rows `get_all` returns, without their
+    /// content.
+    async fn get_all_updated_at<'e, E>(&self, executor: E) -> Result<Vec<BlockUpdatedAtDto>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Returns blocks in the trash, most recently trashed first.
+    async fn get_trashed<'e, E>(&self, executor: E) -> Result<Vec<TrashedBlockDto>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Counts the blocks in the trash.
+    async fn count_trashed<'e, E>(&self, executor: E) -> Result<i64>
+    where
+        E: Executor<'e, Database = DB>;
 }
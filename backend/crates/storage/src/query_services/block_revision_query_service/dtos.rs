@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct BlockRevisionDto {
+    pub block_id: Uuid,
+    pub revision: i64,
+    pub title: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
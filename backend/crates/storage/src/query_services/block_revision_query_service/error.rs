@@ -0,0 +1,7 @@
+#[derive(thiserror::Error, Debug)]
+pub enum BlockRevisionQueryServiceError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+pub type BlockRevisionQueryServiceResult<T> = Result<T, BlockRevisionQueryServiceError>;
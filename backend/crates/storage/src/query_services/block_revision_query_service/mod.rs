@@ -0,0 +1,10 @@
+mod dtos;
+mod error;
+mod traits;
+
+pub use dtos::*;
+pub use error::{BlockRevisionQueryServiceError, BlockRevisionQueryServiceResult};
+pub use traits::BlockRevisionQueryService;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
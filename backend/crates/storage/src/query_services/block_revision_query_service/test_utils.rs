@@ -0,0 +1,97 @@
+use sqlx::{Acquire, Database, Executor};
+
+use domain::blocks::Block;
+
+use super::BlockRevisionQueryServiceResult as Result;
+use crate::query_services::BlockRevisionQueryService;
+use crate::repositories::BlockRepository;
+
+pub async fn assert_save_records_revisions<'a, A, Q, R, DB>(
+    query_service: &Q,
+    block_repo: &R,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockRevisionQueryService<DB>,
+    R: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let mut block = Block::new("draft", "first version");
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to save block");
+
+    block.content = "second version".to_string();
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to update block");
+
+    // Saving unchanged title and content does not add a revision
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to re-save block");
+
+    let revisions = query_service.get_by_block_id(block.id, &mut *tx).await?;
+
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].revision, 2);
+    assert_eq!(revisions[0].content, "second version");
+    assert_eq!(revisions[1].revision, 1);
+    assert_eq!(revisions[1].content, "first version");
+    assert!(revisions.iter().all(|r| r.block_id == block.id));
+
+    let first = query_service
+        .get_by_revision(block.id, 1, &mut *tx)
+        .await?
+        .expect("first revision should exist");
+    assert_eq!(first.title, "draft");
+    assert_eq!(first.content, "first version");
+
+    let missing = query_service.get_by_revision(block.id, 3, &mut *tx).await?;
+    assert!(missing.is_none());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_delete_removes_revisions<'a, A, Q, R, DB>(
+    query_service: &Q,
+    block_repo: &R,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    Q: BlockRevisionQueryService<DB>,
+    R: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let block = Block::new("doomed", "content");
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to save block");
+    block_repo
+        .delete_by_id(block.id, &mut *tx)
+        .await
+        .expect("failed to delete block");
+
+    let revisions = query_service.get_by_block_id(block.id, &mut *tx).await?;
+    assert!(revisions.is_empty());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
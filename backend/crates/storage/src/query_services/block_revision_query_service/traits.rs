@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use sqlx::{Database, Executor};
+use uuid::Uuid;
+
+use super::dtos::BlockRevisionDto;
+use super::error::BlockRevisionQueryServiceResult as Result;
+
+/// Read-only access to `block_revisions`. Rows are written by database
+/// triggers whenever a block is inserted or its title or content changes,
+/// numbered from 1 per block.
+#[async_trait]
+pub trait BlockRevisionQueryService<DB: Database>: Send + Sync {
+    /// Returns every revision of `block_id`, newest first.
+    async fn get_by_block_id<'e, E>(
+        &self,
+        block_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<BlockRevisionDto>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn get_by_revision<'e, E>(
+        &self,
+        block_id: Uuid,
+        revision: i64,
+        executor: E,
+    ) -> Result<Option<BlockRevisionDto>>
+    where
+        E: Executor<'e, Database = DB>;
+}
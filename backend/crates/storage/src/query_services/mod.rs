@@ -1,7 +1,9 @@
-// pub mod block_dag_query_service;
+pub mod block_dag_query_service;
 pub mod block_link_query_service;
 pub mod block_query_service;
+pub mod block_revision_query_service;
 
-// pub use block_dag_query_service::BlockDagQueryService;
+pub use block_dag_query_service::BlockDagQueryService;
 pub use block_link_query_service::BlockLinkQueryService;
 pub use block_query_service::BlockQueryService;
+pub use block_revision_query_service::BlockRevisionQueryService;
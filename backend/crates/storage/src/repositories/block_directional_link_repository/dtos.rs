@@ -5,4 +5,7 @@ pub struct CreateBlockDirectionalLinkDto {
     pub id: Uuid,
     pub block_from_id: Uuid,
     pub block_to_id: Uuid,
+    pub kind: Option<String>,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
 }
@@ -27,6 +27,12 @@ pub enum BlockDirectionalLinkRepositoryError {
 
     #[error("Blocks not found: {from} or {to}")]
     BlocksNotFound { from: Uuid, to: Uuid },
+
+    #[error("Parent block not found: {id}")]
+    ParentNotFound { id: Uuid },
+
+    #[error("Child order must list every child of {parent_id} exactly once")]
+    ChildOrderMismatch { parent_id: Uuid },
 }
 
 pub type BlockDirectionalLinkRepositoryResult<T> = Result<T, BlockDirectionalLinkRepositoryError>;
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use domain::blocks::Block;
@@ -46,16 +47,23 @@ where
         id: link_id,
         block_from_id: from.id,
         block_to_id: to.id,
+        kind: Some("supports".to_string()),
+        label: Some("key evidence".to_string()),
+        weight: Some(0.75),
     };
 
     let created = link_repo.create(&input, &mut *tx).await?;
     assert_eq!(created.id, link_id);
     assert_eq!(created.block_from_id, from.id);
     assert_eq!(created.block_to_id, to.id);
+    assert_eq!(created.kind.as_deref(), Some("supports"));
 
     let fetched = link_repo.get_by_id(link_id, &mut *tx).await?;
     let fetched = fetched.expect("created link should be retrievable");
     assert_eq!(fetched.id, link_id);
+    assert_eq!(fetched.kind.as_deref(), Some("supports"));
+    assert_eq!(fetched.label.as_deref(), Some("key evidence"));
+    assert_eq!(fetched.weight, Some(0.75));
 
     link_repo.delete_by_id(link_id, &mut *tx).await?;
     let fetched = link_repo.get_by_id(link_id, &mut *tx).await?;
@@ -89,6 +97,9 @@ where
         id: link_id,
         block_from_id: from.id,
         block_to_id: to.id,
+        kind: None,
+        label: None,
+        weight: None,
     };
     link_repo.create(&input, &mut *tx).await?;
 
@@ -188,6 +199,9 @@ where
         id: link_id,
         block_from_id: a.id,
         block_to_id: b.id,
+        kind: None,
+        label: None,
+        weight: None,
     };
     link_repo.create(&input, &mut *tx).await?;
 
@@ -195,6 +209,9 @@ where
         id: Uuid::new_v4(),
         block_from_id: b.id,
         block_to_id: a.id,
+        kind: None,
+        label: None,
+        weight: None,
     };
 
     let err = link_repo
@@ -233,6 +250,9 @@ where
         id: Uuid::new_v4(),
         block_from_id: from.id,
         block_to_id: to.id,
+        kind: None,
+        label: None,
+        weight: None,
     };
     link_repo.create(&input, &mut *tx).await?;
 
@@ -240,6 +260,9 @@ where
         id: Uuid::new_v4(),
         block_from_id: from.id,
         block_to_id: to.id,
+        kind: None,
+        label: None,
+        weight: None,
     };
 
     let err = link_repo
@@ -262,3 +285,294 @@ where
 
     Ok(())
 }
+
+pub async fn assert_sibling_positions<'a, A, L, B, DB>(
+    link_repo: &L,
+    block_repo: &B,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    L: BlockDirectionalLinkRepository<DB>,
+    B: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let parent = seed_block(block_repo, "parent", &mut *tx).await;
+    let mut link_ids = Vec::new();
+    for title in ["first", "second", "third"] {
+        let child = seed_block(block_repo, title, &mut *tx).await;
+        let input = CreateBlockDirectionalLinkDto {
+            id: Uuid::new_v4(),
+            block_from_id: parent.id,
+            block_to_id: child.id,
+            kind: None,
+            label: None,
+            weight: None,
+        };
+        let created = link_repo.create(&input, &mut *tx).await?;
+        assert_eq!(created.position, link_ids.len() as i64);
+        link_ids.push(created.id);
+    }
+
+    // Removing the middle child closes the gap
+    link_repo.delete_by_id(link_ids[1], &mut *tx).await?;
+    let last = link_repo
+        .get_by_id(link_ids[2], &mut *tx)
+        .await?
+        .expect("remaining link should be retrievable");
+    assert_eq!(last.position, 1);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_reorder_children<'a, A, L, B, DB>(
+    link_repo: &L,
+    block_repo: &B,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    L: BlockDirectionalLinkRepository<DB>,
+    B: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let parent = seed_block(block_repo, "parent", &mut *tx).await;
+    let mut child_ids = Vec::new();
+    let mut link_ids = Vec::new();
+    for title in ["first", "second", "third"] {
+        let child = seed_block(block_repo, title, &mut *tx).await;
+        let input = CreateBlockDirectionalLinkDto {
+            id: Uuid::new_v4(),
+            block_from_id: parent.id,
+            block_to_id: child.id,
+            kind: None,
+            label: None,
+            weight: None,
+        };
+        link_ids.push(link_repo.create(&input, &mut *tx).await?.id);
+        child_ids.push(child.id);
+    }
+
+    let reordered = [child_ids[2], child_ids[0], child_ids[1]];
+    link_repo
+        .reorder_children(parent.id, &reordered, &mut *tx)
+        .await?;
+
+    let mut positions = Vec::new();
+    for link_id in &link_ids {
+        let link = link_repo
+            .get_by_id(*link_id, &mut *tx)
+            .await?
+            .expect("reordered link should be retrievable");
+        positions.push(link.position);
+    }
+    assert_eq!(positions, vec![1, 2, 0]);
+
+    // Trashed children may be left out and keep their place after the rest
+    block_repo
+        .trash(child_ids[0], &mut *tx)
+        .await
+        .expect("failed to trash child");
+    link_repo
+        .reorder_children(parent.id, &[child_ids[1], child_ids[2]], &mut *tx)
+        .await?;
+    let trashed_link = link_repo
+        .get_by_id(link_ids[0], &mut *tx)
+        .await?
+        .expect("trashed child link should remain");
+    assert_eq!(trashed_link.position, 2);
+
+    let err = link_repo
+        .reorder_children(parent.id, &[child_ids[1]], &mut *tx)
+        .await
+        .expect_err("incomplete order should error");
+    assert!(matches!(
+        err,
+        BlockDirectionalLinkRepositoryError::ChildOrderMismatch { parent_id } if parent_id == parent.id
+    ));
+
+    let err = link_repo
+        .reorder_children(parent.id, &[child_ids[1], child_ids[1]], &mut *tx)
+        .await
+        .expect_err("duplicate child should error");
+    assert!(matches!(
+        err,
+        BlockDirectionalLinkRepositoryError::ChildOrderMismatch { .. }
+    ));
+
+    let missing_parent = Uuid::new_v4();
+    let err = link_repo
+        .reorder_children(missing_parent, &[], &mut *tx)
+        .await
+        .expect_err("missing parent should error");
+    assert!(matches!(
+        err,
+        BlockDirectionalLinkRepositoryError::ParentNotFound { id } if id == missing_parent
+    ));
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_move_link<'a, A, L, B, DB>(link_repo: &L, block_repo: &B, conn: A) -> Result<()>
+where
+    DB: Database,
+    L: BlockDirectionalLinkRepository<DB>,
+    B: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let old_parent = seed_block(block_repo, "old parent", &mut *tx).await;
+    let new_parent = seed_block(block_repo, "new parent", &mut *tx).await;
+    let moved = seed_block(block_repo, "moved", &mut *tx).await;
+    let sibling = seed_block(block_repo, "sibling", &mut *tx).await;
+    let grandchild = seed_block(block_repo, "grandchild", &mut *tx).await;
+
+    let link = |from: Uuid, to: Uuid| CreateBlockDirectionalLinkDto {
+        id: Uuid::new_v4(),
+        block_from_id: from,
+        block_to_id: to,
+        kind: None,
+        label: None,
+        weight: None,
+    };
+
+    let moved_link = link_repo
+        .create(
+            &CreateBlockDirectionalLinkDto {
+                kind: Some("part-of".to_string()),
+                ..link(old_parent.id, moved.id)
+            },
+            &mut *tx,
+        )
+        .await?;
+    let sibling_link = link_repo
+        .create(&link(old_parent.id, sibling.id), &mut *tx)
+        .await?;
+    link_repo
+        .create(&link(new_parent.id, sibling.id), &mut *tx)
+        .await?;
+    link_repo
+        .create(&link(moved.id, grandchild.id), &mut *tx)
+        .await?;
+
+    let result = link_repo
+        .move_link(moved.id, old_parent.id, new_parent.id, &mut *tx)
+        .await?;
+    assert_eq!(result.id, moved_link.id);
+    assert_eq!(result.block_from_id, new_parent.id);
+    assert_eq!(result.kind.as_deref(), Some("part-of"));
+    assert_eq!(result.position, 1);
+
+    let sibling_link = link_repo
+        .get_by_id(sibling_link.id, &mut *tx)
+        .await?
+        .expect("sibling link should remain");
+    assert_eq!(sibling_link.position, 0);
+
+    // The old ancestry is gone, so linking back the other way is no cycle
+    let back = link_repo
+        .create(&link(grandchild.id, old_parent.id), &mut *tx)
+        .await?;
+    link_repo.delete_by_id(back.id, &mut *tx).await?;
+
+    let err = link_repo
+        .create(&link(grandchild.id, new_parent.id), &mut *tx)
+        .await
+        .expect_err("new ancestry should be in the path table");
+    assert!(matches!(
+        err,
+        BlockDirectionalLinkRepositoryError::CycleDetected { .. }
+    ));
+
+    // A failed move leaves the original link in place
+    let err = link_repo
+        .move_link(moved.id, new_parent.id, grandchild.id, &mut *tx)
+        .await
+        .expect_err("moving under a descendant should error");
+    assert!(matches!(
+        err,
+        BlockDirectionalLinkRepositoryError::CycleDetected { .. }
+    ));
+    let unchanged = link_repo
+        .get_by_id(moved_link.id, &mut *tx)
+        .await?
+        .expect("link should survive a failed move");
+    assert_eq!(unchanged.block_from_id, new_parent.id);
+    assert_eq!(unchanged.position, 1);
+
+    let err = link_repo
+        .move_link(moved.id, old_parent.id, new_parent.id, &mut *tx)
+        .await
+        .expect_err("moving from a non-parent should error");
+    assert!(matches!(
+        err,
+        BlockDirectionalLinkRepositoryError::NotFoundByBlocks { .. }
+    ));
+
+    // Postgres aborts the transaction on the unique violation, so check it last
+    let err = link_repo
+        .move_link(sibling.id, old_parent.id, new_parent.id, &mut *tx)
+        .await
+        .expect_err("moving onto an existing link should error");
+    assert!(matches!(
+        err,
+        BlockDirectionalLinkRepositoryError::AlreadyExists { .. }
+    ));
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_create_at<'a, A, L, B, DB>(link_repo: &L, block_repo: &B, conn: A) -> Result<()>
+where
+    DB: Database,
+    L: BlockDirectionalLinkRepository<DB>,
+    B: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let from = seed_block(block_repo, "from", &mut *tx).await;
+    let to = seed_block(block_repo, "to", &mut *tx).await;
+
+    let created_at: DateTime<Utc> = "2024-03-01T12:00:00Z".parse().unwrap();
+    let input = CreateBlockDirectionalLinkDto {
+        id: Uuid::new_v4(),
+        block_from_id: from.id,
+        block_to_id: to.id,
+        kind: None,
+        label: None,
+        weight: None,
+    };
+
+    let created = link_repo.create_at(&input, created_at, &mut *tx).await?;
+    assert_eq!(created.created_at, created_at);
+
+    let fetched = link_repo
+        .get_by_id(input.id, &mut *tx)
+        .await?
+        .expect("created link should be retrievable");
+    assert_eq!(fetched.created_at, created_at);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::{Acquire, Database, Executor};
 use uuid::Uuid;
 
@@ -20,6 +21,17 @@ pub trait BlockDirectionalLinkRepository<DB: Database>: Send + Sync {
     where
         E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
 
+    /// Like `create`, but keeps the given `created_at`, for links restored
+    /// from an archive.
+    async fn create_at<'e, E>(
+        &self,
+        input: &CreateBlockDirectionalLinkDto,
+        created_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<BlockDirectionalLink>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
     async fn delete_by_id<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
     where
         E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
@@ -32,4 +44,29 @@ pub trait BlockDirectionalLinkRepository<DB: Database>: Send + Sync {
     ) -> Result<()>
     where
         E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
+    /// Moves `block_id` from under `from_parent_id` to the end of the children
+    /// of `to_parent_id` in a single transaction. The link keeps its id and
+    /// annotations; on error nothing is changed.
+    async fn move_link<'e, E>(
+        &self,
+        block_id: Uuid,
+        from_parent_id: Uuid,
+        to_parent_id: Uuid,
+        executor: E,
+    ) -> Result<BlockDirectionalLink>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
+    /// Rewrites the sibling positions under `parent_id` to follow `child_ids`,
+    /// which must list every child that is not in the trash. Trashed children
+    /// keep their relative order after the listed ones.
+    async fn reorder_children<'e, E>(
+        &self,
+        parent_id: Uuid,
+        child_ids: &[Uuid],
+        executor: E,
+    ) -> Result<()>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
 }
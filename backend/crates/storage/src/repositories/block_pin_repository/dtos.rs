@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct PinnedBlockDto {
+    pub block_id: Uuid,
+    pub title: String,
+    pub pinned_at: DateTime<Utc>,
+}
@@ -0,0 +1,12 @@
+use uuid::Uuid;
+
+#[derive(thiserror::Error, Debug)]
+pub enum BlockPinRepositoryError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Block is not pinned: {block_id}")]
+    NotPinned { block_id: Uuid },
+}
+
+pub type BlockPinRepositoryResult<T> = Result<T, BlockPinRepositoryError>;
@@ -0,0 +1,120 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use sqlx::{Acquire, Database, Executor};
+
+use super::{
+    error::BlockPinRepositoryError, error::BlockPinRepositoryResult as Result,
+    traits::BlockPinRepository,
+};
+use crate::repositories::BlockRepository;
+use domain::blocks::Block;
+
+pub async fn assert_pin_unpin<'a, A, R, BR, DB>(repo: &R, block_repo: &BR, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: BlockPinRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let block = Block::new("Pinned", "content");
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to seed block");
+
+    assert!(!repo.is_pinned(block.id, &mut *tx).await?);
+
+    repo.pin(block.id, &mut *tx).await?;
+    repo.pin(block.id, &mut *tx).await?;
+    assert!(repo.is_pinned(block.id, &mut *tx).await?);
+
+    // Saving the block again must not drop its pin
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to re-save block");
+    assert!(repo.is_pinned(block.id, &mut *tx).await?);
+
+    let pinned = repo.get_all(&mut *tx).await?;
+    let entry = pinned
+        .iter()
+        .find(|p| p.block_id == block.id)
+        .expect("pinned block should be listed");
+    assert_eq!(entry.title, block.title);
+
+    repo.unpin(block.id, &mut *tx).await?;
+    assert!(!repo.is_pinned(block.id, &mut *tx).await?);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_unpin_missing<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: BlockPinRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let missing_id = Uuid::new_v4();
+    let err = repo
+        .unpin(missing_id, &mut *tx)
+        .await
+        .expect_err("unpinning a block that is not pinned should error");
+
+    match err {
+        BlockPinRepositoryError::NotPinned { block_id } => {
+            assert_eq!(block_id, missing_id);
+        }
+        other => {
+            return Err(other);
+        }
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_pin_at<'a, A, R, BR, DB>(repo: &R, block_repo: &BR, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: BlockPinRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let block = Block::new("Pinned", "content");
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to seed block");
+
+    let pinned_at: DateTime<Utc> = "2024-03-01T12:00:00Z".parse().unwrap();
+    repo.pin_at(block.id, pinned_at, &mut *tx).await?;
+    // An existing pin keeps its time
+    repo.pin(block.id, &mut *tx).await?;
+
+    let pinned = repo.get_all(&mut *tx).await?;
+    let entry = pinned
+        .iter()
+        .find(|p| p.block_id == block.id)
+        .expect("pinned block should be listed");
+    assert_eq!(entry.pinned_at, pinned_at);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Database, Executor};
+use uuid::Uuid;
+
+use super::dtos::PinnedBlockDto;
+use super::error::BlockPinRepositoryResult as Result;
+
+#[async_trait]
+pub trait BlockPinRepository<DB: Database>: Send + Sync {
+    /// Pins the block. Pinning an already pinned block keeps its original `pinned_at`.
+    async fn pin<'e, E>(&self, block_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Pins the block with the given `pinned_at`, for pins restored from an
+    /// archive. Like `pin`, leaves an existing pin alone.
+    async fn pin_at<'e, E>(
+        &self,
+        block_id: Uuid,
+        pinned_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn unpin<'e, E>(&self, block_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn is_pinned<'e, E>(&self, block_id: Uuid, executor: E) -> Result<bool>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Returns pinned blocks, most recently pinned first.
+    async fn get_all<'e, E>(&self, executor: E) -> Result<Vec<PinnedBlockDto>>
+    where
+        E: Executor<'e, Database = DB>;
+}
@@ -5,4 +5,6 @@ pub struct CreateBlockRelatedLinkDto {
     pub id: Uuid,
     pub block_a_id: Uuid,
     pub block_b_id: Uuid,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
 }
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use domain::blocks::Block;
@@ -54,6 +55,8 @@ where
         id: link_id,
         block_a_id: a.id,
         block_b_id: b.id,
+        label: Some("same argument".to_string()),
+        weight: Some(2.0),
     };
 
     let created = link_repo.create(&input, &mut *tx).await?;
@@ -65,6 +68,8 @@ where
     let fetched = link_repo.get_by_id(link_id, &mut *tx).await?;
     let fetched = fetched.expect("created related link should be retrievable");
     assert_eq!(fetched.id, link_id);
+    assert_eq!(fetched.label.as_deref(), Some("same argument"));
+    assert_eq!(fetched.weight, Some(2.0));
 
     link_repo.delete_by_id(link_id, &mut *tx).await?;
     let fetched = link_repo.get_by_id(link_id, &mut *tx).await?;
@@ -98,6 +103,8 @@ where
         id: link_id,
         block_a_id: a.id,
         block_b_id: b.id,
+        label: None,
+        weight: None,
     };
     link_repo.create(&input, &mut *tx).await?;
 
@@ -186,6 +193,8 @@ where
         id: Uuid::new_v4(),
         block_a_id: block.id,
         block_b_id: block.id,
+        label: None,
+        weight: None,
     };
 
     let err = link_repo
@@ -223,6 +232,8 @@ where
         id: Uuid::new_v4(),
         block_a_id: a.id,
         block_b_id: b.id,
+        label: None,
+        weight: None,
     };
     link_repo.create(&input, &mut *tx).await?;
 
@@ -230,6 +241,8 @@ where
         id: Uuid::new_v4(),
         block_a_id: b.id,
         block_b_id: a.id,
+        label: None,
+        weight: None,
     };
 
     let err = link_repo
@@ -250,3 +263,40 @@ where
 
     Ok(())
 }
+
+pub async fn assert_create_at<'a, A, L, B, DB>(link_repo: &L, block_repo: &B, conn: A) -> Result<()>
+where
+    DB: Database,
+    L: BlockRelatedLinkRepository<DB>,
+    B: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let a = seed_block(block_repo, "a", &mut *tx).await;
+    let b = seed_block(block_repo, "b", &mut *tx).await;
+
+    let created_at: DateTime<Utc> = "2024-03-01T12:00:00Z".parse().unwrap();
+    let input = CreateBlockRelatedLinkDto {
+        id: Uuid::new_v4(),
+        block_a_id: a.id,
+        block_b_id: b.id,
+        label: None,
+        weight: None,
+    };
+
+    let created = link_repo.create_at(&input, created_at, &mut *tx).await?;
+    assert_eq!(created.created_at, created_at);
+
+    let fetched = link_repo
+        .get_by_id(input.id, &mut *tx)
+        .await?
+        .expect("created related link should be retrievable");
+    assert_eq!(fetched.created_at, created_at);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::{Acquire, Database, Executor};
 use uuid::Uuid;
 
@@ -20,6 +21,17 @@ pub trait BlockRelatedLinkRepository<DB: Database>: Send + Sync {
     where
         E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
 
+    /// Like `create`, but keeps the given `created_at`, for links restored
+    /// from an archive.
+    async fn create_at<'e, E>(
+        &self,
+        input: &CreateBlockRelatedLinkDto,
+        created_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<BlockRelatedLink>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
     async fn delete_by_id<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
     where
         E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
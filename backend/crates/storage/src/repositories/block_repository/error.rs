@@ -15,6 +15,12 @@ pub enum BlockRepositoryError {
 
     #[error("Block not found: {id}")]
     NotFound { id: Uuid },
+
+    #[error("Block not in trash: {id}")]
+    NotTrashed { id: Uuid },
+
+    #[error("Restoring block would create a cycle: {id}")]
+    RestoreCycle { id: Uuid },
 }
 
 pub type BlockRepostoryResult<T> = Result<T, BlockRepositoryError>;
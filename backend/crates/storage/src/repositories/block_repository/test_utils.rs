@@ -1,3 +1,4 @@
+use chrono::{Duration, SubsecRound, Utc};
 use uuid::Uuid;
 
 use sqlx::{Acquire, Database, Executor};
@@ -5,7 +6,40 @@ use sqlx::{Acquire, Database, Executor};
 use super::{
     error::BlockRepositoryError, error::BlockRepostoryResult as Result, traits::BlockRepository,
 };
-use domain::blocks::Block;
+use crate::helpers::block_directional_path_helper::BlockDirectionalPathHelper;
+use crate::repositories::{
+    BlockDirectionalLinkRepository, BlockRelatedLinkRepository,
+    block_directional_link_repository::CreateBlockDirectionalLinkDto,
+    block_related_link_repository::CreateBlockRelatedLinkDto,
+};
+use domain::blocks::{Block, BlockDirectionalLink};
+
+async fn seed_directional_link<'e, E, DR, DB>(
+    directional_repo: &DR,
+    from: Uuid,
+    to: Uuid,
+    executor: E,
+) -> BlockDirectionalLink
+where
+    DB: Database,
+    DR: BlockDirectionalLinkRepository<DB>,
+    E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>,
+{
+    directional_repo
+        .create(
+            &CreateBlockDirectionalLinkDto {
+                id: Uuid::new_v4(),
+                block_from_id: from,
+                block_to_id: to,
+                kind: None,
+                label: None,
+                weight: None,
+            },
+            executor,
+        )
+        .await
+        .expect("failed to seed directional link")
+}
 
 pub async fn assert_get_delete_save<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
 where
@@ -75,3 +109,324 @@ where
 
     Ok(())
 }
+
+pub async fn assert_trash_keeps_links_and_restore_rebuilds_paths<'a, A, R, DR, RR, H, DB>(
+    repo: &R,
+    directional_repo: &DR,
+    related_repo: &RR,
+    path_helper: &H,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    R: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    RR: BlockRelatedLinkRepository<DB>,
+    H: BlockDirectionalPathHelper<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    // top -> middle -> bottom, middle -- side
+    let top = Block::new("top", "content");
+    let middle = Block::new("middle", "content");
+    let bottom = Block::new("bottom", "content");
+    let side = Block::new("side", "content");
+    for block in [&top, &middle, &bottom, &side] {
+        repo.save(block, &mut *tx).await?;
+    }
+    seed_directional_link(directional_repo, top.id, middle.id, &mut *tx).await;
+    seed_directional_link(directional_repo, middle.id, bottom.id, &mut *tx).await;
+    let related_id = Uuid::new_v4();
+    related_repo
+        .create(
+            &CreateBlockRelatedLinkDto {
+                id: related_id,
+                block_a_id: middle.id,
+                block_b_id: side.id,
+                label: None,
+                weight: None,
+            },
+            &mut *tx,
+        )
+        .await
+        .expect("failed to seed related link");
+
+    repo.trash(middle.id, &mut *tx).await?;
+
+    assert!(repo.get_by_id(middle.id, &mut *tx).await?.is_none());
+    assert!(
+        !path_helper
+            .is_ancestor_descendant(top.id, bottom.id, &mut *tx)
+            .await?
+    );
+    let related = related_repo
+        .get_by_id(related_id, &mut *tx)
+        .await
+        .expect("failed to fetch related link");
+    assert!(related.is_some(), "trash should keep links");
+
+    match repo.trash(middle.id, &mut *tx).await {
+        Err(BlockRepositoryError::NotFound { id }) => assert_eq!(id, middle.id),
+        other => panic!("trashing twice should be NotFound, got {other:?}"),
+    }
+
+    repo.restore(middle.id, &mut *tx).await?;
+
+    assert!(repo.get_by_id(middle.id, &mut *tx).await?.is_some());
+    assert!(
+        path_helper
+            .is_ancestor_descendant(top.id, bottom.id, &mut *tx)
+            .await?
+    );
+    assert!(
+        path_helper
+            .is_ancestor_descendant(top.id, middle.id, &mut *tx)
+            .await?
+    );
+
+    match repo.restore(middle.id, &mut *tx).await {
+        Err(BlockRepositoryError::NotTrashed { id }) => assert_eq!(id, middle.id),
+        other => panic!("restoring a live block should be NotTrashed, got {other:?}"),
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_restore_rejects_cycle<'a, A, R, DR, DB>(
+    repo: &R,
+    directional_repo: &DR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    R: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let top = Block::new("top", "content");
+    let middle = Block::new("middle", "content");
+    let bottom = Block::new("bottom", "content");
+    for block in [&top, &middle, &bottom] {
+        repo.save(block, &mut *tx).await?;
+    }
+    seed_directional_link(directional_repo, top.id, middle.id, &mut *tx).await;
+    seed_directional_link(directional_repo, middle.id, bottom.id, &mut *tx).await;
+
+    repo.trash(middle.id, &mut *tx).await?;
+
+    // Without middle nothing connects bottom back to top
+    seed_directional_link(directional_repo, bottom.id, top.id, &mut *tx).await;
+
+    match repo.restore(middle.id, &mut *tx).await {
+        Err(BlockRepositoryError::RestoreCycle { id }) => assert_eq!(id, middle.id),
+        other => panic!("restore closing a cycle should fail, got {other:?}"),
+    }
+    assert!(repo.get_by_id(middle.id, &mut *tx).await?.is_none());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_purge<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let kept = Block::new("kept", "content");
+    let expired = Block::new("expired", "content");
+    repo.save(&kept, &mut *tx).await?;
+    repo.save(&expired, &mut *tx).await?;
+
+    match repo.purge(kept.id, &mut *tx).await {
+        Err(BlockRepositoryError::NotTrashed { id }) => assert_eq!(id, kept.id),
+        other => panic!("purging a live block should be NotTrashed, got {other:?}"),
+    }
+
+    repo.trash(kept.id, &mut *tx).await?;
+    repo.trash(expired.id, &mut *tx).await?;
+
+    let purged = repo
+        .purge_trashed_before(Utc::now() - Duration::days(1), &mut *tx)
+        .await?;
+    assert_eq!(purged, 0);
+
+    repo.purge(kept.id, &mut *tx).await?;
+    match repo.purge(kept.id, &mut *tx).await {
+        Err(BlockRepositoryError::NotFound { id }) => assert_eq!(id, kept.id),
+        other => panic!("purging twice should be NotFound, got {other:?}"),
+    }
+
+    let purged = repo
+        .purge_trashed_before(Utc::now() + Duration::minutes(1), &mut *tx)
+        .await?;
+    assert!(purged >= 1);
+    match repo.restore(expired.id, &mut *tx).await {
+        Err(BlockRepositoryError::NotFound { id }) => assert_eq!(id, expired.id),
+        other => panic!("auto-purged block should be gone, got {other:?}"),
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_purge_closes_position_gaps<'a, A, R, DR, DB>(
+    repo: &R,
+    directional_repo: &DR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    R: BlockRepository<DB>,
+    DR: BlockDirectionalLinkRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let parent = Block::new("parent", "content");
+    let first = Block::new("first", "content");
+    let second = Block::new("second", "content");
+    let third = Block::new("third", "content");
+    for block in [&parent, &first, &second, &third] {
+        repo.save(block, &mut *tx).await?;
+    }
+    seed_directional_link(directional_repo, parent.id, first.id, &mut *tx).await;
+    seed_directional_link(directional_repo, parent.id, second.id, &mut *tx).await;
+    let last = seed_directional_link(directional_repo, parent.id, third.id, &mut *tx).await;
+    assert_eq!(last.position, 2);
+
+    let position_of_last = |link: Option<BlockDirectionalLink>| {
+        link.expect("link to the last child should remain").position
+    };
+
+    repo.trash(second.id, &mut *tx).await?;
+    repo.purge(second.id, &mut *tx).await?;
+    let link = directional_repo
+        .get_by_id(last.id, &mut *tx)
+        .await
+        .expect("failed to load link");
+    assert_eq!(position_of_last(link), 1);
+
+    repo.trash(first.id, &mut *tx).await?;
+    repo.purge_trashed_before(Utc::now() + Duration::minutes(1), &mut *tx)
+        .await?;
+    let link = directional_repo
+        .get_by_id(last.id, &mut *tx)
+        .await
+        .expect("failed to load link");
+    assert_eq!(position_of_last(link), 0);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_update_if_version<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let mut block = Block::new("versioned", "v1");
+    repo.save(&block, &mut *tx).await?;
+
+    let fetched = repo
+        .get_by_id(block.id, &mut *tx)
+        .await?
+        .expect("saved block should be retrievable");
+    assert_eq!(fetched.version, 1);
+
+    repo.save(&block, &mut *tx).await?;
+    let fetched = repo
+        .get_by_id(block.id, &mut *tx)
+        .await?
+        .expect("saved block should be retrievable");
+    assert_eq!(fetched.version, 2, "save should bump the version");
+
+    block.content = "v3".to_string();
+    let updated = repo
+        .update_if_version(&block, 2, &mut *tx)
+        .await?
+        .expect("matching version should update");
+    assert_eq!(updated.version, 3);
+    assert_eq!(updated.content, "v3");
+
+    block.content = "stale".to_string();
+    let stale = repo.update_if_version(&block, 2, &mut *tx).await?;
+    assert!(stale.is_none(), "stale version should not update");
+
+    let fetched = repo
+        .get_by_id(block.id, &mut *tx)
+        .await?
+        .expect("block should still exist");
+    assert_eq!(fetched.version, 3);
+    assert_eq!(fetched.content, "v3");
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_save_with_timestamps<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    // Whole seconds, so no backend truncates them
+    let created_at = Utc::now().trunc_subsecs(0) - Duration::days(30);
+    let mut block = Block::new("archived", "v1");
+    block.created_at = created_at;
+    block.updated_at = created_at + Duration::days(1);
+    repo.save_with_timestamps(&block, &mut *tx).await?;
+
+    let fetched = repo
+        .get_by_id(block.id, &mut *tx)
+        .await?
+        .expect("saved block should be retrievable");
+    assert_eq!(fetched.created_at, block.created_at);
+    assert_eq!(fetched.updated_at, block.updated_at);
+    assert_eq!(fetched.version, 1);
+
+    block.content = "v2".to_string();
+    block.updated_at = created_at + Duration::days(2);
+    repo.save_with_timestamps(&block, &mut *tx).await?;
+
+    let fetched = repo
+        .get_by_id(block.id, &mut *tx)
+        .await?
+        .expect("saved block should be retrievable");
+    assert_eq!(fetched.content, "v2");
+    assert_eq!(fetched.created_at, block.created_at);
+    assert_eq!(fetched.updated_at, block.updated_at);
+    assert_eq!(fetched.version, 2, "overwriting should bump the version");
+
+    tx.rollback().await?;
+
+    Ok(())
+}
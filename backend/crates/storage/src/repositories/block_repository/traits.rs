@@ -1,10 +1,14 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::{Acquire, Database, Executor};
 use uuid::Uuid;
 
 use super::error::BlockRepostoryResult as Result;
 use domain::blocks::Block;
 
+/// Blocks can be moved to the trash instead of being deleted outright. A
+/// trashed block keeps its row and its links, but `get_by_id` and every query
+/// service treat it as missing until it is restored or purged.
 #[async_trait]
 pub trait BlockRepository<DB: Database>: Send + Sync {
     async fn get_by_id<'e, E>(&self, id: Uuid, executor: E) -> Result<Option<Block>>
@@ -15,7 +19,51 @@ pub trait BlockRepository<DB: Database>: Send + Sync {
     where
         E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
 
+    /// Writes the title and content of `block` only if the stored version still
+    /// equals `expected_version`, bumping the version. Returns the updated block,
+    /// or `None` if the block is missing or was changed in the meantime.
+    async fn update_if_version<'e, E>(
+        &self,
+        block: &Block,
+        expected_version: i64,
+        executor: E,
+    ) -> Result<Option<Block>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Moves a live block to the trash and drops the directional paths through it.
+    async fn trash<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
+    /// Takes a block out of the trash and rebuilds the directional paths for its
+    /// links to other live blocks. Fails with `RestoreCycle` if links created
+    /// while it was trashed would now close a cycle through it.
+    async fn restore<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
+    /// Permanently deletes a trashed block along with its links, closing the
+    /// gap it leaves in each parent's child order.
+    async fn purge<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
+    /// Permanently deletes every block trashed before `cutoff` and returns how
+    /// many were removed.
+    async fn purge_trashed_before<'e, E>(&self, cutoff: DateTime<Utc>, executor: E) -> Result<u64>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
+    /// Inserts a new block with its own version or overwrites an existing one,
+    /// bumping the stored version.
     async fn save<'e, E>(&self, block: &Block, executor: E) -> Result<()>
     where
         E: Executor<'e, Database = DB>;
+
+    /// Like `save`, but keeps the given `created_at` and `updated_at`, for
+    /// blocks restored from an archive.
+    async fn save_with_timestamps<'e, E>(&self, block: &Block, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
 }
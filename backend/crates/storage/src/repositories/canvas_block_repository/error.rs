@@ -0,0 +1,12 @@
+use uuid::Uuid;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CanvasBlockRepositoryError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Block {block_id} is not placed on canvas {canvas_id}")]
+    NotFound { canvas_id: Uuid, block_id: Uuid },
+}
+
+pub type CanvasBlockRepositoryResult<T> = Result<T, CanvasBlockRepositoryError>;
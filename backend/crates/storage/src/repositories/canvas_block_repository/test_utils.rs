@@ -0,0 +1,277 @@
+use uuid::Uuid;
+
+use sqlx::{Acquire, Database, Executor};
+
+use super::{
+    error::CanvasBlockRepositoryError, error::CanvasBlockRepositoryResult as Result,
+    traits::CanvasBlockRepository,
+};
+use crate::repositories::{BlockRepository, CanvasRepository};
+use domain::blocks::Block;
+use domain::canvases::{Canvas, CanvasBlock};
+
+async fn seed_canvas<'a, A, CR, BR, DB>(
+    canvas_repo: &CR,
+    block_repo: &BR,
+    block_count: usize,
+    conn: A,
+) -> (Canvas, Vec<Block>)
+where
+    DB: Database,
+    CR: CanvasRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await.expect("Failed to get connection");
+
+    let canvas = Canvas::new("Canvas");
+    canvas_repo
+        .save(&canvas, &mut *conn)
+        .await
+        .expect("failed to seed canvas");
+
+    let mut blocks = Vec::new();
+    for i in 0..block_count {
+        let block = Block::new(&format!("Block {i}"), "content");
+        block_repo
+            .save(&block, &mut *conn)
+            .await
+            .expect("failed to seed block");
+        blocks.push(block);
+    }
+
+    (canvas, blocks)
+}
+
+pub async fn assert_get_delete_save<'a, A, R, CR, BR, DB>(
+    repo: &R,
+    canvas_repo: &CR,
+    block_repo: &BR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    R: CanvasBlockRepository<DB>,
+    CR: CanvasRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let (canvas, blocks) = seed_canvas(canvas_repo, block_repo, 1, &mut tx).await;
+    let block_id = blocks[0].id;
+
+    let mut placement = CanvasBlock::new(canvas.id, block_id, 2, 3, 4, 5);
+    repo.save(&placement, &mut *tx).await?;
+
+    let fetched = repo
+        .get_by_canvas_and_block(canvas.id, block_id, &mut *tx)
+        .await?
+        .expect("placed block should be retrievable");
+    assert_eq!(fetched.id, placement.id);
+    assert_eq!(
+        (
+            fetched.grid_x,
+            fetched.grid_y,
+            fetched.grid_width,
+            fetched.grid_height
+        ),
+        (2, 3, 4, 5)
+    );
+    assert!(fetched.content_visible);
+
+    placement.grid_x = 10;
+    placement.grid_height = 8;
+    placement.z = 7;
+    placement.scale = 0.5;
+    placement.content_visible = false;
+    repo.save(&placement, &mut *tx).await?;
+
+    let fetched = repo
+        .get_by_canvas_and_block(canvas.id, block_id, &mut *tx)
+        .await?
+        .expect("updated placement should be retrievable");
+    assert_eq!(fetched.grid_x, 10);
+    assert_eq!(fetched.grid_height, 8);
+    assert_eq!(fetched.z, 7);
+    assert_eq!(fetched.scale, 0.5);
+    assert!(!fetched.content_visible);
+
+    repo.delete(canvas.id, block_id, &mut *tx).await?;
+    let fetched = repo
+        .get_by_canvas_and_block(canvas.id, block_id, &mut *tx)
+        .await?;
+    assert!(fetched.is_none());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_get_by_canvas_orders_by_z<'a, A, R, CR, BR, DB>(
+    repo: &R,
+    canvas_repo: &CR,
+    block_repo: &BR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    R: CanvasBlockRepository<DB>,
+    CR: CanvasRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let (canvas, blocks) = seed_canvas(canvas_repo, block_repo, 3, &mut tx).await;
+
+    for (block, z) in blocks.iter().zip([5, 1, 3]) {
+        let mut placement = CanvasBlock::new(canvas.id, block.id, 0, 0, 1, 1);
+        placement.z = z;
+        repo.save(&placement, &mut *tx).await?;
+    }
+
+    let placements = repo.get_by_canvas(canvas.id, &mut *tx).await?;
+    let zs: Vec<i32> = placements.iter().map(|p| p.z).collect();
+    assert_eq!(zs, vec![1, 3, 5]);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_trashed_blocks_are_hidden<'a, A, R, CR, BR, DB>(
+    repo: &R,
+    canvas_repo: &CR,
+    block_repo: &BR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    R: CanvasBlockRepository<DB>,
+    CR: CanvasRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let (canvas, blocks) = seed_canvas(canvas_repo, block_repo, 2, &mut tx).await;
+    for block in &blocks {
+        repo.save(&CanvasBlock::new(canvas.id, block.id, 0, 0, 1, 1), &mut *tx)
+            .await?;
+    }
+
+    block_repo
+        .trash(blocks[0].id, &mut *tx)
+        .await
+        .expect("failed to trash block");
+
+    let placements = repo.get_by_canvas(canvas.id, &mut *tx).await?;
+    let placed: Vec<_> = placements.iter().map(|p| p.block_id).collect();
+    assert_eq!(placed, vec![blocks[1].id]);
+    let fetched = repo
+        .get_by_canvas_and_block(canvas.id, blocks[0].id, &mut *tx)
+        .await?;
+    assert!(fetched.is_none());
+
+    block_repo
+        .restore(blocks[0].id, &mut *tx)
+        .await
+        .expect("failed to restore block");
+
+    let placements = repo.get_by_canvas(canvas.id, &mut *tx).await?;
+    assert_eq!(placements.len(), 2);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_save_many<'a, A, R, CR, BR, DB>(
+    repo: &R,
+    canvas_repo: &CR,
+    block_repo: &BR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    R: CanvasBlockRepository<DB>,
+    CR: CanvasRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let (canvas, blocks) = seed_canvas(canvas_repo, block_repo, 2, &mut tx).await;
+
+    let mut placements: Vec<CanvasBlock> = blocks
+        .iter()
+        .map(|b| CanvasBlock::new(canvas.id, b.id, 0, 0, 2, 2))
+        .collect();
+    repo.save_many(&placements, &mut *tx).await?;
+
+    for (i, placement) in placements.iter_mut().enumerate() {
+        placement.grid_x = 10 + i as i32;
+        placement.grid_y = 20;
+    }
+    repo.save_many(&placements, &mut *tx).await?;
+
+    let fetched = repo.get_by_canvas(canvas.id, &mut *tx).await?;
+    assert_eq!(fetched.len(), 2);
+    for placement in &placements {
+        let saved = fetched
+            .iter()
+            .find(|p| p.block_id == placement.block_id)
+            .expect("placement should be saved");
+        assert_eq!(saved.grid_x, placement.grid_x);
+        assert_eq!(saved.grid_y, 20);
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_delete_missing<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: CanvasBlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let missing_canvas_id = Uuid::new_v4();
+    let missing_block_id = Uuid::new_v4();
+    let err = repo
+        .delete(missing_canvas_id, missing_block_id, &mut *tx)
+        .await
+        .expect_err("deleting a missing placement should error");
+
+    match err {
+        CanvasBlockRepositoryError::NotFound {
+            canvas_id,
+            block_id,
+        } => {
+            assert_eq!(canvas_id, missing_canvas_id);
+            assert_eq!(block_id, missing_block_id);
+        }
+        other => {
+            return Err(other);
+        }
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
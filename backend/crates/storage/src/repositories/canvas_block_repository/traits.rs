@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use sqlx::{Acquire, Database, Executor};
+use uuid::Uuid;
+
+use super::error::CanvasBlockRepositoryResult as Result;
+use domain::canvases::CanvasBlock;
+
+#[async_trait]
+pub trait CanvasBlockRepository<DB: Database>: Send + Sync {
+    /// Returns `None` when the block is not placed on the canvas or is in
+    /// the trash.
+    async fn get_by_canvas_and_block<'e, E>(
+        &self,
+        canvas_id: Uuid,
+        block_id: Uuid,
+        executor: E,
+    ) -> Result<Option<CanvasBlock>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Returns the placements on a canvas, bottom-most (lowest z) first.
+    /// Placements of trashed blocks are left out until the block is restored.
+    async fn get_by_canvas<'e, E>(&self, canvas_id: Uuid, executor: E) -> Result<Vec<CanvasBlock>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn delete<'e, E>(&self, canvas_id: Uuid, block_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn save<'e, E>(&self, canvas_block: &CanvasBlock, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Saves all placements in a single transaction.
+    async fn save_many<'e, E>(&self, canvas_blocks: &[CanvasBlock], executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+}
@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct PinnedCanvasDto {
+    pub canvas_id: Uuid,
+    pub name: String,
+    pub pinned_at: DateTime<Utc>,
+}
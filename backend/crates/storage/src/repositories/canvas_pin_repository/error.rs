@@ -0,0 +1,12 @@
+use uuid::Uuid;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CanvasPinRepositoryError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Canvas is not pinned: {canvas_id}")]
+    NotPinned { canvas_id: Uuid },
+}
+
+pub type CanvasPinRepositoryResult<T> = Result<T, CanvasPinRepositoryError>;
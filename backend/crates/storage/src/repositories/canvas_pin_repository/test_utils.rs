@@ -0,0 +1,120 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use sqlx::{Acquire, Database, Executor};
+
+use super::{
+    error::CanvasPinRepositoryError, error::CanvasPinRepositoryResult as Result,
+    traits::CanvasPinRepository,
+};
+use crate::repositories::CanvasRepository;
+use domain::canvases::Canvas;
+
+pub async fn assert_pin_unpin<'a, A, R, CR, DB>(repo: &R, canvas_repo: &CR, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: CanvasPinRepository<DB>,
+    CR: CanvasRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let canvas = Canvas::new("Pinned");
+    canvas_repo
+        .save(&canvas, &mut *tx)
+        .await
+        .expect("failed to seed canvas");
+
+    assert!(!repo.is_pinned(canvas.id, &mut *tx).await?);
+
+    repo.pin(canvas.id, &mut *tx).await?;
+    repo.pin(canvas.id, &mut *tx).await?;
+    assert!(repo.is_pinned(canvas.id, &mut *tx).await?);
+
+    // Saving the canvas again must not drop its pin
+    canvas_repo
+        .save(&canvas, &mut *tx)
+        .await
+        .expect("failed to re-save canvas");
+    assert!(repo.is_pinned(canvas.id, &mut *tx).await?);
+
+    let pinned = repo.get_all(&mut *tx).await?;
+    let entry = pinned
+        .iter()
+        .find(|p| p.canvas_id == canvas.id)
+        .expect("pinned canvas should be listed");
+    assert_eq!(entry.name, canvas.name);
+
+    repo.unpin(canvas.id, &mut *tx).await?;
+    assert!(!repo.is_pinned(canvas.id, &mut *tx).await?);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_unpin_missing<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: CanvasPinRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let missing_id = Uuid::new_v4();
+    let err = repo
+        .unpin(missing_id, &mut *tx)
+        .await
+        .expect_err("unpinning a canvas that is not pinned should error");
+
+    match err {
+        CanvasPinRepositoryError::NotPinned { canvas_id } => {
+            assert_eq!(canvas_id, missing_id);
+        }
+        other => {
+            return Err(other);
+        }
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_pin_at<'a, A, R, CR, DB>(repo: &R, canvas_repo: &CR, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: CanvasPinRepository<DB>,
+    CR: CanvasRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let canvas = Canvas::new("Pinned");
+    canvas_repo
+        .save(&canvas, &mut *tx)
+        .await
+        .expect("failed to seed canvas");
+
+    let pinned_at: DateTime<Utc> = "2024-03-01T12:00:00Z".parse().unwrap();
+    repo.pin_at(canvas.id, pinned_at, &mut *tx).await?;
+    // An existing pin keeps its time
+    repo.pin(canvas.id, &mut *tx).await?;
+
+    let pinned = repo.get_all(&mut *tx).await?;
+    let entry = pinned
+        .iter()
+        .find(|p| p.canvas_id == canvas.id)
+        .expect("pinned canvas should be listed");
+    assert_eq!(entry.pinned_at, pinned_at);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Database, Executor};
+use uuid::Uuid;
+
+use super::dtos::PinnedCanvasDto;
+use super::error::CanvasPinRepositoryResult as Result;
+
+#[async_trait]
+pub trait CanvasPinRepository<DB: Database>: Send + Sync {
+    /// Pins the canvas. Pinning an already pinned canvas keeps its original `pinned_at`.
+    async fn pin<'e, E>(&self, canvas_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Pins the canvas with the given `pinned_at`, for pins restored from an
+    /// archive. Like `pin`, leaves an existing pin alone.
+    async fn pin_at<'e, E>(
+        &self,
+        canvas_id: Uuid,
+        pinned_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn unpin<'e, E>(&self, canvas_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn is_pinned<'e, E>(&self, canvas_id: Uuid, executor: E) -> Result<bool>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Returns pinned canvass, most recently pinned first.
+    async fn get_all<'e, E>(&self, executor: E) -> Result<Vec<PinnedCanvasDto>>
+    where
+        E: Executor<'e, Database = DB>;
+}
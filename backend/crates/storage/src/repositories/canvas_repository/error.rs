@@ -0,0 +1,12 @@
+use uuid::Uuid;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CanvasRepositoryError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Canvas not found: {id}")]
+    NotFound { id: Uuid },
+}
+
+pub type CanvasRepositoryResult<T> = Result<T, CanvasRepositoryError>;
@@ -0,0 +1,8 @@
+mod error;
+mod traits;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
+pub use error::{CanvasRepositoryError, CanvasRepositoryResult};
+pub use traits::CanvasRepository;
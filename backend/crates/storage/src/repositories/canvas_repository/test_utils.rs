@@ -0,0 +1,107 @@
+use uuid::Uuid;
+
+use sqlx::{Acquire, Database, Executor};
+
+use super::{
+    error::CanvasRepositoryError, error::CanvasRepositoryResult as Result, traits::CanvasRepository,
+};
+use domain::canvases::Canvas;
+
+pub async fn assert_get_delete_save<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: CanvasRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let mut created_canvas = Canvas::new("Canvas");
+    let canvas_id = created_canvas.id;
+
+    repo.save(&created_canvas, &mut *tx).await?;
+
+    let fetched = repo.get_by_id(canvas_id, &mut *tx).await?;
+    let fetched = fetched.expect("created canvas should be retrievable");
+    assert_eq!(fetched.id, created_canvas.id);
+    assert_eq!(fetched.name, created_canvas.name);
+    assert_eq!(fetched.zoom_level, 1.0);
+
+    created_canvas.name = "Renamed".to_string();
+    created_canvas.viewport_x = 120.5;
+    created_canvas.viewport_y = -40.0;
+    created_canvas.zoom_level = 1.75;
+    repo.save(&created_canvas, &mut *tx).await?;
+
+    let fetched = repo.get_by_id(canvas_id, &mut *tx).await?;
+    let fetched = fetched.expect("updated canvas should be retrievable");
+    assert_eq!(fetched.name, created_canvas.name);
+    assert_eq!(fetched.viewport_x, created_canvas.viewport_x);
+    assert_eq!(fetched.viewport_y, created_canvas.viewport_y);
+    assert_eq!(fetched.zoom_level, created_canvas.zoom_level);
+
+    repo.delete_by_id(canvas_id, &mut *tx).await?;
+    let fetched = repo.get_by_id(canvas_id, &mut *tx).await?;
+    assert!(fetched.is_none());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_get_all<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: CanvasRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let first = Canvas::new("First");
+    let second = Canvas::new("Second");
+
+    repo.save(&first, &mut *tx).await?;
+    repo.save(&second, &mut *tx).await?;
+
+    let canvases = repo.get_all(&mut *tx).await?;
+    let ids: Vec<Uuid> = canvases.iter().map(|c| c.id).collect();
+    assert!(ids.contains(&first.id));
+    assert!(ids.contains(&second.id));
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_delete_missing<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: CanvasRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let missing_id = Uuid::new_v4();
+    let err = repo
+        .delete_by_id(missing_id, &mut *tx)
+        .await
+        .expect_err("deleting a missing canvas should error");
+
+    match err {
+        CanvasRepositoryError::NotFound { id } => {
+            assert_eq!(id, missing_id);
+        }
+        other => {
+            return Err(other);
+        }
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use sqlx::{Database, Executor};
+use uuid::Uuid;
+
+use super::error::CanvasRepositoryResult as Result;
+use domain::canvases::Canvas;
+
+#[async_trait]
+pub trait CanvasRepository<DB: Database>: Send + Sync {
+    async fn get_by_id<'e, E>(&self, id: Uuid, executor: E) -> Result<Option<Canvas>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Returns every canvas, most recently updated first.
+    async fn get_all<'e, E>(&self, executor: E) -> Result<Vec<Canvas>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn delete_by_id<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn save<'e, E>(&self, canvas: &Canvas, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+}
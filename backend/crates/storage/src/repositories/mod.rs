@@ -1,14 +1,22 @@
 pub mod block_directional_link_repository;
 // pub mod block_open_repository;
-// pub mod block_pin_repository;
+pub mod block_pin_repository;
 pub mod block_related_link_repository;
 pub mod block_repository;
 pub mod workspace_repository;
-// pub mod canvas_block_repository;
-// pub mod canvas_pin_repository;
-// pub mod canvas_repository;
+pub mod canvas_block_repository;
+pub mod canvas_pin_repository;
+pub mod canvas_repository;
+pub mod snapshot_repository;
+pub mod tag_repository;
 
 pub use block_directional_link_repository::BlockDirectionalLinkRepository;
+pub use block_pin_repository::BlockPinRepository;
 pub use block_related_link_repository::BlockRelatedLinkRepository;
 pub use block_repository::BlockRepository;
+pub use canvas_block_repository::CanvasBlockRepository;
+pub use canvas_pin_repository::CanvasPinRepository;
+pub use canvas_repository::CanvasRepository;
+pub use snapshot_repository::SnapshotRepository;
+pub use tag_repository::TagRepository;
 pub use workspace_repository::WorkspaceRepository;
@@ -0,0 +1,81 @@
+use sqlx::{Acquire, Database, Executor, Pool};
+
+use super::dtos::TableRowCountsDto;
+use super::error::{CopyInstanceError, CopyInstanceResult as Result};
+use super::traits::SnapshotRepository;
+use crate::helpers::block_directional_path_helper::BlockDirectionalPathHelper;
+
+/// Copies every row from `source_pool` into the empty `target_pool`, possibly
+/// on a different backend, then checks that each table holds as many rows on
+/// both sides and rebuilds the target's closure from the copied links. The
+/// target is written in one transaction, so a failed copy leaves it empty.
+pub async fn copy_instance<SDB, S, TDB, T, H>(
+    source: &S,
+    source_pool: &Pool<SDB>,
+    target: &T,
+    target_path_helper: &H,
+    target_pool: &Pool<TDB>,
+) -> Result<TableRowCountsDto>
+where
+    SDB: Database,
+    TDB: Database,
+    S: SnapshotRepository<SDB>,
+    T: SnapshotRepository<TDB>,
+    H: BlockDirectionalPathHelper<TDB>,
+    for<'c> &'c mut SDB::Connection: Executor<'c, Database = SDB> + Acquire<'c, Database = SDB>,
+    for<'c> &'c mut TDB::Connection: Executor<'c, Database = TDB> + Acquire<'c, Database = TDB>,
+{
+    let mut source_tx = source_pool
+        .begin()
+        .await
+        .map_err(|e| CopyInstanceError::Source(e.into()))?;
+    let snapshot = source
+        .read_snapshot(&mut *source_tx)
+        .await
+        .map_err(CopyInstanceError::Source)?;
+    let source_counts = source
+        .count_rows(&mut *source_tx)
+        .await
+        .map_err(CopyInstanceError::Source)?;
+    source_tx
+        .commit()
+        .await
+        .map_err(|e| CopyInstanceError::Source(e.into()))?;
+
+    let mut tx = target_pool
+        .begin()
+        .await
+        .map_err(|e| CopyInstanceError::Target(e.into()))?;
+
+    target
+        .write_snapshot(&snapshot, &mut *tx)
+        .await
+        .map_err(CopyInstanceError::Target)?;
+
+    let target_counts = target
+        .count_rows(&mut *tx)
+        .await
+        .map_err(CopyInstanceError::Target)?;
+
+    for ((table, source_count), (_, target_count)) in source_counts
+        .tables()
+        .into_iter()
+        .zip(target_counts.tables())
+    {
+        if source_count != target_count {
+            return Err(CopyInstanceError::RowCountMismatch {
+                table,
+                source_count,
+                target_count,
+            });
+        }
+    }
+
+    target_path_helper.rebuild(&mut *tx).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| CopyInstanceError::Target(e.into()))?;
+
+    Ok(target_counts)
+}
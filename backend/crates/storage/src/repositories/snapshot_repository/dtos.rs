@@ -0,0 +1,179 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockRowDto {
+    pub id: Uuid,
+    pub title: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub trashed_at: Option<DateTime<Utc>>,
+    pub version: i64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockRevisionRowDto {
+    pub block_id: Uuid,
+    pub revision: i64,
+    pub title: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirectionalLinkRowDto {
+    pub id: Uuid,
+    pub block_from_id: Uuid,
+    pub block_to_id: Uuid,
+    pub kind: Option<String>,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
+    pub position: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelatedLinkRowDto {
+    pub id: Uuid,
+    pub block_a_id: Uuid,
+    pub block_b_id: Uuid,
+    pub label: Option<String>,
+    pub weight: Option<f64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockPinRowDto {
+    pub block_id: Uuid,
+    pub pinned_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockOpenRowDto {
+    pub block_id: Uuid,
+    pub opened_at: DateTime<Utc>,
+    pub tab_index: i64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CanvasRowDto {
+    pub id: Uuid,
+    pub name: String,
+    pub viewport_x: f64,
+    pub viewport_y: f64,
+    pub zoom_level: f64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CanvasBlockRowDto {
+    pub id: Uuid,
+    pub canvas_id: Uuid,
+    pub block_id: Uuid,
+    pub grid_x: i64,
+    pub grid_y: i64,
+    pub grid_width: i64,
+    pub grid_height: i64,
+    pub z: i64,
+    pub scale: f64,
+    pub content_visible: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CanvasPinRowDto {
+    pub canvas_id: Uuid,
+    pub pinned_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TagRowDto {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockTagRowDto {
+    pub block_id: Uuid,
+    pub tag_id: Uuid,
+    pub tagged_at: DateTime<Utc>,
+}
+
+/// Every stored row except the derived ones (`block_directional_closure` and
+/// the search index), including trashed blocks and their links. Rows come
+/// back in a stable order so snapshots of equal instances compare equal.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InstanceSnapshotDto {
+    pub blocks: Vec<BlockRowDto>,
+    pub block_revisions: Vec<BlockRevisionRowDto>,
+    pub directional_links: Vec<DirectionalLinkRowDto>,
+    pub related_links: Vec<RelatedLinkRowDto>,
+    pub block_pins: Vec<BlockPinRowDto>,
+    pub block_opens: Vec<BlockOpenRowDto>,
+    pub canvases: Vec<CanvasRowDto>,
+    pub canvas_blocks: Vec<CanvasBlockRowDto>,
+    pub canvas_pins: Vec<CanvasPinRowDto>,
+    pub tags: Vec<TagRowDto>,
+    pub block_tags: Vec<BlockTagRowDto>,
+}
+
+impl InstanceSnapshotDto {
+    pub fn row_counts(&self) -> TableRowCountsDto {
+        TableRowCountsDto {
+            blocks: self.blocks.len() as i64,
+            block_revisions: self.block_revisions.len() as i64,
+            block_directional_links: self.directional_links.len() as i64,
+            block_related_links: self.related_links.len() as i64,
+            block_pins: self.block_pins.len() as i64,
+            block_opens: self.block_opens.len() as i64,
+            canvases: self.canvases.len() as i64,
+            canvas_blocks: self.canvas_blocks.len() as i64,
+            canvas_pins: self.canvas_pins.len() as i64,
+            tags: self.tags.len() as i64,
+            block_tags: self.block_tags.len() as i64,
+        }
+    }
+}
+
+/// Row counts of the tables an `InstanceSnapshotDto` covers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TableRowCountsDto {
+    pub blocks: i64,
+    pub block_revisions: i64,
+    pub block_directional_links: i64,
+    pub block_related_links: i64,
+    pub block_pins: i64,
+    pub block_opens: i64,
+    pub canvases: i64,
+    pub canvas_blocks: i64,
+    pub canvas_pins: i64,
+    pub tags: i64,
+    pub block_tags: i64,
+}
+
+impl TableRowCountsDto {
+    /// Table names paired with their counts, in insertion order.
+    pub fn tables(&self) -> [(&'static str, i64); 11] {
+        [
+            ("blocks", self.blocks),
+            ("block_revisions", self.block_revisions),
+            ("tags", self.tags),
+            ("block_tags", self.block_tags),
+            ("block_directional_links", self.block_directional_links),
+            ("block_related_links", self.block_related_links),
+            ("block_pins", self.block_pins),
+            ("block_opens", self.block_opens),
+            ("canvases", self.canvases),
+            ("canvas_blocks", self.canvas_blocks),
+            ("canvas_pins", self.canvas_pins),
+        ]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables().iter().all(|(_, count)| *count == 0)
+    }
+}
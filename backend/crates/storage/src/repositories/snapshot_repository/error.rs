@@ -0,0 +1,33 @@
+use crate::helpers::block_directional_path_helper::BlockDirectionalPathHelperError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum SnapshotRepositoryError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Target database already holds data - snapshots only restore into an empty one")]
+    TargetNotEmpty,
+}
+
+pub type SnapshotRepositoryResult<T> = Result<T, SnapshotRepositoryError>;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CopyInstanceError {
+    #[error("Source database error: {0}")]
+    Source(#[source] SnapshotRepositoryError),
+
+    #[error("Target database error: {0}")]
+    Target(#[source] SnapshotRepositoryError),
+
+    #[error("Path helper error: {0}")]
+    PathHelper(#[from] BlockDirectionalPathHelperError),
+
+    #[error("Row count mismatch in {table}: {source_count} in source, {target_count} in target")]
+    RowCountMismatch {
+        table: &'static str,
+        source_count: i64,
+        target_count: i64,
+    },
+}
+
+pub type CopyInstanceResult<T> = Result<T, CopyInstanceError>;
@@ -0,0 +1,14 @@
+mod copy;
+mod dtos;
+mod error;
+mod traits;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
+pub use copy::copy_instance;
+pub use dtos::*;
+pub use error::{
+    CopyInstanceError, CopyInstanceResult, SnapshotRepositoryError, SnapshotRepositoryResult,
+};
+pub use traits::SnapshotRepository;
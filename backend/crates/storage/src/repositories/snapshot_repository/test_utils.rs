@@ -0,0 +1,331 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use sqlx::{Acquire, Database, Executor, Pool};
+
+use super::copy::copy_instance;
+use super::dtos::*;
+use super::error::{
+    CopyInstanceError, CopyInstanceResult, SnapshotRepositoryError,
+    SnapshotRepositoryResult as Result,
+};
+use super::traits::SnapshotRepository;
+use crate::helpers::block_directional_path_helper::BlockDirectionalPathHelper;
+use crate::repositories::BlockRepository;
+use domain::blocks::Block;
+
+fn at(seconds: i64) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+        + Duration::seconds(seconds)
+}
+
+fn id(n: u128) -> Uuid {
+    Uuid::from_u128(n)
+}
+
+fn block(n: u128, title: &str, version: i64, trashed_at: Option<DateTime<Utc>>) -> BlockRowDto {
+    BlockRowDto {
+        id: id(n),
+        title: title.to_string(),
+        content: format!("{title} content"),
+        created_at: at(0),
+        updated_at: at(version * 10),
+        trashed_at,
+        version,
+    }
+}
+
+fn revision(block_id: u128, revision: i64, title: &str) -> BlockRevisionRowDto {
+    BlockRevisionRowDto {
+        block_id: id(block_id),
+        revision,
+        title: title.to_string(),
+        content: format!("{title} content"),
+        created_at: at(revision * 10),
+    }
+}
+
+fn directional_link(n: u128, from: u128, to: u128, position: i64) -> DirectionalLinkRowDto {
+    DirectionalLinkRowDto {
+        id: id(n),
+        block_from_id: id(from),
+        block_to_id: id(to),
+        kind: None,
+        label: None,
+        weight: None,
+        position,
+        created_at: at(100 + n as i64),
+    }
+}
+
+/// A small instance touching every covered table: a trashed block that keeps
+/// its links, a block with several revisions, annotated links, sibling
+/// positions, tags, workspace tabs, pins and a canvas. Rows are listed in the
+/// order `read_snapshot` returns them.
+pub fn sample_snapshot() -> InstanceSnapshotDto {
+    let (a, b, c, d) = (1, 2, 3, 4);
+    let (rust, notes) = (0x10, 0x11);
+    let canvas = 0x30;
+
+    InstanceSnapshotDto {
+        blocks: vec![
+            block(a, "a", 3, None),
+            block(b, "b", 1, None),
+            block(c, "c", 1, None),
+            block(d, "d", 1, Some(at(500))),
+        ],
+        block_revisions: vec![
+            revision(a, 1, "a draft"),
+            revision(a, 2, "a second draft"),
+            revision(a, 3, "a"),
+            revision(b, 1, "b"),
+            revision(c, 1, "c"),
+            revision(d, 1, "d"),
+        ],
+        directional_links: vec![
+            DirectionalLinkRowDto {
+                kind: Some("part-of".to_string()),
+                label: Some("chapter".to_string()),
+                weight: Some(0.5),
+                ..directional_link(0x20, a, b, 0)
+            },
+            directional_link(0x21, a, c, 1),
+            directional_link(0x22, b, c, 0),
+            directional_link(0x23, c, d, 0),
+        ],
+        related_links: vec![RelatedLinkRowDto {
+            id: id(0x28),
+            block_a_id: id(a),
+            block_b_id: id(c),
+            label: Some("see also".to_string()),
+            weight: Some(2.0),
+            created_at: at(200),
+        }],
+        block_pins: vec![BlockPinRowDto {
+            block_id: id(a),
+            pinned_at: at(300),
+        }],
+        block_opens: vec![
+            BlockOpenRowDto {
+                block_id: id(a),
+                opened_at: at(301),
+                tab_index: 1,
+            },
+            BlockOpenRowDto {
+                block_id: id(c),
+                opened_at: at(302),
+                tab_index: 0,
+            },
+        ],
+        canvases: vec![CanvasRowDto {
+            id: id(canvas),
+            name: "Board".to_string(),
+            viewport_x: 12.5,
+            viewport_y: -4.0,
+            zoom_level: 1.5,
+            created_at: at(400),
+            updated_at: at(410),
+        }],
+        canvas_blocks: vec![
+            CanvasBlockRowDto {
+                id: id(0x40),
+                canvas_id: id(canvas),
+                block_id: id(a),
+                grid_x: 0,
+                grid_y: 0,
+                grid_width: 4,
+                grid_height: 3,
+                z: 0,
+                scale: 1.0,
+                content_visible: true,
+                created_at: at(401),
+                updated_at: at(401),
+            },
+            CanvasBlockRowDto {
+                id: id(0x41),
+                canvas_id: id(canvas),
+                block_id: id(b),
+                grid_x: -2,
+                grid_y: 5,
+                grid_width: 2,
+                grid_height: 2,
+                z: 1,
+                scale: 0.75,
+                content_visible: false,
+                created_at: at(402),
+                updated_at: at(405),
+            },
+        ],
+        canvas_pins: vec![CanvasPinRowDto {
+            canvas_id: id(canvas),
+            pinned_at: at(420),
+        }],
+        tags: vec![
+            TagRowDto {
+                id: id(rust),
+                name: "Rust".to_string(),
+                created_at: at(50),
+            },
+            TagRowDto {
+                id: id(notes),
+                name: "notes".to_string(),
+                created_at: at(51),
+            },
+        ],
+        block_tags: vec![
+            BlockTagRowDto {
+                block_id: id(a),
+                tag_id: id(rust),
+                tagged_at: at(60),
+            },
+            BlockTagRowDto {
+                block_id: id(b),
+                tag_id: id(rust),
+                tagged_at: at(61),
+            },
+            BlockTagRowDto {
+                block_id: id(b),
+                tag_id: id(notes),
+                tagged_at: at(62),
+            },
+        ],
+    }
+}
+
+pub async fn assert_count_rows_empty<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: SnapshotRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let counts = repo.count_rows(&mut *tx).await?;
+    assert!(counts.is_empty());
+    assert_eq!(
+        repo.read_snapshot(&mut *tx).await?,
+        InstanceSnapshotDto::default()
+    );
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_write_read_round_trip<'a, A, R, DB>(repo: &R, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: SnapshotRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let snapshot = sample_snapshot();
+    repo.write_snapshot(&snapshot, &mut *tx).await?;
+
+    // Ids, timestamps, versions, revision history and positions survive as is
+    assert_eq!(repo.read_snapshot(&mut *tx).await?, snapshot);
+    assert_eq!(repo.count_rows(&mut *tx).await?, snapshot.row_counts());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_write_into_non_empty_target<'a, A, R, BR, DB>(
+    repo: &R,
+    block_repo: &BR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    R: SnapshotRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let existing = Block::new("Existing", "content");
+    block_repo
+        .save(&existing, &mut *tx)
+        .await
+        .expect("failed to seed block");
+
+    let err = repo
+        .write_snapshot(&sample_snapshot(), &mut *tx)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, SnapshotRepositoryError::TargetNotEmpty));
+
+    let counts = repo.count_rows(&mut *tx).await?;
+    assert_eq!(counts.blocks, 1);
+    assert_eq!(counts.block_directional_links, 0);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+/// Copies `sample_snapshot` from one fresh instance to another. Both pools
+/// must point at empty, migrated databases the test owns, since the copy
+/// commits.
+pub async fn assert_copy_instance<SDB, S, TDB, T, H>(
+    source: &S,
+    source_pool: &Pool<SDB>,
+    target: &T,
+    target_path_helper: &H,
+    target_pool: &Pool<TDB>,
+) -> CopyInstanceResult<()>
+where
+    SDB: Database,
+    TDB: Database,
+    S: SnapshotRepository<SDB>,
+    T: SnapshotRepository<TDB>,
+    H: BlockDirectionalPathHelper<TDB>,
+    for<'c> &'c mut SDB::Connection: Executor<'c, Database = SDB> + Acquire<'c, Database = SDB>,
+    for<'c> &'c mut TDB::Connection: Executor<'c, Database = TDB> + Acquire<'c, Database = TDB>,
+{
+    let snapshot = sample_snapshot();
+    source
+        .write_snapshot(&snapshot, source_pool)
+        .await
+        .map_err(CopyInstanceError::Source)?;
+
+    let counts =
+        copy_instance(source, source_pool, target, target_path_helper, target_pool).await?;
+    assert_eq!(counts, snapshot.row_counts());
+
+    let copied = target
+        .read_snapshot(target_pool)
+        .await
+        .map_err(CopyInstanceError::Target)?;
+    assert_eq!(copied, snapshot);
+
+    let report = target_path_helper.check_integrity(target_pool).await?;
+    assert!(report.is_consistent());
+
+    // The target is no longer empty, so a second copy is refused and leaves
+    // it untouched
+    let err = copy_instance(source, source_pool, target, target_path_helper, target_pool)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        CopyInstanceError::Target(SnapshotRepositoryError::TargetNotEmpty)
+    ));
+    let counts = target
+        .count_rows(target_pool)
+        .await
+        .map_err(CopyInstanceError::Target)?;
+    assert_eq!(counts, snapshot.row_counts());
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use sqlx::{Acquire, Database, Executor};
+
+use super::dtos::{InstanceSnapshotDto, TableRowCountsDto};
+use super::error::SnapshotRepositoryResult as Result;
+
+/// Reads and writes whole instances row for row, keeping ids, timestamps,
+/// versions, revisions and sibling positions. Used to move an instance
+/// between backends; regular writes go through the other repositories.
+#[async_trait]
+pub trait SnapshotRepository<DB: Database>: Send + Sync {
+    async fn count_rows<'e, E>(&self, executor: E) -> Result<TableRowCountsDto>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn read_snapshot<'e, E>(&self, executor: E) -> Result<InstanceSnapshotDto>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
+    /// Inserts every row of the snapshot. Fails with `TargetNotEmpty` unless
+    /// all covered tables are empty. Leaves `block_directional_closure` alone,
+    /// so callers rebuild it afterwards.
+    async fn write_snapshot<'e, E>(
+        &self,
+        snapshot: &InstanceSnapshotDto,
+        executor: E,
+    ) -> Result<()>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+}
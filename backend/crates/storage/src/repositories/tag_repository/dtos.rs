@@ -0,0 +1,15 @@
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct TagCountDto {
+    pub id: Uuid,
+    pub name: String,
+    /// Number of live blocks carrying the tag
+    pub block_count: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct BlockTagDto {
+    pub block_id: Uuid,
+    pub tag_name: String,
+}
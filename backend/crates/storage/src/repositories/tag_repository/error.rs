@@ -0,0 +1,24 @@
+use uuid::Uuid;
+
+#[derive(thiserror::Error, Debug)]
+pub enum TagRepositoryError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Tag not found: {id}")]
+    NotFound { id: Uuid },
+
+    #[error("Tag already exists: {name}")]
+    AlreadyExists { name: String },
+
+    #[error("Block not found: {block_id}")]
+    BlockNotFound { block_id: Uuid },
+
+    #[error("Block {block_id} is not tagged with {tag_id}")]
+    NotTagged { block_id: Uuid, tag_id: Uuid },
+
+    #[error("Cannot merge tag into itself: {id}")]
+    SelfMerge { id: Uuid },
+}
+
+pub type TagRepositoryResult<T> = Result<T, TagRepositoryError>;
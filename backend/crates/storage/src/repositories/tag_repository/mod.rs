@@ -0,0 +1,10 @@
+mod dtos;
+mod error;
+mod traits;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
+pub use dtos::*;
+pub use error::{TagRepositoryError, TagRepositoryResult};
+pub use traits::TagRepository;
@@ -0,0 +1,250 @@
+use uuid::Uuid;
+
+use sqlx::{Acquire, Database, Executor};
+
+use super::{
+    error::TagRepositoryError, error::TagRepositoryResult as Result, traits::TagRepository,
+};
+use crate::repositories::BlockRepository;
+use domain::blocks::Block;
+use domain::tags::Tag;
+
+pub async fn assert_tag_untag<'a, A, R, BR, DB>(repo: &R, block_repo: &BR, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: TagRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let block = Block::new("Tagged", "content");
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to seed block");
+
+    // Stored names are normally lowercase already; the backend still
+    // matches names written before that in any case
+    let rust = Tag {
+        name: "Rust".to_string(),
+        ..Tag::new("rust").unwrap()
+    };
+    let rust = repo.tag_block(block.id, &rust, &mut *tx).await?;
+
+    // Reusing a name in another case resolves to the existing tag
+    let again = repo
+        .tag_block(block.id, &Tag::new("rust").unwrap(), &mut *tx)
+        .await?;
+    assert_eq!(again.id, rust.id);
+    assert_eq!(again.name, "Rust");
+
+    repo.tag_block(block.id, &Tag::new("async").unwrap(), &mut *tx)
+        .await?;
+
+    let tags = repo.get_by_block(block.id, &mut *tx).await?;
+    let names: Vec<&str> = tags.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["async", "Rust"]);
+
+    // Saving the block again must not drop its tags
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to re-save block");
+    assert_eq!(repo.get_by_block(block.id, &mut *tx).await?.len(), 2);
+
+    repo.untag_block(block.id, rust.id, &mut *tx).await?;
+    let tags = repo.get_by_block(block.id, &mut *tx).await?;
+    assert_eq!(tags.len(), 1);
+
+    let err = repo
+        .untag_block(block.id, rust.id, &mut *tx)
+        .await
+        .expect_err("removing a missing tag should error");
+    assert!(matches!(err, TagRepositoryError::NotTagged { .. }));
+
+    let fetched = repo
+        .get_by_id(rust.id, &mut *tx)
+        .await?
+        .expect("untagged tag should still exist");
+    assert_eq!(fetched.name, "Rust");
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_counts_skip_trashed<'a, A, R, BR, DB>(
+    repo: &R,
+    block_repo: &BR,
+    conn: A,
+) -> Result<()>
+where
+    DB: Database,
+    R: TagRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let live = Block::new("Live", "content");
+    let trashed = Block::new("Trashed", "content");
+    for block in [&live, &trashed] {
+        block_repo
+            .save(block, &mut *tx)
+            .await
+            .expect("failed to seed block");
+    }
+
+    let name = format!("counted-{}", Uuid::new_v4());
+    let tag = repo
+        .tag_block(live.id, &Tag::new(&name).unwrap(), &mut *tx)
+        .await?;
+    repo.tag_block(trashed.id, &tag, &mut *tx).await?;
+    let unused = repo
+        .tag_block(
+            live.id,
+            &Tag::new(&format!("unused-{name}")).unwrap(),
+            &mut *tx,
+        )
+        .await?;
+    repo.untag_block(live.id, unused.id, &mut *tx).await?;
+
+    block_repo
+        .trash(trashed.id, &mut *tx)
+        .await
+        .expect("failed to trash block");
+
+    let counts = repo.get_all_with_counts(&mut *tx).await?;
+    let count_of = |id: Uuid| {
+        counts
+            .iter()
+            .find(|c| c.id == id)
+            .map(|c| c.block_count)
+            .expect("tag should be listed")
+    };
+    assert_eq!(count_of(tag.id), 1);
+    assert_eq!(count_of(unused.id), 0);
+
+    let assignments = repo.get_all_block_tags(&mut *tx).await?;
+    assert!(
+        assignments
+            .iter()
+            .any(|a| a.block_id == live.id && a.tag_name == name)
+    );
+    assert!(!assignments.iter().any(|a| a.block_id == trashed.id));
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_rename<'a, A, R, BR, DB>(repo: &R, block_repo: &BR, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: TagRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let block = Block::new("Tagged", "content");
+    block_repo
+        .save(&block, &mut *tx)
+        .await
+        .expect("failed to seed block");
+
+    let todo = repo
+        .tag_block(block.id, &Tag::new("todo").unwrap(), &mut *tx)
+        .await?;
+    repo.tag_block(block.id, &Tag::new("done").unwrap(), &mut *tx)
+        .await?;
+
+    let renamed = repo.rename(todo.id, "TODO", &mut *tx).await?;
+    assert_eq!(renamed.id, todo.id);
+    assert_eq!(renamed.name, "TODO");
+
+    let missing_id = Uuid::new_v4();
+    let err = repo
+        .rename(missing_id, "anything", &mut *tx)
+        .await
+        .expect_err("renaming a missing tag should error");
+    match err {
+        TagRepositoryError::NotFound { id } => assert_eq!(id, missing_id),
+        other => return Err(other),
+    }
+
+    // Postgres aborts the transaction on the unique violation, so check it last
+    let err = repo
+        .rename(todo.id, "Done", &mut *tx)
+        .await
+        .expect_err("renaming onto another tag's name should error");
+    assert!(matches!(err, TagRepositoryError::AlreadyExists { .. }));
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+pub async fn assert_merge<'a, A, R, BR, DB>(repo: &R, block_repo: &BR, conn: A) -> Result<()>
+where
+    DB: Database,
+    R: TagRepository<DB>,
+    BR: BlockRepository<DB>,
+    A: Acquire<'a, Database = DB>,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB> + Acquire<'c, Database = DB>,
+{
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let both = Block::new("Both", "content");
+    let source_only = Block::new("Source only", "content");
+    for block in [&both, &source_only] {
+        block_repo
+            .save(block, &mut *tx)
+            .await
+            .expect("failed to seed block");
+    }
+
+    let source = repo
+        .tag_block(both.id, &Tag::new("js").unwrap(), &mut *tx)
+        .await?;
+    repo.tag_block(source_only.id, &source, &mut *tx).await?;
+    let target = repo
+        .tag_block(both.id, &Tag::new("javascript").unwrap(), &mut *tx)
+        .await?;
+
+    let err = repo
+        .merge(target.id, target.id, &mut *tx)
+        .await
+        .expect_err("merging a tag into itself should error");
+    assert!(matches!(err, TagRepositoryError::SelfMerge { .. }));
+
+    repo.merge(source.id, target.id, &mut *tx).await?;
+
+    assert!(repo.get_by_id(source.id, &mut *tx).await?.is_none());
+    for block_id in [both.id, source_only.id] {
+        let tags = repo.get_by_block(block_id, &mut *tx).await?;
+        let ids: Vec<Uuid> = tags.iter().map(|t| t.id).collect();
+        assert_eq!(ids, [target.id]);
+    }
+
+    let err = repo
+        .merge(source.id, target.id, &mut *tx)
+        .await
+        .expect_err("merging a deleted tag should error");
+    match err {
+        TagRepositoryError::NotFound { id } => assert_eq!(id, source.id),
+        other => return Err(other),
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
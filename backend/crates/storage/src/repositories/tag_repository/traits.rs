@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use sqlx::{Acquire, Database, Executor};
+use uuid::Uuid;
+
+use super::dtos::{BlockTagDto, TagCountDto};
+use super::error::TagRepositoryResult as Result;
+use domain::tags::Tag;
+
+#[async_trait]
+pub trait TagRepository<DB: Database>: Send + Sync {
+    async fn get_by_id<'e, E>(&self, id: Uuid, executor: E) -> Result<Option<Tag>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Returns every tag with the number of live blocks using it, ordered by name.
+    async fn get_all_with_counts<'e, E>(&self, executor: E) -> Result<Vec<TagCountDto>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Returns the tags of a block, ordered by name.
+    async fn get_by_block<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<Tag>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Returns every tag assignment on live blocks, for export.
+    async fn get_all_block_tags<'e, E>(&self, executor: E) -> Result<Vec<BlockTagDto>>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Tags the block with the tag named `tag.name`, creating `tag` if no tag
+    /// with that name exists yet. Tagging twice is a no-op. Returns the stored tag.
+    async fn tag_block<'e, E>(&self, block_id: Uuid, tag: &Tag, executor: E) -> Result<Tag>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+
+    async fn untag_block<'e, E>(&self, block_id: Uuid, tag_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB>;
+
+    async fn rename<'e, E>(&self, id: Uuid, name: &str, executor: E) -> Result<Tag>
+    where
+        E: Executor<'e, Database = DB>;
+
+    /// Moves every block from `source_id` to `target_id` and deletes the source tag.
+    async fn merge<'e, E>(&self, source_id: Uuid, target_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = DB> + Acquire<'e, Database = DB>;
+}
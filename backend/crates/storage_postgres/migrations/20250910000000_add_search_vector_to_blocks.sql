@@ -0,0 +1,8 @@
+-- Full-text search vector over blocks; titles are weighted above contents
+ALTER TABLE blocks
+ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', coalesce(title, '')), 'A') ||
+    setweight(to_tsvector('simple', coalesce(content, '')), 'B')
+) STORED;
+
+CREATE INDEX idx_blocks_search_vector ON blocks USING GIN (search_vector);
@@ -0,0 +1,39 @@
+-- Snapshot of every saved title/content, written by a trigger on blocks
+CREATE TABLE IF NOT EXISTS block_revisions (
+    block_id UUID NOT NULL,
+    revision BIGINT NOT NULL,
+    title TEXT NOT NULL,
+    content TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (block_id, revision),
+    FOREIGN KEY (block_id) REFERENCES blocks (id)
+        ON DELETE CASCADE
+);
+
+INSERT INTO block_revisions (block_id, revision, title, content, created_at)
+SELECT id, 1, title, content, updated_at FROM blocks;
+
+CREATE FUNCTION record_block_revision() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'UPDATE'
+        AND OLD.title IS NOT DISTINCT FROM NEW.title
+        AND OLD.content IS NOT DISTINCT FROM NEW.content THEN
+        RETURN NEW;
+    END IF;
+
+    INSERT INTO block_revisions (block_id, revision, title, content, created_at)
+    VALUES (
+        NEW.id,
+        (SELECT COALESCE(MAX(revision), 0) + 1 FROM block_revisions WHERE block_id = NEW.id),
+        NEW.title,
+        NEW.content,
+        NEW.updated_at
+    );
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER block_revisions_after_save
+AFTER INSERT OR UPDATE OF title, content ON blocks
+FOR EACH ROW EXECUTE FUNCTION record_block_revision();
@@ -0,0 +1,4 @@
+-- Trashed blocks keep their links but are hidden until restored or purged
+ALTER TABLE blocks ADD COLUMN trashed_at TIMESTAMPTZ;
+
+CREATE INDEX idx_blocks_trashed_at ON blocks (trashed_at);
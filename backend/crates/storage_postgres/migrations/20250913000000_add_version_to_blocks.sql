@@ -0,0 +1,2 @@
+-- Incremented on every save so clients can detect concurrent edits
+ALTER TABLE blocks ADD COLUMN version BIGINT NOT NULL DEFAULT 1;
@@ -0,0 +1,19 @@
+-- Tags are unique ignoring case and attach to blocks many-to-many
+CREATE TABLE IF NOT EXISTS tags (
+    id UUID PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE UNIQUE INDEX idx_tags_name_lower ON tags (lower(name));
+
+CREATE TABLE IF NOT EXISTS block_tags (
+    block_id UUID NOT NULL,
+    tag_id UUID NOT NULL,
+    tagged_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (block_id, tag_id),
+    FOREIGN KEY (block_id) REFERENCES blocks (id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tags (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_block_tags_tag_id ON block_tags (tag_id);
@@ -0,0 +1,9 @@
+-- Optional kind on directional links and label/weight on both link types
+ALTER TABLE block_directional_links
+    ADD COLUMN kind TEXT,
+    ADD COLUMN label TEXT,
+    ADD COLUMN weight DOUBLE PRECISION;
+
+ALTER TABLE block_related_links
+    ADD COLUMN label TEXT,
+    ADD COLUMN weight DOUBLE PRECISION;
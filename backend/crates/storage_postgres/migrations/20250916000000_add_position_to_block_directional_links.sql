@@ -0,0 +1,16 @@
+-- Sibling order of children under the same parent
+ALTER TABLE block_directional_links ADD COLUMN position BIGINT NOT NULL DEFAULT 0;
+
+-- Existing children keep their creation order
+UPDATE block_directional_links bdl
+SET position = ranked.position
+FROM (
+    SELECT
+        id,
+        ROW_NUMBER() OVER (PARTITION BY block_from_id ORDER BY created_at, id) - 1 AS position
+    FROM block_directional_links
+) ranked
+WHERE bdl.id = ranked.id;
+
+CREATE INDEX IF NOT EXISTS idx_block_directional_links_block_from_id_position
+    ON block_directional_links (block_from_id, position);
@@ -0,0 +1,23 @@
+-- Store reachability once per (ancestor, descendant) pair instead of once per path.
+-- Paths are enumerated from the links on demand.
+CREATE TABLE IF NOT EXISTS block_directional_closure (
+    block_ancestor_id UUID NOT NULL,
+    block_descendant_id UUID NOT NULL,
+    path_count BIGINT NOT NULL,
+    min_depth BIGINT NOT NULL,
+    PRIMARY KEY (block_ancestor_id, block_descendant_id),
+    FOREIGN KEY (block_ancestor_id) REFERENCES blocks (id) ON DELETE CASCADE,
+    FOREIGN KEY (block_descendant_id) REFERENCES blocks (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_block_directional_closure_descendant
+    ON block_directional_closure (block_descendant_id);
+
+INSERT INTO block_directional_closure
+    (block_ancestor_id, block_descendant_id, path_count, min_depth)
+SELECT block_ancestor_id, block_descendant_id, COUNT(*), MIN(path_length) - 1
+FROM block_directional_paths
+WHERE block_ancestor_id != block_descendant_id
+GROUP BY block_ancestor_id, block_descendant_id;
+
+DROP TABLE block_directional_paths;
@@ -0,0 +1,3 @@
+-- Tag names are now lowercased by the domain before they are stored or looked up.
+-- The unique index on lower(name) rules out clashes.
+UPDATE tags SET name = lower(name);
@@ -1,13 +1,11 @@
-use std::collections::HashSet;
-
 use async_trait::async_trait;
-use chrono::Utc;
-use sqlx::{Acquire, Executor, Postgres};
+use sqlx::{Acquire, Executor, PgConnection, Postgres};
 use storage::helpers::block_directional_path_helper::{
     BlockDirectionalPathHelper, BlockDirectionalPathHelperError,
-    BlockDirectionalPathHelperResult as Result,
+    BlockDirectionalPathHelperResult as Result, ClosureRowDto, PathIntegrityReportDto,
+    compare_closure, derive_closure,
 };
-use storage::helpers::sqlx_error_kind_helpers::{is_foreign_key_violation, is_unique_violation};
+use storage::helpers::sqlx_error_kind_helpers::is_foreign_key_violation;
 use uuid::Uuid;
 
 #[derive(Clone, Debug, Default)]
@@ -31,7 +29,7 @@ impl BlockDirectionalPathHelper<Postgres> for PostgresBlockDirectionalPathHelper
         E: Executor<'e, Database = Postgres>,
     {
         let exist = sqlx::query_scalar!(
-            "SELECT EXISTS (SELECT 1 FROM block_directional_paths
+            "SELECT EXISTS (SELECT 1 FROM block_directional_closure
             WHERE block_ancestor_id = $1 AND block_descendant_id = $2)",
             ancestor_id,
             descendant_id,
@@ -55,32 +53,68 @@ impl BlockDirectionalPathHelper<Postgres> for PostgresBlockDirectionalPathHelper
         let mut conn = executor.acquire().await?;
         let mut tx = conn.begin().await?;
 
-        let direct_path = vec![from_id, to_id];
-        self.create_path(direct_path, &mut *tx).await?;
-
-        let from_id_ancestor_paths = self.get_paths_to_block(from_id, &mut *tx).await?;
-
-        for path in &from_id_ancestor_paths {
-            let mut extended_path = path.clone();
-            extended_path.push(to_id);
-            self.create_path(extended_path, &mut *tx).await?;
-        }
-
-        let to_id_descendant_paths = self.get_paths_from_block(to_id, &mut *tx).await?;
-
-        for path in &to_id_descendant_paths {
-            let mut extended_path = vec![from_id];
-            extended_path.extend(path.iter());
-            self.create_path(extended_path, &mut *tx).await?;
+        if from_id == to_id
+            || self
+                .is_ancestor_descendant(to_id, from_id, &mut *tx)
+                .await?
+        {
+            return Err(BlockDirectionalPathHelperError::CyclicPathCreation);
         }
 
-        for ancestor_path in &from_id_ancestor_paths {
-            for descendant_path in &to_id_descendant_paths {
-                let mut combined_path = ancestor_path.clone();
-                combined_path.extend(descendant_path.iter());
-                self.create_path(combined_path, &mut *tx).await?;
+        // Every ancestor of from_id (and from_id itself) now reaches every
+        // descendant of to_id (and to_id itself) through the new link. Counts
+        // saturate at i64::MAX instead of overflowing.
+        sqlx::query!(
+            "
+            WITH
+                ancestors(id, path_count, depth) AS (
+                    SELECT $1::uuid, 1::bigint, 0::bigint
+                    UNION ALL
+                    SELECT block_ancestor_id, path_count, min_depth
+                    FROM block_directional_closure
+                    WHERE block_descendant_id = $1
+                ),
+                descendants(id, path_count, depth) AS (
+                    SELECT $2::uuid, 1::bigint, 0::bigint
+                    UNION ALL
+                    SELECT block_descendant_id, path_count, min_depth
+                    FROM block_directional_closure
+                    WHERE block_ancestor_id = $2
+                )
+            INSERT INTO block_directional_closure
+                (block_ancestor_id, block_descendant_id, path_count, min_depth)
+            SELECT
+                a.id,
+                d.id,
+                CASE WHEN a.path_count > 9223372036854775807 / d.path_count
+                    THEN 9223372036854775807
+                    ELSE a.path_count * d.path_count
+                END,
+                a.depth + 1 + d.depth
+            FROM ancestors a CROSS JOIN descendants d
+            ON CONFLICT (block_ancestor_id, block_descendant_id) DO UPDATE SET
+                path_count = CASE
+                    WHEN block_directional_closure.path_count
+                        > 9223372036854775807 - EXCLUDED.path_count
+                    THEN 9223372036854775807
+                    ELSE block_directional_closure.path_count + EXCLUDED.path_count
+                END,
+                min_depth = LEAST(block_directional_closure.min_depth, EXCLUDED.min_depth)
+            ",
+            from_id,
+            to_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            if is_foreign_key_violation(&e) {
+                return BlockDirectionalPathHelperError::PathBlocksNotFound {
+                    from: from_id,
+                    to: to_id,
+                };
             }
-        }
+            BlockDirectionalPathHelperError::Database(e)
+        })?;
 
         tx.commit().await?;
 
@@ -94,137 +128,444 @@ impl BlockDirectionalPathHelper<Postgres> for PostgresBlockDirectionalPathHelper
         executor: E,
     ) -> Result<()>
     where
-        E: Executor<'e, Database = Postgres>,
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
     {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        // Links to trashed blocks never made it into the closure
+        if !self
+            .is_ancestor_descendant(from_id, to_id, &mut *tx)
+            .await?
+        {
+            return Ok(());
+        }
+
         sqlx::query!(
             "
-            DELETE FROM block_directional_paths
-            WHERE EXISTS (
-                SELECT 1
-                FROM generate_subscripts(block_path_ids, 1) AS idx
-                WHERE block_path_ids[idx] = $1
-                  AND idx < array_length(block_path_ids, 1)
-                  AND block_path_ids[idx + 1] = $2
-            )
+            WITH
+                ancestors(id, path_count) AS (
+                    SELECT $1::uuid, 1::bigint
+                    UNION ALL
+                    SELECT block_ancestor_id, path_count
+                    FROM block_directional_closure
+                    WHERE block_descendant_id = $1
+                ),
+                descendants(id, path_count) AS (
+                    SELECT $2::uuid, 1::bigint
+                    UNION ALL
+                    SELECT block_descendant_id, path_count
+                    FROM block_directional_closure
+                    WHERE block_ancestor_id = $2
+                )
+            UPDATE block_directional_closure AS target
+            SET path_count = CASE
+                WHEN target.path_count = 9223372036854775807 THEN target.path_count
+                ELSE target.path_count - a.path_count * d.path_count
+            END
+            FROM ancestors a, descendants d
+            WHERE target.block_ancestor_id = a.id
+              AND target.block_descendant_id = d.id
             ",
             from_id,
             to_id,
         )
-        .execute(executor)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM block_directional_closure
+            WHERE path_count <= 0
+              AND (block_descendant_id = $1 OR block_descendant_id IN (
+                SELECT block_descendant_id FROM block_directional_closure
+                WHERE block_ancestor_id = $1
+              ))
+            ",
+            to_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Children are settled before their parents, and from_id has the most
+        // ancestors of all the blocks above the link
+        let ancestors = sqlx::query!(
+            r#"
+            SELECT
+                c.block_ancestor_id as "id!",
+                c.min_depth as "depth!"
+            FROM block_directional_closure c
+            WHERE c.block_descendant_id = $1
+            ORDER BY (
+                SELECT COUNT(*) FROM block_directional_closure up
+                WHERE up.block_descendant_id = c.block_ancestor_id
+            ) DESC
+            "#,
+            from_id,
+        )
+        .fetch_all(&mut *tx)
         .await?;
 
+        let ancestor_ids = std::iter::once(from_id).chain(ancestors.iter().map(|a| a.id));
+        for ancestor_id in ancestor_ids {
+            Self::recount_saturated_rows(ancestor_id, to_id, Some(from_id), to_id, &mut tx).await?;
+        }
+
+        // Pairs whose shortest route ran through the link need a new one
+        self.refresh_depths_after_link_removal(from_id, 0, from_id, to_id, &mut tx)
+            .await?;
+        for ancestor in ancestors {
+            self.refresh_depths_after_link_removal(
+                ancestor.id,
+                ancestor.depth,
+                from_id,
+                to_id,
+                &mut tx,
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
         Ok(())
     }
 
     async fn delete_paths_using_block<'e, E>(&self, block_id: Uuid, executor: E) -> Result<()>
     where
-        E: Executor<'e, Database = Postgres>,
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
     {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
         sqlx::query!(
-            "DELETE FROM block_directional_paths
-            WHERE $1 = ANY(block_path_ids)",
+            "
+            UPDATE block_directional_closure AS target
+            SET path_count = CASE
+                WHEN target.path_count = 9223372036854775807 THEN target.path_count
+                ELSE target.path_count - up.path_count * down.path_count
+            END
+            FROM block_directional_closure up, block_directional_closure down
+            WHERE up.block_descendant_id = $1
+              AND down.block_ancestor_id = $1
+              AND target.block_ancestor_id = up.block_ancestor_id
+              AND target.block_descendant_id = down.block_descendant_id
+            ",
             block_id,
         )
-        .execute(executor)
+        .execute(&mut *tx)
         .await?;
 
+        sqlx::query!(
+            "
+            DELETE FROM block_directional_closure
+            WHERE path_count <= 0
+              AND block_descendant_id IN (
+                SELECT block_descendant_id FROM block_directional_closure
+                WHERE block_ancestor_id = $1
+              )
+            ",
+            block_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // The block's own rows stay until the end so the remaining pairs can
+        // still be matched against the depths that ran through it
+        let ancestors = sqlx::query!(
+            r#"
+            SELECT
+                c.block_ancestor_id as "id!",
+                c.min_depth as "depth!"
+            FROM block_directional_closure c
+            WHERE c.block_descendant_id = $1
+            ORDER BY (
+                SELECT COUNT(*) FROM block_directional_closure up
+                WHERE up.block_descendant_id = c.block_ancestor_id
+            ) DESC
+            "#,
+            block_id,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for ancestor in &ancestors {
+            Self::recount_saturated_rows(ancestor.id, block_id, None, block_id, &mut tx).await?;
+        }
+
+        for ancestor in ancestors {
+            sqlx::query!(
+                "
+                UPDATE block_directional_closure AS target
+                SET min_depth = (
+                    SELECT MIN(CASE
+                        WHEN l.block_to_id = target.block_descendant_id THEN 1
+                        ELSE 1 + c.min_depth
+                    END)
+                    FROM block_directional_links l
+                    LEFT JOIN block_directional_closure c
+                      ON c.block_ancestor_id = l.block_to_id
+                     AND c.block_descendant_id = target.block_descendant_id
+                    WHERE l.block_from_id = target.block_ancestor_id
+                      AND l.block_to_id != $3
+                      AND (l.block_to_id = target.block_descendant_id
+                           OR c.block_ancestor_id IS NOT NULL)
+                )
+                FROM block_directional_closure down
+                WHERE target.block_ancestor_id = $1
+                  AND down.block_ancestor_id = $3
+                  AND down.block_descendant_id = target.block_descendant_id
+                  AND target.min_depth = $2 + down.min_depth
+                ",
+                ancestor.id,
+                ancestor.depth,
+                block_id,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query!(
+            "DELETE FROM block_directional_closure
+            WHERE block_ancestor_id = $1 OR block_descendant_id = $1",
+            block_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
         Ok(())
     }
-}
 
-impl PostgresBlockDirectionalPathHelper {
-    async fn get_paths_from_block<'c, E>(
-        &self,
-        block_id: Uuid,
-        executor: E,
-    ) -> Result<Vec<Vec<Uuid>>>
+    async fn check_integrity<'e, E>(&self, executor: E) -> Result<PathIntegrityReportDto>
     where
-        E: Executor<'c, Database = Postgres>,
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
     {
-        let paths = sqlx::query_scalar!(
-            r#"SELECT block_path_ids as "block_path_ids: Vec<Uuid>"
-            FROM block_directional_paths
-            WHERE block_ancestor_id = $1"#,
-            block_id,
-        )
-        .fetch_all(executor)
-        .await?;
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let derived = derive_closure(&Self::get_live_links(&mut tx).await?);
+        let stored = Self::get_stored_rows(&mut tx).await?;
+
+        tx.commit().await?;
 
-        Ok(paths)
+        Ok(compare_closure(&derived, stored))
     }
 
-    async fn get_paths_to_block<'c, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<Vec<Uuid>>>
+    async fn rebuild<'e, E>(&self, executor: E) -> Result<PathIntegrityReportDto>
     where
-        E: Executor<'c, Database = Postgres>,
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
     {
-        let paths = sqlx::query_scalar!(
-            r#"SELECT block_path_ids as "block_path_ids: Vec<Uuid>"
-            FROM block_directional_paths
-            WHERE block_descendant_id = $1"#,
-            block_id,
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let derived = derive_closure(&Self::get_live_links(&mut tx).await?);
+        if !derived.cyclic_block_ids.is_empty() {
+            return Err(BlockDirectionalPathHelperError::CyclicLinks {
+                block_ids: derived.cyclic_block_ids,
+            });
+        }
+
+        let stored = Self::get_stored_rows(&mut tx).await?;
+        let report = compare_closure(&derived, stored);
+
+        sqlx::query!("DELETE FROM block_directional_closure")
+            .execute(&mut *tx)
+            .await?;
+
+        let mut ancestor_ids = Vec::with_capacity(derived.rows.len());
+        let mut descendant_ids = Vec::with_capacity(derived.rows.len());
+        let mut path_counts = Vec::with_capacity(derived.rows.len());
+        let mut min_depths = Vec::with_capacity(derived.rows.len());
+        for row in &derived.rows {
+            ancestor_ids.push(row.block_ancestor_id);
+            descendant_ids.push(row.block_descendant_id);
+            path_counts.push(row.path_count);
+            min_depths.push(row.min_depth);
+        }
+
+        sqlx::query!(
+            "
+            INSERT INTO block_directional_closure
+                (block_ancestor_id, block_descendant_id, path_count, min_depth)
+            SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::bigint[], $4::bigint[])
+            ",
+            &ancestor_ids,
+            &descendant_ids,
+            &path_counts,
+            &min_depths,
         )
-        .fetch_all(executor)
+        .execute(&mut *tx)
         .await?;
 
-        Ok(paths)
+        tx.commit().await?;
+
+        Ok(report)
     }
+}
 
-    async fn create_path<'e, E>(&self, path_ids: Vec<Uuid>, executor: E) -> Result<()>
-    where
-        E: Executor<'e, Database = Postgres>,
-    {
-        self.ensure_nonempty_path(&path_ids)?;
-        self.ensure_noncyclic_path(&path_ids)?;
+impl PostgresBlockDirectionalPathHelper {
+    /// Links whose blocks are both out of the trash, as (from, to) pairs.
+    async fn get_live_links(conn: &mut PgConnection) -> Result<Vec<(Uuid, Uuid)>> {
+        let links = sqlx::query!(
+            "
+            SELECT l.block_from_id, l.block_to_id
+            FROM block_directional_links l
+            JOIN blocks f ON f.id = l.block_from_id
+            JOIN blocks t ON t.id = l.block_to_id
+            WHERE f.trashed_at IS NULL AND t.trashed_at IS NULL
+            ",
+        )
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|link| (link.block_from_id, link.block_to_id))
+        .collect();
 
-        let id = Uuid::new_v4();
-        let ancestor_id = path_ids.first().unwrap();
-        let descendant_id = path_ids.last().unwrap();
-        let path_len = path_ids.len() as i32;
-        let now = Utc::now();
+        Ok(links)
+    }
 
+    async fn get_stored_rows(conn: &mut PgConnection) -> Result<Vec<ClosureRowDto>> {
+        let rows = sqlx::query_as!(
+            ClosureRowDto,
+            "
+            SELECT block_ancestor_id, block_descendant_id, path_count, min_depth
+            FROM block_directional_closure
+            ",
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// Recomputes `min_depth` for the rows of `ancestor_id` whose shortest
+    /// route may have used the removed link, from the depths of its children.
+    async fn refresh_depths_after_link_removal(
+        &self,
+        ancestor_id: Uuid,
+        depth_to_from: i64,
+        from_id: Uuid,
+        to_id: Uuid,
+        conn: &mut PgConnection,
+    ) -> Result<()> {
         sqlx::query!(
-            "INSERT INTO block_directional_paths
-            (id, block_ancestor_id, block_descendant_id, block_path_ids, path_length, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6)",
-            id,
+            "
+            UPDATE block_directional_closure AS target
+            SET min_depth = (
+                SELECT MIN(CASE
+                    WHEN l.block_to_id = target.block_descendant_id THEN 1
+                    ELSE 1 + c.min_depth
+                END)
+                FROM block_directional_links l
+                LEFT JOIN block_directional_closure c
+                  ON c.block_ancestor_id = l.block_to_id
+                 AND c.block_descendant_id = target.block_descendant_id
+                WHERE l.block_from_id = target.block_ancestor_id
+                  AND NOT (l.block_from_id = $3 AND l.block_to_id = $4)
+                  AND (l.block_to_id = target.block_descendant_id
+                       OR c.block_ancestor_id IS NOT NULL)
+            )
+            WHERE target.block_ancestor_id = $1
+              AND (
+                (target.block_descendant_id = $4 AND target.min_depth = $2::bigint + 1)
+                OR target.min_depth = $2::bigint + 1 + (
+                    SELECT down.min_depth FROM block_directional_closure down
+                    WHERE down.block_ancestor_id = $4
+                      AND down.block_descendant_id = target.block_descendant_id
+                )
+              )
+            ",
             ancestor_id,
-            descendant_id,
-            &path_ids,
-            path_len,
-            now,
+            depth_to_from,
+            from_id,
+            to_id,
         )
-        .execute(executor)
-        .await
-        .map_err(|e| {
-            if is_foreign_key_violation(&e) {
-                return BlockDirectionalPathHelperError::PathBlocksNotFound {
-                    from: *ancestor_id,
-                    to: *descendant_id,
-                };
-            } else if is_unique_violation(&e) {
-                return BlockDirectionalPathHelperError::AlreadyExists {
-                    from: *ancestor_id,
-                    to: *descendant_id,
-                };
-            }
-            BlockDirectionalPathHelperError::Database(e)
-        })?;
+        .execute(&mut *conn)
+        .await?;
 
         Ok(())
     }
 
-    fn ensure_nonempty_path(&self, path_ids: &[Uuid]) -> Result<()> {
-        if path_ids.is_empty() {
-            return Err(BlockDirectionalPathHelperError::EmptyPathCreation);
-        }
-        Ok(())
-    }
+    /// Saturated counts are never decremented, as the removed paths may be only
+    /// part of them. Once the other counts are settled, recounts the saturated
+    /// rows from `ancestor_id` to `scope_id` and its descendants from the rows
+    /// of its children, skipping the removed links into `removed_to_id` (from
+    /// `removed_from_id` only, when given). Ancestors must be passed children
+    /// first.
+    async fn recount_saturated_rows(
+        ancestor_id: Uuid,
+        scope_id: Uuid,
+        removed_from_id: Option<Uuid>,
+        removed_to_id: Uuid,
+        conn: &mut PgConnection,
+    ) -> Result<()> {
+        let descendant_ids = sqlx::query_scalar!(
+            "
+            SELECT block_descendant_id FROM block_directional_closure
+            WHERE block_ancestor_id = $1
+              AND path_count = 9223372036854775807
+              AND (block_descendant_id = $2 OR block_descendant_id IN (
+                SELECT block_descendant_id FROM block_directional_closure
+                WHERE block_ancestor_id = $2
+              ))
+            ",
+            ancestor_id,
+            scope_id,
+        )
+        .fetch_all(&mut *conn)
+        .await?;
 
-    fn ensure_noncyclic_path(&self, path_ids: &[Uuid]) -> Result<()> {
-        let unique_ids: HashSet<_> = path_ids.iter().collect();
-        if path_ids.len() != unique_ids.len() {
-            return Err(BlockDirectionalPathHelperError::CyclicPathCreation);
+        for descendant_id in descendant_ids {
+            let through_children = sqlx::query_scalar!(
+                r#"
+                SELECT CASE
+                    WHEN l.block_to_id = $2 THEN 1
+                    ELSE COALESCE(c.path_count, 0)
+                END as "path_count!"
+                FROM block_directional_links l
+                LEFT JOIN block_directional_closure c
+                  ON c.block_ancestor_id = l.block_to_id
+                 AND c.block_descendant_id = $2
+                WHERE l.block_from_id = $1
+                  AND NOT (l.block_to_id = $4 AND ($3::uuid IS NULL OR l.block_from_id = $3))
+                "#,
+                ancestor_id,
+                descendant_id,
+                removed_from_id,
+                removed_to_id,
+            )
+            .fetch_all(&mut *conn)
+            .await?;
+
+            let path_count = through_children.into_iter().fold(0i64, i64::saturating_add);
+
+            if path_count > 0 {
+                sqlx::query!(
+                    "
+                    UPDATE block_directional_closure SET path_count = $3
+                    WHERE block_ancestor_id = $1 AND block_descendant_id = $2
+                    ",
+                    ancestor_id,
+                    descendant_id,
+                    path_count,
+                )
+                .execute(&mut *conn)
+                .await?;
+            } else {
+                sqlx::query!(
+                    "
+                    DELETE FROM block_directional_closure
+                    WHERE block_ancestor_id = $1 AND block_descendant_id = $2
+                    ",
+                    ancestor_id,
+                    descendant_id,
+                )
+                .execute(&mut *conn)
+                .await?;
+            }
         }
+
         Ok(())
     }
 }
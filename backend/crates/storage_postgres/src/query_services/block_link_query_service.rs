@@ -22,6 +22,10 @@ struct LinkedBlockModel {
     link_id: Uuid,
     block_id: Uuid,
     title: String,
+    kind: Option<String>,
+    label: Option<String>,
+    weight: Option<f64>,
+    position: i64,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -32,6 +36,9 @@ impl From<&LinkedBlockModel> for LinkedBlockDto {
             link_id: model.link_id,
             block_id: model.block_id,
             title: model.title.clone(),
+            kind: model.kind.clone(),
+            label: model.label.clone(),
+            weight: model.weight,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -58,7 +65,7 @@ impl BlockLinkQueryService<Postgres> for PostgresBlockLinkQueryService {
         E: Executor<'e, Database = Postgres>,
     {
         // SQLx primarily looks at the first SELECT for the schema definition.
-        let linked_blocks = sqlx::query_as!(
+        let mut linked_blocks = sqlx::query_as!(
             LinkedBlockModel,
             r#"
             SELECT
@@ -66,28 +73,40 @@ impl BlockLinkQueryService<Postgres> for PostgresBlockLinkQueryService {
                 bdl.id as "link_id!",
                 b.id as "block_id!",
                 b.title as "title!",
+                bdl.kind as "kind?",
+                bdl.label as "label?",
+                bdl.weight as "weight?",
+                bdl.position as "position!",
                 b.created_at as "created_at!",
                 b.updated_at as "updated_at!"
             FROM block_directional_links bdl
             JOIN blocks b ON b.id = bdl.block_from_id
-            WHERE bdl.block_to_id = $1
+            WHERE bdl.block_to_id = $1 AND b.trashed_at IS NULL
             UNION ALL
             SELECT
                 'child',
                 bdl.id,
                 b.id,
                 b.title,
+                bdl.kind,
+                bdl.label,
+                bdl.weight,
+                bdl.position,
                 b.created_at,
                 b.updated_at
             FROM block_directional_links bdl
             JOIN blocks b ON b.id = bdl.block_to_id
-            WHERE bdl.block_from_id = $1
+            WHERE bdl.block_from_id = $1 AND b.trashed_at IS NULL
             UNION ALL
             SELECT
                 'related',
                 brl.id,
                 b.id,
                 b.title,
+                NULL::TEXT,
+                brl.label,
+                brl.weight,
+                0,
                 b.created_at,
                 b.updated_at
             FROM block_related_links brl
@@ -95,13 +114,20 @@ impl BlockLinkQueryService<Postgres> for PostgresBlockLinkQueryService {
                 (brl.block_a_id = $1 AND b.id = brl.block_b_id) OR 
                 (brl.block_b_id = $1 AND b.id = brl.block_a_id)
             )
-            WHERE brl.block_a_id = $1 OR brl.block_b_id = $1
+            WHERE (brl.block_a_id = $1 OR brl.block_b_id = $1) AND b.trashed_at IS NULL
             "#,
             block_id
         )
         .fetch_all(executor)
         .await?;
 
+        // Children come back in sibling order. Positions only rank siblings,
+        // so parents and related blocks keep the order the query returned
+        linked_blocks.sort_by_key(|linked_block| match linked_block.link_type {
+            LinkType::Child => linked_block.position,
+            LinkType::Parent | LinkType::Related => 0,
+        });
+
         let mut parent_blocks = Vec::new();
         let mut child_blocks = Vec::new();
         let mut related_blocks = Vec::new();
@@ -138,11 +164,14 @@ impl BlockLinkQueryService<Postgres> for PostgresBlockLinkQueryService {
                 bdl.id as "link_id: _",
                 b.id as "block_id: _",
                 b.title,
+                bdl.kind,
+                bdl.label,
+                bdl.weight,
                 b.created_at,
                 b.updated_at
             FROM block_directional_links bdl
             JOIN blocks b ON b.id = bdl.block_from_id
-            WHERE bdl.block_to_id = $1
+            WHERE bdl.block_to_id = $1 AND b.trashed_at IS NULL
             "#,
             block_id
         )
@@ -167,11 +196,15 @@ impl BlockLinkQueryService<Postgres> for PostgresBlockLinkQueryService {
                 bdl.id as "link_id: _",
                 b.id as "block_id: _",
                 b.title,
+                bdl.kind,
+                bdl.label,
+                bdl.weight,
                 b.created_at,
                 b.updated_at
             FROM block_directional_links bdl
             JOIN blocks b ON b.id = bdl.block_to_id
-            WHERE bdl.block_from_id = $1
+            WHERE bdl.block_from_id = $1 AND b.trashed_at IS NULL
+            ORDER BY bdl.position ASC
             "#,
             block_id
         )
@@ -196,6 +229,9 @@ impl BlockLinkQueryService<Postgres> for PostgresBlockLinkQueryService {
                 brl.id as "link_id: _",
                 b.id as "block_id: _",
                 b.title,
+                NULL::TEXT as "kind",
+                brl.label,
+                brl.weight,
                 b.created_at,
                 b.updated_at
             FROM block_related_links brl
@@ -203,7 +239,7 @@ impl BlockLinkQueryService<Postgres> for PostgresBlockLinkQueryService {
                 (brl.block_a_id = $1 AND b.id = brl.block_b_id) OR
                 (brl.block_b_id = $1 AND b.id = brl.block_a_id)
             )
-            WHERE brl.block_a_id = $1 OR brl.block_b_id = $1
+            WHERE (brl.block_a_id = $1 OR brl.block_b_id = $1) AND b.trashed_at IS NULL
             "#,
             block_id
         )
@@ -220,8 +256,14 @@ impl BlockLinkQueryService<Postgres> for PostgresBlockLinkQueryService {
         let links = sqlx::query_as!(
             DirectionalLinkExportDto,
             r#"
-            SELECT block_from_id, block_to_id
-            FROM block_directional_links
+            SELECT id, block_from_id, block_to_id, kind, label, weight, created_at
+            FROM block_directional_links bdl
+            WHERE NOT EXISTS (
+                SELECT 1 FROM blocks b
+                WHERE b.id IN (bdl.block_from_id, bdl.block_to_id)
+                  AND b.trashed_at IS NOT NULL
+            )
+            ORDER BY bdl.block_from_id, bdl.position
             "#,
         )
         .fetch_all(executor)
@@ -237,8 +279,13 @@ impl BlockLinkQueryService<Postgres> for PostgresBlockLinkQueryService {
         let links = sqlx::query_as!(
             RelatedLinkExportDto,
             r#"
-            SELECT block_a_id, block_b_id
-            FROM block_related_links
+            SELECT id, block_a_id, block_b_id, label, weight, created_at
+            FROM block_related_links brl
+            WHERE NOT EXISTS (
+                SELECT 1 FROM blocks b
+                WHERE b.id IN (brl.block_a_id, brl.block_b_id)
+                  AND b.trashed_at IS NOT NULL
+            )
             "#,
         )
         .fetch_all(executor)
@@ -1,11 +1,13 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures_util::{StreamExt, TryStreamExt, stream::BoxStream};
 use sqlx::{Executor, Postgres};
 use uuid::Uuid;
 
 use storage::query_services::BlockQueryService;
 use storage::query_services::block_query_service::{
-    BlockExportDto, BlockQueryServiceResult as Result, BlockSummaryDto, OpenedBlockDto,
+    BlockExportDto, BlockQueryServiceResult as Result, BlockSearchDto, BlockSummaryDto,
+    BlockUpdatedAtDto, OpenedBlockDto, TrashedBlockDto, render_snippet,
 };
 
 struct OpenedBlockModel {
@@ -51,6 +53,7 @@ impl BlockQueryService<Postgres> for PostgresBlockQueryService {
                 bo.tab_index
             FROM block_opens bo
             JOIN blocks b on b.id = bo.block_id
+            WHERE b.trashed_at IS NULL
             ORDER BY bo.tab_index ASC
             "#,
         )
@@ -63,13 +66,85 @@ impl BlockQueryService<Postgres> for PostgresBlockQueryService {
         Ok(opened_blocks)
     }
 
-    async fn search<'e, E>(&self, query: &str, executor: E) -> Result<Vec<BlockSummaryDto>>
+    async fn search<'e, E>(
+        &self,
+        search: &BlockSearchDto,
+        executor: E,
+    ) -> Result<Vec<BlockSummaryDto>>
     where
         E: Executor<'e, Database = Postgres>,
     {
-        let sql_query_str = format!("%{query}%");
+        // Pages continue from the sort key of the previous page's last row
+        let after_rank = search.after.and_then(|after| after.rank);
+        let after_updated_at = search.after.map(|after| after.updated_at);
+        let after_id = search.after.map(|after| after.id);
 
-        let blocks = sqlx::query_as!(
+        let Some(ts_query) = to_ts_query(&search.query) else {
+            let blocks = sqlx::query_as!(
+                BlockSummaryDto,
+                r#"
+                SELECT
+                    b.id,
+                    b.title,
+                    b.created_at,
+                    b.updated_at,
+                    bo.opened_at as "opened_at?",
+                    EXISTS(
+                        SELECT 1 FROM block_pins bp WHERE bp.block_id = b.id
+                    ) as "is_pinned!",
+                    NULL::TEXT as "snippet?",
+                    NULL::FLOAT8 as "rank?"
+                FROM blocks b
+                LEFT JOIN block_opens bo ON bo.block_id = b.id
+                WHERE b.trashed_at IS NULL
+                  AND ($1::timestamptz IS NULL OR b.created_at >= $1)
+                  AND ($2::timestamptz IS NULL OR b.created_at < $2)
+                  AND ($3::timestamptz IS NULL OR b.updated_at >= $3)
+                  AND ($4::timestamptz IS NULL OR b.updated_at < $4)
+                  AND ($5::uuid IS NULL OR EXISTS(
+                      SELECT 1 FROM block_directional_closure p
+                      WHERE p.block_ancestor_id = $5
+                        AND p.block_descendant_id = b.id
+                        AND p.block_descendant_id != p.block_ancestor_id
+                  ))
+                  AND (NOT $6::bool OR bo.opened_at IS NOT NULL)
+                  AND (NOT $7::bool OR EXISTS(
+                      SELECT 1 FROM block_pins bp WHERE bp.block_id = b.id
+                  ))
+                  AND ($8::text IS NULL OR EXISTS(
+                      SELECT 1 FROM block_tags bt
+                      JOIN tags t ON t.id = bt.tag_id
+                      WHERE bt.block_id = b.id AND lower(t.name) = lower($8)
+                  ))
+                  AND ($10::timestamptz IS NULL
+                      OR b.updated_at < $10
+                      OR (b.updated_at = $10 AND b.id > $11))
+                ORDER BY b.updated_at DESC, b.id ASC
+                LIMIT $9
+                "#,
+                search.created_after,
+                search.created_before,
+                search.updated_after,
+                search.updated_before,
+                search.descendant_of,
+                search.only_opened,
+                search.only_pinned,
+                search.tag,
+                search.limit,
+                after_updated_at,
+                after_id,
+            )
+            .fetch_all(executor)
+            .await?;
+
+            return Ok(blocks);
+        };
+
+        // search_vector weights titles as 'A' and contents as 'B'. The rank is
+        // negated so that, as with SQLite's bm25, lower ranks sort first
+        // Hits come back between U+E000 and U+E001 so the content can be
+        // escaped before render_snippet adds the markup
+        let blocks: Vec<BlockSummaryDto> = sqlx::query_as!(
             BlockSummaryDto,
             r#"
             SELECT
@@ -77,19 +152,74 @@ impl BlockQueryService<Postgres> for PostgresBlockQueryService {
                 b.title,
                 b.created_at,
                 b.updated_at,
-                bo.opened_at as "opened_at?"
+                bo.opened_at as "opened_at?",
+                EXISTS(
+                    SELECT 1 FROM block_pins bp WHERE bp.block_id = b.id
+                ) as "is_pinned!",
+                ts_headline(
+                    'simple',
+                    b.content,
+                    q.query,
+                    'StartSel=' || chr(57344) || ', StopSel=' || chr(57345)
+                        || ', MaxWords=24, MinWords=8'
+                ) as "snippet?",
+                r.rank as "rank?"
             FROM blocks b
+            CROSS JOIN to_tsquery('simple', $10) AS q(query)
+            CROSS JOIN LATERAL (
+                SELECT -ts_rank_cd(b.search_vector, q.query)::FLOAT8 AS rank
+            ) r
             LEFT JOIN block_opens bo ON bo.block_id = b.id
-            WHERE
-                title LIKE $1 OR
-                content LIKE $1
-            ORDER BY updated_at DESC
-            LIMIT 50
+            WHERE b.search_vector @@ q.query
+              AND b.trashed_at IS NULL
+                AND ($1::timestamptz IS NULL OR b.created_at >= $1)
+                AND ($2::timestamptz IS NULL OR b.created_at < $2)
+                AND ($3::timestamptz IS NULL OR b.updated_at >= $3)
+                AND ($4::timestamptz IS NULL OR b.updated_at < $4)
+                AND ($5::uuid IS NULL OR EXISTS(
+                    SELECT 1 FROM block_directional_closure p
+                    WHERE p.block_ancestor_id = $5
+                      AND p.block_descendant_id = b.id
+                      AND p.block_descendant_id != p.block_ancestor_id
+                ))
+                AND (NOT $6::bool OR bo.opened_at IS NOT NULL)
+                AND (NOT $7::bool OR EXISTS(
+                    SELECT 1 FROM block_pins bp WHERE bp.block_id = b.id
+                ))
+                AND ($8::text IS NULL OR EXISTS(
+                    SELECT 1 FROM block_tags bt
+                    JOIN tags t ON t.id = bt.tag_id
+                    WHERE bt.block_id = b.id AND lower(t.name) = lower($8)
+                ))
+                AND ($12::timestamptz IS NULL
+                    OR r.rank > $11
+                    OR (r.rank = $11
+                        AND (b.updated_at < $12 OR (b.updated_at = $12 AND b.id > $13))))
+            ORDER BY r.rank ASC, b.updated_at DESC, b.id ASC
+            LIMIT $9
             "#,
-            sql_query_str,
+            search.created_after,
+            search.created_before,
+            search.updated_after,
+            search.updated_before,
+            search.descendant_of,
+            search.only_opened,
+            search.only_pinned,
+            search.tag,
+            search.limit,
+            ts_query,
+            after_rank,
+            after_updated_at,
+            after_id,
         )
         .fetch_all(executor)
-        .await?;
+        .await?
+        .into_iter()
+        .map(|mut block| {
+            block.snippet = block.snippet.as_deref().map(render_snippet);
+            block
+        })
+        .collect();
 
         Ok(blocks)
     }
@@ -103,12 +233,100 @@ impl BlockQueryService<Postgres> for PostgresBlockQueryService {
             r#"
             SELECT id, title, content, created_at, updated_at
             FROM blocks
+            WHERE trashed_at IS NULL
+            ORDER BY created_at ASC
+            "#,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(blocks)
+    }
+
+    async fn get_all_updated_at<'e, E>(&self, executor: E) -> Result<Vec<BlockUpdatedAtDto>>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let blocks = sqlx::query_as!(
+            BlockUpdatedAtDto,
+            r#"
+            SELECT id, updated_at
+            FROM blocks
+            WHERE trashed_at IS NULL
+            "#,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(blocks)
+    }
+
+    fn stream_all<'e, E>(&self, executor: E) -> BoxStream<'e, Result<BlockExportDto>>
+    where
+        E: Executor<'e, Database = Postgres> + 'e,
+    {
+        sqlx::query_as!(
+            BlockExportDto,
+            r#"
+            SELECT id, title, content, created_at, updated_at
+            FROM blocks
+            WHERE trashed_at IS NULL
             ORDER BY created_at ASC
             "#,
         )
+        .fetch(executor)
+        .map_err(Into::into)
+        .boxed()
+    }
+
+    async fn get_trashed<'e, E>(&self, executor: E) -> Result<Vec<TrashedBlockDto>>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let blocks = sqlx::query_as!(
+            TrashedBlockDto,
+            r#"
+            SELECT
+                id,
+                title,
+                trashed_at as "trashed_at!"
+            FROM blocks
+            WHERE trashed_at IS NOT NULL
+            ORDER BY trashed_at DESC
+            "#,
+        )
         .fetch_all(executor)
         .await?;
 
         Ok(blocks)
     }
+
+    async fn count_trashed<'e, E>(&self, executor: E) -> Result<i64>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64" FROM blocks WHERE trashed_at IS NOT NULL"#
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(count)
+    }
+}
+
+/// Turns free text into a tsquery that prefix-matches every word, so user
+/// input can never be parsed as tsquery syntax. Returns `None` for blank input.
+fn to_ts_query(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("{term}:*"))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" & "))
+    }
 }
@@ -1,5 +1,9 @@
+pub mod block_dag_query_service;
 pub mod block_link_query_service;
 pub mod block_query_service;
+pub mod block_revision_query_service;
 
+pub use block_dag_query_service::PostgresBlockDagQueryService;
 pub use block_link_query_service::PostgresBlockLinkQueryService;
 pub use block_query_service::PostgresBlockQueryService;
+pub use block_revision_query_service::PostgresBlockRevisionQueryService;
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
-use chrono::Utc;
-use sqlx::{Acquire, Executor, Postgres};
+use chrono::{DateTime, Utc};
+use sqlx::{Acquire, Executor, PgConnection, Postgres};
 use uuid::Uuid;
 
 use crate::helpers::PostgresBlockDirectionalPathHelper;
@@ -44,51 +44,22 @@ impl BlockDirectionalLinkRepository<Postgres> for PostgresBlockDirectionalLinkRe
     where
         E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
     {
-        let now = Utc::now();
+        self.create_at(input, Utc::now(), executor).await
+    }
 
+    async fn create_at<'e, E>(
+        &self,
+        input: &CreateBlockDirectionalLinkDto,
+        created_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<BlockDirectionalLink>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
         let mut conn = executor.acquire().await?;
         let mut tx = conn.begin().await?;
 
-        self.ensure_no_cycle(input.block_from_id, input.block_to_id, &mut *tx)
-            .await?;
-
-        let link = sqlx::query_as!(
-            BlockDirectionalLink,
-            r#"
-            INSERT INTO block_directional_links
-                (id, block_from_id, block_to_id, created_at)
-                VALUES ($1, $2, $3, $4)
-            RETURNING
-                id,
-                block_from_id,
-                block_to_id,
-                created_at
-            "#,
-            input.id,
-            input.block_from_id,
-            input.block_to_id,
-            now
-        )
-        .fetch_one(&mut *tx)
-        .await
-        .map_err(|e| {
-            if is_foreign_key_violation(&e) {
-                return BlockDirectionalLinkRepositoryError::BlocksNotFound {
-                    from: input.block_from_id,
-                    to: input.block_to_id,
-                };
-            } else if is_unique_violation(&e) {
-                return BlockDirectionalLinkRepositoryError::AlreadyExists {
-                    from: input.block_from_id,
-                    to: input.block_to_id,
-                };
-            }
-            BlockDirectionalLinkRepositoryError::Database(e)
-        })?;
-
-        self.path_helper
-            .create_paths_for_link(input.block_from_id, input.block_to_id, &mut *tx)
-            .await?;
+        let link = self.insert_link(input, created_at, &mut tx).await?;
 
         tx.commit().await?;
 
@@ -115,6 +86,8 @@ impl BlockDirectionalLinkRepository<Postgres> for PostgresBlockDirectionalLinkRe
             .execute(&mut *tx)
             .await?;
 
+        Self::close_position_gap(link.block_from_id, link.position, &mut tx).await?;
+
         tx.commit().await?;
 
         Ok(())
@@ -136,21 +109,168 @@ impl BlockDirectionalLinkRepository<Postgres> for PostgresBlockDirectionalLinkRe
             .delete_paths_using_link(block_from_id, block_to_id, &mut *tx)
             .await?;
 
-        let result = sqlx::query!(
-            "DELETE FROM block_directional_links WHERE block_from_id = $1 AND block_to_id = $2",
+        let position = sqlx::query_scalar!(
+            r#"
+            DELETE FROM block_directional_links
+            WHERE block_from_id = $1 AND block_to_id = $2
+            RETURNING position
+            "#,
             block_from_id,
             block_to_id
         )
-        .execute(&mut *tx)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(BlockDirectionalLinkRepositoryError::NotFoundByBlocks {
+            from: block_from_id,
+            to: block_to_id,
+        })?;
+
+        Self::close_position_gap(block_from_id, position, &mut tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn move_link<'e, E>(
+        &self,
+        block_id: Uuid,
+        from_parent_id: Uuid,
+        to_parent_id: Uuid,
+        executor: E,
+    ) -> Result<BlockDirectionalLink>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        self.path_helper
+            .delete_paths_using_link(from_parent_id, block_id, &mut *tx)
+            .await?;
+
+        let old_link = sqlx::query_as!(
+            BlockDirectionalLink,
+            r#"
+            DELETE FROM block_directional_links
+            WHERE block_from_id = $1 AND block_to_id = $2
+            RETURNING
+                id,
+                block_from_id,
+                block_to_id,
+                kind,
+                label,
+                weight,
+                position,
+                created_at
+            "#,
+            from_parent_id,
+            block_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(BlockDirectionalLinkRepositoryError::NotFoundByBlocks {
+            from: from_parent_id,
+            to: block_id,
+        })?;
+
+        Self::close_position_gap(from_parent_id, old_link.position, &mut tx).await?;
+
+        // The link keeps its identity and annotations under the new parent
+        let input = CreateBlockDirectionalLinkDto {
+            id: old_link.id,
+            block_from_id: to_parent_id,
+            block_to_id: block_id,
+            kind: old_link.kind,
+            label: old_link.label,
+            weight: old_link.weight,
+        };
+        let link = self
+            .insert_link(&input, old_link.created_at, &mut tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(link)
+    }
+
+    async fn reorder_children<'e, E>(
+        &self,
+        parent_id: Uuid,
+        child_ids: &[Uuid],
+        executor: E,
+    ) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let parent_exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM blocks WHERE id = $1 AND trashed_at IS NULL
+            ) as "exists!""#,
+            parent_id,
+        )
+        .fetch_one(&mut *tx)
         .await?;
 
-        if result.rows_affected() == 0 {
-            return Err(BlockDirectionalLinkRepositoryError::NotFoundByBlocks {
-                from: block_from_id,
-                to: block_to_id,
-            });
+        if !parent_exists {
+            return Err(BlockDirectionalLinkRepositoryError::ParentNotFound { id: parent_id });
         }
 
+        Self::lock_children(parent_id, &mut tx).await?;
+
+        // Lock the sibling rows so concurrent reorders apply one after the other
+        let children = sqlx::query!(
+            r#"
+            SELECT
+                bdl.block_to_id,
+                b.trashed_at IS NOT NULL as "trashed!"
+            FROM block_directional_links bdl
+            JOIN blocks b ON b.id = bdl.block_to_id
+            WHERE bdl.block_from_id = $1
+            ORDER BY bdl.position ASC
+            FOR UPDATE OF bdl
+            "#,
+            parent_id,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut visible: Vec<Uuid> = Vec::new();
+        let mut trashed: Vec<Uuid> = Vec::new();
+        for child in children {
+            if child.trashed {
+                trashed.push(child.block_to_id);
+            } else {
+                visible.push(child.block_to_id);
+            }
+        }
+
+        let mut requested = child_ids.to_vec();
+        requested.sort();
+        requested.dedup();
+        visible.sort();
+        if requested.len() != child_ids.len() || requested != visible {
+            return Err(BlockDirectionalLinkRepositoryError::ChildOrderMismatch { parent_id });
+        }
+
+        let ordered: Vec<Uuid> = child_ids.iter().chain(trashed.iter()).copied().collect();
+
+        sqlx::query!(
+            r#"
+            UPDATE block_directional_links bdl
+            SET position = ordered.position - 1
+            FROM UNNEST($2::uuid[]) WITH ORDINALITY AS ordered(block_to_id, position)
+            WHERE bdl.block_from_id = $1 AND bdl.block_to_id = ordered.block_to_id
+            "#,
+            parent_id,
+            &ordered,
+        )
+        .execute(&mut *tx)
+        .await?;
+
         tx.commit().await?;
 
         Ok(())
@@ -158,6 +278,125 @@ impl BlockDirectionalLinkRepository<Postgres> for PostgresBlockDirectionalLinkRe
 }
 
 impl PostgresBlockDirectionalLinkRepository {
+    async fn insert_link(
+        &self,
+        input: &CreateBlockDirectionalLinkDto,
+        created_at: DateTime<Utc>,
+        conn: &mut PgConnection,
+    ) -> Result<BlockDirectionalLink> {
+        Self::lock_children(input.block_from_id, &mut *conn).await?;
+
+        self.ensure_no_cycle(input.block_from_id, input.block_to_id, &mut *conn)
+            .await?;
+
+        // Trashed blocks keep their links but cannot gain new ones
+        let trashed = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM blocks WHERE id IN ($1, $2) AND trashed_at IS NOT NULL
+            ) as "trashed!""#,
+            input.block_from_id,
+            input.block_to_id,
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if trashed {
+            return Err(BlockDirectionalLinkRepositoryError::BlocksNotFound {
+                from: input.block_from_id,
+                to: input.block_to_id,
+            });
+        }
+
+        let link = sqlx::query_as!(
+            BlockDirectionalLink,
+            r#"
+            INSERT INTO block_directional_links
+                (id, block_from_id, block_to_id, kind, label, weight, position, created_at)
+                VALUES (
+                    $1, $2, $3, $4, $5, $6,
+                    -- New children go after their existing siblings
+                    (SELECT COALESCE(MAX(position) + 1, 0)
+                     FROM block_directional_links WHERE block_from_id = $2),
+                    $7
+                )
+            RETURNING
+                id,
+                block_from_id,
+                block_to_id,
+                kind,
+                label,
+                weight,
+                position,
+                created_at
+            "#,
+            input.id,
+            input.block_from_id,
+            input.block_to_id,
+            input.kind,
+            input.label,
+            input.weight,
+            created_at
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            if is_foreign_key_violation(&e) {
+                return BlockDirectionalLinkRepositoryError::BlocksNotFound {
+                    from: input.block_from_id,
+                    to: input.block_to_id,
+                };
+            } else if is_unique_violation(&e) {
+                return BlockDirectionalLinkRepositoryError::AlreadyExists {
+                    from: input.block_from_id,
+                    to: input.block_to_id,
+                };
+            }
+            BlockDirectionalLinkRepositoryError::Database(e)
+        })?;
+
+        self.path_helper
+            .create_paths_for_link(input.block_from_id, input.block_to_id, &mut *conn)
+            .await?;
+
+        Ok(link)
+    }
+
+    /// Serialises changes to the child positions under one parent by locking
+    /// the parent's block row. Without it two inserts can both read the same
+    /// `MAX(position)`, since Postgres has no gap locks to guard it.
+    async fn lock_children(parent_id: Uuid, conn: &mut PgConnection) -> Result<()> {
+        sqlx::query!(
+            "SELECT id FROM blocks WHERE id = $1 FOR NO KEY UPDATE",
+            parent_id,
+        )
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn close_position_gap(
+        parent_id: Uuid,
+        position: i64,
+        conn: &mut PgConnection,
+    ) -> Result<()> {
+        Self::lock_children(parent_id, &mut *conn).await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE block_directional_links
+            SET position = position - 1
+            WHERE block_from_id = $1 AND position > $2
+            "#,
+            parent_id,
+            position,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
     async fn get_by_id_with_executor<'c, E>(
         id: Uuid,
         executor: E,
@@ -172,6 +411,10 @@ impl PostgresBlockDirectionalLinkRepository {
                 id,
                 block_from_id,
                 block_to_id,
+                kind,
+                label,
+                weight,
+                position,
                 created_at
             FROM block_directional_links
             WHERE id = $1
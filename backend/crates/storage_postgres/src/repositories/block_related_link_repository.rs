@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use sqlx::{Acquire, Executor, Postgres};
 use uuid::Uuid;
 
@@ -32,6 +32,8 @@ impl BlockRelatedLinkRepository<Postgres> for PostgresBlockRelatedLinkRepository
                 id,
                 block_a_id,
                 block_b_id,
+                label,
+                weight,
                 created_at
             FROM block_related_links
             WHERE id = $1
@@ -52,30 +54,64 @@ impl BlockRelatedLinkRepository<Postgres> for PostgresBlockRelatedLinkRepository
     where
         E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
     {
-        let now = Utc::now();
+        self.create_at(input, Utc::now(), executor).await
+    }
 
+    async fn create_at<'e, E>(
+        &self,
+        input: &CreateBlockRelatedLinkDto,
+        created_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<BlockRelatedLink>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
         Self::ensure_no_selflink(input.block_a_id, input.block_b_id)?;
 
         let (block_a_id, block_b_id) = Self::ordered_ids(input.block_a_id, input.block_b_id);
 
+        let mut conn = executor.acquire().await?;
+
+        // Trashed blocks keep their links but cannot gain new ones
+        let trashed = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM blocks WHERE id IN ($1, $2) AND trashed_at IS NOT NULL
+            ) as "trashed!""#,
+            block_a_id,
+            block_b_id,
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if trashed {
+            return Err(BlockRelatedLinkError::BlocksNotFound {
+                a: block_a_id,
+                b: block_b_id,
+            });
+        }
+
         let link = sqlx::query_as!(
             BlockRelatedLink,
             r#"
             INSERT INTO block_related_links
-                (id, block_a_id, block_b_id, created_at)
-            VALUES ($1, $2, $3, $4)
+                (id, block_a_id, block_b_id, label, weight, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
             RETURNING
                 id,
                 block_a_id,
                 block_b_id,
+                label,
+                weight,
                 created_at
             "#,
             input.id,
             block_a_id,
             block_b_id,
-            now
+            input.label,
+            input.weight,
+            created_at
         )
-        .fetch_one(executor)
+        .fetch_one(&mut *conn)
         .await
         .map_err(|e| {
             if is_foreign_key_violation(&e) {
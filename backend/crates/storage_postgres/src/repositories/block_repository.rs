@@ -1,11 +1,13 @@
 use async_trait::async_trait;
-use chrono::Utc;
-use sqlx::{Acquire, Executor, Postgres};
+use chrono::{DateTime, Utc};
+use sqlx::{Acquire, Executor, PgConnection, Postgres};
 use uuid::Uuid;
 
 use crate::helpers::PostgresBlockDirectionalPathHelper;
 use domain::blocks::Block;
-use storage::helpers::block_directional_path_helper::BlockDirectionalPathHelper;
+use storage::helpers::block_directional_path_helper::{
+    BlockDirectionalPathHelper, BlockDirectionalPathHelperError,
+};
 use storage::repositories::block_repository::{
     BlockRepository, BlockRepositoryError, BlockRepostoryResult as Result,
 };
@@ -34,9 +36,10 @@ impl BlockRepository<Postgres> for PostgresBlockRepository {
                 title,
                 content,
                 created_at,
-                updated_at
+                updated_at,
+                version
             FROM blocks
-            WHERE id = $1"#,
+            WHERE id = $1 AND trashed_at IS NULL"#,
             id
         )
         .fetch_optional(executor)
@@ -55,6 +58,7 @@ impl BlockRepository<Postgres> for PostgresBlockRepository {
         self.path_helper
             .delete_paths_using_block(id, &mut *tx)
             .await?;
+        Self::close_child_position_gaps(id, &mut tx).await?;
 
         let result = sqlx::query!("DELETE FROM blocks WHERE id = $1", id)
             .execute(&mut *tx)
@@ -69,6 +73,185 @@ impl BlockRepository<Postgres> for PostgresBlockRepository {
         Ok(())
     }
 
+    async fn update_if_version<'e, E>(
+        &self,
+        block: &Block,
+        expected_version: i64,
+        executor: E,
+    ) -> Result<Option<Block>>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let now = Utc::now();
+
+        let block = sqlx::query_as!(
+            Block,
+            r#"
+            UPDATE blocks SET
+                title = $2,
+                content = $3,
+                updated_at = $4,
+                version = version + 1
+            WHERE id = $1 AND version = $5 AND trashed_at IS NULL
+            RETURNING
+                id,
+                title,
+                content,
+                created_at,
+                updated_at,
+                version
+            "#,
+            block.id,
+            block.title,
+            block.content,
+            now,
+            expected_version,
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(block)
+    }
+
+    async fn trash<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+        let now = Utc::now();
+
+        let result = sqlx::query!(
+            "UPDATE blocks SET trashed_at = $2 WHERE id = $1 AND trashed_at IS NULL",
+            id,
+            now,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(BlockRepositoryError::NotFound { id });
+        }
+
+        self.path_helper
+            .delete_paths_using_block(id, &mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn restore<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let result = sqlx::query!(
+            "UPDATE blocks SET trashed_at = NULL WHERE id = $1 AND trashed_at IS NOT NULL",
+            id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(self.not_trashed_error(id, &mut *tx).await?);
+        }
+
+        // Links to blocks that are still in the trash stay dormant
+        let links = sqlx::query!(
+            r#"
+            SELECT block_from_id, block_to_id
+            FROM block_directional_links bdl
+            WHERE (bdl.block_from_id = $1 OR bdl.block_to_id = $1)
+              AND NOT EXISTS (
+                SELECT 1 FROM blocks b
+                WHERE b.id IN (bdl.block_from_id, bdl.block_to_id)
+                  AND b.trashed_at IS NOT NULL
+              )
+            ORDER BY bdl.created_at ASC
+            "#,
+            id,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for link in links {
+            self.path_helper
+                .create_paths_for_link(link.block_from_id, link.block_to_id, &mut *tx)
+                .await
+                .map_err(|err| match err {
+                    BlockDirectionalPathHelperError::CyclicPathCreation => {
+                        BlockRepositoryError::RestoreCycle { id }
+                    }
+                    other => other.into(),
+                })?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn purge<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let is_trashed = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM blocks WHERE id = $1 AND trashed_at IS NOT NULL
+            ) as "is_trashed!""#,
+            id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if !is_trashed {
+            return Err(self.not_trashed_error(id, &mut *tx).await?);
+        }
+
+        Self::close_child_position_gaps(id, &mut tx).await?;
+
+        sqlx::query!("DELETE FROM blocks WHERE id = $1", id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn purge_trashed_before<'e, E>(&self, cutoff: DateTime<Utc>, executor: E) -> Result<u64>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let ids = sqlx::query_scalar!(
+            "SELECT id FROM blocks WHERE trashed_at IS NOT NULL AND trashed_at < $1",
+            cutoff,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for &id in &ids {
+            Self::close_child_position_gaps(id, &mut tx).await?;
+            sqlx::query!("DELETE FROM blocks WHERE id = $1", id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(ids.len() as u64)
+    }
+
     async fn save<'e, E>(&self, block: &Block, executor: E) -> Result<()>
     where
         E: Executor<'e, Database = Postgres>,
@@ -78,19 +261,50 @@ impl BlockRepository<Postgres> for PostgresBlockRepository {
         sqlx::query!(
             r#"
             INSERT INTO blocks
-                (id, title, content, created_at, updated_at)
-                VALUES ($1, $2, $3, $4, $5)
+                (id, title, content, created_at, updated_at, version)
+                VALUES ($1, $2, $3, $4, $5, $6)
             ON CONFLICT (id) DO UPDATE SET
                 title = EXCLUDED.title,
                 content = EXCLUDED.content,
                 created_at = EXCLUDED.created_at,
-                updated_at = EXCLUDED.updated_at
+                updated_at = EXCLUDED.updated_at,
+                version = blocks.version + 1
             "#,
             block.id,
             block.title,
             block.content,
             block.created_at,
             now,
+            block.version,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn save_with_timestamps<'e, E>(&self, block: &Block, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            INSERT INTO blocks
+                (id, title, content, created_at, updated_at, version)
+                VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (id) DO UPDATE SET
+                title = EXCLUDED.title,
+                content = EXCLUDED.content,
+                created_at = EXCLUDED.created_at,
+                updated_at = EXCLUDED.updated_at,
+                version = blocks.version + 1
+            "#,
+            block.id,
+            block.title,
+            block.content,
+            block.created_at,
+            block.updated_at,
+            block.version,
         )
         .execute(executor)
         .await?;
@@ -98,3 +312,59 @@ impl BlockRepository<Postgres> for PostgresBlockRepository {
         Ok(())
     }
 }
+
+impl PostgresBlockRepository {
+    /// Shifts up the siblings that follow the block under each of its parents,
+    /// as removing a single link does. Must run before the block's links go.
+    /// The parents' rows are locked first, like the link repository does
+    /// before it renumbers children.
+    async fn close_child_position_gaps(id: Uuid, conn: &mut PgConnection) -> Result<()> {
+        sqlx::query!(
+            r#"
+            SELECT b.id FROM blocks b
+            JOIN block_directional_links bdl ON bdl.block_from_id = b.id
+            WHERE bdl.block_to_id = $1
+            ORDER BY b.id
+            FOR NO KEY UPDATE OF b
+            "#,
+            id,
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE block_directional_links AS sibling
+            SET position = sibling.position - 1
+            FROM block_directional_links removed
+            WHERE removed.block_to_id = $1
+              AND removed.block_from_id = sibling.block_from_id
+              AND removed.position < sibling.position
+            "#,
+            id,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Explains why a block could not be restored or purged.
+    async fn not_trashed_error<'e, E>(&self, id: Uuid, executor: E) -> Result<BlockRepositoryError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM blocks WHERE id = $1) as "exists!""#,
+            id,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        if exists {
+            Ok(BlockRepositoryError::NotTrashed { id })
+        } else {
+            Ok(BlockRepositoryError::NotFound { id })
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, Postgres};
+use uuid::Uuid;
+
+use storage::repositories::CanvasPinRepository;
+use storage::repositories::canvas_pin_repository::{
+    CanvasPinRepositoryError, CanvasPinRepositoryResult as Result, PinnedCanvasDto,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct PostgresCanvasPinRepository;
+
+impl PostgresCanvasPinRepository {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait]
+impl CanvasPinRepository<Postgres> for PostgresCanvasPinRepository {
+    async fn pin<'e, E>(&self, canvas_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        self.pin_at(canvas_id, Utc::now(), executor).await
+    }
+
+    async fn pin_at<'e, E>(
+        &self,
+        canvas_id: Uuid,
+        pinned_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        sqlx::query!(
+            "INSERT INTO canvas_pins (canvas_id, pinned_at)
+            VALUES ($1, $2)
+            ON CONFLICT(canvas_id) DO NOTHING",
+            canvas_id,
+            pinned_at,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn unpin<'e, E>(&self, canvas_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let result = sqlx::query!("DELETE FROM canvas_pins WHERE canvas_id = $1", canvas_id)
+            .execute(executor)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(CanvasPinRepositoryError::NotPinned { canvas_id });
+        }
+
+        Ok(())
+    }
+
+    async fn is_pinned<'e, E>(&self, canvas_id: Uuid, executor: E) -> Result<bool>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let is_pinned = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM canvas_pins WHERE canvas_id = $1
+            ) as "is_pinned!""#,
+            canvas_id,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(is_pinned)
+    }
+
+    async fn get_all<'e, E>(&self, executor: E) -> Result<Vec<PinnedCanvasDto>>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let pinned = sqlx::query_as!(
+            PinnedCanvasDto,
+            r#"
+            SELECT
+                p.canvas_id,
+                s.name,
+                p.pinned_at
+            FROM canvas_pins p
+            JOIN canvases s ON s.id = p.canvas_id
+            ORDER BY p.pinned_at DESC
+            "#,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(pinned)
+    }
+}
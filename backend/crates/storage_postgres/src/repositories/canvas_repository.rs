@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use sqlx::{Executor, Postgres};
+use uuid::Uuid;
+
+use domain::canvases::Canvas;
+use storage::repositories::CanvasRepository;
+use storage::repositories::canvas_repository::{
+    CanvasRepositoryError, CanvasRepositoryResult as Result,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct PostgresCanvasRepository;
+
+impl PostgresCanvasRepository {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait]
+impl CanvasRepository<Postgres> for PostgresCanvasRepository {
+    async fn get_by_id<'e, E>(&self, id: Uuid, executor: E) -> Result<Option<Canvas>>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let canvas = sqlx::query_as!(
+            Canvas,
+            r#"SELECT
+                id,
+                name,
+                viewport_x,
+                viewport_y,
+                zoom_level,
+                created_at,
+                updated_at
+            FROM canvases
+            WHERE id = $1"#,
+            id,
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(canvas)
+    }
+
+    async fn get_all<'e, E>(&self, executor: E) -> Result<Vec<Canvas>>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let canvases = sqlx::query_as!(
+            Canvas,
+            r#"SELECT
+                id,
+                name,
+                viewport_x,
+                viewport_y,
+                zoom_level,
+                created_at,
+                updated_at
+            FROM canvases
+            ORDER BY updated_at DESC"#,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(canvases)
+    }
+
+    async fn delete_by_id<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let result = sqlx::query!("DELETE FROM canvases WHERE id = $1", id)
+            .execute(executor)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(CanvasRepositoryError::NotFound { id });
+        }
+
+        Ok(())
+    }
+
+    async fn save<'e, E>(&self, canvas: &Canvas, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        sqlx::query!(
+            "INSERT INTO canvases
+                (id, name, viewport_x, viewport_y, zoom_level, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                viewport_x = excluded.viewport_x,
+                viewport_y = excluded.viewport_y,
+                zoom_level = excluded.zoom_level,
+                updated_at = excluded.updated_at",
+            canvas.id,
+            canvas.name,
+            canvas.viewport_x,
+            canvas.viewport_y,
+            canvas.zoom_level,
+            canvas.created_at,
+            canvas.updated_at,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,21 @@
 mod block_directional_link_repository;
 mod block_repository;
+mod block_pin_repository;
 mod block_related_link_repository;
+mod canvas_block_repository;
+mod canvas_pin_repository;
+mod canvas_repository;
+mod snapshot_repository;
+mod tag_repository;
 mod workspace_repository;
 
 pub use block_directional_link_repository::PostgresBlockDirectionalLinkRepository;
 pub use block_repository::PostgresBlockRepository;
+pub use block_pin_repository::PostgresBlockPinRepository;
 pub use block_related_link_repository::PostgresBlockRelatedLinkRepository;
+pub use canvas_block_repository::PostgresCanvasBlockRepository;
+pub use canvas_pin_repository::PostgresCanvasPinRepository;
+pub use canvas_repository::PostgresCanvasRepository;
+pub use snapshot_repository::PostgresSnapshotRepository;
+pub use tag_repository::PostgresTagRepository;
 pub use workspace_repository::PostgresWorkspaceRepository;
@@ -0,0 +1,438 @@
+use async_trait::async_trait;
+use sqlx::{Acquire, Executor, Postgres};
+
+use storage::repositories::SnapshotRepository;
+use storage::repositories::snapshot_repository::{
+    BlockOpenRowDto, BlockPinRowDto, BlockRevisionRowDto, BlockRowDto, BlockTagRowDto,
+    CanvasBlockRowDto, CanvasPinRowDto, CanvasRowDto, DirectionalLinkRowDto, InstanceSnapshotDto,
+    RelatedLinkRowDto, SnapshotRepositoryError, SnapshotRepositoryResult as Result,
+    TableRowCountsDto, TagRowDto,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct PostgresSnapshotRepository;
+
+impl PostgresSnapshotRepository {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait]
+impl SnapshotRepository<Postgres> for PostgresSnapshotRepository {
+    async fn count_rows<'e, E>(&self, executor: E) -> Result<TableRowCountsDto>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let counts = sqlx::query_as!(
+            TableRowCountsDto,
+            r#"
+            SELECT
+                (SELECT COUNT(*) FROM blocks) as "blocks!",
+                (SELECT COUNT(*) FROM block_revisions) as "block_revisions!",
+                (SELECT COUNT(*) FROM block_directional_links) as "block_directional_links!",
+                (SELECT COUNT(*) FROM block_related_links) as "block_related_links!",
+                (SELECT COUNT(*) FROM block_pins) as "block_pins!",
+                (SELECT COUNT(*) FROM block_opens) as "block_opens!",
+                (SELECT COUNT(*) FROM canvases) as "canvases!",
+                (SELECT COUNT(*) FROM canvas_blocks) as "canvas_blocks!",
+                (SELECT COUNT(*) FROM canvas_pins) as "canvas_pins!",
+                (SELECT COUNT(*) FROM tags) as "tags!",
+                (SELECT COUNT(*) FROM block_tags) as "block_tags!"
+            "#,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(counts)
+    }
+
+    async fn read_snapshot<'e, E>(&self, executor: E) -> Result<InstanceSnapshotDto>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let blocks = sqlx::query_as!(
+            BlockRowDto,
+            r#"
+            SELECT
+                id,
+                title,
+                content,
+                created_at,
+                updated_at,
+                trashed_at,
+                version
+            FROM blocks
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let block_revisions = sqlx::query_as!(
+            BlockRevisionRowDto,
+            r#"
+            SELECT
+                block_id,
+                revision,
+                title,
+                content,
+                created_at
+            FROM block_revisions
+            ORDER BY block_id, revision
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let directional_links = sqlx::query_as!(
+            DirectionalLinkRowDto,
+            r#"
+            SELECT
+                id,
+                block_from_id,
+                block_to_id,
+                kind,
+                label,
+                weight,
+                position,
+                created_at
+            FROM block_directional_links
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let related_links = sqlx::query_as!(
+            RelatedLinkRowDto,
+            r#"
+            SELECT
+                id,
+                block_a_id,
+                block_b_id,
+                label,
+                weight,
+                created_at
+            FROM block_related_links
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let block_pins = sqlx::query_as!(
+            BlockPinRowDto,
+            r#"
+            SELECT block_id, pinned_at
+            FROM block_pins
+            ORDER BY block_id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let block_opens = sqlx::query_as!(
+            BlockOpenRowDto,
+            r#"
+            SELECT
+                block_id,
+                opened_at,
+                tab_index::bigint as "tab_index!"
+            FROM block_opens
+            ORDER BY block_id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let canvases = sqlx::query_as!(
+            CanvasRowDto,
+            r#"
+            SELECT
+                id,
+                name,
+                viewport_x,
+                viewport_y,
+                zoom_level,
+                created_at,
+                updated_at
+            FROM canvases
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let canvas_blocks = sqlx::query_as!(
+            CanvasBlockRowDto,
+            r#"
+            SELECT
+                id,
+                canvas_id,
+                block_id,
+                grid_x::bigint as "grid_x!",
+                grid_y::bigint as "grid_y!",
+                grid_width::bigint as "grid_width!",
+                grid_height::bigint as "grid_height!",
+                z::bigint as "z!",
+                scale,
+                content_visible,
+                created_at,
+                updated_at
+            FROM canvas_blocks
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let canvas_pins = sqlx::query_as!(
+            CanvasPinRowDto,
+            r#"
+            SELECT canvas_id, pinned_at
+            FROM canvas_pins
+            ORDER BY canvas_id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let tags = sqlx::query_as!(
+            TagRowDto,
+            r#"
+            SELECT id, name, created_at
+            FROM tags
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let block_tags = sqlx::query_as!(
+            BlockTagRowDto,
+            r#"
+            SELECT
+                block_id,
+                tag_id,
+                tagged_at
+            FROM block_tags
+            ORDER BY block_id, tag_id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(InstanceSnapshotDto {
+            blocks,
+            block_revisions,
+            directional_links,
+            related_links,
+            block_pins,
+            block_opens,
+            canvases,
+            canvas_blocks,
+            canvas_pins,
+            tags,
+            block_tags,
+        })
+    }
+
+    async fn write_snapshot<'e, E>(&self, snapshot: &InstanceSnapshotDto, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        if !self.count_rows(&mut *tx).await?.is_empty() {
+            return Err(SnapshotRepositoryError::TargetNotEmpty);
+        }
+
+        for block in &snapshot.blocks {
+            sqlx::query!(
+                r#"
+                INSERT INTO blocks
+                    (id, title, content, created_at, updated_at, trashed_at, version)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#,
+                block.id,
+                block.title,
+                block.content,
+                block.created_at,
+                block.updated_at,
+                block.trashed_at,
+                block.version,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        // The insert trigger recorded a first revision per block; the
+        // snapshot carries the real history instead
+        sqlx::query!("DELETE FROM block_revisions")
+            .execute(&mut *tx)
+            .await?;
+
+        for revision in &snapshot.block_revisions {
+            sqlx::query!(
+                r#"
+                INSERT INTO block_revisions (block_id, revision, title, content, created_at)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+                revision.block_id,
+                revision.revision,
+                revision.title,
+                revision.content,
+                revision.created_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for tag in &snapshot.tags {
+            sqlx::query!(
+                "INSERT INTO tags (id, name, created_at) VALUES ($1, $2, $3)",
+                tag.id,
+                tag.name,
+                tag.created_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for block_tag in &snapshot.block_tags {
+            sqlx::query!(
+                "INSERT INTO block_tags (block_id, tag_id, tagged_at) VALUES ($1, $2, $3)",
+                block_tag.block_id,
+                block_tag.tag_id,
+                block_tag.tagged_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for link in &snapshot.directional_links {
+            sqlx::query!(
+                r#"
+                INSERT INTO block_directional_links
+                    (id, block_from_id, block_to_id, kind, label, weight, position, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+                link.id,
+                link.block_from_id,
+                link.block_to_id,
+                link.kind,
+                link.label,
+                link.weight,
+                link.position,
+                link.created_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for link in &snapshot.related_links {
+            sqlx::query!(
+                r#"
+                INSERT INTO block_related_links
+                    (id, block_a_id, block_b_id, label, weight, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                "#,
+                link.id,
+                link.block_a_id,
+                link.block_b_id,
+                link.label,
+                link.weight,
+                link.created_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for pin in &snapshot.block_pins {
+            sqlx::query!(
+                "INSERT INTO block_pins (block_id, pinned_at) VALUES ($1, $2)",
+                pin.block_id,
+                pin.pinned_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for open in &snapshot.block_opens {
+            sqlx::query!(
+                "INSERT INTO block_opens (block_id, opened_at, tab_index) VALUES ($1, $2, $3::bigint)",
+                open.block_id,
+                open.opened_at,
+                open.tab_index,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for canvas in &snapshot.canvases {
+            sqlx::query!(
+                r#"
+                INSERT INTO canvases
+                    (id, name, viewport_x, viewport_y, zoom_level, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#,
+                canvas.id,
+                canvas.name,
+                canvas.viewport_x,
+                canvas.viewport_y,
+                canvas.zoom_level,
+                canvas.created_at,
+                canvas.updated_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for canvas_block in &snapshot.canvas_blocks {
+            sqlx::query!(
+                r#"
+                INSERT INTO canvas_blocks
+                    (id, canvas_id, block_id, grid_x, grid_y, grid_width, grid_height,
+                     z, scale, content_visible, created_at, updated_at)
+                VALUES ($1, $2, $3, $4::bigint, $5::bigint, $6::bigint, $7::bigint, $8::bigint,
+                        $9, $10, $11, $12)
+                "#,
+                canvas_block.id,
+                canvas_block.canvas_id,
+                canvas_block.block_id,
+                canvas_block.grid_x,
+                canvas_block.grid_y,
+                canvas_block.grid_width,
+                canvas_block.grid_height,
+                canvas_block.z,
+                canvas_block.scale,
+                canvas_block.content_visible,
+                canvas_block.created_at,
+                canvas_block.updated_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for pin in &snapshot.canvas_pins {
+            sqlx::query!(
+                "INSERT INTO canvas_pins (canvas_id, pinned_at) VALUES ($1, $2)",
+                pin.canvas_id,
+                pin.pinned_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
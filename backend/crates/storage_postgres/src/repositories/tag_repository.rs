@@ -0,0 +1,261 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{Acquire, Executor, Postgres};
+use uuid::Uuid;
+
+use domain::tags::Tag;
+use storage::helpers::sqlx_error_kind_helpers::{is_foreign_key_violation, is_unique_violation};
+use storage::repositories::TagRepository;
+use storage::repositories::tag_repository::{
+    BlockTagDto, TagCountDto, TagRepositoryError, TagRepositoryResult as Result,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct PostgresTagRepository;
+
+impl PostgresTagRepository {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait]
+impl TagRepository<Postgres> for PostgresTagRepository {
+    async fn get_by_id<'e, E>(&self, id: Uuid, executor: E) -> Result<Option<Tag>>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let tag = sqlx::query_as!(
+            Tag,
+            r#"
+            SELECT
+                id,
+                name,
+                created_at
+            FROM tags
+            WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(tag)
+    }
+
+    async fn get_all_with_counts<'e, E>(&self, executor: E) -> Result<Vec<TagCountDto>>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let tags = sqlx::query_as!(
+            TagCountDto,
+            r#"
+            SELECT
+                t.id,
+                t.name,
+                COUNT(b.id) as "block_count!"
+            FROM tags t
+            LEFT JOIN block_tags bt ON bt.tag_id = t.id
+            LEFT JOIN blocks b ON b.id = bt.block_id AND b.trashed_at IS NULL
+            GROUP BY t.id, t.name
+            ORDER BY lower(t.name) ASC
+            "#,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(tags)
+    }
+
+    async fn get_by_block<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<Tag>>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let tags = sqlx::query_as!(
+            Tag,
+            r#"
+            SELECT
+                t.id,
+                t.name,
+                t.created_at
+            FROM block_tags bt
+            JOIN tags t ON t.id = bt.tag_id
+            WHERE bt.block_id = $1
+            ORDER BY lower(t.name) ASC
+            "#,
+            block_id,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(tags)
+    }
+
+    async fn get_all_block_tags<'e, E>(&self, executor: E) -> Result<Vec<BlockTagDto>>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let block_tags = sqlx::query_as!(
+            BlockTagDto,
+            r#"
+            SELECT
+                bt.block_id,
+                t.name as tag_name
+            FROM block_tags bt
+            JOIN tags t ON t.id = bt.tag_id
+            JOIN blocks b ON b.id = bt.block_id
+            WHERE b.trashed_at IS NULL
+            ORDER BY bt.block_id, lower(t.name) ASC
+            "#,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(block_tags)
+    }
+
+    async fn tag_block<'e, E>(&self, block_id: Uuid, tag: &Tag, executor: E) -> Result<Tag>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+        let now = Utc::now();
+
+        sqlx::query!(
+            "INSERT INTO tags (id, name, created_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT ((lower(name))) DO NOTHING",
+            tag.id,
+            tag.name,
+            tag.created_at,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Tag names are unique on lower(name), so match the existing tag in any case
+        let stored = sqlx::query_as!(
+            Tag,
+            r#"
+            SELECT
+                id,
+                name,
+                created_at
+            FROM tags
+            WHERE lower(name) = lower($1)
+            "#,
+            tag.name,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO block_tags (block_id, tag_id, tagged_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT(block_id, tag_id) DO NOTHING",
+            block_id,
+            stored.id,
+            now,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| {
+            if is_foreign_key_violation(&err) {
+                TagRepositoryError::BlockNotFound { block_id }
+            } else {
+                err.into()
+            }
+        })?;
+
+        tx.commit().await?;
+
+        Ok(stored)
+    }
+
+    async fn untag_block<'e, E>(&self, block_id: Uuid, tag_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let result = sqlx::query!(
+            "DELETE FROM block_tags WHERE block_id = $1 AND tag_id = $2",
+            block_id,
+            tag_id,
+        )
+        .execute(executor)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(TagRepositoryError::NotTagged { block_id, tag_id });
+        }
+
+        Ok(())
+    }
+
+    async fn rename<'e, E>(&self, id: Uuid, name: &str, executor: E) -> Result<Tag>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let tag = sqlx::query_as!(
+            Tag,
+            r#"
+            UPDATE tags SET name = $2
+            WHERE id = $1
+            RETURNING
+                id,
+                name,
+                created_at
+            "#,
+            id,
+            name,
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(|err| {
+            if is_unique_violation(&err) {
+                TagRepositoryError::AlreadyExists {
+                    name: name.to_string(),
+                }
+            } else {
+                err.into()
+            }
+        })?;
+
+        tag.ok_or(TagRepositoryError::NotFound { id })
+    }
+
+    async fn merge<'e, E>(&self, source_id: Uuid, target_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Postgres> + Acquire<'e, Database = Postgres>,
+    {
+        if source_id == target_id {
+            return Err(TagRepositoryError::SelfMerge { id: source_id });
+        }
+
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        for id in [source_id, target_id] {
+            if self.get_by_id(id, &mut *tx).await?.is_none() {
+                return Err(TagRepositoryError::NotFound { id });
+            }
+        }
+
+        sqlx::query!(
+            "INSERT INTO block_tags (block_id, tag_id, tagged_at)
+            SELECT block_id, $2, tagged_at FROM block_tags WHERE tag_id = $1
+            ON CONFLICT(block_id, tag_id) DO NOTHING",
+            source_id,
+            target_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("DELETE FROM tags WHERE id = $1", source_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::postgres_db;
+use storage::database::Database;
+use storage::query_services::block_dag_query_service::BlockDagQueryServiceResult;
+use storage::query_services::block_dag_query_service::test_utils::{
+    assert_closure_follows_link_changes, assert_get_ancestors, assert_get_descendants,
+    assert_get_paths_between, assert_get_roots_and_leaves,
+};
+use storage_postgres::PostgresDb;
+use storage_postgres::query_services::PostgresBlockDagQueryService;
+use storage_postgres::repositories::{
+    PostgresBlockDirectionalLinkRepository, PostgresBlockRepository,
+};
+
+#[rstest]
+#[tokio::test]
+async fn block_dag_query_service_get_descendants(
+    #[future] postgres_db: PostgresDb,
+) -> BlockDagQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockDagQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+    let directional_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    assert_get_descendants(&query_service, &block_repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_dag_query_service_get_ancestors(
+    #[future] postgres_db: PostgresDb,
+) -> BlockDagQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockDagQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+    let directional_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    assert_get_ancestors(&query_service, &block_repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_dag_query_service_get_roots_and_leaves(
+    #[future] postgres_db: PostgresDb,
+) -> BlockDagQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockDagQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+    let directional_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    assert_get_roots_and_leaves(&query_service, &block_repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_dag_query_service_get_paths_between(
+    #[future] postgres_db: PostgresDb,
+) -> BlockDagQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockDagQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+    let directional_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    assert_get_paths_between(&query_service, &block_repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_dag_query_service_closure_follows_link_changes(
+    #[future] postgres_db: PostgresDb,
+) -> BlockDagQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockDagQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+    let directional_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    assert_closure_follows_link_changes(&query_service, &block_repo, &directional_repo, db.pool())
+        .await
+}
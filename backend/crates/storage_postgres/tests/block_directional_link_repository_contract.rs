@@ -80,3 +80,51 @@ async fn block_directional_link_duplicate(
 
     test_utils::assert_duplicate(&link_repo, &block_repo, db.pool()).await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_link_sibling_positions(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockDirectionalLinkRepositoryResult<()> {
+    let db = postgres_db.await;
+    let block_repo = PostgresBlockRepository::new();
+    let link_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_sibling_positions(&link_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_link_reorder_children(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockDirectionalLinkRepositoryResult<()> {
+    let db = postgres_db.await;
+    let block_repo = PostgresBlockRepository::new();
+    let link_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_reorder_children(&link_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_link_move_link(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockDirectionalLinkRepositoryResult<()> {
+    let db = postgres_db.await;
+    let block_repo = PostgresBlockRepository::new();
+    let link_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_move_link(&link_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_link_create_at(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockDirectionalLinkRepositoryResult<()> {
+    let db = postgres_db.await;
+    let block_repo = PostgresBlockRepository::new();
+    let link_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_create_at(&link_repo, &block_repo, db.pool()).await
+}
@@ -9,7 +9,9 @@ use storage::helpers::block_directional_path_helper::{
 };
 use storage_postgres::PostgresDb;
 use storage_postgres::helpers::PostgresBlockDirectionalPathHelper;
-use storage_postgres::repositories::PostgresBlockRepository;
+use storage_postgres::repositories::{
+    PostgresBlockDirectionalLinkRepository, PostgresBlockRepository,
+};
 
 #[rstest]
 #[tokio::test]
@@ -46,3 +48,44 @@ async fn block_directional_path_delete_by_block(
 
     test_utils::assert_delete_paths_using_block(&helper, &block_repo, db.pool()).await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_path_check_integrity_and_rebuild(
+    #[future] postgres_db: PostgresDb,
+) -> BlockDirectionalPathHelperResult<()> {
+    let db = postgres_db.await;
+    let helper = PostgresBlockDirectionalPathHelper::new();
+    let block_repo = PostgresBlockRepository::new();
+    let link_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_check_integrity_and_rebuild(&helper, &block_repo, &link_repo, db.pool())
+        .await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_path_check_integrity_reports_cycles(
+    #[future] postgres_db: PostgresDb,
+) -> BlockDirectionalPathHelperResult<()> {
+    let db = postgres_db.await;
+    let helper = PostgresBlockDirectionalPathHelper::new();
+    let block_repo = PostgresBlockRepository::new();
+    let link_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_check_integrity_reports_cycles(&helper, &block_repo, &link_repo, db.pool())
+        .await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_path_counts_saturate(
+    #[future] postgres_db: PostgresDb,
+) -> BlockDirectionalPathHelperResult<()> {
+    let db = postgres_db.await;
+    let helper = PostgresBlockDirectionalPathHelper::new();
+    let block_repo = PostgresBlockRepository::new();
+    let link_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_path_counts_saturate(&helper, &block_repo, &link_repo, db.pool()).await
+}
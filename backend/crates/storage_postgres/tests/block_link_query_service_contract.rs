@@ -8,13 +8,14 @@ use storage::query_services::block_link_query_service::BlockLinkQueryServiceResu
 use storage::query_services::block_link_query_service::test_utils::{
     assert_get_all_directional, assert_get_all_related, assert_get_child_blocks,
     assert_get_linked_blocks, assert_get_parent_blocks, assert_get_related_blocks,
+    assert_linked_blocks_skip_trashed,
 };
+use storage_postgres::PostgresDb;
 use storage_postgres::query_services::PostgresBlockLinkQueryService;
 use storage_postgres::repositories::{
     PostgresBlockDirectionalLinkRepository, PostgresBlockRelatedLinkRepository,
     PostgresBlockRepository,
 };
-use storage_postgres::PostgresDb;
 
 #[rstest]
 #[tokio::test]
@@ -141,3 +142,24 @@ async fn block_link_query_service_get_all_related(
     )
     .await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_link_query_service_linked_blocks_skip_trashed(
+    #[future] postgres_db: PostgresDb,
+) -> BlockLinkQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockLinkQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+    let directional_repo = PostgresBlockDirectionalLinkRepository::new();
+    let related_repo = PostgresBlockRelatedLinkRepository::new();
+
+    assert_linked_blocks_skip_trashed(
+        &query_service,
+        &block_repo,
+        &directional_repo,
+        &related_repo,
+        db.pool(),
+    )
+    .await
+}
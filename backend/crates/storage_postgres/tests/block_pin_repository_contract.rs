@@ -0,0 +1,44 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::postgres_db;
+use storage::database::Database;
+use storage::repositories::block_pin_repository::BlockPinRepositoryResult;
+use storage::repositories::block_pin_repository::test_utils::{
+    assert_pin_at, assert_pin_unpin, assert_unpin_missing,
+};
+use storage_postgres::PostgresDb;
+use storage_postgres::repositories::{PostgresBlockPinRepository, PostgresBlockRepository};
+
+#[rstest]
+#[tokio::test]
+async fn block_pin_repository_pin_unpin(
+    #[future] postgres_db: PostgresDb,
+) -> BlockPinRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresBlockPinRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+    assert_pin_unpin(&repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_pin_repository_unpin_missing(
+    #[future] postgres_db: PostgresDb,
+) -> BlockPinRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresBlockPinRepository::new();
+    assert_unpin_missing(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_pin_repository_pin_at(
+    #[future] postgres_db: PostgresDb,
+) -> BlockPinRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresBlockPinRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+    assert_pin_at(&repo, &block_repo, db.pool()).await
+}
@@ -7,10 +7,17 @@ use storage::database::Database;
 use storage::query_services::block_query_service::BlockQueryServiceResult;
 use storage::query_services::block_query_service::test_utils::{
     assert_get_all_returns_all_blocks, assert_get_opened_orders_by_tab_index,
-    assert_search_matches_title_or_content,
+    assert_search_applies_filters, assert_search_blank_query_returns_recent,
+    assert_search_escapes_snippet,
+    assert_search_filters_by_tag, assert_search_paginates, assert_search_ranks_title_above_content,
+    assert_search_reports_pinned, assert_stream_all_matches_get_all,
+    assert_trashed_blocks_are_hidden,
 };
 use storage_postgres::query_services::PostgresBlockQueryService;
-use storage_postgres::repositories::{PostgresBlockRepository, PostgresWorkspaceRepository};
+use storage_postgres::repositories::{
+    PostgresBlockDirectionalLinkRepository, PostgresBlockPinRepository, PostgresBlockRepository,
+    PostgresTagRepository, PostgresWorkspaceRepository,
+};
 use storage_postgres::PostgresDb;
 
 #[rstest]
@@ -34,14 +41,14 @@ async fn block_query_service_get_opened_orders_by_tab_index(
 
 #[rstest]
 #[tokio::test]
-async fn block_query_service_search_matches_title_or_content(
+async fn block_query_service_search_ranks_title_above_content(
     #[future] postgres_db: PostgresDb,
 ) -> BlockQueryServiceResult<()> {
     let db = postgres_db.await;
     let query_service = PostgresBlockQueryService::new();
     let block_repo = PostgresBlockRepository::new();
 
-    assert_search_matches_title_or_content(&query_service, &block_repo, db.pool()).await
+    assert_search_ranks_title_above_content(&query_service, &block_repo, db.pool()).await
 }
 
 #[rstest]
@@ -55,3 +62,113 @@ async fn block_query_service_get_all_returns_all_blocks(
 
     assert_get_all_returns_all_blocks(&query_service, &block_repo, db.pool()).await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_stream_all_matches_get_all(
+    #[future] postgres_db: PostgresDb,
+) -> BlockQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    assert_stream_all_matches_get_all(&query_service, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_escapes_snippet(
+    #[future] postgres_db: PostgresDb,
+) -> BlockQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    assert_search_escapes_snippet(&query_service, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_reports_pinned(
+    #[future] postgres_db: PostgresDb,
+) -> BlockQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+    let pin_repo = PostgresBlockPinRepository::new();
+
+    assert_search_reports_pinned(&query_service, &block_repo, &pin_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_filters_by_tag(
+    #[future] postgres_db: PostgresDb,
+) -> BlockQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+    let tag_repo = PostgresTagRepository::new();
+
+    assert_search_filters_by_tag(&query_service, &block_repo, &tag_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_blank_query_returns_recent(
+    #[future] postgres_db: PostgresDb,
+) -> BlockQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    assert_search_blank_query_returns_recent(&query_service, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_applies_filters(
+    #[future] postgres_db: PostgresDb,
+) -> BlockQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+    let directional_repo = PostgresBlockDirectionalLinkRepository::new();
+    let pin_repo = PostgresBlockPinRepository::new();
+    let workspace_repo = PostgresWorkspaceRepository::new();
+
+    assert_search_applies_filters(
+        &query_service,
+        &block_repo,
+        &directional_repo,
+        &pin_repo,
+        &workspace_repo,
+        db.pool(),
+    )
+    .await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_paginates(
+    #[future] postgres_db: PostgresDb,
+) -> BlockQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    assert_search_paginates(&query_service, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_trashed_blocks_are_hidden(
+    #[future] postgres_db: PostgresDb,
+) -> BlockQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+    let workspace_repo = PostgresWorkspaceRepository::new();
+
+    assert_trashed_blocks_are_hidden(&query_service, &block_repo, &workspace_repo, db.pool()).await
+}
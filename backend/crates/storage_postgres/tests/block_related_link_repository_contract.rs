@@ -78,3 +78,15 @@ async fn block_related_link_duplicate(
 
     test_utils::assert_duplicate(&link_repo, &block_repo, db.pool()).await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_related_link_create_at(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockRelatedLinkResult<()> {
+    let db = postgres_db.await;
+    let block_repo = PostgresBlockRepository::new();
+    let link_repo = PostgresBlockRelatedLinkRepository::new();
+
+    test_utils::assert_create_at(&link_repo, &block_repo, db.pool()).await
+}
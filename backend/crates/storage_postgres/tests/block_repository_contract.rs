@@ -5,7 +5,11 @@ use rstest::rstest;
 use fixtures::postgres_db;
 use storage::Database;
 use storage::repositories::block_repository::{BlockRepostoryResult, test_utils};
-use storage_postgres::repositories::PostgresBlockRepository;
+use storage_postgres::helpers::PostgresBlockDirectionalPathHelper;
+use storage_postgres::repositories::{
+    PostgresBlockDirectionalLinkRepository, PostgresBlockRelatedLinkRepository,
+    PostgresBlockRepository,
+};
 
 #[rstest]
 #[tokio::test]
@@ -28,3 +32,81 @@ async fn block_repository_delete_missing(
 
     test_utils::assert_delete_missing(&repo, db.pool()).await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_trash_keeps_links_and_restore_rebuilds_paths(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockRepostoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresBlockRepository::new();
+    let directional_repo = PostgresBlockDirectionalLinkRepository::new();
+    let related_repo = PostgresBlockRelatedLinkRepository::new();
+    let path_helper = PostgresBlockDirectionalPathHelper::new();
+
+    test_utils::assert_trash_keeps_links_and_restore_rebuilds_paths(
+        &repo,
+        &directional_repo,
+        &related_repo,
+        &path_helper,
+        db.pool(),
+    )
+    .await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_restore_rejects_cycle(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockRepostoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresBlockRepository::new();
+    let directional_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_restore_rejects_cycle(&repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_purge(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockRepostoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresBlockRepository::new();
+
+    test_utils::assert_purge(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_purge_closes_position_gaps(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockRepostoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresBlockRepository::new();
+    let directional_repo = PostgresBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_purge_closes_position_gaps(&repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_update_if_version(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockRepostoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresBlockRepository::new();
+
+    test_utils::assert_update_if_version(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_save_with_timestamps(
+    #[future] postgres_db: storage_postgres::PostgresDb,
+) -> BlockRepostoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresBlockRepository::new();
+
+    test_utils::assert_save_with_timestamps(&repo, db.pool()).await
+}
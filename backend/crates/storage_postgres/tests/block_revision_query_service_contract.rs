@@ -0,0 +1,37 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::postgres_db;
+use storage::database::Database;
+use storage::query_services::block_revision_query_service::BlockRevisionQueryServiceResult;
+use storage::query_services::block_revision_query_service::test_utils::{
+    assert_delete_removes_revisions, assert_save_records_revisions,
+};
+use storage_postgres::PostgresDb;
+use storage_postgres::query_services::PostgresBlockRevisionQueryService;
+use storage_postgres::repositories::PostgresBlockRepository;
+
+#[rstest]
+#[tokio::test]
+async fn block_revision_query_service_save_records_revisions(
+    #[future] postgres_db: PostgresDb,
+) -> BlockRevisionQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockRevisionQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    assert_save_records_revisions(&query_service, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_revision_query_service_delete_removes_revisions(
+    #[future] postgres_db: PostgresDb,
+) -> BlockRevisionQueryServiceResult<()> {
+    let db = postgres_db.await;
+    let query_service = PostgresBlockRevisionQueryService::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    assert_delete_removes_revisions(&query_service, &block_repo, db.pool()).await
+}
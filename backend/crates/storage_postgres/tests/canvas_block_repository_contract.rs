@@ -0,0 +1,77 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::postgres_db;
+use storage::database::Database;
+use storage::repositories::canvas_block_repository::CanvasBlockRepositoryResult;
+use storage::repositories::canvas_block_repository::test_utils::{
+    assert_delete_missing, assert_get_by_canvas_orders_by_z, assert_get_delete_save,
+    assert_save_many, assert_trashed_blocks_are_hidden,
+};
+use storage_postgres::PostgresDb;
+use storage_postgres::repositories::{
+    PostgresBlockRepository, PostgresCanvasBlockRepository, PostgresCanvasRepository,
+};
+
+#[rstest]
+#[tokio::test]
+async fn canvas_block_repository_get_delete_save(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasBlockRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasBlockRepository::new();
+    let canvas_repo = PostgresCanvasRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    assert_get_delete_save(&repo, &canvas_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_block_repository_get_by_canvas_orders_by_z(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasBlockRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasBlockRepository::new();
+    let canvas_repo = PostgresCanvasRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    assert_get_by_canvas_orders_by_z(&repo, &canvas_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_block_repository_save_many(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasBlockRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasBlockRepository::new();
+    let canvas_repo = PostgresCanvasRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    assert_save_many(&repo, &canvas_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_block_repository_delete_missing(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasBlockRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasBlockRepository::new();
+    assert_delete_missing(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_block_repository_trashed_blocks_are_hidden(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasBlockRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasBlockRepository::new();
+    let canvas_repo = PostgresCanvasRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    assert_trashed_blocks_are_hidden(&repo, &canvas_repo, &block_repo, db.pool()).await
+}
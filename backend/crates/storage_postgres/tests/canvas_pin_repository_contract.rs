@@ -0,0 +1,44 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::postgres_db;
+use storage::database::Database;
+use storage::repositories::canvas_pin_repository::CanvasPinRepositoryResult;
+use storage::repositories::canvas_pin_repository::test_utils::{
+    assert_pin_at, assert_pin_unpin, assert_unpin_missing,
+};
+use storage_postgres::PostgresDb;
+use storage_postgres::repositories::{PostgresCanvasPinRepository, PostgresCanvasRepository};
+
+#[rstest]
+#[tokio::test]
+async fn canvas_pin_repository_pin_unpin(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasPinRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasPinRepository::new();
+    let canvas_repo = PostgresCanvasRepository::new();
+    assert_pin_unpin(&repo, &canvas_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_pin_repository_unpin_missing(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasPinRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasPinRepository::new();
+    assert_unpin_missing(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_pin_repository_pin_at(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasPinRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasPinRepository::new();
+    let canvas_repo = PostgresCanvasRepository::new();
+    assert_pin_at(&repo, &canvas_repo, db.pool()).await
+}
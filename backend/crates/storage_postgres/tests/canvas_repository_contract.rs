@@ -0,0 +1,42 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::postgres_db;
+use storage::database::Database;
+use storage::repositories::canvas_repository::CanvasRepositoryResult;
+use storage::repositories::canvas_repository::test_utils::{
+    assert_delete_missing, assert_get_all, assert_get_delete_save,
+};
+use storage_postgres::PostgresDb;
+use storage_postgres::repositories::PostgresCanvasRepository;
+
+#[rstest]
+#[tokio::test]
+async fn canvas_repository_get_delete_save(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasRepository::new();
+    assert_get_delete_save(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_repository_get_all(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasRepository::new();
+    assert_get_all(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_repository_delete_missing(
+    #[future] postgres_db: PostgresDb,
+) -> CanvasRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresCanvasRepository::new();
+    assert_delete_missing(&repo, db.pool()).await
+}
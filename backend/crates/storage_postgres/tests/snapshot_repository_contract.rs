@@ -0,0 +1,43 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::postgres_db;
+use storage::database::Database;
+use storage::repositories::snapshot_repository::{SnapshotRepositoryResult, test_utils};
+use storage_postgres::PostgresDb;
+use storage_postgres::repositories::{PostgresBlockRepository, PostgresSnapshotRepository};
+
+#[rstest]
+#[tokio::test]
+async fn snapshot_count_rows_empty(
+    #[future] postgres_db: PostgresDb,
+) -> SnapshotRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresSnapshotRepository::new();
+
+    test_utils::assert_count_rows_empty(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn snapshot_write_read_round_trip(
+    #[future] postgres_db: PostgresDb,
+) -> SnapshotRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresSnapshotRepository::new();
+
+    test_utils::assert_write_read_round_trip(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn snapshot_write_into_non_empty_target(
+    #[future] postgres_db: PostgresDb,
+) -> SnapshotRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresSnapshotRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+
+    test_utils::assert_write_into_non_empty_target(&repo, &block_repo, db.pool()).await
+}
@@ -0,0 +1,50 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::postgres_db;
+use storage::database::Database;
+use storage::repositories::tag_repository::TagRepositoryResult;
+use storage::repositories::tag_repository::test_utils::{
+    assert_counts_skip_trashed, assert_merge, assert_rename, assert_tag_untag,
+};
+use storage_postgres::PostgresDb;
+use storage_postgres::repositories::{PostgresBlockRepository, PostgresTagRepository};
+
+#[rstest]
+#[tokio::test]
+async fn tag_repository_tag_untag(#[future] postgres_db: PostgresDb) -> TagRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresTagRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+    assert_tag_untag(&repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn tag_repository_counts_skip_trashed(
+    #[future] postgres_db: PostgresDb,
+) -> TagRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresTagRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+    assert_counts_skip_trashed(&repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn tag_repository_rename(#[future] postgres_db: PostgresDb) -> TagRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresTagRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+    assert_rename(&repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn tag_repository_merge(#[future] postgres_db: PostgresDb) -> TagRepositoryResult<()> {
+    let db = postgres_db.await;
+    let repo = PostgresTagRepository::new();
+    let block_repo = PostgresBlockRepository::new();
+    assert_merge(&repo, &block_repo, db.pool()).await
+}
@@ -0,0 +1,30 @@
+-- Migration: create_blocks_fts_table
+-- Full-text index over block titles and contents, kept in sync with blocks by triggers
+
+CREATE VIRTUAL TABLE IF NOT EXISTS blocks_fts USING fts5(
+    block_id UNINDEXED,
+    title,
+    content,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO blocks_fts (block_id, title, content)
+SELECT id, title, content FROM blocks;
+
+CREATE TRIGGER blocks_fts_after_insert AFTER INSERT ON blocks
+BEGIN
+    INSERT INTO blocks_fts (block_id, title, content)
+    VALUES (new.id, new.title, new.content);
+END;
+
+CREATE TRIGGER blocks_fts_after_update AFTER UPDATE OF title, content ON blocks
+BEGIN
+    UPDATE blocks_fts
+    SET title = new.title, content = new.content
+    WHERE block_id = old.id;
+END;
+
+CREATE TRIGGER blocks_fts_after_delete AFTER DELETE ON blocks
+BEGIN
+    DELETE FROM blocks_fts WHERE block_id = old.id;
+END;
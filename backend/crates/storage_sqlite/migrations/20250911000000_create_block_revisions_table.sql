@@ -0,0 +1,41 @@
+-- Migration: create_block_revisions_table
+-- Snapshot of every saved title/content, written by triggers on blocks
+
+CREATE TABLE IF NOT EXISTS block_revisions (
+    block_id BLOB NOT NULL,
+    revision INTEGER NOT NULL,
+    title TEXT NOT NULL,
+    content TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (block_id, revision),
+    FOREIGN KEY (block_id) REFERENCES blocks (id)
+        ON DELETE CASCADE
+);
+
+INSERT INTO block_revisions (block_id, revision, title, content, created_at)
+SELECT id, 1, title, content, updated_at FROM blocks;
+
+CREATE TRIGGER block_revisions_after_insert AFTER INSERT ON blocks
+BEGIN
+    INSERT INTO block_revisions (block_id, revision, title, content, created_at)
+    VALUES (
+        new.id,
+        (SELECT COALESCE(MAX(revision), 0) + 1 FROM block_revisions WHERE block_id = new.id),
+        new.title,
+        new.content,
+        new.updated_at
+    );
+END;
+
+CREATE TRIGGER block_revisions_after_update AFTER UPDATE OF title, content ON blocks
+WHEN old.title IS NOT new.title OR old.content IS NOT new.content
+BEGIN
+    INSERT INTO block_revisions (block_id, revision, title, content, created_at)
+    VALUES (
+        new.id,
+        (SELECT COALESCE(MAX(revision), 0) + 1 FROM block_revisions WHERE block_id = new.id),
+        new.title,
+        new.content,
+        new.updated_at
+    );
+END;
@@ -0,0 +1,6 @@
+-- Migration: add_trashed_at_to_blocks
+-- Trashed blocks keep their links but are hidden until restored or purged
+
+ALTER TABLE blocks ADD COLUMN trashed_at TEXT;
+
+CREATE INDEX idx_blocks_trashed_at ON blocks (trashed_at);
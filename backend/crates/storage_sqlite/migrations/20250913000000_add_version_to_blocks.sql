@@ -0,0 +1,4 @@
+-- Migration: add_version_to_blocks
+-- Incremented on every save so clients can detect concurrent edits
+
+ALTER TABLE blocks ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,19 @@
+-- Migration: create_tag_tables
+-- Tags are unique ignoring case and attach to blocks many-to-many
+
+CREATE TABLE IF NOT EXISTS tags (
+    id BLOB PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL COLLATE NOCASE UNIQUE,
+    created_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS block_tags (
+    block_id BLOB NOT NULL,
+    tag_id BLOB NOT NULL,
+    tagged_at TEXT NOT NULL,
+    PRIMARY KEY (block_id, tag_id),
+    FOREIGN KEY (block_id) REFERENCES blocks (id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tags (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_block_tags_tag_id ON block_tags (tag_id);
@@ -0,0 +1,9 @@
+-- Migration: add_annotations_to_block_links
+-- Optional kind on directional links and label/weight on both link types
+
+ALTER TABLE block_directional_links ADD COLUMN kind TEXT;
+ALTER TABLE block_directional_links ADD COLUMN label TEXT;
+ALTER TABLE block_directional_links ADD COLUMN weight REAL;
+
+ALTER TABLE block_related_links ADD COLUMN label TEXT;
+ALTER TABLE block_related_links ADD COLUMN weight REAL;
@@ -0,0 +1,19 @@
+-- Migration: add_position_to_block_directional_links
+-- Sibling order of children under the same parent
+
+ALTER TABLE block_directional_links ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
+
+-- Existing children keep their creation order
+UPDATE block_directional_links
+SET position = (
+    SELECT COUNT(*)
+    FROM block_directional_links o
+    WHERE o.block_from_id = block_directional_links.block_from_id
+      AND (
+          o.created_at < block_directional_links.created_at
+          OR (o.created_at = block_directional_links.created_at AND o.id < block_directional_links.id)
+      )
+);
+
+CREATE INDEX idx_block_directional_links_block_from_id_position
+    ON block_directional_links (block_from_id, position);
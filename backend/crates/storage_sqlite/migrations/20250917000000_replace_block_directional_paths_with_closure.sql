@@ -0,0 +1,24 @@
+-- Migration: replace_block_directional_paths_with_closure
+-- Store reachability once per (ancestor, descendant) pair instead of once per path.
+-- Paths are enumerated from the links on demand.
+CREATE TABLE block_directional_closure (
+    block_ancestor_id BLOB NOT NULL,
+    block_descendant_id BLOB NOT NULL,
+    path_count INTEGER NOT NULL, -- Number of distinct paths between the pair
+    min_depth INTEGER NOT NULL, -- Links on the shortest path
+    PRIMARY KEY (block_ancestor_id, block_descendant_id),
+    FOREIGN KEY (block_ancestor_id) REFERENCES blocks (id) ON DELETE CASCADE,
+    FOREIGN KEY (block_descendant_id) REFERENCES blocks (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_block_directional_closure_descendant
+    ON block_directional_closure (block_descendant_id);
+
+INSERT INTO block_directional_closure
+    (block_ancestor_id, block_descendant_id, path_count, min_depth)
+SELECT block_ancestor_id, block_descendant_id, COUNT(*), MIN(path_length) - 1
+FROM block_directional_paths
+WHERE block_ancestor_id != block_descendant_id
+GROUP BY block_ancestor_id, block_descendant_id;
+
+DROP TABLE block_directional_paths;
@@ -0,0 +1,4 @@
+-- Migration: lowercase_tag_names
+-- Tag names are now lowercased by the domain before they are stored or looked up.
+-- SQLite's lower() only folds ASCII; the NOCASE unique column rules out clashes.
+UPDATE tags SET name = lower(name);
@@ -0,0 +1,40 @@
+-- Migration: key_blocks_fts_by_rowid
+-- blocks_fts was keyed by an UNINDEXED block_id column, so syncing a block and
+-- joining search hits back to blocks both scanned the whole index. It is now an
+-- external-content table over blocks, keyed by the blocks rowid.
+
+DROP TRIGGER IF EXISTS blocks_fts_after_insert;
+DROP TRIGGER IF EXISTS blocks_fts_after_update;
+DROP TRIGGER IF EXISTS blocks_fts_after_delete;
+DROP TABLE IF EXISTS blocks_fts;
+
+CREATE VIRTUAL TABLE blocks_fts USING fts5(
+    title,
+    content,
+    content = 'blocks',
+    content_rowid = 'rowid',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- 'rebuild' re-reads every row of blocks, and can be rerun to resync the index
+INSERT INTO blocks_fts (blocks_fts) VALUES ('rebuild');
+
+CREATE TRIGGER blocks_fts_after_insert AFTER INSERT ON blocks
+BEGIN
+    INSERT INTO blocks_fts (rowid, title, content)
+    VALUES (new.rowid, new.title, new.content);
+END;
+
+CREATE TRIGGER blocks_fts_after_update AFTER UPDATE OF title, content ON blocks
+BEGIN
+    INSERT INTO blocks_fts (blocks_fts, rowid, title, content)
+    VALUES ('delete', old.rowid, old.title, old.content);
+    INSERT INTO blocks_fts (rowid, title, content)
+    VALUES (new.rowid, new.title, new.content);
+END;
+
+CREATE TRIGGER blocks_fts_after_delete AFTER DELETE ON blocks
+BEGIN
+    INSERT INTO blocks_fts (blocks_fts, rowid, title, content)
+    VALUES ('delete', old.rowid, old.title, old.content);
+END;
@@ -0,0 +1,55 @@
+-- Migration: key_blocks_fts_by_stable_id
+-- blocks has a BLOB primary key, so its implicit rowid is not stable: VACUUM
+-- may renumber it, which would leave an index keyed by that rowid pointing at
+-- the wrong blocks. The index now stores each block's id and is keyed by an
+-- integer from blocks_fts_keys, so syncing a block is still a rowid lookup.
+
+DROP TRIGGER IF EXISTS blocks_fts_after_insert;
+DROP TRIGGER IF EXISTS blocks_fts_after_update;
+DROP TRIGGER IF EXISTS blocks_fts_after_delete;
+DROP TABLE IF EXISTS blocks_fts;
+
+-- INTEGER PRIMARY KEY is an alias for the rowid, which VACUUM keeps
+CREATE TABLE blocks_fts_keys (
+    id INTEGER PRIMARY KEY,
+    block_id BLOB NOT NULL UNIQUE
+);
+
+CREATE VIRTUAL TABLE blocks_fts USING fts5(
+    block_id UNINDEXED,
+    title,
+    content,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO blocks_fts_keys (block_id)
+SELECT id FROM blocks;
+
+INSERT INTO blocks_fts (rowid, block_id, title, content)
+SELECT k.id, b.id, b.title, b.content
+FROM blocks b
+JOIN blocks_fts_keys k ON k.block_id = b.id;
+
+CREATE TRIGGER blocks_fts_after_insert AFTER INSERT ON blocks
+BEGIN
+    INSERT INTO blocks_fts_keys (block_id) VALUES (new.id);
+    INSERT INTO blocks_fts (rowid, block_id, title, content)
+    VALUES (
+        (SELECT id FROM blocks_fts_keys WHERE block_id = new.id),
+        new.id, new.title, new.content
+    );
+END;
+
+CREATE TRIGGER blocks_fts_after_update AFTER UPDATE OF title, content ON blocks
+BEGIN
+    UPDATE blocks_fts
+    SET title = new.title, content = new.content
+    WHERE rowid = (SELECT id FROM blocks_fts_keys WHERE block_id = old.id);
+END;
+
+CREATE TRIGGER blocks_fts_after_delete AFTER DELETE ON blocks
+BEGIN
+    DELETE FROM blocks_fts
+    WHERE rowid = (SELECT id FROM blocks_fts_keys WHERE block_id = old.id);
+    DELETE FROM blocks_fts_keys WHERE block_id = old.id;
+END;
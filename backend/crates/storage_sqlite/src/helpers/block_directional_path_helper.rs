@@ -1,15 +1,13 @@
-use std::collections::HashSet;
-
 use async_trait::async_trait;
-use chrono::Utc;
-use sqlx::{Acquire, Executor, Sqlite};
+use sqlx::{Acquire, Executor, Sqlite, SqliteConnection};
 use uuid::Uuid;
 
 use storage::helpers::block_directional_path_helper::{
     BlockDirectionalPathHelper, BlockDirectionalPathHelperError,
-    BlockDirectionalPathHelperResult as Result,
+    BlockDirectionalPathHelperResult as Result, ClosureRowDto, PathIntegrityReportDto,
+    compare_closure, derive_closure,
 };
-use storage::helpers::sqlx_error_kind_helpers::{is_foreign_key_violation, is_unique_violation};
+use storage::helpers::sqlx_error_kind_helpers::is_foreign_key_violation;
 
 #[derive(Clone, Debug, Default)]
 pub struct SqliteBlockDirectionalPathHelper;
@@ -32,7 +30,7 @@ impl BlockDirectionalPathHelper<Sqlite> for SqliteBlockDirectionalPathHelper {
         E: Executor<'e, Database = Sqlite>,
     {
         let count = sqlx::query_scalar!(
-            "SELECT EXISTS (SELECT 1 FROM block_directional_paths
+            "SELECT EXISTS (SELECT 1 FROM block_directional_closure
             WHERE block_ancestor_id = $1 AND block_descendant_id = $2)",
             ancestor_id,
             descendant_id,
@@ -55,34 +53,68 @@ impl BlockDirectionalPathHelper<Sqlite> for SqliteBlockDirectionalPathHelper {
         let mut conn = executor.acquire().await?;
         let mut tx = conn.begin().await?;
 
-        // Add direct path
-        let direct_path = vec![from_id, to_id];
-        self.create_path(direct_path, &mut *tx).await?;
-
-        //  Extend all paths ending at from_id
-        let from_id_ancestor_paths = self.get_paths_to_block(from_id, &mut *tx).await?;
-        for path in &from_id_ancestor_paths {
-            let mut extended_path = path.clone();
-            extended_path.push(to_id);
-            self.create_path(extended_path, &mut *tx).await?;
-        }
-
-        // Extend all paths starting from to_id
-        let to_id_descendant_paths = self.get_paths_from_block(to_id, &mut *tx).await?;
-        for path in &to_id_descendant_paths {
-            let mut extended_path = vec![from_id];
-            extended_path.extend(path.iter());
-            self.create_path(extended_path, &mut *tx).await?;
+        if from_id == to_id
+            || self
+                .is_ancestor_descendant(to_id, from_id, &mut *tx)
+                .await?
+        {
+            return Err(BlockDirectionalPathHelperError::CyclicPathCreation);
         }
 
-        // Connect all ancestors of from_id to all descendants of to_id
-        for ancestor_path in &from_id_ancestor_paths {
-            for descendant_path in &to_id_descendant_paths {
-                let mut combined_path = ancestor_path.clone();
-                combined_path.extend(descendant_path.iter());
-                self.create_path(combined_path, &mut *tx).await?;
+        // Every ancestor of from_id (and from_id itself) now reaches every
+        // descendant of to_id (and to_id itself) through the new link. Counts
+        // saturate at i64::MAX instead of turning into REAL.
+        sqlx::query!(
+            "
+            WITH
+                ancestors(id, path_count, depth) AS (
+                    SELECT $1, 1, 0
+                    UNION ALL
+                    SELECT block_ancestor_id, path_count, min_depth
+                    FROM block_directional_closure
+                    WHERE block_descendant_id = $1
+                ),
+                descendants(id, path_count, depth) AS (
+                    SELECT $2, 1, 0
+                    UNION ALL
+                    SELECT block_descendant_id, path_count, min_depth
+                    FROM block_directional_closure
+                    WHERE block_ancestor_id = $2
+                )
+            INSERT INTO block_directional_closure
+                (block_ancestor_id, block_descendant_id, path_count, min_depth)
+            SELECT
+                a.id,
+                d.id,
+                CASE WHEN a.path_count > 9223372036854775807 / d.path_count
+                    THEN 9223372036854775807
+                    ELSE a.path_count * d.path_count
+                END,
+                a.depth + 1 + d.depth
+            FROM ancestors a CROSS JOIN descendants d
+            WHERE true
+            ON CONFLICT (block_ancestor_id, block_descendant_id) DO UPDATE SET
+                path_count = CASE
+                    WHEN path_count > 9223372036854775807 - excluded.path_count
+                    THEN 9223372036854775807
+                    ELSE path_count + excluded.path_count
+                END,
+                min_depth = MIN(min_depth, excluded.min_depth)
+            ",
+            from_id,
+            to_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            if is_foreign_key_violation(&e) {
+                return BlockDirectionalPathHelperError::PathBlocksNotFound {
+                    from: from_id,
+                    to: to_id,
+                };
             }
-        }
+            BlockDirectionalPathHelperError::Database(e)
+        })?;
 
         tx.commit().await?;
 
@@ -96,165 +128,456 @@ impl BlockDirectionalPathHelper<Sqlite> for SqliteBlockDirectionalPathHelper {
         executor: E,
     ) -> Result<()>
     where
-        E: Executor<'e, Database = Sqlite>,
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
     {
-        // sqlx stores Uuid as BLOB to sqlite by default (even if it is configured as TEXT type)
-        // So we need to manually convert Uuid to string to match the format in paths
-        let from_id_str = from_id.to_string();
-        let to_id_str = to_id.to_string();
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        // Links to trashed blocks never made it into the closure
+        if !self
+            .is_ancestor_descendant(from_id, to_id, &mut *tx)
+            .await?
+        {
+            return Ok(());
+        }
 
         sqlx::query!(
             "
-            DELETE FROM block_directional_paths
-            WHERE id IN (
-                SELECT bdp.id
-                FROM block_directional_paths AS bdp
-                WHERE EXISTS (
-                    SELECT 1 FROM json_each(bdp.block_path_ids) AS a
-                    JOIN json_each(bdp.block_path_ids) AS b ON a.key = b.key - 1
-                    WHERE a.value = $1 AND b.value = $2
+            WITH
+                ancestors(id, path_count) AS (
+                    SELECT $1, 1
+                    UNION ALL
+                    SELECT block_ancestor_id, path_count
+                    FROM block_directional_closure
+                    WHERE block_descendant_id = $1
+                ),
+                descendants(id, path_count) AS (
+                    SELECT $2, 1
+                    UNION ALL
+                    SELECT block_descendant_id, path_count
+                    FROM block_directional_closure
+                    WHERE block_ancestor_id = $2
                 )
-            )
+            UPDATE block_directional_closure
+            SET path_count = CASE
+                WHEN path_count = 9223372036854775807 THEN path_count
+                ELSE path_count - (
+                    SELECT a.path_count * d.path_count
+                    FROM ancestors a, descendants d
+                    WHERE a.id = block_directional_closure.block_ancestor_id
+                      AND d.id = block_directional_closure.block_descendant_id
+                )
+            END
+            WHERE block_ancestor_id IN (SELECT id FROM ancestors)
+              AND block_descendant_id IN (SELECT id FROM descendants)
             ",
-            from_id_str,
-            to_id_str,
+            from_id,
+            to_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM block_directional_closure
+            WHERE path_count <= 0
+              AND (block_descendant_id = $1 OR block_descendant_id IN (
+                SELECT block_descendant_id FROM block_directional_closure
+                WHERE block_ancestor_id = $1
+              ))
+            ",
+            to_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Children are settled before their parents, and from_id has the most
+        // ancestors of all the blocks above the link
+        let ancestors = sqlx::query!(
+            r#"
+            SELECT
+                c.block_ancestor_id as "id: Uuid",
+                c.min_depth as "depth: i64"
+            FROM block_directional_closure c
+            WHERE c.block_descendant_id = $1
+            ORDER BY (
+                SELECT COUNT(*) FROM block_directional_closure up
+                WHERE up.block_descendant_id = c.block_ancestor_id
+            ) DESC
+            "#,
+            from_id,
         )
-        .execute(executor)
+        .fetch_all(&mut *tx)
         .await?;
 
+        let ancestor_ids = std::iter::once(from_id).chain(ancestors.iter().map(|a| a.id));
+        for ancestor_id in ancestor_ids {
+            Self::recount_saturated_rows(ancestor_id, to_id, Some(from_id), to_id, &mut tx).await?;
+        }
+
+        // Pairs whose shortest route ran through the link need a new one
+        self.refresh_depths_after_link_removal(from_id, 0, from_id, to_id, &mut tx)
+            .await?;
+        for ancestor in ancestors {
+            self.refresh_depths_after_link_removal(
+                ancestor.id,
+                ancestor.depth,
+                from_id,
+                to_id,
+                &mut tx,
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
         Ok(())
     }
 
     async fn delete_paths_using_block<'e, E>(&self, block_id: Uuid, executor: E) -> Result<()>
     where
-        E: Executor<'e, Database = Sqlite>,
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
     {
-        // sqlx stores Uuid as BLOB to sqlite by default (even if it is configured as TEXT type)
-        // So we need to manually convert Uuid to string to match the format in paths
-        let block_id_str = block_id.to_string();
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
 
         sqlx::query!(
-            "DELETE FROM block_directional_paths
-            WHERE EXISTS (
-                SELECT 1 FROM json_each(block_path_ids)
-                WHERE value = $1
-            )",
-            block_id_str
+            "
+            UPDATE block_directional_closure
+            SET path_count = CASE
+                WHEN path_count = 9223372036854775807 THEN path_count
+                ELSE path_count - (
+                    SELECT up.path_count * down.path_count
+                    FROM block_directional_closure up, block_directional_closure down
+                    WHERE up.block_ancestor_id = block_directional_closure.block_ancestor_id
+                      AND up.block_descendant_id = $1
+                      AND down.block_ancestor_id = $1
+                      AND down.block_descendant_id = block_directional_closure.block_descendant_id
+                )
+            END
+            WHERE block_ancestor_id IN (
+                SELECT block_ancestor_id FROM block_directional_closure
+                WHERE block_descendant_id = $1
+              )
+              AND block_descendant_id IN (
+                SELECT block_descendant_id FROM block_directional_closure
+                WHERE block_ancestor_id = $1
+              )
+            ",
+            block_id,
         )
-        .execute(executor)
+        .execute(&mut *tx)
         .await?;
 
-        Ok(())
-    }
-}
+        sqlx::query!(
+            "
+            DELETE FROM block_directional_closure
+            WHERE path_count <= 0
+              AND block_descendant_id IN (
+                SELECT block_descendant_id FROM block_directional_closure
+                WHERE block_ancestor_id = $1
+              )
+            ",
+            block_id,
+        )
+        .execute(&mut *tx)
+        .await?;
 
-impl SqliteBlockDirectionalPathHelper {
-    async fn get_paths_from_block<'e, E>(
-        &self,
-        block_id: Uuid,
-        executor: E,
-    ) -> Result<Vec<Vec<Uuid>>>
-    where
-        E: Executor<'e, Database = Sqlite>,
-    {
-        let path_strs = sqlx::query_scalar!(
-            "SELECT block_path_ids from block_directional_paths
-            WHERE block_ancestor_id = $1",
+        // The block's own rows stay until the end so the remaining pairs can
+        // still be matched against the depths that ran through it
+        let ancestors = sqlx::query!(
+            r#"
+            SELECT
+                c.block_ancestor_id as "id: Uuid",
+                c.min_depth as "depth: i64"
+            FROM block_directional_closure c
+            WHERE c.block_descendant_id = $1
+            ORDER BY (
+                SELECT COUNT(*) FROM block_directional_closure up
+                WHERE up.block_descendant_id = c.block_ancestor_id
+            ) DESC
+            "#,
             block_id,
         )
-        .fetch_all(executor)
+        .fetch_all(&mut *tx)
         .await?;
 
-        let mut paths: Vec<Vec<Uuid>> = Vec::new();
+        for ancestor in &ancestors {
+            Self::recount_saturated_rows(ancestor.id, block_id, None, block_id, &mut tx).await?;
+        }
 
-        for path_str in path_strs {
-            let path_ids: Vec<Uuid> = serde_json::from_str(&path_str)?;
-            paths.push(path_ids);
+        for ancestor in ancestors {
+            sqlx::query!(
+                "
+                UPDATE block_directional_closure
+                SET min_depth = (
+                    SELECT MIN(CASE
+                        WHEN l.block_to_id = block_directional_closure.block_descendant_id THEN 1
+                        ELSE 1 + c.min_depth
+                    END)
+                    FROM block_directional_links l
+                    LEFT JOIN block_directional_closure c
+                      ON c.block_ancestor_id = l.block_to_id
+                     AND c.block_descendant_id = block_directional_closure.block_descendant_id
+                    WHERE l.block_from_id = block_directional_closure.block_ancestor_id
+                      AND l.block_to_id != $3
+                      AND (l.block_to_id = block_directional_closure.block_descendant_id
+                           OR c.block_ancestor_id IS NOT NULL)
+                )
+                WHERE block_ancestor_id = $1
+                  AND min_depth = $2 + (
+                    SELECT down.min_depth FROM block_directional_closure down
+                    WHERE down.block_ancestor_id = $3
+                      AND down.block_descendant_id = block_directional_closure.block_descendant_id
+                  )
+                ",
+                ancestor.id,
+                ancestor.depth,
+                block_id,
+            )
+            .execute(&mut *tx)
+            .await?;
         }
 
-        Ok(paths)
+        sqlx::query!(
+            "DELETE FROM block_directional_closure
+            WHERE block_ancestor_id = $1 OR block_descendant_id = $1",
+            block_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
     }
 
-    async fn get_paths_to_block<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<Vec<Uuid>>>
+    async fn check_integrity<'e, E>(&self, executor: E) -> Result<PathIntegrityReportDto>
     where
-        E: Executor<'e, Database = Sqlite>,
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
     {
-        let path_strs = sqlx::query_scalar!(
-            "SELECT block_path_ids from block_directional_paths
-            WHERE block_descendant_id = $1",
-            block_id,
-        )
-        .fetch_all(executor)
-        .await?;
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
 
-        let mut paths: Vec<Vec<Uuid>> = Vec::new();
+        let derived = derive_closure(&Self::get_live_links(&mut tx).await?);
+        let stored = Self::get_stored_rows(&mut tx).await?;
 
-        for path_str in path_strs {
-            let path_ids: Vec<Uuid> = serde_json::from_str(&path_str)?;
-            paths.push(path_ids);
-        }
+        tx.commit().await?;
 
-        Ok(paths)
+        Ok(compare_closure(&derived, stored))
     }
 
-    async fn create_path<'e, E>(&self, path_ids: Vec<Uuid>, executor: E) -> Result<()>
+    async fn rebuild<'e, E>(&self, executor: E) -> Result<PathIntegrityReportDto>
     where
-        E: Executor<'e, Database = Sqlite>,
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
     {
-        self.ensure_nonempty_path(&path_ids)?;
-        self.ensure_noncyclic_path(&path_ids)?;
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let derived = derive_closure(&Self::get_live_links(&mut tx).await?);
+        if !derived.cyclic_block_ids.is_empty() {
+            return Err(BlockDirectionalPathHelperError::CyclicLinks {
+                block_ids: derived.cyclic_block_ids,
+            });
+        }
 
-        let id = Uuid::new_v4();
-        let ancestor_id = path_ids.first().unwrap();
-        let descendant_id = path_ids.last().unwrap();
+        let stored = Self::get_stored_rows(&mut tx).await?;
+        let report = compare_closure(&derived, stored);
+
+        sqlx::query!("DELETE FROM block_directional_closure")
+            .execute(&mut *tx)
+            .await?;
+
+        for row in &derived.rows {
+            sqlx::query!(
+                "
+                INSERT INTO block_directional_closure
+                    (block_ancestor_id, block_descendant_id, path_count, min_depth)
+                VALUES ($1, $2, $3, $4)
+                ",
+                row.block_ancestor_id,
+                row.block_descendant_id,
+                row.path_count,
+                row.min_depth,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
 
-        let path_json = serde_json::to_string(&path_ids)?;
-        let path_len = path_ids.len() as i32;
-        let now = Utc::now();
+        tx.commit().await?;
+
+        Ok(report)
+    }
+}
+
+impl SqliteBlockDirectionalPathHelper {
+    /// Links whose blocks are both out of the trash, as (from, to) pairs.
+    async fn get_live_links(conn: &mut SqliteConnection) -> Result<Vec<(Uuid, Uuid)>> {
+        let links = sqlx::query!(
+            r#"
+            SELECT
+                l.block_from_id as "block_from_id: Uuid",
+                l.block_to_id as "block_to_id: Uuid"
+            FROM block_directional_links l
+            JOIN blocks f ON f.id = l.block_from_id
+            JOIN blocks t ON t.id = l.block_to_id
+            WHERE f.trashed_at IS NULL AND t.trashed_at IS NULL
+            "#,
+        )
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|link| (link.block_from_id, link.block_to_id))
+        .collect();
+
+        Ok(links)
+    }
+
+    async fn get_stored_rows(conn: &mut SqliteConnection) -> Result<Vec<ClosureRowDto>> {
+        let rows = sqlx::query_as!(
+            ClosureRowDto,
+            r#"
+            SELECT
+                block_ancestor_id as "block_ancestor_id: Uuid",
+                block_descendant_id as "block_descendant_id: Uuid",
+                path_count,
+                min_depth
+            FROM block_directional_closure
+            "#,
+        )
+        .fetch_all(&mut *conn)
+        .await?;
 
+        Ok(rows)
+    }
+
+    /// Recomputes `min_depth` for the rows of `ancestor_id` whose shortest
+    /// route may have used the removed link, from the depths of its children.
+    async fn refresh_depths_after_link_removal(
+        &self,
+        ancestor_id: Uuid,
+        depth_to_from: i64,
+        from_id: Uuid,
+        to_id: Uuid,
+        conn: &mut SqliteConnection,
+    ) -> Result<()> {
         sqlx::query!(
-            "INSERT INTO block_directional_paths
-            (id, block_ancestor_id, block_descendant_id, block_path_ids, path_length, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6)",
-            id,
+            "
+            UPDATE block_directional_closure
+            SET min_depth = (
+                SELECT MIN(CASE
+                    WHEN l.block_to_id = block_directional_closure.block_descendant_id THEN 1
+                    ELSE 1 + c.min_depth
+                END)
+                FROM block_directional_links l
+                LEFT JOIN block_directional_closure c
+                  ON c.block_ancestor_id = l.block_to_id
+                 AND c.block_descendant_id = block_directional_closure.block_descendant_id
+                WHERE l.block_from_id = block_directional_closure.block_ancestor_id
+                  AND NOT (l.block_from_id = $3 AND l.block_to_id = $4)
+                  AND (l.block_to_id = block_directional_closure.block_descendant_id
+                       OR c.block_ancestor_id IS NOT NULL)
+            )
+            WHERE block_ancestor_id = $1
+              AND (
+                (block_descendant_id = $4 AND min_depth = $2 + 1)
+                OR min_depth = $2 + 1 + (
+                    SELECT down.min_depth FROM block_directional_closure down
+                    WHERE down.block_ancestor_id = $4
+                      AND down.block_descendant_id = block_directional_closure.block_descendant_id
+                )
+              )
+            ",
             ancestor_id,
-            descendant_id,
-            path_json,
-            path_len,
-            now,
+            depth_to_from,
+            from_id,
+            to_id,
         )
-        .execute(executor)
-        .await
-        .map_err(|e| {
-            if is_foreign_key_violation(&e) {
-                return BlockDirectionalPathHelperError::PathBlocksNotFound {
-                    from: *ancestor_id,
-                    to: *descendant_id,
-                };
-            } else if is_unique_violation(&e) {
-                return BlockDirectionalPathHelperError::AlreadyExists {
-                    from: *ancestor_id,
-                    to: *descendant_id,
-                };
-            }
-            BlockDirectionalPathHelperError::Database(e)
-        })?;
+        .execute(&mut *conn)
+        .await?;
 
         Ok(())
     }
 
-    fn ensure_nonempty_path(&self, path_ids: &[Uuid]) -> Result<()> {
-        if path_ids.is_empty() {
-            return Err(BlockDirectionalPathHelperError::EmptyPathCreation);
-        }
-        Ok(())
-    }
+    /// Saturated counts are never decremented, as the removed paths may be only
+    /// part of them. Once the other counts are settled, recounts the saturated
+    /// rows from `ancestor_id` to `scope_id` and its descendants from the rows
+    /// of its children, skipping the removed links into `removed_to_id` (from
+    /// `removed_from_id` only, when given). Ancestors must be passed children
+    /// first.
+    async fn recount_saturated_rows(
+        ancestor_id: Uuid,
+        scope_id: Uuid,
+        removed_from_id: Option<Uuid>,
+        removed_to_id: Uuid,
+        conn: &mut SqliteConnection,
+    ) -> Result<()> {
+        let descendant_ids = sqlx::query_scalar!(
+            r#"
+            SELECT block_descendant_id as "id: Uuid" FROM block_directional_closure
+            WHERE block_ancestor_id = $1
+              AND path_count = 9223372036854775807
+              AND (block_descendant_id = $2 OR block_descendant_id IN (
+                SELECT block_descendant_id FROM block_directional_closure
+                WHERE block_ancestor_id = $2
+              ))
+            "#,
+            ancestor_id,
+            scope_id,
+        )
+        .fetch_all(&mut *conn)
+        .await?;
 
-    fn ensure_noncyclic_path(&self, path_ids: &[Uuid]) -> Result<()> {
-        let unique_ids: HashSet<_> = path_ids.iter().collect();
-        if path_ids.len() != unique_ids.len() {
-            return Err(BlockDirectionalPathHelperError::CyclicPathCreation);
+        for descendant_id in descendant_ids {
+            let through_children = sqlx::query_scalar!(
+                r#"
+                SELECT CASE
+                    WHEN l.block_to_id = $2 THEN 1
+                    ELSE COALESCE(c.path_count, 0)
+                END as "path_count!: i64"
+                FROM block_directional_links l
+                LEFT JOIN block_directional_closure c
+                  ON c.block_ancestor_id = l.block_to_id
+                 AND c.block_descendant_id = $2
+                WHERE l.block_from_id = $1
+                  AND NOT (l.block_to_id = $4 AND ($3 IS NULL OR l.block_from_id = $3))
+                "#,
+                ancestor_id,
+                descendant_id,
+                removed_from_id,
+                removed_to_id,
+            )
+            .fetch_all(&mut *conn)
+            .await?;
+
+            let path_count = through_children.into_iter().fold(0i64, i64::saturating_add);
+
+            if path_count > 0 {
+                sqlx::query!(
+                    "
+                    UPDATE block_directional_closure SET path_count = $3
+                    WHERE block_ancestor_id = $1 AND block_descendant_id = $2
+                    ",
+                    ancestor_id,
+                    descendant_id,
+                    path_count,
+                )
+                .execute(&mut *conn)
+                .await?;
+            } else {
+                sqlx::query!(
+                    "
+                    DELETE FROM block_directional_closure
+                    WHERE block_ancestor_id = $1 AND block_descendant_id = $2
+                    ",
+                    ancestor_id,
+                    descendant_id,
+                )
+                .execute(&mut *conn)
+                .await?;
+            }
         }
+
         Ok(())
     }
 }
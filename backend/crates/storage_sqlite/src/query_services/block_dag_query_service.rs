@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use sqlx::{Executor, Sqlite};
+use uuid::Uuid;
+
+use storage::query_services::BlockDagQueryService;
+use storage::query_services::block_dag_query_service::{
+    BlockDagQueryServiceResult as Result, BlockPathsDto, DagBlockDto, enumerate_paths,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct SqliteBlockDagQueryService;
+
+impl SqliteBlockDagQueryService {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait]
+impl BlockDagQueryService<Sqlite> for SqliteBlockDagQueryService {
+    async fn get_descendants<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<DagBlockDto>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let descendants = sqlx::query_as!(
+            DagBlockDto,
+            r#"
+            SELECT
+                b.id as "id: _",
+                b.title,
+                bdp.min_depth as "depth!: i64",
+                b.created_at as "created_at: _",
+                b.updated_at as "updated_at: _"
+            FROM block_directional_closure bdp
+            JOIN blocks b ON b.id = bdp.block_descendant_id
+            WHERE bdp.block_ancestor_id = $1
+            ORDER BY bdp.min_depth ASC, b.created_at ASC
+            "#,
+            block_id,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(descendants)
+    }
+
+    async fn get_ancestors<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<DagBlockDto>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let ancestors = sqlx::query_as!(
+            DagBlockDto,
+            r#"
+            SELECT
+                b.id as "id: _",
+                b.title,
+                bdp.min_depth as "depth!: i64",
+                b.created_at as "created_at: _",
+                b.updated_at as "updated_at: _"
+            FROM block_directional_closure bdp
+            JOIN blocks b ON b.id = bdp.block_ancestor_id
+            WHERE bdp.block_descendant_id = $1
+            ORDER BY bdp.min_depth ASC, b.created_at ASC
+            "#,
+            block_id,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(ancestors)
+    }
+
+    async fn get_roots<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<DagBlockDto>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let roots = sqlx::query_as!(
+            DagBlockDto,
+            r#"
+            SELECT
+                b.id as "id: _",
+                b.title,
+                bdp.min_depth as "depth!: i64",
+                b.created_at as "created_at: _",
+                b.updated_at as "updated_at: _"
+            FROM block_directional_closure bdp
+            JOIN blocks b ON b.id = bdp.block_ancestor_id
+            WHERE bdp.block_descendant_id = $1
+              AND NOT EXISTS (
+                SELECT 1 FROM block_directional_links bdl
+                JOIN blocks linked ON linked.id = bdl.block_from_id
+                WHERE bdl.block_to_id = b.id AND linked.trashed_at IS NULL
+              )
+            ORDER BY bdp.min_depth ASC, b.created_at ASC
+            "#,
+            block_id,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(roots)
+    }
+
+    async fn get_leaves<'e, E>(&self, block_id: Uuid, executor: E) -> Result<Vec<DagBlockDto>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let leaves = sqlx::query_as!(
+            DagBlockDto,
+            r#"
+            SELECT
+                b.id as "id: _",
+                b.title,
+                bdp.min_depth as "depth!: i64",
+                b.created_at as "created_at: _",
+                b.updated_at as "updated_at: _"
+            FROM block_directional_closure bdp
+            JOIN blocks b ON b.id = bdp.block_descendant_id
+            WHERE bdp.block_ancestor_id = $1
+              AND NOT EXISTS (
+                SELECT 1 FROM block_directional_links bdl
+                JOIN blocks linked ON linked.id = bdl.block_to_id
+                WHERE bdl.block_from_id = b.id AND linked.trashed_at IS NULL
+              )
+            ORDER BY bdp.min_depth ASC, b.created_at ASC
+            "#,
+            block_id,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(leaves)
+    }
+
+    async fn get_paths_between<'e, E>(
+        &self,
+        ancestor_id: Uuid,
+        descendant_id: Uuid,
+        limit: usize,
+        executor: E,
+    ) -> Result<BlockPathsDto>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        // Only links between blocks that sit on some path can appear in one
+        let links = sqlx::query!(
+            r#"
+            SELECT
+                bdl.block_from_id as "block_from_id: Uuid",
+                bdl.block_to_id as "block_to_id: Uuid"
+            FROM block_directional_links bdl
+            WHERE (bdl.block_from_id = $1 OR EXISTS (
+                    SELECT 1 FROM block_directional_closure bdc
+                    WHERE bdc.block_ancestor_id = $1 AND bdc.block_descendant_id = bdl.block_from_id
+                ))
+              AND (bdl.block_to_id = $2 OR EXISTS (
+                    SELECT 1 FROM block_directional_closure bdc
+                    WHERE bdc.block_ancestor_id = bdl.block_to_id AND bdc.block_descendant_id = $2
+                ))
+            ORDER BY bdl.position ASC
+            "#,
+            ancestor_id,
+            descendant_id,
+        )
+        .fetch_all(executor)
+        .await?
+        .into_iter()
+        .map(|link| (link.block_from_id, link.block_to_id));
+
+        let paths = enumerate_paths(ancestor_id, descendant_id, links, limit);
+
+        Ok(paths)
+    }
+}
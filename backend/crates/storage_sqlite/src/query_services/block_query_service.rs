@@ -1,11 +1,13 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures_util::{StreamExt, TryStreamExt, stream::BoxStream};
 use sqlx::{Executor, Sqlite};
 use uuid::Uuid;
 
 use storage::query_services::BlockQueryService;
 use storage::query_services::block_query_service::{
-    BlockExportDto, BlockQueryServiceResult as Result, BlockSummaryDto, OpenedBlockDto,
+    BlockExportDto, BlockQueryServiceResult as Result, BlockSearchDto, BlockSummaryDto,
+    BlockUpdatedAtDto, OpenedBlockDto, TrashedBlockDto, render_snippet,
 };
 
 struct OpenedBlockModel {
@@ -51,6 +53,7 @@ impl BlockQueryService<Sqlite> for SqliteBlockQueryService {
                 bo.tab_index as "tab_index: _"
             FROM block_opens bo
             JOIN blocks b on b.id = bo.block_id
+            WHERE b.trashed_at IS NULL
             ORDER BY bo.tab_index ASC
             "#,
         )
@@ -63,13 +66,82 @@ impl BlockQueryService<Sqlite> for SqliteBlockQueryService {
         Ok(opened_blocks)
     }
 
-    async fn search<'e, E>(&self, query: &str, executor: E) -> Result<Vec<BlockSummaryDto>>
+    async fn search<'e, E>(
+        &self,
+        search: &BlockSearchDto,
+        executor: E,
+    ) -> Result<Vec<BlockSummaryDto>>
     where
         E: Executor<'e, Database = Sqlite>,
     {
-        let sql_query_str = format!("%{query}%");
+        // Pages continue from the sort key of the previous page's last row
+        let after_rank = search.after.and_then(|after| after.rank);
+        let after_updated_at = search.after.map(|after| after.updated_at);
+        let after_id = search.after.map(|after| after.id);
 
-        let blocks = sqlx::query_as!(
+        let Some(fts_query) = to_fts_query(&search.query) else {
+            let blocks = sqlx::query_as!(
+                BlockSummaryDto,
+                r#"
+                SELECT
+                    b.id as "id: _",
+                    b.title,
+                    b.created_at as "created_at: _",
+                    b.updated_at as "updated_at: _",
+                    bo.opened_at as "opened_at: _",
+                    EXISTS(
+                        SELECT 1 FROM block_pins bp WHERE bp.block_id = b.id
+                    ) as "is_pinned!: bool",
+                    NULL as "snippet?: String",
+                    NULL as "rank?: f64"
+                FROM blocks b
+                LEFT JOIN block_opens bo ON bo.block_id = b.id
+                WHERE b.trashed_at IS NULL
+                  AND ($1 IS NULL OR julianday(b.created_at) >= julianday($1))
+                  AND ($2 IS NULL OR julianday(b.created_at) < julianday($2))
+                  AND ($3 IS NULL OR julianday(b.updated_at) >= julianday($3))
+                  AND ($4 IS NULL OR julianday(b.updated_at) < julianday($4))
+                  AND ($5 IS NULL OR EXISTS(
+                      SELECT 1 FROM block_directional_closure p
+                      WHERE p.block_ancestor_id = $5
+                        AND p.block_descendant_id = b.id
+                        AND p.block_descendant_id != p.block_ancestor_id
+                  ))
+                  AND (NOT $6 OR bo.opened_at IS NOT NULL)
+                  AND (NOT $7 OR EXISTS(
+                      SELECT 1 FROM block_pins bp WHERE bp.block_id = b.id
+                  ))
+                  AND ($8 IS NULL OR EXISTS(
+                      SELECT 1 FROM block_tags bt
+                      JOIN tags t ON t.id = bt.tag_id
+                      WHERE bt.block_id = b.id AND t.name = $8
+                  ))
+                  AND ($10 IS NULL OR b.updated_at < $10 OR (b.updated_at = $10 AND b.id > $11))
+                ORDER BY b.updated_at DESC, b.id ASC
+                LIMIT $9
+                "#,
+                search.created_after,
+                search.created_before,
+                search.updated_after,
+                search.updated_before,
+                search.descendant_of,
+                search.only_opened,
+                search.only_pinned,
+                search.tag,
+                search.limit,
+                after_updated_at,
+                after_id,
+            )
+            .fetch_all(executor)
+            .await?;
+
+            return Ok(blocks);
+        };
+
+        // bm25 weights per column: block_id (unindexed), title, content
+        // Hits come back between U+E000 and U+E001 so the content can be
+        // escaped before render_snippet adds the markup
+        let blocks: Vec<BlockSummaryDto> = sqlx::query_as!(
             BlockSummaryDto,
             r#"
             SELECT
@@ -77,19 +149,65 @@ impl BlockQueryService<Sqlite> for SqliteBlockQueryService {
                 b.title,
                 b.created_at as "created_at: _",
                 b.updated_at as "updated_at: _",
-                bo.opened_at as "opened_at: _"
-            FROM blocks b
+                bo.opened_at as "opened_at: _",
+                EXISTS(
+                    SELECT 1 FROM block_pins bp WHERE bp.block_id = b.id
+                ) as "is_pinned!: bool",
+                snippet(blocks_fts, 2, char(57344), char(57345), '…', 16) as "snippet?: String",
+                bm25(blocks_fts, 0.0, 10.0, 1.0) as "rank?: f64"
+            FROM blocks_fts
+            JOIN blocks b ON b.id = blocks_fts.block_id
             LEFT JOIN block_opens bo ON bo.block_id = b.id
-            WHERE
-                title LIKE $1 OR
-                content LIKE $1
-            ORDER BY updated_at DESC
-            LIMIT 50
+            WHERE blocks_fts MATCH $10
+              AND b.trashed_at IS NULL
+                AND ($1 IS NULL OR julianday(b.created_at) >= julianday($1))
+                AND ($2 IS NULL OR julianday(b.created_at) < julianday($2))
+                AND ($3 IS NULL OR julianday(b.updated_at) >= julianday($3))
+                AND ($4 IS NULL OR julianday(b.updated_at) < julianday($4))
+                AND ($5 IS NULL OR EXISTS(
+                    SELECT 1 FROM block_directional_closure p
+                    WHERE p.block_ancestor_id = $5
+                      AND p.block_descendant_id = b.id
+                      AND p.block_descendant_id != p.block_ancestor_id
+                ))
+                AND (NOT $6 OR bo.opened_at IS NOT NULL)
+                AND (NOT $7 OR EXISTS(
+                    SELECT 1 FROM block_pins bp WHERE bp.block_id = b.id
+                ))
+                AND ($8 IS NULL OR EXISTS(
+                    SELECT 1 FROM block_tags bt
+                    JOIN tags t ON t.id = bt.tag_id
+                    WHERE bt.block_id = b.id AND t.name = $8
+                ))
+                AND ($12 IS NULL
+                    OR bm25(blocks_fts, 0.0, 10.0, 1.0) > $11
+                    OR (bm25(blocks_fts, 0.0, 10.0, 1.0) = $11
+                        AND (b.updated_at < $12 OR (b.updated_at = $12 AND b.id > $13))))
+            ORDER BY bm25(blocks_fts, 0.0, 10.0, 1.0) ASC, b.updated_at DESC, b.id ASC
+            LIMIT $9
             "#,
-            sql_query_str,
+            search.created_after,
+            search.created_before,
+            search.updated_after,
+            search.updated_before,
+            search.descendant_of,
+            search.only_opened,
+            search.only_pinned,
+            search.tag,
+            search.limit,
+            fts_query,
+            after_rank,
+            after_updated_at,
+            after_id,
         )
         .fetch_all(executor)
-        .await?;
+        .await?
+        .into_iter()
+        .map(|mut block| {
+            block.snippet = block.snippet.as_deref().map(render_snippet);
+            block
+        })
+        .collect();
 
         Ok(blocks)
     }
@@ -108,6 +226,7 @@ impl BlockQueryService<Sqlite> for SqliteBlockQueryService {
                 created_at as "created_at: _",
                 updated_at as "updated_at: _"
             FROM blocks
+            WHERE trashed_at IS NULL
             ORDER BY created_at ASC
             "#,
         )
@@ -116,4 +235,98 @@ impl BlockQueryService<Sqlite> for SqliteBlockQueryService {
 
         Ok(blocks)
     }
+
+    async fn get_all_updated_at<'e, E>(&self, executor: E) -> Result<Vec<BlockUpdatedAtDto>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let blocks = sqlx::query_as!(
+            BlockUpdatedAtDto,
+            r#"
+            SELECT
+                id as "id: _",
+                updated_at as "updated_at: _"
+            FROM blocks
+            WHERE trashed_at IS NULL
+            "#,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(blocks)
+    }
+
+    fn stream_all<'e, E>(&self, executor: E) -> BoxStream<'e, Result<BlockExportDto>>
+    where
+        E: Executor<'e, Database = Sqlite> + 'e,
+    {
+        sqlx::query_as!(
+            BlockExportDto,
+            r#"
+            SELECT
+                id as "id: _",
+                title,
+                content,
+                created_at as "created_at: _",
+                updated_at as "updated_at: _"
+            FROM blocks
+            WHERE trashed_at IS NULL
+            ORDER BY created_at ASC
+            "#,
+        )
+        .fetch(executor)
+        .map_err(Into::into)
+        .boxed()
+    }
+
+    async fn get_trashed<'e, E>(&self, executor: E) -> Result<Vec<TrashedBlockDto>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let blocks = sqlx::query_as!(
+            TrashedBlockDto,
+            r#"
+            SELECT
+                id as "id: _",
+                title,
+                trashed_at as "trashed_at!: _"
+            FROM blocks
+            WHERE trashed_at IS NOT NULL
+            ORDER BY trashed_at DESC
+            "#,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(blocks)
+    }
+
+    async fn count_trashed<'e, E>(&self, executor: E) -> Result<i64>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64" FROM blocks WHERE trashed_at IS NOT NULL"#
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(count)
+    }
+}
+
+/// Turns free text into an FTS5 query that prefix-matches every word, so user
+/// input can never be parsed as FTS5 syntax. Returns `None` for blank input.
+fn to_fts_query(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("\"{term}\"*"))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
 }
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use sqlx::{Executor, Sqlite};
+use uuid::Uuid;
+
+use storage::query_services::BlockRevisionQueryService;
+use storage::query_services::block_revision_query_service::{
+    BlockRevisionDto, BlockRevisionQueryServiceResult as Result,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct SqliteBlockRevisionQueryService;
+
+impl SqliteBlockRevisionQueryService {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait]
+impl BlockRevisionQueryService<Sqlite> for SqliteBlockRevisionQueryService {
+    async fn get_by_block_id<'e, E>(
+        &self,
+        block_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<BlockRevisionDto>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let revisions = sqlx::query_as!(
+            BlockRevisionDto,
+            r#"
+            SELECT
+                block_id as "block_id: _",
+                revision,
+                title,
+                content,
+                created_at as "created_at: _"
+            FROM block_revisions
+            WHERE block_id = $1
+            ORDER BY revision DESC
+            "#,
+            block_id,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(revisions)
+    }
+
+    async fn get_by_revision<'e, E>(
+        &self,
+        block_id: Uuid,
+        revision: i64,
+        executor: E,
+    ) -> Result<Option<BlockRevisionDto>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let revision = sqlx::query_as!(
+            BlockRevisionDto,
+            r#"
+            SELECT
+                block_id as "block_id: _",
+                revision,
+                title,
+                content,
+                created_at as "created_at: _"
+            FROM block_revisions
+            WHERE block_id = $1 AND revision = $2
+            "#,
+            block_id,
+            revision,
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(revision)
+    }
+}
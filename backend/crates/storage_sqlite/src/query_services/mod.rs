@@ -1,5 +1,9 @@
+pub mod block_dag_query_service;
 pub mod block_link_query_service;
 pub mod block_query_service;
+pub mod block_revision_query_service;
 
+pub use block_dag_query_service::SqliteBlockDagQueryService;
 pub use block_link_query_service::SqliteBlockLinkQueryService;
 pub use block_query_service::SqliteBlockQueryService;
+pub use block_revision_query_service::SqliteBlockRevisionQueryService;
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
-use chrono::Utc;
-use sqlx::{Acquire, Executor, Sqlite};
+use chrono::{DateTime, Utc};
+use sqlx::{Acquire, Executor, Sqlite, SqliteConnection};
 use uuid::Uuid;
 
 use crate::helpers::SqliteBlockDirectionalPathHelper;
@@ -39,6 +39,10 @@ impl BlockDirectionalLinkRepository<Sqlite> for SqliteBlockDirectionalLinkReposi
                 id as "id: _" , 
                 block_from_id as "block_from_id: _",
                 block_to_id as "block_to_id: _",
+                kind,
+                label,
+                weight,
+                position,
                 created_at as "created_at: _"
             FROM block_directional_links 
             WHERE id = $1
@@ -59,51 +63,22 @@ impl BlockDirectionalLinkRepository<Sqlite> for SqliteBlockDirectionalLinkReposi
     where
         E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
     {
-        let now = Utc::now();
+        self.create_at(input, Utc::now(), executor).await
+    }
 
+    async fn create_at<'e, E>(
+        &self,
+        input: &CreateBlockDirectionalLinkDto,
+        created_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<BlockDirectionalLink>
+    where
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
+    {
         let mut conn = executor.acquire().await?;
         let mut tx = conn.begin().await?;
 
-        self.ensure_no_cycle(input.block_from_id, input.block_to_id, &mut *tx)
-            .await?;
-
-        let link = sqlx::query_as!(
-            BlockDirectionalLink,
-            r#"
-            INSERT INTO block_directional_links
-                (id, block_from_id, block_to_id, created_at)
-                VALUES ($1, $2, $3, $4)
-            RETURNING
-                id as "id: _",
-                block_from_id as "block_from_id: _",
-                block_to_id as "block_to_id: _",
-                created_at as "created_at: _"
-            "#,
-            input.id,
-            input.block_from_id,
-            input.block_to_id,
-            now
-        )
-        .fetch_one(&mut *tx)
-        .await
-        .map_err(|e| {
-            if is_foreign_key_violation(&e) {
-                return BlockDirectionalLinkRepositoryError::BlocksNotFound {
-                    from: input.block_from_id,
-                    to: input.block_to_id,
-                };
-            } else if is_unique_violation(&e) {
-                return BlockDirectionalLinkRepositoryError::AlreadyExists {
-                    from: input.block_from_id,
-                    to: input.block_to_id,
-                };
-            }
-            BlockDirectionalLinkRepositoryError::Database(e)
-        })?;
-
-        self.path_helper
-            .create_paths_for_link(input.block_from_id, input.block_to_id, &mut *tx)
-            .await?;
+        let link = self.insert_link(input, created_at, &mut tx).await?;
 
         tx.commit().await?;
 
@@ -130,6 +105,8 @@ impl BlockDirectionalLinkRepository<Sqlite> for SqliteBlockDirectionalLinkReposi
             .execute(&mut *tx)
             .await?;
 
+        Self::close_position_gap(link.block_from_id, link.position, &mut *tx).await?;
+
         tx.commit().await?;
 
         Ok(())
@@ -151,19 +128,163 @@ impl BlockDirectionalLinkRepository<Sqlite> for SqliteBlockDirectionalLinkReposi
             .delete_paths_using_link(block_from_id, block_to_id, &mut *tx)
             .await?;
 
-        let result = sqlx::query!(
-            "DELETE FROM block_directional_links WHERE block_from_id = $1 AND block_to_id = $2",
+        let position = sqlx::query_scalar!(
+            r#"
+            DELETE FROM block_directional_links
+            WHERE block_from_id = $1 AND block_to_id = $2
+            RETURNING position
+            "#,
             block_from_id,
             block_to_id
         )
-        .execute(&mut *tx)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(BlockDirectionalLinkRepositoryError::NotFoundByBlocks {
+            from: block_from_id,
+            to: block_to_id,
+        })?;
+
+        Self::close_position_gap(block_from_id, position, &mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn move_link<'e, E>(
+        &self,
+        block_id: Uuid,
+        from_parent_id: Uuid,
+        to_parent_id: Uuid,
+        executor: E,
+    ) -> Result<BlockDirectionalLink>
+    where
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        self.path_helper
+            .delete_paths_using_link(from_parent_id, block_id, &mut *tx)
+            .await?;
+
+        let old_link = sqlx::query_as!(
+            BlockDirectionalLink,
+            r#"
+            DELETE FROM block_directional_links
+            WHERE block_from_id = $1 AND block_to_id = $2
+            RETURNING
+                id as "id: _",
+                block_from_id as "block_from_id: _",
+                block_to_id as "block_to_id: _",
+                kind,
+                label,
+                weight,
+                position,
+                created_at as "created_at: _"
+            "#,
+            from_parent_id,
+            block_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(BlockDirectionalLinkRepositoryError::NotFoundByBlocks {
+            from: from_parent_id,
+            to: block_id,
+        })?;
+
+        Self::close_position_gap(from_parent_id, old_link.position, &mut *tx).await?;
+
+        // The link keeps its identity and annotations under the new parent
+        let input = CreateBlockDirectionalLinkDto {
+            id: old_link.id,
+            block_from_id: to_parent_id,
+            block_to_id: block_id,
+            kind: old_link.kind,
+            label: old_link.label,
+            weight: old_link.weight,
+        };
+        let link = self
+            .insert_link(&input, old_link.created_at, &mut tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(link)
+    }
+
+    async fn reorder_children<'e, E>(
+        &self,
+        parent_id: Uuid,
+        child_ids: &[Uuid],
+        executor: E,
+    ) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let parent_exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM blocks WHERE id = $1 AND trashed_at IS NULL
+            ) as "exists!: bool""#,
+            parent_id,
+        )
+        .fetch_one(&mut *tx)
         .await?;
 
-        if result.rows_affected() == 0 {
-            return Err(BlockDirectionalLinkRepositoryError::NotFoundByBlocks {
-                from: block_from_id,
-                to: block_to_id,
-            });
+        if !parent_exists {
+            return Err(BlockDirectionalLinkRepositoryError::ParentNotFound { id: parent_id });
+        }
+
+        let children = sqlx::query!(
+            r#"
+            SELECT
+                bdl.block_to_id as "block_to_id: Uuid",
+                b.trashed_at IS NOT NULL as "trashed!: bool"
+            FROM block_directional_links bdl
+            JOIN blocks b ON b.id = bdl.block_to_id
+            WHERE bdl.block_from_id = $1
+            ORDER BY bdl.position ASC
+            "#,
+            parent_id,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut visible: Vec<Uuid> = Vec::new();
+        let mut trashed: Vec<Uuid> = Vec::new();
+        for child in children {
+            if child.trashed {
+                trashed.push(child.block_to_id);
+            } else {
+                visible.push(child.block_to_id);
+            }
+        }
+
+        let mut requested = child_ids.to_vec();
+        requested.sort();
+        requested.dedup();
+        visible.sort();
+        if requested.len() != child_ids.len() || requested != visible {
+            return Err(BlockDirectionalLinkRepositoryError::ChildOrderMismatch { parent_id });
+        }
+
+        for (position, child_id) in child_ids.iter().chain(trashed.iter()).enumerate() {
+            let position = position as i64;
+            sqlx::query!(
+                r#"
+                UPDATE block_directional_links
+                SET position = $1
+                WHERE block_from_id = $2 AND block_to_id = $3
+                "#,
+                position,
+                parent_id,
+                child_id,
+            )
+            .execute(&mut *tx)
+            .await?;
         }
 
         tx.commit().await?;
@@ -173,6 +294,107 @@ impl BlockDirectionalLinkRepository<Sqlite> for SqliteBlockDirectionalLinkReposi
 }
 
 impl SqliteBlockDirectionalLinkRepository {
+    async fn insert_link(
+        &self,
+        input: &CreateBlockDirectionalLinkDto,
+        created_at: DateTime<Utc>,
+        conn: &mut SqliteConnection,
+    ) -> Result<BlockDirectionalLink> {
+        self.ensure_no_cycle(input.block_from_id, input.block_to_id, &mut *conn)
+            .await?;
+
+        // Trashed blocks keep their links but cannot gain new ones
+        let trashed = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM blocks WHERE id IN ($1, $2) AND trashed_at IS NOT NULL
+            ) as "trashed!: bool""#,
+            input.block_from_id,
+            input.block_to_id,
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if trashed {
+            return Err(BlockDirectionalLinkRepositoryError::BlocksNotFound {
+                from: input.block_from_id,
+                to: input.block_to_id,
+            });
+        }
+
+        let link = sqlx::query_as!(
+            BlockDirectionalLink,
+            r#"
+            INSERT INTO block_directional_links
+                (id, block_from_id, block_to_id, kind, label, weight, position, created_at)
+                VALUES (
+                    $1, $2, $3, $4, $5, $6,
+                    -- New children go after their existing siblings. The read runs
+                    -- inside this write, which SQLite serialises, so it can't race
+                    (SELECT COALESCE(MAX(position) + 1, 0)
+                     FROM block_directional_links WHERE block_from_id = $2),
+                    $7
+                )
+            RETURNING
+                id as "id: _",
+                block_from_id as "block_from_id: _",
+                block_to_id as "block_to_id: _",
+                kind,
+                label,
+                weight,
+                position,
+                created_at as "created_at: _"
+            "#,
+            input.id,
+            input.block_from_id,
+            input.block_to_id,
+            input.kind,
+            input.label,
+            input.weight,
+            created_at
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            if is_foreign_key_violation(&e) {
+                return BlockDirectionalLinkRepositoryError::BlocksNotFound {
+                    from: input.block_from_id,
+                    to: input.block_to_id,
+                };
+            } else if is_unique_violation(&e) {
+                return BlockDirectionalLinkRepositoryError::AlreadyExists {
+                    from: input.block_from_id,
+                    to: input.block_to_id,
+                };
+            }
+            BlockDirectionalLinkRepositoryError::Database(e)
+        })?;
+
+        self.path_helper
+            .create_paths_for_link(input.block_from_id, input.block_to_id, &mut *conn)
+            .await?;
+
+        Ok(link)
+    }
+
+    async fn close_position_gap<'c, E>(parent_id: Uuid, position: i64, executor: E) -> Result<()>
+    where
+        E: Executor<'c, Database = Sqlite>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE block_directional_links
+            SET position = position - 1
+            WHERE block_from_id = $1 AND position > $2
+            "#,
+            parent_id,
+            position,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
     async fn ensure_no_cycle<'c, E>(
         &self,
         block_from_id: Uuid,
@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, Sqlite};
+use uuid::Uuid;
+
+use storage::repositories::BlockPinRepository;
+use storage::repositories::block_pin_repository::{
+    BlockPinRepositoryError, BlockPinRepositoryResult as Result, PinnedBlockDto,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct SqliteBlockPinRepository;
+
+impl SqliteBlockPinRepository {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait]
+impl BlockPinRepository<Sqlite> for SqliteBlockPinRepository {
+    async fn pin<'e, E>(&self, block_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        self.pin_at(block_id, Utc::now(), executor).await
+    }
+
+    async fn pin_at<'e, E>(
+        &self,
+        block_id: Uuid,
+        pinned_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        sqlx::query!(
+            "INSERT INTO block_pins (block_id, pinned_at)
+            VALUES ($1, $2)
+            ON CONFLICT(block_id) DO NOTHING",
+            block_id,
+            pinned_at,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn unpin<'e, E>(&self, block_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let result = sqlx::query!("DELETE FROM block_pins WHERE block_id = $1", block_id)
+            .execute(executor)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(BlockPinRepositoryError::NotPinned { block_id });
+        }
+
+        Ok(())
+    }
+
+    async fn is_pinned<'e, E>(&self, block_id: Uuid, executor: E) -> Result<bool>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let is_pinned = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM block_pins WHERE block_id = $1
+            ) as "is_pinned!: bool""#,
+            block_id,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(is_pinned)
+    }
+
+    async fn get_all<'e, E>(&self, executor: E) -> Result<Vec<PinnedBlockDto>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let pinned = sqlx::query_as!(
+            PinnedBlockDto,
+            r#"
+            SELECT
+                p.block_id as "block_id: _",
+                s.title,
+                p.pinned_at as "pinned_at: _"
+            FROM block_pins p
+            JOIN blocks s ON s.id = p.block_id
+            WHERE s.trashed_at IS NULL
+            ORDER BY p.pinned_at DESC
+            "#,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(pinned)
+    }
+}
@@ -1,11 +1,13 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use sqlx::{Acquire, Executor, Sqlite};
 use uuid::Uuid;
 
 use crate::helpers::SqliteBlockDirectionalPathHelper;
 use domain::blocks::Block;
-use storage::helpers::block_directional_path_helper::BlockDirectionalPathHelper;
+use storage::helpers::block_directional_path_helper::{
+    BlockDirectionalPathHelper, BlockDirectionalPathHelperError,
+};
 use storage::repositories::BlockRepository;
 use storage::repositories::block_repository::{
     BlockRepositoryError, BlockRepostoryResult as Result,
@@ -35,9 +37,10 @@ impl BlockRepository<Sqlite> for SqliteBlockRepository {
                 title, 
                 content, 
                 created_at as "created_at: _", 
-                updated_at as "updated_at: _" 
+                updated_at as "updated_at: _",
+                version
             FROM blocks 
-            WHERE id = $1"#,
+            WHERE id = $1 AND trashed_at IS NULL"#,
             id,
         )
         .fetch_optional(executor)
@@ -56,6 +59,7 @@ impl BlockRepository<Sqlite> for SqliteBlockRepository {
         self.path_helper
             .delete_paths_using_block(id, &mut *tx)
             .await?;
+        Self::close_child_position_gaps(id, &mut *tx).await?;
 
         let result = sqlx::query!("DELETE FROM blocks WHERE id = $1", id)
             .execute(&mut *tx)
@@ -70,6 +74,187 @@ impl BlockRepository<Sqlite> for SqliteBlockRepository {
         Ok(())
     }
 
+    async fn update_if_version<'e, E>(
+        &self,
+        block: &Block,
+        expected_version: i64,
+        executor: E,
+    ) -> Result<Option<Block>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let now = Utc::now();
+
+        let block = sqlx::query_as!(
+            Block,
+            r#"
+            UPDATE blocks SET
+                title = $2,
+                content = $3,
+                updated_at = $4,
+                version = version + 1
+            WHERE id = $1 AND version = $5 AND trashed_at IS NULL
+            RETURNING
+                id as "id: _",
+                title,
+                content,
+                created_at as "created_at: _",
+                updated_at as "updated_at: _",
+                version
+            "#,
+            block.id,
+            block.title,
+            block.content,
+            now,
+            expected_version,
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(block)
+    }
+
+    async fn trash<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+        let now = Utc::now();
+
+        let result = sqlx::query!(
+            "UPDATE blocks SET trashed_at = $2 WHERE id = $1 AND trashed_at IS NULL",
+            id,
+            now,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(BlockRepositoryError::NotFound { id });
+        }
+
+        self.path_helper
+            .delete_paths_using_block(id, &mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn restore<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let result = sqlx::query!(
+            "UPDATE blocks SET trashed_at = NULL WHERE id = $1 AND trashed_at IS NOT NULL",
+            id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(self.not_trashed_error(id, &mut *tx).await?);
+        }
+
+        // Links to blocks that are still in the trash stay dormant
+        let links = sqlx::query!(
+            r#"
+            SELECT
+                block_from_id as "block_from_id: Uuid",
+                block_to_id as "block_to_id: Uuid"
+            FROM block_directional_links bdl
+            WHERE (bdl.block_from_id = $1 OR bdl.block_to_id = $1)
+              AND NOT EXISTS (
+                SELECT 1 FROM blocks b
+                WHERE b.id IN (bdl.block_from_id, bdl.block_to_id)
+                  AND b.trashed_at IS NOT NULL
+              )
+            ORDER BY bdl.created_at ASC
+            "#,
+            id,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for link in links {
+            self.path_helper
+                .create_paths_for_link(link.block_from_id, link.block_to_id, &mut *tx)
+                .await
+                .map_err(|err| match err {
+                    BlockDirectionalPathHelperError::CyclicPathCreation => {
+                        BlockRepositoryError::RestoreCycle { id }
+                    }
+                    other => other.into(),
+                })?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn purge<'e, E>(&self, id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let is_trashed = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM blocks WHERE id = $1 AND trashed_at IS NOT NULL
+            ) as "is_trashed!: bool""#,
+            id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if !is_trashed {
+            return Err(self.not_trashed_error(id, &mut *tx).await?);
+        }
+
+        Self::close_child_position_gaps(id, &mut *tx).await?;
+
+        sqlx::query!("DELETE FROM blocks WHERE id = $1", id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn purge_trashed_before<'e, E>(&self, cutoff: DateTime<Utc>, executor: E) -> Result<u64>
+    where
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let ids = sqlx::query_scalar!(
+            r#"SELECT id as "id: Uuid" FROM blocks WHERE trashed_at IS NOT NULL AND trashed_at < $1"#,
+            cutoff,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for &id in &ids {
+            Self::close_child_position_gaps(id, &mut *tx).await?;
+            sqlx::query!("DELETE FROM blocks WHERE id = $1", id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(ids.len() as u64)
+    }
+
     async fn save<'e, E>(&self, block: &Block, executor: E) -> Result<()>
     where
         E: Executor<'e, Database = Sqlite>,
@@ -77,18 +262,97 @@ impl BlockRepository<Sqlite> for SqliteBlockRepository {
         let now = Utc::now();
 
         sqlx::query!(
-            "INSERT OR REPLACE INTO blocks 
-                (id, title, content, created_at, updated_at)
-                VALUES ($1, $2, $3, $4, $5)",
+            "INSERT INTO blocks
+                (id, title, content, created_at, updated_at, version)
+                VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT(id) DO UPDATE SET
+                title = excluded.title,
+                content = excluded.content,
+                updated_at = excluded.updated_at,
+                version = blocks.version + 1",
             block.id,
             block.title,
             block.content,
             block.created_at,
             now,
+            block.version,
         )
         .execute(executor)
         .await?;
 
         Ok(())
     }
+
+    async fn save_with_timestamps<'e, E>(&self, block: &Block, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        sqlx::query!(
+            "INSERT INTO blocks
+                (id, title, content, created_at, updated_at, version)
+                VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT(id) DO UPDATE SET
+                title = excluded.title,
+                content = excluded.content,
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at,
+                version = blocks.version + 1",
+            block.id,
+            block.title,
+            block.content,
+            block.created_at,
+            block.updated_at,
+            block.version,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl SqliteBlockRepository {
+    /// Shifts up the siblings that follow the block under each of its parents,
+    /// as removing a single link does. Must run before the block's links go.
+    async fn close_child_position_gaps<'c, E>(id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'c, Database = Sqlite>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE block_directional_links AS sibling
+            SET position = position - 1
+            WHERE EXISTS (
+                SELECT 1 FROM block_directional_links removed
+                WHERE removed.block_to_id = $1
+                  AND removed.block_from_id = sibling.block_from_id
+                  AND removed.position < sibling.position
+            )
+            "#,
+            id,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Explains why a block could not be restored or purged.
+    async fn not_trashed_error<'e, E>(&self, id: Uuid, executor: E) -> Result<BlockRepositoryError>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM blocks WHERE id = $1) as "exists!: bool""#,
+            id,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        if exists {
+            Ok(BlockRepositoryError::NotTrashed { id })
+        } else {
+            Ok(BlockRepositoryError::NotFound { id })
+        }
+    }
 }
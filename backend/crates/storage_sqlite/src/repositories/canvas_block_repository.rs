@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use sqlx::{Acquire, Executor, Sqlite};
+use uuid::Uuid;
+
+use domain::canvases::CanvasBlock;
+use storage::repositories::CanvasBlockRepository;
+use storage::repositories::canvas_block_repository::{
+    CanvasBlockRepositoryError, CanvasBlockRepositoryResult as Result,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct SqliteCanvasBlockRepository;
+
+impl SqliteCanvasBlockRepository {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait]
+impl CanvasBlockRepository<Sqlite> for SqliteCanvasBlockRepository {
+    async fn get_by_canvas_and_block<'e, E>(
+        &self,
+        canvas_id: Uuid,
+        block_id: Uuid,
+        executor: E,
+    ) -> Result<Option<CanvasBlock>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let canvas_block = sqlx::query_as!(
+            CanvasBlock,
+            r#"SELECT
+                cb.id as "id: _",
+                cb.canvas_id as "canvas_id: _",
+                cb.block_id as "block_id: _",
+                cb.grid_x as "grid_x: _",
+                cb.grid_y as "grid_y: _",
+                cb.grid_width as "grid_width: _",
+                cb.grid_height as "grid_height: _",
+                cb.z as "z: _",
+                cb.scale,
+                cb.content_visible,
+                cb.created_at as "created_at: _",
+                cb.updated_at as "updated_at: _"
+            FROM canvas_blocks cb
+            JOIN blocks b ON b.id = cb.block_id
+            WHERE cb.canvas_id = $1 AND cb.block_id = $2 AND b.trashed_at IS NULL"#,
+            canvas_id,
+            block_id,
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(canvas_block)
+    }
+
+    async fn get_by_canvas<'e, E>(&self, canvas_id: Uuid, executor: E) -> Result<Vec<CanvasBlock>>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let canvas_blocks = sqlx::query_as!(
+            CanvasBlock,
+            r#"SELECT
+                cb.id as "id: _",
+                cb.canvas_id as "canvas_id: _",
+                cb.block_id as "block_id: _",
+                cb.grid_x as "grid_x: _",
+                cb.grid_y as "grid_y: _",
+                cb.grid_width as "grid_width: _",
+                cb.grid_height as "grid_height: _",
+                cb.z as "z: _",
+                cb.scale,
+                cb.content_visible,
+                cb.created_at as "created_at: _",
+                cb.updated_at as "updated_at: _"
+            FROM canvas_blocks cb
+            JOIN blocks b ON b.id = cb.block_id
+            WHERE cb.canvas_id = $1 AND b.trashed_at IS NULL
+            ORDER BY cb.z ASC, cb.created_at ASC"#,
+            canvas_id,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(canvas_blocks)
+    }
+
+    async fn delete<'e, E>(&self, canvas_id: Uuid, block_id: Uuid, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let result = sqlx::query!(
+            "DELETE FROM canvas_blocks WHERE canvas_id = $1 AND block_id = $2",
+            canvas_id,
+            block_id,
+        )
+        .execute(executor)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(CanvasBlockRepositoryError::NotFound {
+                canvas_id,
+                block_id,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn save<'e, E>(&self, canvas_block: &CanvasBlock, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        sqlx::query!(
+            "INSERT INTO canvas_blocks
+                (id, canvas_id, block_id, grid_x, grid_y, grid_width, grid_height,
+                 z, scale, content_visible, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT(id) DO UPDATE SET
+                grid_x = excluded.grid_x,
+                grid_y = excluded.grid_y,
+                grid_width = excluded.grid_width,
+                grid_height = excluded.grid_height,
+                z = excluded.z,
+                scale = excluded.scale,
+                content_visible = excluded.content_visible,
+                updated_at = excluded.updated_at",
+            canvas_block.id,
+            canvas_block.canvas_id,
+            canvas_block.block_id,
+            canvas_block.grid_x,
+            canvas_block.grid_y,
+            canvas_block.grid_width,
+            canvas_block.grid_height,
+            canvas_block.z,
+            canvas_block.scale,
+            canvas_block.content_visible,
+            canvas_block.created_at,
+            canvas_block.updated_at,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn save_many<'e, E>(&self, canvas_blocks: &[CanvasBlock], executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        for canvas_block in canvas_blocks {
+            self.save(canvas_block, &mut *tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
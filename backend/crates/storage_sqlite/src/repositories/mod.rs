@@ -1,9 +1,21 @@
 mod block_directional_link_repository;
+mod block_pin_repository;
 mod block_related_link_repository;
 mod block_repository;
+mod canvas_block_repository;
+mod canvas_pin_repository;
+mod canvas_repository;
+mod snapshot_repository;
+mod tag_repository;
 mod workspace_repository;
 
 pub use block_directional_link_repository::SqliteBlockDirectionalLinkRepository;
+pub use block_pin_repository::SqliteBlockPinRepository;
 pub use block_related_link_repository::SqliteBlockRelatedLinkRepository;
 pub use block_repository::SqliteBlockRepository;
+pub use canvas_block_repository::SqliteCanvasBlockRepository;
+pub use canvas_pin_repository::SqliteCanvasPinRepository;
+pub use canvas_repository::SqliteCanvasRepository;
+pub use snapshot_repository::SqliteSnapshotRepository;
+pub use tag_repository::SqliteTagRepository;
 pub use workspace_repository::SqliteWorkspaceRepository;
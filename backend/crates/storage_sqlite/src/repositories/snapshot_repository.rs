@@ -0,0 +1,437 @@
+use async_trait::async_trait;
+use sqlx::{Acquire, Executor, Sqlite};
+
+use storage::repositories::SnapshotRepository;
+use storage::repositories::snapshot_repository::{
+    BlockOpenRowDto, BlockPinRowDto, BlockRevisionRowDto, BlockRowDto, BlockTagRowDto,
+    CanvasBlockRowDto, CanvasPinRowDto, CanvasRowDto, DirectionalLinkRowDto, InstanceSnapshotDto,
+    RelatedLinkRowDto, SnapshotRepositoryError, SnapshotRepositoryResult as Result,
+    TableRowCountsDto, TagRowDto,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct SqliteSnapshotRepository;
+
+impl SqliteSnapshotRepository {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait]
+impl SnapshotRepository<Sqlite> for SqliteSnapshotRepository {
+    async fn count_rows<'e, E>(&self, executor: E) -> Result<TableRowCountsDto>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let counts = sqlx::query_as!(
+            TableRowCountsDto,
+            r#"
+            SELECT
+                (SELECT COUNT(*) FROM blocks) as "blocks!: i64",
+                (SELECT COUNT(*) FROM block_revisions) as "block_revisions!: i64",
+                (SELECT COUNT(*) FROM block_directional_links) as "block_directional_links!: i64",
+                (SELECT COUNT(*) FROM block_related_links) as "block_related_links!: i64",
+                (SELECT COUNT(*) FROM block_pins) as "block_pins!: i64",
+                (SELECT COUNT(*) FROM block_opens) as "block_opens!: i64",
+                (SELECT COUNT(*) FROM canvases) as "canvases!: i64",
+                (SELECT COUNT(*) FROM canvas_blocks) as "canvas_blocks!: i64",
+                (SELECT COUNT(*) FROM canvas_pins) as "canvas_pins!: i64",
+                (SELECT COUNT(*) FROM tags) as "tags!: i64",
+                (SELECT COUNT(*) FROM block_tags) as "block_tags!: i64"
+            "#,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(counts)
+    }
+
+    async fn read_snapshot<'e, E>(&self, executor: E) -> Result<InstanceSnapshotDto>
+    where
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let blocks = sqlx::query_as!(
+            BlockRowDto,
+            r#"
+            SELECT
+                id as "id: _",
+                title,
+                content,
+                created_at as "created_at: _",
+                updated_at as "updated_at: _",
+                trashed_at as "trashed_at: _",
+                version
+            FROM blocks
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let block_revisions = sqlx::query_as!(
+            BlockRevisionRowDto,
+            r#"
+            SELECT
+                block_id as "block_id: _",
+                revision,
+                title,
+                content,
+                created_at as "created_at: _"
+            FROM block_revisions
+            ORDER BY block_id, revision
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let directional_links = sqlx::query_as!(
+            DirectionalLinkRowDto,
+            r#"
+            SELECT
+                id as "id: _",
+                block_from_id as "block_from_id: _",
+                block_to_id as "block_to_id: _",
+                kind,
+                label,
+                weight,
+                position,
+                created_at as "created_at: _"
+            FROM block_directional_links
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let related_links = sqlx::query_as!(
+            RelatedLinkRowDto,
+            r#"
+            SELECT
+                id as "id: _",
+                block_a_id as "block_a_id: _",
+                block_b_id as "block_b_id: _",
+                label,
+                weight,
+                created_at as "created_at: _"
+            FROM block_related_links
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let block_pins = sqlx::query_as!(
+            BlockPinRowDto,
+            r#"
+            SELECT block_id as "block_id: _", pinned_at as "pinned_at: _"
+            FROM block_pins
+            ORDER BY block_id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let block_opens = sqlx::query_as!(
+            BlockOpenRowDto,
+            r#"
+            SELECT
+                block_id as "block_id: _",
+                opened_at as "opened_at: _",
+                tab_index as "tab_index: _"
+            FROM block_opens
+            ORDER BY block_id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let canvases = sqlx::query_as!(
+            CanvasRowDto,
+            r#"
+            SELECT
+                id as "id: _",
+                name,
+                viewport_x,
+                viewport_y,
+                zoom_level,
+                created_at as "created_at: _",
+                updated_at as "updated_at: _"
+            FROM canvases
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let canvas_blocks = sqlx::query_as!(
+            CanvasBlockRowDto,
+            r#"
+            SELECT
+                id as "id: _",
+                canvas_id as "canvas_id: _",
+                block_id as "block_id: _",
+                grid_x as "grid_x: _",
+                grid_y as "grid_y: _",
+                grid_width as "grid_width: _",
+                grid_height as "grid_height: _",
+                z as "z: _",
+                scale,
+                content_visible as "content_visible: _",
+                created_at as "created_at: _",
+                updated_at as "updated_at: _"
+            FROM canvas_blocks
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let canvas_pins = sqlx::query_as!(
+            CanvasPinRowDto,
+            r#"
+            SELECT canvas_id as "canvas_id: _", pinned_at as "pinned_at: _"
+            FROM canvas_pins
+            ORDER BY canvas_id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let tags = sqlx::query_as!(
+            TagRowDto,
+            r#"
+            SELECT id as "id: _", name, created_at as "created_at: _"
+            FROM tags
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let block_tags = sqlx::query_as!(
+            BlockTagRowDto,
+            r#"
+            SELECT
+                block_id as "block_id: _",
+                tag_id as "tag_id: _",
+                tagged_at as "tagged_at: _"
+            FROM block_tags
+            ORDER BY block_id, tag_id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(InstanceSnapshotDto {
+            blocks,
+            block_revisions,
+            directional_links,
+            related_links,
+            block_pins,
+            block_opens,
+            canvases,
+            canvas_blocks,
+            canvas_pins,
+            tags,
+            block_tags,
+        })
+    }
+
+    async fn write_snapshot<'e, E>(&self, snapshot: &InstanceSnapshotDto, executor: E) -> Result<()>
+    where
+        E: Executor<'e, Database = Sqlite> + Acquire<'e, Database = Sqlite>,
+    {
+        let mut conn = executor.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        if !self.count_rows(&mut *tx).await?.is_empty() {
+            return Err(SnapshotRepositoryError::TargetNotEmpty);
+        }
+
+        for block in &snapshot.blocks {
+            sqlx::query!(
+                r#"
+                INSERT INTO blocks
+                    (id, title, content, created_at, updated_at, trashed_at, version)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#,
+                block.id,
+                block.title,
+                block.content,
+                block.created_at,
+                block.updated_at,
+                block.trashed_at,
+                block.version,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        // The insert trigger recorded a first revision per block; the
+        // snapshot carries the real history instead
+        sqlx::query!("DELETE FROM block_revisions")
+            .execute(&mut *tx)
+            .await?;
+
+        for revision in &snapshot.block_revisions {
+            sqlx::query!(
+                r#"
+                INSERT INTO block_revisions (block_id, revision, title, content, created_at)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+                revision.block_id,
+                revision.revision,
+                revision.title,
+                revision.content,
+                revision.created_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for tag in &snapshot.tags {
+            sqlx::query!(
+                "INSERT INTO tags (id, name, created_at) VALUES ($1, $2, $3)",
+                tag.id,
+                tag.name,
+                tag.created_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for block_tag in &snapshot.block_tags {
+            sqlx::query!(
+                "INSERT INTO block_tags (block_id, tag_id, tagged_at) VALUES ($1, $2, $3)",
+                block_tag.block_id,
+                block_tag.tag_id,
+                block_tag.tagged_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for link in &snapshot.directional_links {
+            sqlx::query!(
+                r#"
+                INSERT INTO block_directional_links
+                    (id, block_from_id, block_to_id, kind, label, weight, position, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+                link.id,
+                link.block_from_id,
+                link.block_to_id,
+                link.kind,
+                link.label,
+                link.weight,
+                link.position,
+                link.created_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for link in &snapshot.related_links {
+            sqlx::query!(
+                r#"
+                INSERT INTO block_related_links
+                    (id, block_a_id, block_b_id, label, weight, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                "#,
+                link.id,
+                link.block_a_id,
+                link.block_b_id,
+                link.label,
+                link.weight,
+                link.created_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for pin in &snapshot.block_pins {
+            sqlx::query!(
+                "INSERT INTO block_pins (block_id, pinned_at) VALUES ($1, $2)",
+                pin.block_id,
+                pin.pinned_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for open in &snapshot.block_opens {
+            sqlx::query!(
+                "INSERT INTO block_opens (block_id, opened_at, tab_index) VALUES ($1, $2, $3)",
+                open.block_id,
+                open.opened_at,
+                open.tab_index,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for canvas in &snapshot.canvases {
+            sqlx::query!(
+                r#"
+                INSERT INTO canvases
+                    (id, name, viewport_x, viewport_y, zoom_level, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#,
+                canvas.id,
+                canvas.name,
+                canvas.viewport_x,
+                canvas.viewport_y,
+                canvas.zoom_level,
+                canvas.created_at,
+                canvas.updated_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for canvas_block in &snapshot.canvas_blocks {
+            sqlx::query!(
+                r#"
+                INSERT INTO canvas_blocks
+                    (id, canvas_id, block_id, grid_x, grid_y, grid_width, grid_height,
+                     z, scale, content_visible, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                "#,
+                canvas_block.id,
+                canvas_block.canvas_id,
+                canvas_block.block_id,
+                canvas_block.grid_x,
+                canvas_block.grid_y,
+                canvas_block.grid_width,
+                canvas_block.grid_height,
+                canvas_block.z,
+                canvas_block.scale,
+                canvas_block.content_visible,
+                canvas_block.created_at,
+                canvas_block.updated_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for pin in &snapshot.canvas_pins {
+            sqlx::query!(
+                "INSERT INTO canvas_pins (canvas_id, pinned_at) VALUES ($1, $2)",
+                pin.canvas_id,
+                pin.pinned_at,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
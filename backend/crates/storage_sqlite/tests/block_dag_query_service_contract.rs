@@ -0,0 +1,80 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::sqlite_db;
+use storage::database::Database;
+use storage::query_services::block_dag_query_service::BlockDagQueryServiceResult;
+use storage::query_services::block_dag_query_service::test_utils::{
+    assert_closure_follows_link_changes, assert_get_ancestors, assert_get_descendants,
+    assert_get_paths_between, assert_get_roots_and_leaves,
+};
+use storage_sqlite::SqliteDb;
+use storage_sqlite::query_services::SqliteBlockDagQueryService;
+use storage_sqlite::repositories::{SqliteBlockDirectionalLinkRepository, SqliteBlockRepository};
+
+#[rstest]
+#[tokio::test]
+async fn block_dag_query_service_get_descendants(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDagQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockDagQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let directional_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    assert_get_descendants(&query_service, &block_repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_dag_query_service_get_ancestors(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDagQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockDagQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let directional_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    assert_get_ancestors(&query_service, &block_repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_dag_query_service_get_roots_and_leaves(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDagQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockDagQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let directional_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    assert_get_roots_and_leaves(&query_service, &block_repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_dag_query_service_get_paths_between(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDagQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockDagQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let directional_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    assert_get_paths_between(&query_service, &block_repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_dag_query_service_closure_follows_link_changes(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDagQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockDagQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let directional_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    assert_closure_follows_link_changes(&query_service, &block_repo, &directional_repo, db.pool())
+        .await
+}
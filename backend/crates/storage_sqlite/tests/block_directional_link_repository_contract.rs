@@ -79,3 +79,51 @@ async fn block_directional_link_duplicate(
 
     test_utils::assert_duplicate(&link_repo, &block_repo, db.pool()).await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_link_sibling_positions(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDirectionalLinkRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let block_repo = SqliteBlockRepository::new();
+    let link_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_sibling_positions(&link_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_link_reorder_children(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDirectionalLinkRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let block_repo = SqliteBlockRepository::new();
+    let link_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_reorder_children(&link_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_link_move_link(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDirectionalLinkRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let block_repo = SqliteBlockRepository::new();
+    let link_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_move_link(&link_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_link_create_at(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDirectionalLinkRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let block_repo = SqliteBlockRepository::new();
+    let link_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_create_at(&link_repo, &block_repo, db.pool()).await
+}
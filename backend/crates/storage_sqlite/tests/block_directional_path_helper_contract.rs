@@ -9,7 +9,7 @@ use storage::helpers::block_directional_path_helper::{
 };
 use storage_sqlite::SqliteDb;
 use storage_sqlite::helpers::SqliteBlockDirectionalPathHelper;
-use storage_sqlite::repositories::SqliteBlockRepository;
+use storage_sqlite::repositories::{SqliteBlockDirectionalLinkRepository, SqliteBlockRepository};
 
 #[rstest]
 #[tokio::test]
@@ -46,3 +46,44 @@ async fn block_directional_path_delete_by_block(
 
     test_utils::assert_delete_paths_using_block(&helper, &block_repo, db.pool()).await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_path_check_integrity_and_rebuild(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDirectionalPathHelperResult<()> {
+    let db = sqlite_db.await;
+    let helper = SqliteBlockDirectionalPathHelper::new();
+    let block_repo = SqliteBlockRepository::new();
+    let link_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_check_integrity_and_rebuild(&helper, &block_repo, &link_repo, db.pool())
+        .await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_path_check_integrity_reports_cycles(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDirectionalPathHelperResult<()> {
+    let db = sqlite_db.await;
+    let helper = SqliteBlockDirectionalPathHelper::new();
+    let block_repo = SqliteBlockRepository::new();
+    let link_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_check_integrity_reports_cycles(&helper, &block_repo, &link_repo, db.pool())
+        .await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_directional_path_counts_saturate(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockDirectionalPathHelperResult<()> {
+    let db = sqlite_db.await;
+    let helper = SqliteBlockDirectionalPathHelper::new();
+    let block_repo = SqliteBlockRepository::new();
+    let link_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    test_utils::assert_path_counts_saturate(&helper, &block_repo, &link_repo, db.pool()).await
+}
@@ -8,12 +8,13 @@ use storage::query_services::block_link_query_service::BlockLinkQueryServiceResu
 use storage::query_services::block_link_query_service::test_utils::{
     assert_get_all_directional, assert_get_all_related, assert_get_child_blocks,
     assert_get_linked_blocks, assert_get_parent_blocks, assert_get_related_blocks,
+    assert_linked_blocks_skip_trashed,
 };
+use storage_sqlite::SqliteDb;
 use storage_sqlite::query_services::SqliteBlockLinkQueryService;
 use storage_sqlite::repositories::{
     SqliteBlockDirectionalLinkRepository, SqliteBlockRelatedLinkRepository, SqliteBlockRepository,
 };
-use storage_sqlite::SqliteDb;
 
 #[rstest]
 #[tokio::test]
@@ -140,3 +141,24 @@ async fn block_link_query_service_get_all_related(
     )
     .await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_link_query_service_linked_blocks_skip_trashed(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockLinkQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockLinkQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let directional_repo = SqliteBlockDirectionalLinkRepository::new();
+    let related_repo = SqliteBlockRelatedLinkRepository::new();
+
+    assert_linked_blocks_skip_trashed(
+        &query_service,
+        &block_repo,
+        &directional_repo,
+        &related_repo,
+        db.pool(),
+    )
+    .await
+}
@@ -0,0 +1,44 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::sqlite_db;
+use storage::database::Database;
+use storage::repositories::block_pin_repository::BlockPinRepositoryResult;
+use storage::repositories::block_pin_repository::test_utils::{
+    assert_pin_at, assert_pin_unpin, assert_unpin_missing,
+};
+use storage_sqlite::SqliteDb;
+use storage_sqlite::repositories::{SqliteBlockPinRepository, SqliteBlockRepository};
+
+#[rstest]
+#[tokio::test]
+async fn block_pin_repository_pin_unpin(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockPinRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteBlockPinRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+    assert_pin_unpin(&repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_pin_repository_unpin_missing(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockPinRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteBlockPinRepository::new();
+    assert_unpin_missing(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_pin_repository_pin_at(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockPinRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteBlockPinRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+    assert_pin_at(&repo, &block_repo, db.pool()).await
+}
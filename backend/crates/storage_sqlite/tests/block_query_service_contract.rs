@@ -2,15 +2,25 @@ mod fixtures;
 
 use rstest::rstest;
 
+use domain::blocks::Block;
 use fixtures::sqlite_db;
 use storage::database::Database;
-use storage::query_services::block_query_service::BlockQueryServiceResult;
+use storage::query_services::BlockQueryService;
 use storage::query_services::block_query_service::test_utils::{
     assert_get_all_returns_all_blocks, assert_get_opened_orders_by_tab_index,
-    assert_search_matches_title_or_content,
+    assert_search_applies_filters, assert_search_blank_query_returns_recent,
+    assert_search_escapes_snippet,
+    assert_search_filters_by_tag, assert_search_paginates, assert_search_ranks_title_above_content,
+    assert_search_reports_pinned, assert_stream_all_matches_get_all,
+    assert_trashed_blocks_are_hidden,
 };
+use storage::query_services::block_query_service::{BlockQueryServiceResult, BlockSearchDto};
+use storage::repositories::BlockRepository;
 use storage_sqlite::query_services::SqliteBlockQueryService;
-use storage_sqlite::repositories::{SqliteBlockRepository, SqliteWorkspaceRepository};
+use storage_sqlite::repositories::{
+    SqliteBlockDirectionalLinkRepository, SqliteBlockPinRepository, SqliteBlockRepository,
+    SqliteTagRepository, SqliteWorkspaceRepository,
+};
 use storage_sqlite::SqliteDb;
 
 #[rstest]
@@ -34,14 +44,14 @@ async fn block_query_service_get_opened_orders_by_tab_index(
 
 #[rstest]
 #[tokio::test]
-async fn block_query_service_search_matches_title_or_content(
+async fn block_query_service_search_ranks_title_above_content(
     #[future] sqlite_db: SqliteDb,
 ) -> BlockQueryServiceResult<()> {
     let db = sqlite_db.await;
     let query_service = SqliteBlockQueryService::new();
     let block_repo = SqliteBlockRepository::new();
 
-    assert_search_matches_title_or_content(&query_service, &block_repo, db.pool()).await
+    assert_search_ranks_title_above_content(&query_service, &block_repo, db.pool()).await
 }
 
 #[rstest]
@@ -55,3 +65,180 @@ async fn block_query_service_get_all_returns_all_blocks(
 
     assert_get_all_returns_all_blocks(&query_service, &block_repo, db.pool()).await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_stream_all_matches_get_all(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    assert_stream_all_matches_get_all(&query_service, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_escapes_snippet(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    assert_search_escapes_snippet(&query_service, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_reports_pinned(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let pin_repo = SqliteBlockPinRepository::new();
+
+    assert_search_reports_pinned(&query_service, &block_repo, &pin_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_filters_by_tag(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let tag_repo = SqliteTagRepository::new();
+
+    assert_search_filters_by_tag(&query_service, &block_repo, &tag_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_blank_query_returns_recent(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    assert_search_blank_query_returns_recent(&query_service, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_applies_filters(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let directional_repo = SqliteBlockDirectionalLinkRepository::new();
+    let pin_repo = SqliteBlockPinRepository::new();
+    let workspace_repo = SqliteWorkspaceRepository::new();
+
+    assert_search_applies_filters(
+        &query_service,
+        &block_repo,
+        &directional_repo,
+        &pin_repo,
+        &workspace_repo,
+        db.pool(),
+    )
+    .await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_paginates(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    assert_search_paginates(&query_service, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_query_service_trashed_blocks_are_hidden(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let workspace_repo = SqliteWorkspaceRepository::new();
+
+    assert_trashed_blocks_are_hidden(&query_service, &block_repo, &workspace_repo, db.pool()).await
+}
+
+// VACUUM and table rebuilds may renumber the rowids of blocks, which has a
+// BLOB primary key, so the search index must not depend on them
+#[rstest]
+#[tokio::test]
+async fn block_query_service_search_survives_renumbered_rowids(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+    let mut tx = db.pool().begin().await?;
+
+    let first = Block::new("rowidneedle first", "");
+    let second = Block::new("rowidneedle second", "");
+    for block in [&first, &second] {
+        block_repo
+            .save(block, &mut *tx)
+            .await
+            .expect("failed to save block");
+    }
+
+    // Move both blocks to new rowids, as a renumbering could
+    sqlx::query("UPDATE blocks SET rowid = rowid + 1000000 WHERE id IN ($1, $2)")
+        .bind(first.id)
+        .bind(second.id)
+        .execute(&mut *tx)
+        .await?;
+
+    let results = query_service
+        .search(&BlockSearchDto::new("first"), &mut *tx)
+        .await?;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, first.id);
+    assert_eq!(results[0].title, first.title);
+
+    // Updates and deletes still reach the right index rows
+    let renamed = Block {
+        title: "rowidneedle renamed".to_string(),
+        ..second.clone()
+    };
+    block_repo
+        .save(&renamed, &mut *tx)
+        .await
+        .expect("failed to save block");
+    block_repo
+        .delete_by_id(first.id, &mut *tx)
+        .await
+        .expect("failed to delete block");
+
+    let results = query_service
+        .search(&BlockSearchDto::new("rowidneedle"), &mut *tx)
+        .await?;
+    let ids: Vec<_> = results.iter().map(|r| r.id).collect();
+    assert_eq!(ids, [second.id]);
+    assert_eq!(results[0].title, "rowidneedle renamed");
+    assert!(
+        query_service
+            .search(&BlockSearchDto::new("second"), &mut *tx)
+            .await?
+            .is_empty()
+    );
+
+    tx.rollback().await?;
+
+    Ok(())
+}
@@ -77,3 +77,15 @@ async fn block_related_link_duplicate(
 
     test_utils::assert_duplicate(&link_repo, &block_repo, db.pool()).await
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_related_link_create_at(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockRelatedLinkResult<()> {
+    let db = sqlite_db.await;
+    let block_repo = SqliteBlockRepository::new();
+    let link_repo = SqliteBlockRelatedLinkRepository::new();
+
+    test_utils::assert_create_at(&link_repo, &block_repo, db.pool()).await
+}
@@ -6,10 +6,15 @@ use fixtures::sqlite_db;
 use storage::database::Database;
 use storage::repositories::block_repository::BlockRepostoryResult;
 use storage::repositories::block_repository::test_utils::{
-    assert_delete_missing, assert_get_delete_save,
+    assert_delete_missing, assert_get_delete_save, assert_purge, assert_purge_closes_position_gaps,
+    assert_restore_rejects_cycle, assert_save_with_timestamps,
+    assert_trash_keeps_links_and_restore_rebuilds_paths, assert_update_if_version,
 };
 use storage_sqlite::SqliteDb;
-use storage_sqlite::repositories::SqliteBlockRepository;
+use storage_sqlite::helpers::SqliteBlockDirectionalPathHelper;
+use storage_sqlite::repositories::{
+    SqliteBlockDirectionalLinkRepository, SqliteBlockRelatedLinkRepository, SqliteBlockRepository,
+};
 
 #[rstest]
 #[tokio::test]
@@ -33,3 +38,79 @@ async fn block_repository_delete_missing(
     let _ = tx.rollback().await;
     result
 }
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_trash_keeps_links_and_restore_rebuilds_paths(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockRepostoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteBlockRepository::new();
+    let directional_repo = SqliteBlockDirectionalLinkRepository::new();
+    let related_repo = SqliteBlockRelatedLinkRepository::new();
+    let path_helper = SqliteBlockDirectionalPathHelper::new();
+
+    assert_trash_keeps_links_and_restore_rebuilds_paths(
+        &repo,
+        &directional_repo,
+        &related_repo,
+        &path_helper,
+        db.pool(),
+    )
+    .await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_restore_rejects_cycle(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockRepostoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteBlockRepository::new();
+    let directional_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    assert_restore_rejects_cycle(&repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_purge(#[future] sqlite_db: SqliteDb) -> BlockRepostoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteBlockRepository::new();
+
+    assert_purge(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_purge_closes_position_gaps(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockRepostoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteBlockRepository::new();
+    let directional_repo = SqliteBlockDirectionalLinkRepository::new();
+
+    assert_purge_closes_position_gaps(&repo, &directional_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_update_if_version(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockRepostoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteBlockRepository::new();
+
+    assert_update_if_version(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_repository_save_with_timestamps(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockRepostoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteBlockRepository::new();
+
+    assert_save_with_timestamps(&repo, db.pool()).await
+}
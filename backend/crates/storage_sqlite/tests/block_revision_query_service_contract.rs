@@ -0,0 +1,37 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::sqlite_db;
+use storage::database::Database;
+use storage::query_services::block_revision_query_service::BlockRevisionQueryServiceResult;
+use storage::query_services::block_revision_query_service::test_utils::{
+    assert_delete_removes_revisions, assert_save_records_revisions,
+};
+use storage_sqlite::SqliteDb;
+use storage_sqlite::query_services::SqliteBlockRevisionQueryService;
+use storage_sqlite::repositories::SqliteBlockRepository;
+
+#[rstest]
+#[tokio::test]
+async fn block_revision_query_service_save_records_revisions(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockRevisionQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockRevisionQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    assert_save_records_revisions(&query_service, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn block_revision_query_service_delete_removes_revisions(
+    #[future] sqlite_db: SqliteDb,
+) -> BlockRevisionQueryServiceResult<()> {
+    let db = sqlite_db.await;
+    let query_service = SqliteBlockRevisionQueryService::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    assert_delete_removes_revisions(&query_service, &block_repo, db.pool()).await
+}
@@ -0,0 +1,77 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::sqlite_db;
+use storage::database::Database;
+use storage::repositories::canvas_block_repository::CanvasBlockRepositoryResult;
+use storage::repositories::canvas_block_repository::test_utils::{
+    assert_delete_missing, assert_get_by_canvas_orders_by_z, assert_get_delete_save,
+    assert_save_many, assert_trashed_blocks_are_hidden,
+};
+use storage_sqlite::SqliteDb;
+use storage_sqlite::repositories::{
+    SqliteBlockRepository, SqliteCanvasBlockRepository, SqliteCanvasRepository,
+};
+
+#[rstest]
+#[tokio::test]
+async fn canvas_block_repository_get_delete_save(
+    #[future] sqlite_db: SqliteDb,
+) -> CanvasBlockRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasBlockRepository::new();
+    let canvas_repo = SqliteCanvasRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    assert_get_delete_save(&repo, &canvas_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_block_repository_get_by_canvas_orders_by_z(
+    #[future] sqlite_db: SqliteDb,
+) -> CanvasBlockRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasBlockRepository::new();
+    let canvas_repo = SqliteCanvasRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    assert_get_by_canvas_orders_by_z(&repo, &canvas_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_block_repository_save_many(
+    #[future] sqlite_db: SqliteDb,
+) -> CanvasBlockRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasBlockRepository::new();
+    let canvas_repo = SqliteCanvasRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    assert_save_many(&repo, &canvas_repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_block_repository_delete_missing(
+    #[future] sqlite_db: SqliteDb,
+) -> CanvasBlockRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasBlockRepository::new();
+    assert_delete_missing(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_block_repository_trashed_blocks_are_hidden(
+    #[future] sqlite_db: SqliteDb,
+) -> CanvasBlockRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasBlockRepository::new();
+    let canvas_repo = SqliteCanvasRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    assert_trashed_blocks_are_hidden(&repo, &canvas_repo, &block_repo, db.pool()).await
+}
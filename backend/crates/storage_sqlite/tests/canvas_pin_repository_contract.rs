@@ -0,0 +1,44 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::sqlite_db;
+use storage::database::Database;
+use storage::repositories::canvas_pin_repository::CanvasPinRepositoryResult;
+use storage::repositories::canvas_pin_repository::test_utils::{
+    assert_pin_at, assert_pin_unpin, assert_unpin_missing,
+};
+use storage_sqlite::SqliteDb;
+use storage_sqlite::repositories::{SqliteCanvasPinRepository, SqliteCanvasRepository};
+
+#[rstest]
+#[tokio::test]
+async fn canvas_pin_repository_pin_unpin(
+    #[future] sqlite_db: SqliteDb,
+) -> CanvasPinRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasPinRepository::new();
+    let canvas_repo = SqliteCanvasRepository::new();
+    assert_pin_unpin(&repo, &canvas_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_pin_repository_unpin_missing(
+    #[future] sqlite_db: SqliteDb,
+) -> CanvasPinRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasPinRepository::new();
+    assert_unpin_missing(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_pin_repository_pin_at(
+    #[future] sqlite_db: SqliteDb,
+) -> CanvasPinRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasPinRepository::new();
+    let canvas_repo = SqliteCanvasRepository::new();
+    assert_pin_at(&repo, &canvas_repo, db.pool()).await
+}
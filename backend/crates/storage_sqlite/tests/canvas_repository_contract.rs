@@ -0,0 +1,40 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::sqlite_db;
+use storage::database::Database;
+use storage::repositories::canvas_repository::CanvasRepositoryResult;
+use storage::repositories::canvas_repository::test_utils::{
+    assert_delete_missing, assert_get_all, assert_get_delete_save,
+};
+use storage_sqlite::SqliteDb;
+use storage_sqlite::repositories::SqliteCanvasRepository;
+
+#[rstest]
+#[tokio::test]
+async fn canvas_repository_get_delete_save(
+    #[future] sqlite_db: SqliteDb,
+) -> CanvasRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasRepository::new();
+    assert_get_delete_save(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_repository_get_all(#[future] sqlite_db: SqliteDb) -> CanvasRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasRepository::new();
+    assert_get_all(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn canvas_repository_delete_missing(
+    #[future] sqlite_db: SqliteDb,
+) -> CanvasRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteCanvasRepository::new();
+    assert_delete_missing(&repo, db.pool()).await
+}
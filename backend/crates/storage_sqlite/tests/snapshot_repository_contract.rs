@@ -0,0 +1,81 @@
+mod fixtures;
+
+use rstest::rstest;
+use uuid::Uuid;
+
+use fixtures::sqlite_db;
+use storage::database::Database;
+use storage::repositories::snapshot_repository::{
+    CopyInstanceResult, SnapshotRepositoryResult, test_utils,
+};
+use storage_sqlite::SqliteDb;
+use storage_sqlite::helpers::SqliteBlockDirectionalPathHelper;
+use storage_sqlite::repositories::{SqliteBlockRepository, SqliteSnapshotRepository};
+
+// The copy commits, so it runs between two throwaway databases instead of the
+// shared test database
+async fn fresh_sqlite_db(path: &std::path::Path) -> SqliteDb {
+    let db = SqliteDb::connect(&format!("sqlite://{}?mode=rwc", path.display()))
+        .await
+        .expect("Failed to create scratch database");
+
+    db.run_migration().await.expect("Failed to run migrations");
+
+    db
+}
+
+#[rstest]
+#[tokio::test]
+async fn snapshot_count_rows_empty(#[future] sqlite_db: SqliteDb) -> SnapshotRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteSnapshotRepository::new();
+
+    test_utils::assert_count_rows_empty(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn snapshot_write_read_round_trip(
+    #[future] sqlite_db: SqliteDb,
+) -> SnapshotRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteSnapshotRepository::new();
+
+    test_utils::assert_write_read_round_trip(&repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn snapshot_write_into_non_empty_target(
+    #[future] sqlite_db: SqliteDb,
+) -> SnapshotRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteSnapshotRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+
+    test_utils::assert_write_into_non_empty_target(&repo, &block_repo, db.pool()).await
+}
+
+#[tokio::test]
+async fn snapshot_copy_instance() -> CopyInstanceResult<()> {
+    let dir = std::env::temp_dir();
+    let run = Uuid::new_v4();
+    let source_path = dir.join(format!("modunote-copy-{run}-source.sqlite"));
+    let target_path = dir.join(format!("modunote-copy-{run}-target.sqlite"));
+
+    let source_db = fresh_sqlite_db(&source_path).await;
+    let target_db = fresh_sqlite_db(&target_path).await;
+    let repo = SqliteSnapshotRepository::new();
+    let helper = SqliteBlockDirectionalPathHelper::new();
+
+    let result =
+        test_utils::assert_copy_instance(&repo, source_db.pool(), &repo, &helper, target_db.pool())
+            .await;
+
+    source_db.pool().close().await;
+    target_db.pool().close().await;
+    let _ = std::fs::remove_file(&source_path);
+    let _ = std::fs::remove_file(&target_path);
+
+    result
+}
@@ -0,0 +1,50 @@
+mod fixtures;
+
+use rstest::rstest;
+
+use fixtures::sqlite_db;
+use storage::database::Database;
+use storage::repositories::tag_repository::TagRepositoryResult;
+use storage::repositories::tag_repository::test_utils::{
+    assert_counts_skip_trashed, assert_merge, assert_rename, assert_tag_untag,
+};
+use storage_sqlite::SqliteDb;
+use storage_sqlite::repositories::{SqliteBlockRepository, SqliteTagRepository};
+
+#[rstest]
+#[tokio::test]
+async fn tag_repository_tag_untag(#[future] sqlite_db: SqliteDb) -> TagRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteTagRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+    assert_tag_untag(&repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn tag_repository_counts_skip_trashed(
+    #[future] sqlite_db: SqliteDb,
+) -> TagRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteTagRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+    assert_counts_skip_trashed(&repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn tag_repository_rename(#[future] sqlite_db: SqliteDb) -> TagRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteTagRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+    assert_rename(&repo, &block_repo, db.pool()).await
+}
+
+#[rstest]
+#[tokio::test]
+async fn tag_repository_merge(#[future] sqlite_db: SqliteDb) -> TagRepositoryResult<()> {
+    let db = sqlite_db.await;
+    let repo = SqliteTagRepository::new();
+    let block_repo = SqliteBlockRepository::new();
+    assert_merge(&repo, &block_repo, db.pool()).await
+}